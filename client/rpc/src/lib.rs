@@ -25,8 +25,10 @@ use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::{AtLeast32BitUnsigned, Block as BlockT, Member};
 use up_data_structs::{
-	CollectionId, CollectionLimits, CollectionStats, Property, PropertyKeyPermission,
-	RpcCollection, TokenChild, TokenData, TokenId,
+	CollectionDestruction, CollectionId, CollectionLimits, CollectionStats, DistributionId,
+	DividendDistribution, MarketListing, Property, PropertyWriteGrant, RftBuyout, Royalty,
+	RpcCollection, RpcCollectionAdminRoles, RpcPropertyKeyPermission, RpcTokenProperty, TokenChild,
+	TokenData, TokenId,
 };
 use up_rpc::UniqueApi as UniqueRuntimeApi;
 
@@ -108,6 +110,7 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 	) -> Result<Vec<Property>>;
 
 	/// Get token properties, optionally limited to the provided keys.
+	/// Values inherited from the default token properties of the collection are flagged.
 	#[method(name = "unique_tokenProperties")]
 	fn token_properties(
 		&self,
@@ -115,7 +118,7 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		token_id: TokenId,
 		keys: Option<Vec<String>>,
 		at: Option<BlockHash>,
-	) -> Result<Vec<Property>>;
+	) -> Result<Vec<RpcTokenProperty>>;
 
 	/// Get property permissions, optionally limited to the provided keys.
 	#[method(name = "unique_propertyPermissions")]
@@ -124,7 +127,15 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		collection: CollectionId,
 		keys: Option<Vec<String>>,
 		at: Option<BlockHash>,
-	) -> Result<Vec<PropertyKeyPermission>>;
+	) -> Result<Vec<RpcPropertyKeyPermission>>;
+
+	/// Get the list of accounts granted to write token properties, together with the property keys.
+	#[method(name = "unique_propertyWriteGrants")]
+	fn property_write_grants(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<PropertyWriteGrant<CrossAccountId>>>;
 
 	/// Get token data, including properties, optionally limited to the provided keys, and total pieces for an RFT.
 	#[method(name = "unique_tokenData")]
@@ -178,6 +189,14 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		at: Option<BlockHash>,
 	) -> Result<Vec<CrossAccountId>>;
 
+	/// Get the list of admin accounts of a collection together with their roles.
+	#[method(name = "unique_adminlistWithRoles")]
+	fn adminlist_with_roles(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(CrossAccountId, RpcCollectionAdminRoles)>>;
+
 	/// Get the list of accounts allowed to operate within a collection.
 	#[method(name = "unique_allowlist")]
 	fn allowlist(
@@ -247,6 +266,173 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		operator: CrossAccountId,
 		at: Option<BlockHash>,
 	) -> Result<bool>;
+
+	/// Get the EIP-2981 royalty of a token, falling back to the collection royalty.
+	/// If the token is not specified, get the royalty of the collection.
+	#[method(name = "unique_royalty")]
+	fn royalty(
+		&self,
+		collection: CollectionId,
+		token: Option<TokenId>,
+		at: Option<BlockHash>,
+	) -> Result<Option<Royalty<CrossAccountId>>>;
+
+	/// Check if a token is frozen.
+	#[method(name = "unique_tokenFrozen")]
+	fn token_frozen(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+
+	/// Check if an account is frozen within a collection.
+	#[method(name = "unique_accountFrozen")]
+	fn account_frozen(
+		&self,
+		collection: CollectionId,
+		account: CrossAccountId,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+
+	/// Get the block after which the allowance can no longer be used, if it has a deadline.
+	#[method(name = "unique_allowanceDeadline")]
+	fn allowance_deadline(
+		&self,
+		collection: CollectionId,
+		sender: CrossAccountId,
+		spender: CrossAccountId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Option<u64>>;
+
+	/// Get the block after which the operator approval can no longer be used, if it has a deadline.
+	#[method(name = "unique_allowanceForAllDeadline")]
+	fn allowance_for_all_deadline(
+		&self,
+		collection: CollectionId,
+		owner: CrossAccountId,
+		operator: CrossAccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<u64>>;
+
+	/// Get the marketplace listing of the token by the seller.
+	#[method(name = "unique_marketListing")]
+	fn market_listing(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		seller: CrossAccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<MarketListing>>;
+
+	/// Get all marketplace listings of the token.
+	#[method(name = "unique_marketListings")]
+	fn market_listings(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(CrossAccountId, MarketListing)>>;
+
+	/// Get the progress of the collection destruction, if it is being destroyed.
+	#[method(name = "unique_collectionDestructionProgress")]
+	fn collection_destruction_progress(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<Option<CollectionDestruction>>;
+
+	/// Get the URI of a token, resolved the same way as the ERC-721 `tokenURI` method.
+	#[method(name = "unique_tokenUri")]
+	fn token_uri(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Option<String>>;
+
+	/// Check if the token properties are permanently frozen.
+	#[method(name = "unique_tokenPropertiesFrozen")]
+	fn token_properties_frozen(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+
+	/// Check if the collection metadata is permanently frozen.
+	#[method(name = "unique_collectionMetadataFrozen")]
+	fn collection_metadata_frozen(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+
+	/// Get the RFT token linked to the fractionalized NFT.
+	#[method(name = "unique_rftOfNft")]
+	fn rft_of_nft(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Option<(CollectionId, TokenId)>>;
+
+	/// Get the fractionalized NFT linked to the RFT token.
+	#[method(name = "unique_nftOfRft")]
+	fn nft_of_rft(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Option<(CollectionId, TokenId)>>;
+
+	/// Get the buyout of the RFT token.
+	#[method(name = "unique_rftBuyout")]
+	fn rft_buyout(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> Result<Option<RftBuyout<CrossAccountId>>>;
+
+	/// Get the dividend distribution.
+	#[method(name = "unique_dividendDistribution")]
+	fn dividend_distribution(
+		&self,
+		distribution: DistributionId,
+		at: Option<BlockHash>,
+	) -> Result<Option<DividendDistribution<CrossAccountId>>>;
+
+	/// Get the dividends, which the account can claim from the distribution.
+	#[method(name = "unique_claimableDividends")]
+	fn claimable_dividends(
+		&self,
+		distribution: DistributionId,
+		account: CrossAccountId,
+		at: Option<BlockHash>,
+	) -> Result<String>;
+
+	/// Get the distributions, from which the account can claim dividends, with the amounts.
+	#[method(name = "unique_accountClaimableDividends")]
+	fn account_claimable_dividends(
+		&self,
+		account: CrossAccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(DistributionId, String)>>;
+
+	/// Get the supply cap of the fungible collection, if it is set.
+	#[method(name = "unique_supplyCap")]
+	fn supply_cap(&self, collection: CollectionId, at: Option<BlockHash>)
+		-> Result<Option<String>>;
+
+	/// Get the mint authority of the fungible collection, if it is set.
+	#[method(name = "unique_mintAuthority")]
+	fn mint_authority(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<Option<CrossAccountId>>;
 }
 
 mod app_promotion_unique_rpc {
@@ -418,14 +604,23 @@ where
 
 		#[map = string_keys_to_bytes_keys]
 		keys: Option<Vec<String>>
-	) -> Vec<Property>, unique_api);
+	) -> Vec<RpcTokenProperty>, unique_api;
+		changed_in 7, token_properties_before_version_7(collection, token_id, string_keys_to_bytes_keys(keys)) => |value| {
+			Ok(value.into_iter().map(|property| property.into()).collect())
+		}
+	);
 
 	pass_method!(property_permissions(
 		collection: CollectionId,
 
 		#[map = string_keys_to_bytes_keys]
 		keys: Option<Vec<String>>
-	) -> Vec<PropertyKeyPermission>, unique_api);
+	) -> Vec<RpcPropertyKeyPermission>, unique_api;
+		changed_in 6, property_permissions_before_version_6(collection, string_keys_to_bytes_keys(keys)) => |value| {
+			Ok(value.into_iter().map(|permission| permission.into()).collect())
+		}
+	);
+	pass_method!(property_write_grants(collection: CollectionId) -> Vec<PropertyWriteGrant<CrossAccountId>>, unique_api);
 
 	fn token_data(
 		&self,
@@ -438,6 +633,7 @@ where
 	}
 
 	pass_method!(adminlist(collection: CollectionId) -> Vec<CrossAccountId>, unique_api);
+	pass_method!(adminlist_with_roles(collection: CollectionId) -> Vec<(CrossAccountId, RpcCollectionAdminRoles)>, unique_api);
 	pass_method!(allowlist(collection: CollectionId) -> Vec<CrossAccountId>, unique_api);
 	pass_method!(allowed(collection: CollectionId, user: CrossAccountId) -> bool, unique_api);
 	pass_method!(last_token_id(collection: CollectionId) -> TokenId, unique_api);
@@ -452,6 +648,12 @@ where
 			} else {
 				Ok(None)
 			}
+		};
+		changed_in 5, collection_by_id_before_version_5(collection) => |value| {
+			Ok(value.map(|collection| up_data_structs::RpcCollectionVersion3::from(collection).into()))
+		};
+		changed_in 6, collection_by_id_before_version_6(collection) => |value| {
+			Ok(value.map(|collection| collection.into()))
 		}
 	);
 	pass_method!(collection_stats() -> CollectionStats, unique_api);
@@ -460,6 +662,29 @@ where
 	pass_method!(total_pieces(collection_id: CollectionId, token_id: TokenId) -> Option<String> => |o| o.map(|number| number.to_string()) , unique_api);
 	pass_method!(token_owners(collection: CollectionId, token: TokenId) -> Vec<CrossAccountId>, unique_api);
 	pass_method!(allowance_for_all(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> bool, unique_api);
+	pass_method!(royalty(collection: CollectionId, token: Option<TokenId>) -> Option<Royalty<CrossAccountId>>, unique_api);
+	pass_method!(token_frozen(collection: CollectionId, token: TokenId) -> bool, unique_api);
+	pass_method!(account_frozen(collection: CollectionId, account: CrossAccountId) -> bool, unique_api);
+	pass_method!(allowance_deadline(collection: CollectionId, sender: CrossAccountId, spender: CrossAccountId, token: TokenId) -> Option<u64>, unique_api);
+	pass_method!(allowance_for_all_deadline(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> Option<u64>, unique_api);
+	pass_method!(market_listing(collection: CollectionId, token: TokenId, seller: CrossAccountId) -> Option<MarketListing>, unique_api);
+	pass_method!(market_listings(collection: CollectionId, token: TokenId) -> Vec<(CrossAccountId, MarketListing)>, unique_api);
+	pass_method!(collection_destruction_progress(collection: CollectionId) -> Option<CollectionDestruction>, unique_api);
+	pass_method!(token_uri(collection: CollectionId, token: TokenId) -> Option<String>, unique_api);
+	pass_method!(token_properties_frozen(collection: CollectionId, token: TokenId) -> bool, unique_api);
+	pass_method!(collection_metadata_frozen(collection: CollectionId) -> bool, unique_api);
+	pass_method!(rft_of_nft(collection: CollectionId, token: TokenId) -> Option<(CollectionId, TokenId)>, unique_api);
+	pass_method!(nft_of_rft(collection: CollectionId, token: TokenId) -> Option<(CollectionId, TokenId)>, unique_api);
+	pass_method!(rft_buyout(collection: CollectionId, token: TokenId) -> Option<RftBuyout<CrossAccountId>>, unique_api);
+	pass_method!(dividend_distribution(distribution: DistributionId) -> Option<DividendDistribution<CrossAccountId>>, unique_api);
+	pass_method!(claimable_dividends(distribution: DistributionId, account: CrossAccountId) -> String => |v| v.to_string(), unique_api);
+	pass_method!(account_claimable_dividends(account: CrossAccountId) -> Vec<(DistributionId, String)> =>
+		|v| v
+		.into_iter()
+		.map(|(d, a)| (d, a.to_string()))
+		.collect::<Vec<_>>(), unique_api);
+	pass_method!(supply_cap(collection: CollectionId) -> Option<String> => |o| o.map(|cap| cap.to_string()), unique_api);
+	pass_method!(mint_authority(collection: CollectionId) -> Option<CrossAccountId>, unique_api);
 }
 
 impl<C, Block, BlockNumber, CrossAccountId, AccountId>
@@ -514,8 +739,26 @@ where
 	} else {
 		return Err(ErrorCode::MethodNotFound.into());
 	};
-	let result = if api_version >= 3 {
+	let result = if api_version >= 7 {
 		api.token_data(at, collection, token_id, string_keys_to_bytes_keys(keys))
+	} else if api_version >= 4 {
+		#[allow(deprecated)]
+		api.token_data_before_version_7(at, collection, token_id, string_keys_to_bytes_keys(keys))
+			.map(
+				|r: sc_service::Result<
+					up_data_structs::TokenDataVersion3<CrossAccountId>,
+					sp_runtime::DispatchError,
+				>| r.map(|value| value.into()),
+			)
+	} else if api_version >= 3 {
+		#[allow(deprecated)]
+		api.token_data_before_version_4(at, collection, token_id, string_keys_to_bytes_keys(keys))
+			.map(
+				|r: sc_service::Result<
+					up_data_structs::TokenDataVersion2<CrossAccountId>,
+					sp_runtime::DispatchError,
+				>| r.map(|value| up_data_structs::TokenDataVersion3::from(value).into()),
+			)
 	} else {
 		#[allow(deprecated)]
 		api.token_data_before_version_3(at, collection, token_id, string_keys_to_bytes_keys(keys))
@@ -523,7 +766,14 @@ where
 				|r: sc_service::Result<
 					up_data_structs::TokenDataVersion1<CrossAccountId>,
 					sp_runtime::DispatchError,
-				>| r.map(|value| value.into()),
+				>| {
+					r.map(|value| {
+						up_data_structs::TokenDataVersion3::from(
+							up_data_structs::TokenDataVersion2::from(value),
+						)
+						.into()
+					})
+				},
 			)
 			.or_else(|_| {
 				Ok(api
@@ -532,6 +782,7 @@ where
 						properties: Vec::new(),
 						owner,
 						pieces: 0,
+						user: None,
 					}))
 			})
 	};
@@ -554,13 +805,23 @@ fn decode_collection_from_bytes<T: parity_scale_codec::Decode>(
 fn detect_type_and_decode_collection<AccountId: Decode>(
 	bytes: &[u8],
 ) -> core::result::Result<RpcCollection<AccountId>, parity_scale_codec::Error> {
-	use up_data_structs::{CollectionVersion1, RpcCollectionVersion1};
+	use up_data_structs::{
+		CollectionVersion1, RpcCollectionVersion1, RpcCollectionVersion2, RpcCollectionVersion3,
+	};
 
 	decode_collection_from_bytes::<RpcCollection<AccountId>>(bytes)
 		.or_else(|_| {
-			decode_collection_from_bytes::<RpcCollectionVersion1<AccountId>>(bytes)
+			decode_collection_from_bytes::<RpcCollectionVersion3<AccountId>>(bytes)
 				.map(|col| col.into())
 		})
+		.or_else(|_| {
+			decode_collection_from_bytes::<RpcCollectionVersion2<AccountId>>(bytes)
+				.map(|col| RpcCollectionVersion3::from(col).into())
+		})
+		.or_else(|_| {
+			decode_collection_from_bytes::<RpcCollectionVersion1<AccountId>>(bytes)
+				.map(|col| RpcCollectionVersion3::from(RpcCollectionVersion2::from(col)).into())
+		})
 		.or_else(|_| {
 			decode_collection_from_bytes::<CollectionVersion1<AccountId>>(bytes)
 				.map(|col| col.into())
@@ -593,7 +854,7 @@ mod tests {
 
 	#[test]
 	fn decoding_rpc_collection_v2() {
-		decode_collection_from_bytes::<RpcCollection<[u8; 32]>>(
+		decode_collection_from_bytes::<up_data_structs::RpcCollectionVersion2<[u8; 32]>>(
 			ENCODED_RPC_COLLECTION_V2.as_slice(),
 		)
 		.unwrap();
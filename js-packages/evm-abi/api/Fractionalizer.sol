@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: OTHER
+// This code is automatically generated
+
+pragma solidity >=0.8.0 <0.9.0;
+
+/// @dev common stubs holder
+interface Dummy {
+}
+interface ERC165 is Dummy {
+	function supportsInterface(bytes4 interfaceID) external view returns (bool);
+}
+
+/// @title Vault splitting NFTs into refungible tokens
+/// @dev the ERC-165 identifier for this interface is 0x7963c765
+interface Fractionalizer is Dummy, ERC165 {
+	/// @notice Lock the NFT in the vault and mint the linked RFT token in the pieces collection.
+	/// @dev All the pieces of the minted token go to the caller.
+	/// @param nftCollection Address of the NFT collection.
+	/// @param nftTokenId ID of the fractionalized NFT.
+	/// @param pieces Number of the pieces of the minted RFT token.
+	/// @return Minted RFT token.
+	/// @dev EVM selector for this function is: 0xcfca483b,
+	///  or in textual repr: fractionalize(address,uint256,uint256)
+	function fractionalize(address nftCollection, uint256 nftTokenId, uint256 pieces) external returns (Token memory);
+	/// @notice Burn all the pieces of the RFT token and get the linked NFT back.
+	/// @param rftCollection Address of the RFT collection.
+	/// @param rftTokenId ID of the RFT token linked to the NFT.
+	/// @dev EVM selector for this function is: 0x1e9a6950,
+	///  or in textual repr: redeem(address,uint256)
+	function redeem(address rftCollection, uint256 rftTokenId) external;
+	/// @notice Get the RFT collection owned by the vault, in which the pieces are minted.
+	/// @return Address of the pieces collection, or zero address if no NFT was fractionalized yet.
+	/// @dev EVM selector for this function is: 0x72c16f40,
+	///  or in textual repr: piecesCollection()
+	function piecesCollection() external view returns (address);
+	/// @notice Get the RFT token linked to the fractionalized NFT.
+	/// @param collection Address of the NFT collection.
+	/// @param tokenId ID of the NFT.
+	/// @return Linked RFT token.
+	/// @dev EVM selector for this function is: 0xdcce2e02,
+	///  or in textual repr: rftOfNft(address,uint256)
+	function rftOfNft(address collection, uint256 tokenId) external view returns (Token memory);
+	/// @notice Get the fractionalized NFT linked to the RFT token.
+	/// @param collection Address of the RFT collection.
+	/// @param tokenId ID of the RFT token.
+	/// @return Linked NFT.
+	/// @dev EVM selector for this function is: 0x063ca74c,
+	///  or in textual repr: nftOfRft(address,uint256)
+	function nftOfRft(address collection, uint256 tokenId) external view returns (Token memory);
+}
+
+/// Token of a Unique collection.
+struct Token {
+/// Address of the collection.
+	address collection;
+/// ID of the token.
+	uint256 tokenId;
+}
@@ -10,9 +10,14 @@ interface ERC165 is Dummy {
 	function supportsInterface(bytes4 interfaceID) external view returns (bool);
 }
 
+/// @dev inlined interface
+interface CollectionMetadataEvents {
+	event PermanentURI(string value, uint256 indexed id);
+}
+
 /// @title A contract that allows you to work with collections.
-/// @dev the ERC-165 identifier for this interface is 0xb34d97e9
-interface Collection is Dummy, ERC165 {
+/// @dev the ERC-165 identifier for this interface is 0x68d9f991
+interface Collection is Dummy, ERC165, CollectionMetadataEvents {
 	// /// Set collection property.
 	// ///
 	// /// @param key Property key.
@@ -256,6 +261,109 @@ interface Collection is Dummy, ERC165 {
 	/// @dev EVM selector for this function is: 0x6496c497,
 	///  or in textual repr: changeCollectionOwnerCross((address,uint256))
 	function changeCollectionOwnerCross(CrossAddress memory newOwner) external;
+	/// Get the royalty of the collection.
+	///
+	/// @return Royalty receiver and amount in basis points. If the collection has no royalty, zeroed royalty is returned.
+	/// @dev EVM selector for this function is: 0x24021258,
+	///  or in textual repr: collectionRoyalty()
+	function collectionRoyalty() external view returns (Royalty memory);
+	/// Set the royalty of the collection, used by EIP-2981 `royaltyInfo` for tokens without their own royalty.
+	///
+	/// @param royalty Royalty receiver and amount in basis points, 10000 is 100%.
+	/// @dev EVM selector for this function is: 0x22fd4464,
+	///  or in textual repr: setCollectionRoyalty(((address,uint256),uint32))
+	function setCollectionRoyalty(Royalty memory royalty) external;
+	/// Remove the royalty of the collection.
+	/// @dev EVM selector for this function is: 0xd69f3b42,
+	///  or in textual repr: deleteCollectionRoyalty()
+	function deleteCollectionRoyalty() external;
+	/// Set the royalty of the token, overriding the royalty of the collection.
+	///
+	/// @param tokenId Id of the token.
+	/// @param royalty Royalty receiver and amount in basis points, 10000 is 100%.
+	/// @dev EVM selector for this function is: 0x0060e084,
+	///  or in textual repr: setTokenRoyalty(uint256,((address,uint256),uint32))
+	function setTokenRoyalty(uint256 tokenId, Royalty memory royalty) external;
+	/// Remove the royalty of the token, so the royalty of the collection applies to it.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xc11d5bb0,
+	///  or in textual repr: deleteTokenRoyalty(uint256)
+	function deleteTokenRoyalty(uint256 tokenId) external;
+	/// Check whether the token is frozen.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xcc888bab,
+	///  or in textual repr: isTokenFrozen(uint256)
+	function isTokenFrozen(uint256 tokenId) external view returns (bool);
+	/// Freeze the token, blocking its transfers, approvals and nesting under it.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xb6854f96,
+	///  or in textual repr: freezeToken(uint256)
+	function freezeToken(uint256 tokenId) external;
+	/// Thaw the frozen token.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xe1ecafd4,
+	///  or in textual repr: thawToken(uint256)
+	function thawToken(uint256 tokenId) external;
+	/// Check whether the account is frozen in the collection.
+	///
+	/// @param account Account cross address to check.
+	/// @dev EVM selector for this function is: 0xd9934ff4,
+	///  or in textual repr: isAccountFrozenCross((address,uint256))
+	function isAccountFrozenCross(CrossAddress memory account) external view returns (bool);
+	/// Freeze the account in the collection, blocking transfers and approvals of its tokens.
+	///
+	/// @param account Account cross address to freeze.
+	/// @dev EVM selector for this function is: 0xe971d778,
+	///  or in textual repr: freezeAccountCross((address,uint256))
+	function freezeAccountCross(CrossAddress memory account) external;
+	/// Thaw the frozen account in the collection.
+	///
+	/// @param account Account cross address to thaw.
+	/// @dev EVM selector for this function is: 0x649d5be9,
+	///  or in textual repr: thawAccountCross((address,uint256))
+	function thawAccountCross(CrossAddress memory account) external;
+	/// Propose a new owner of the collection.
+	///
+	/// @dev Ownership is transferred only after the proposed owner accepts it with `acceptCollectionOwnership`.
+	/// @param newOwner Cross account address of the proposed owner.
+	/// @dev EVM selector for this function is: 0x59378b0d,
+	///  or in textual repr: proposeCollectionOwnerCross((address,uint256))
+	function proposeCollectionOwnerCross(CrossAddress memory newOwner) external;
+	/// Accept the proposed ownership of the collection.
+	///
+	/// @dev The caller must be proposed as the owner with `proposeCollectionOwnerCross`.
+	/// @dev EVM selector for this function is: 0xb48773ac,
+	///  or in textual repr: acceptCollectionOwnership()
+	function acceptCollectionOwnership() external;
+	/// Cancel the proposed transfer of the collection ownership.
+	/// @dev EVM selector for this function is: 0x01b866cc,
+	///  or in textual repr: cancelCollectionOwnershipTransfer()
+	function cancelCollectionOwnershipTransfer() external;
+	/// Get the proposed owner of the collection.
+	///
+	/// @return Cross address of the proposed owner. If there is no pending ownership transfer, zero address is returned.
+	/// @dev EVM selector for this function is: 0xe71220dd,
+	///  or in textual repr: collectionPendingOwner()
+	function collectionPendingOwner() external view returns (CrossAddress memory);
+	/// Set the roles of the collection admin.
+	///
+	/// @dev Admins added without explicitly set roles have all of them.
+	/// @param admin Cross account administrator address.
+	/// @param roles New roles of the administrator.
+	/// @dev EVM selector for this function is: 0xcc3dc830,
+	///  or in textual repr: setCollectionAdminRolesCross((address,uint256),uint8)
+	function setCollectionAdminRolesCross(CrossAddress memory admin, CollectionAdminRoles roles) external;
+	/// Get the roles of the collection admin.
+	///
+	/// @param admin Cross account administrator address.
+	/// @return Roles of the administrator. The owner has all the roles, other accounts have none.
+	/// @dev EVM selector for this function is: 0xc230663e,
+	///  or in textual repr: collectionAdminRolesCross((address,uint256))
+	function collectionAdminRolesCross(CrossAddress memory admin) external view returns (CollectionAdminRoles);
 }
 
 /// Cross account struct
@@ -338,13 +446,43 @@ enum CollectionLimitField {
 	TransferEnabled
 }
 
+/// Ethereum representation of [`up_data_structs::Royalty`].
+struct Royalty {
+/// Account receiving the royalty.
+	CrossAddress receiver;
+/// Royalty amount in basis points of the sale price, 10000 is 100%.
+	uint32 basisPoints;
+}
+
+type CollectionAdminRoles is uint8;
+library CollectionAdminRolesLib {
+/// Can mint tokens
+	CollectionAdminRoles constant minterField = CollectionAdminRoles.wrap(128);
+/// Can change collection and token properties, property permissions and royalties
+	CollectionAdminRoles constant propertyEditorField = CollectionAdminRoles.wrap(64);
+/// Can manage the allowlist and freeze tokens and accounts
+	CollectionAdminRoles constant allowlistManagerField = CollectionAdminRoles.wrap(32);
+/// Can set and remove the collection sponsor
+	CollectionAdminRoles constant sponsorManagerField = CollectionAdminRoles.wrap(16);
+/// Can change collection limits and permissions
+	CollectionAdminRoles constant limitsManagerField = CollectionAdminRoles.wrap(8);
+/// Can transfer, burn and approve tokens of other owners when the collection limits allow it,
+/// and nest tokens when the nesting permissions allow it to admins
+	CollectionAdminRoles constant tokenManagerField = CollectionAdminRoles.wrap(4);
+/// Reserved roles
+	function reservedField(uint8 value) public pure returns (CollectionAdminRoles) {
+		require(value < 1 << 2, "out of bound value");
+		return CollectionAdminRoles.wrap(value << 0);
+	}
+}
+
 /// Ethereum representation of collection [`PropertyKey`](up_data_structs::PropertyKey) and [`PropertyValue`](up_data_structs::PropertyValue).
 struct Property {
 	string key;
 	bytes value;
 }
 
-/// @dev the ERC-165 identifier for this interface is 0x69d14d3e
+/// @dev the ERC-165 identifier for this interface is 0x30afccc7
 interface ERC20UniqueExtensions is Dummy, ERC165 {
 	/// @dev Function to check the amount of tokens that an owner allowed to a spender.
 	/// @param owner crossAddress The address which owns the funds.
@@ -391,6 +529,12 @@ interface ERC20UniqueExtensions is Dummy, ERC165 {
 	/// @dev EVM selector for this function is: 0xd5cf430b,
 	///  or in textual repr: transferFromCross((address,uint256),(address,uint256),uint256)
 	function transferFromCross(CrossAddress memory from, CrossAddress memory to, uint256 amount) external returns (bool);
+	/// Transfer tokens to multiple accounts.
+	/// Either all transfers succeed, or none of them is applied.
+	/// @param transfers array of pairs of recipient cross address and amount
+	/// @dev EVM selector for this function is: 0xedec5954,
+	///  or in textual repr: transferBatch(((address,uint256),uint256)[])
+	function transferBatch(AmountForCrossAddress[] memory transfers) external returns (bool);
 	/// @notice Returns collection helper contract address
 	/// @dev EVM selector for this function is: 0x1896cce6,
 	///  or in textual repr: collectionHelperAddress()
@@ -401,6 +545,18 @@ interface ERC20UniqueExtensions is Dummy, ERC165 {
 	/// @dev EVM selector for this function is: 0xec069398,
 	///  or in textual repr: balanceOfCross((address,uint256))
 	function balanceOfCross(CrossAddress memory owner) external view returns (uint256);
+	/// @notice Set the allowance of `spender` over the tokens of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `PermitCross` struct.
+	///  Ethereum owners sign it with ECDSA (`r`, `s` and `v` concatenated),
+	///  Substrate owners sign it with sr25519.
+	/// @param owner Cross address, which signed the permit.
+	/// @param spender Cross address, which will spend the tokens.
+	/// @param value Amount of tokens the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param signature Signature of the owner.
+	/// @dev EVM selector for this function is: 0xb492d8ad,
+	///  or in textual repr: permitCross((address,uint256),(address,uint256),uint256,uint256,bytes)
+	function permitCross(CrossAddress memory owner, CrossAddress memory spender, uint256 value, uint256 deadline, bytes memory signature) external;
 }
 
 struct AmountForAddress {
@@ -408,7 +564,38 @@ struct AmountForAddress {
 	uint256 amount;
 }
 
-/// @dev the ERC-165 identifier for this interface is 0x40c10f19
+/// Recipient and amount of a single transfer of a batch.
+struct AmountForCrossAddress {
+	CrossAddress to;
+	uint256 amount;
+}
+
+/// @title ERC-20 extension for approvals by signature, see EIP-2612.
+/// @dev the ERC-165 identifier for this interface is 0x9d8ff7da
+interface ERC20Permit is Dummy, ERC165 {
+	/// @notice Set the allowance of `spender` over the tokens of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `Permit` struct.
+	/// @param owner Address, which signed the permit.
+	/// @param spender Address, which will spend the tokens.
+	/// @param value Amount of tokens the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param v Recovery ID of the signature.
+	/// @param r First half of the signature.
+	/// @param s Second half of the signature.
+	/// @dev EVM selector for this function is: 0xd505accf,
+	///  or in textual repr: permit(address,address,uint256,uint256,uint8,bytes32,bytes32)
+	function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+	/// @notice Nonce of the next permit signed by the `owner`.
+	/// @dev EVM selector for this function is: 0x7ecebe00,
+	///  or in textual repr: nonces(address)
+	function nonces(address owner) external view returns (uint256);
+	/// @notice EIP-712 domain separator of the permits.
+	/// @dev EVM selector for this function is: 0x3644e515,
+	///  or in textual repr: DOMAIN_SEPARATOR()
+	function DOMAIN_SEPARATOR() external view returns (bytes32);
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x75937bf3
 interface ERC20Mintable is Dummy, ERC165 {
 	/// Mint tokens for `to` account.
 	/// @param to account that will receive minted tokens
@@ -416,6 +603,11 @@ interface ERC20Mintable is Dummy, ERC165 {
 	/// @dev EVM selector for this function is: 0x40c10f19,
 	///  or in textual repr: mint(address,uint256)
 	function mint(address to, uint256 amount) external returns (bool);
+	/// @notice Maximum total supply of the tokens.
+	/// @dev Returns the maximum `uint128` value if the supply is not capped.
+	/// @dev EVM selector for this function is: 0x355274ea,
+	///  or in textual repr: cap()
+	function cap() external view returns (uint256);
 }
 
 /// @dev inlined interface
@@ -455,6 +647,6 @@ interface ERC20 is Dummy, ERC165, ERC20Events {
 	function allowance(address owner, address spender) external view returns (uint256);
 }
 
-interface UniqueFungible is Dummy, ERC165, ERC20, ERC20Mintable, ERC20UniqueExtensions, Collection {
+interface UniqueFungible is Dummy, ERC165, ERC20, ERC20Mintable, ERC20Permit, ERC20UniqueExtensions, Collection {
 }
 
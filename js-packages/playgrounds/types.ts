@@ -114,11 +114,26 @@ export interface ICollectionPermissions {
   nesting?: INestingPermissions;
 }
 
+export interface ICollectionAdminRoles {
+  minter?: boolean;
+  propertyEditor?: boolean;
+  allowlistManager?: boolean;
+  sponsorManager?: boolean;
+  limitsManager?: boolean;
+  tokenManager?: boolean;
+}
+
 export interface IProperty {
   key: string;
   value?: string;
 }
 
+export interface ITokenProperty extends IProperty {
+  inherited?: boolean;
+}
+
+export type TPropertyValueType = 'U32' | 'U64' | 'U128' | 'Bool' | 'String' | 'Uri' | 'Address' | {Bytes: number} | {Enum: string[]};
+
 export interface ITokenPropertyPermission {
   key: string;
   permission: {
@@ -126,6 +141,7 @@ export interface ITokenPropertyPermission {
     tokenOwner?: boolean;
     collectionAdmin?: boolean;
   }
+  valueType?: TPropertyValueType;
 }
 
 export interface IToken {
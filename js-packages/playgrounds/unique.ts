@@ -18,6 +18,7 @@ import type {
   IBlock,
   IEvent,
   IChainProperties,
+  ICollectionAdminRoles,
   ICollectionCreationOptions,
   ICollectionLimits,
   ICollectionPermissions,
@@ -29,7 +30,9 @@ import type {
   IStakingInfo,
   ISubstrateBalance,
   IToken,
+  ITokenProperty,
   ITokenPropertyPermission,
+  TPropertyValueType,
   ITransactionResult,
   IUniqueHelperLog,
   TApiAllowedListeners,
@@ -300,6 +303,20 @@ class UniqueUtil {
     return isSuccess;
   }
 
+  static normalizePropertyPermissions(permissions: any[]): ITokenPropertyPermission[] {
+    return permissions.map(({valueType, ...permission}) => {
+      if(valueType === null || valueType === undefined) return permission;
+      const [kind, value] = Object.entries(valueType)[0] as [string, any];
+      if(kind === 'Bytes') return {...permission, valueType: {Bytes: Number(value.replace(/,/g, ''))}};
+      if(kind === 'Enum') return {...permission, valueType: {Enum: value}};
+      return {...permission, valueType: kind};
+    });
+  }
+
+  static normalizeTokenProperties(properties: any[]): ITokenProperty[] {
+    return properties.map(({inherited, ...property}) => (inherited ? {...property, inherited} : property));
+  }
+
   static bigIntToDecimals(number: bigint, decimals = 18) {
     const numberStr = number.toString();
     const dotPos = numberStr.length - decimals;
@@ -942,6 +959,7 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     if(humanCollection === null) return null;
     collectionData.raw.limits = jsonCollection.limits;
     collectionData.raw.permissions = jsonCollection.permissions;
+    collectionData.raw.tokenPropertyPermissions = this.helper.util.normalizePropertyPermissions(humanCollection.tokenPropertyPermissions);
     collectionData.normalizedOwner = this.helper.address.normalizeSubstrate(collectionData.raw.owner);
     for(const key of ['name', 'description']) {
       collectionData[key] = this.helper.util.vec2str(humanCollection[key]);
@@ -1115,6 +1133,62 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnerChanged');
   }
 
+  /**
+   * Proposes the new Substrate address as the owner of the collection.
+   * The ownership is transferred only after the proposed owner accepts it.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param ownerAddress substrate address of the proposed owner
+   * @example proposeOwner(aliceKeyring, 10, "5DyN4Y92vZCjv38fg...")
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async proposeOwner(signer: TSigner, collectionId: number, ownerAddress: TSubstrateAccount): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.proposeCollectionOwner', [collectionId, ownerAddress],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnershipTransferProposed');
+  }
+
+  /**
+   * Accepts the proposed ownership of the collection on behalf of the signer.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @example acceptOwnership(bobKeyring, 10)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async acceptOwnership(signer: TSigner, collectionId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.acceptCollectionOwnership', [collectionId],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnerChanged');
+  }
+
+  /**
+   * Cancels the proposed transfer of the collection ownership.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @example cancelOwnershipTransfer(aliceKeyring, 10)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async cancelOwnershipTransfer(signer: TSigner, collectionId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.cancelCollectionOwnershipTransfer', [collectionId],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnershipTransferCancelled');
+  }
+
   /**
    * Adds a collection administrator.
    *
@@ -1153,6 +1227,45 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionAdminRemoved');
   }
 
+  /**
+   * Sets the roles of a collection administrator, restricting the operations it is allowed to perform.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param adminAddressObj Administrator address (substrate or ethereum)
+   * @param roles roles of the administrator, the omitted ones are not granted
+   * @example setAdminRoles(aliceKeyring, 10, {Substrate: "5DyN4Y92vZCjv38fg..."}, {minter: true})
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async setAdminRoles(signer: TSigner, collectionId: number, adminAddressObj: ICrossAccountId, roles: ICollectionAdminRoles): Promise<boolean> {
+    const flags = (roles.minter ? 128 : 0)
+      | (roles.propertyEditor ? 64 : 0)
+      | (roles.allowlistManager ? 32 : 0)
+      | (roles.sponsorManager ? 16 : 0)
+      | (roles.limitsManager ? 8 : 0)
+      | (roles.tokenManager ? 4 : 0);
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setCollectionAdminRoles', [collectionId, adminAddressObj, [flags]],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionAdminRolesSet');
+  }
+
+  /**
+   * Get the addresses of the collection's administrators together with their roles.
+   *
+   * @param collectionId ID of collection
+   * @example await getAdminsWithRoles(1)
+   * @returns array of administrators with their roles
+   */
+  async getAdminsWithRoles(collectionId: number): Promise<{admin: ICrossAccountId, roles: Required<ICollectionAdminRoles>}[]> {
+    const admins = (await this.helper.callRpc('api.rpc.unique.adminlistWithRoles', [collectionId])).toHuman() as [ICrossAccountId, Required<ICollectionAdminRoles>][];
+
+    return admins.map(([admin, roles]) => ({admin, roles}));
+  }
+
   /**
    * Check if user is in allow list.
    *
@@ -1305,6 +1418,73 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionPropertyDeleted');
   }
 
+  /**
+   * Sets default token properties of the collection, used by the tokens without own values.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param properties array of property objects
+   * @example setDefaultTokenProperties(aliceKeyring, 10, [{key: "background", value: "blue"}]);
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async setDefaultTokenProperties(signer: TSigner, collectionId: number, properties: IProperty[]): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setDefaultTokenProperties', [collectionId, properties],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'DefaultTokenPropertySet');
+  }
+
+  /**
+   * Deletes default token properties of the collection.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param propertyKeys array of property keys to delete
+   * @example deleteDefaultTokenProperties(aliceKeyring, 10, ["background"]);
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async deleteDefaultTokenProperties(signer: TSigner, collectionId: number, propertyKeys: string[]): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.deleteDefaultTokenProperties', [collectionId, propertyKeys],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'DefaultTokenPropertyDeleted');
+  }
+
+  /**
+   * Permanently freezes the properties, royalty and token property permissions of the collection.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @example freezeCollectionMetadata(aliceKeyring, 10);
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async freezeCollectionMetadata(signer: TSigner, collectionId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.freezeCollectionMetadata', [collectionId],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionMetadataFrozen');
+  }
+
+  /**
+   * Check if the collection metadata is permanently frozen
+   *
+   * @param collectionId ID of collection
+   * @example isCollectionMetadataFrozen(10);
+   * @returns true if the collection metadata is frozen, otherwise false
+   */
+  async isCollectionMetadataFrozen(collectionId: number): Promise<boolean> {
+    return (await this.helper.callRpc('api.rpc.unique.collectionMetadataFrozen', [collectionId])).toJSON();
+  }
+
   /**
    * Changes the owner of the token.
    *
@@ -1509,7 +1689,7 @@ class NFTnRFT extends CollectionGroup {
    * @returns human readable token data
    */
   async getToken(collectionId: number, tokenId: number, propertyKeys: string[] = [], blockHashAt?: string): Promise<{
-    properties: IProperty[];
+    properties: ITokenProperty[];
     owner: ICrossAccountId;
     normalizedOwner: ICrossAccountId;
   } | null> {
@@ -1527,6 +1707,7 @@ class NFTnRFT extends CollectionGroup {
     }
     const tokenData = (await this.helper.callRpc('api.rpc.unique.tokenData', args)).toHuman();
     if(tokenData === null || tokenData.owner === null) return null;
+    tokenData.properties = this.helper.util.normalizeTokenProperties(tokenData.properties);
     tokenData.normalizedOwner = CrossAccountId.withNormalizedSubstrate(tokenData.owner);
     return tokenData;
   }
@@ -1619,13 +1800,86 @@ class NFTnRFT extends CollectionGroup {
   async setTokenPropertyPermissions(signer: TSigner, collectionId: number, permissions: ITokenPropertyPermission[]): Promise<boolean> {
     const result = await this.helper.executeExtrinsic(
       signer,
-      'api.tx.unique.setTokenPropertyPermissions', [collectionId, permissions],
+      'api.tx.unique.setTokenPropertyPermissions', [collectionId, permissions.map(({valueType: _valueType, ...permission}) => permission)],
       true,
     );
 
+    const valueTypes = permissions.filter(({valueType}) => valueType !== undefined);
+    if(valueTypes.length > 0) await this.setTokenPropertyValueTypes(signer, collectionId, valueTypes);
+
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'PropertyPermissionSet');
   }
 
+  /**
+   * Declare or remove the types of token property values
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param valueTypes types of the values of the properties, the omitted type allows any value
+   * @example setTokenPropertyValueTypes(aliceKeyring, 10, [{key: "level", valueType: "U32"}])
+   * @returns true if extrinsic success otherwise false
+   */
+  async setTokenPropertyValueTypes(signer: TSigner, collectionId: number, valueTypes: {key: string, valueType?: TPropertyValueType}[]): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setTokenPropertyValueTypes', [collectionId, valueTypes.map(({key, valueType}) => ({key, valueType: valueType ?? null}))],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'PropertyPermissionSet');
+  }
+
+  /**
+   * Allow an account to write token properties with the given keys, regardless of the property permissions
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param addressObj account to grant the right to
+   * @param propertyKeys keys of the token properties
+   * @example grantTokenPropertyWrite(aliceKeyring, 10, {Substrate: "5DyN4Y92vZCjv38fg..."}, ["score"])
+   * @returns true if extrinsic success otherwise false
+   */
+  async grantTokenPropertyWrite(signer: TSigner, collectionId: number, addressObj: ICrossAccountId, propertyKeys: string[]): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.grantTokenPropertyWrite', [collectionId, addressObj, propertyKeys],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'TokenPropertyWriteGranted');
+  }
+
+  /**
+   * Revoke the right to write token properties with the given keys from an account
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param addressObj account to revoke the right from
+   * @param propertyKeys keys of the token properties
+   * @example revokeTokenPropertyWrite(aliceKeyring, 10, {Substrate: "5DyN4Y92vZCjv38fg..."}, ["score"])
+   * @returns true if extrinsic success otherwise false
+   */
+  async revokeTokenPropertyWrite(signer: TSigner, collectionId: number, addressObj: ICrossAccountId, propertyKeys: string[]): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.revokeTokenPropertyWrite', [collectionId, addressObj, propertyKeys],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'TokenPropertyWriteRevoked');
+  }
+
+  /**
+   * Get the accounts granted to write token properties, together with the property keys.
+   *
+   * @param collectionId ID of collection
+   * @example getTokenPropertyWriteGrants(1219);
+   * @returns array of account-key pairs
+   */
+  async getTokenPropertyWriteGrants(collectionId: number): Promise<{account: ICrossAccountId, key: string}[]> {
+    return (await this.helper.callRpc('api.rpc.unique.propertyWriteGrants', [collectionId])).toHuman();
+  }
+
   /**
    * Get token property permissions.
    *
@@ -1635,7 +1889,8 @@ class NFTnRFT extends CollectionGroup {
    * @returns array of key-permission pairs
    */
   async getPropertyPermissions(collectionId: number, propertyKeys: string[] | null = null): Promise<ITokenPropertyPermission[]> {
-    return (await this.helper.callRpc('api.rpc.unique.propertyPermissions', [collectionId, ...(propertyKeys === null ? [] : [propertyKeys])])).toHuman();
+    const permissions = (await this.helper.callRpc('api.rpc.unique.propertyPermissions', [collectionId, ...(propertyKeys === null ? [] : [propertyKeys])])).toHuman();
+    return this.helper.util.normalizePropertyPermissions(permissions);
   }
 
   /**
@@ -1667,8 +1922,21 @@ class NFTnRFT extends CollectionGroup {
    * @example getTokenProperties(1219, ['location', 'date', 'time', 'isParadise']);
    * @returns array of key-value pairs
    */
-  async getTokenProperties(collectionId: number, tokenId: number, propertyKeys?: string[] | null): Promise<IProperty[]> {
-    return (await this.helper.callRpc('api.rpc.unique.tokenProperties', [collectionId, tokenId, propertyKeys])).toHuman();
+  async getTokenProperties(collectionId: number, tokenId: number, propertyKeys?: string[] | null): Promise<ITokenProperty[]> {
+    const properties = (await this.helper.callRpc('api.rpc.unique.tokenProperties', [collectionId, tokenId, propertyKeys])).toHuman();
+    return this.helper.util.normalizeTokenProperties(properties);
+  }
+
+  /**
+   * Get the URI of a token, resolved the same way as the ERC-721 tokenURI method.
+   *
+   * @param collectionId ID of collection
+   * @param tokenId ID of token
+   * @example getTokenUri(1219, 5);
+   * @returns the token URI, or null if the token does not exist
+   */
+  async getTokenUri(collectionId: number, tokenId: number): Promise<string | null> {
+    return (await this.helper.callRpc('api.rpc.unique.tokenUri', [collectionId, tokenId])).toHuman();
   }
 
   /**
@@ -1690,6 +1958,36 @@ class NFTnRFT extends CollectionGroup {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'TokenPropertyDeleted');
   }
 
+  /**
+   * Permanently freeze the properties of a token
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param tokenId ID of token
+   * @example freezeTokenProperties(aliceKeyring, 10, 5)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async freezeTokenProperties(signer: TSigner, collectionId: number, tokenId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.freezeTokenProperties', [collectionId, tokenId],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'TokenPropertiesFrozen');
+  }
+
+  /**
+   * Check if the token properties are permanently frozen
+   *
+   * @param collectionId ID of collection
+   * @param tokenId ID of token
+   * @example isTokenPropertiesFrozen(10, 5);
+   * @returns true if the token properties are frozen, otherwise false
+   */
+  async isTokenPropertiesFrozen(collectionId: number, tokenId: number): Promise<boolean> {
+    return (await this.helper.callRpc('api.rpc.unique.tokenPropertiesFrozen', [collectionId, tokenId])).toJSON();
+  }
+
   /**
    * Mint new collection
    *
@@ -1716,12 +2014,21 @@ class NFTnRFT extends CollectionGroup {
     }
     collectionOptions.flags = [flags];
 
+    // Value types are declared by a separate call once the collection is created
+    const permissions = collectionOptions.tokenPropertyPermissions ?? [];
+    collectionOptions.tokenPropertyPermissions = permissions.map(({valueType: _valueType, ...permission}) => permission);
+
     const creationResult = await this.helper.executeExtrinsic(
       signer,
       'api.tx.unique.createCollectionEx', [collectionOptions],
       true, // errorLabel,
     );
-    return this.getCollectionObject(this.helper.util.extractCollectionIdFromCreationResult(creationResult));
+    const collectionId = this.helper.util.extractCollectionIdFromCreationResult(creationResult);
+
+    const valueTypes = permissions.filter(({valueType}) => valueType !== undefined);
+    if(valueTypes.length > 0) await this.setTokenPropertyValueTypes(signer, collectionId, valueTypes);
+
+    return this.getCollectionObject(collectionId);
   }
 
   getCollectionObject(_collectionId: number): any {
@@ -3117,10 +3424,30 @@ export class UniqueBaseCollection {
     return await this.helper.collection.changeOwner(signer, this.collectionId, ownerAddress);
   }
 
+  async proposeOwner(signer: TSigner, ownerAddress: TSubstrateAccount) {
+    return await this.helper.collection.proposeOwner(signer, this.collectionId, ownerAddress);
+  }
+
+  async acceptOwnership(signer: TSigner) {
+    return await this.helper.collection.acceptOwnership(signer, this.collectionId);
+  }
+
+  async cancelOwnershipTransfer(signer: TSigner) {
+    return await this.helper.collection.cancelOwnershipTransfer(signer, this.collectionId);
+  }
+
   async addAdmin(signer: TSigner, adminAddressObj: ICrossAccountId) {
     return await this.helper.collection.addAdmin(signer, this.collectionId, adminAddressObj);
   }
 
+  async setAdminRoles(signer: TSigner, adminAddressObj: ICrossAccountId, roles: ICollectionAdminRoles) {
+    return await this.helper.collection.setAdminRoles(signer, this.collectionId, adminAddressObj, roles);
+  }
+
+  async getAdminsWithRoles() {
+    return await this.helper.collection.getAdminsWithRoles(this.collectionId);
+  }
+
   async addToAllowList(signer: TSigner, addressObj: ICrossAccountId) {
     return await this.helper.collection.addToAllowList(signer, this.collectionId, addressObj);
   }
@@ -3141,6 +3468,22 @@ export class UniqueBaseCollection {
     return await this.helper.collection.deleteProperties(signer, this.collectionId, propertyKeys);
   }
 
+  async setDefaultTokenProperties(signer: TSigner, properties: IProperty[]) {
+    return await this.helper.collection.setDefaultTokenProperties(signer, this.collectionId, properties);
+  }
+
+  async deleteDefaultTokenProperties(signer: TSigner, propertyKeys: string[]) {
+    return await this.helper.collection.deleteDefaultTokenProperties(signer, this.collectionId, propertyKeys);
+  }
+
+  async freezeMetadata(signer: TSigner) {
+    return await this.helper.collection.freezeCollectionMetadata(signer, this.collectionId);
+  }
+
+  async isMetadataFrozen() {
+    return await this.helper.collection.isCollectionMetadataFrozen(this.collectionId);
+  }
+
   async setPermissions(signer: TSigner, permissions: ICollectionPermissions) {
     return await this.helper.collection.setPermissions(signer, this.collectionId, permissions);
   }
@@ -3238,10 +3581,34 @@ export class UniqueNFTCollection extends UniqueBaseCollection {
     return await this.helper.nft.deleteTokenProperties(signer, this.collectionId, tokenId, propertyKeys);
   }
 
+  async freezeTokenProperties(signer: TSigner, tokenId: number) {
+    return await this.helper.nft.freezeTokenProperties(signer, this.collectionId, tokenId);
+  }
+
+  async isTokenPropertiesFrozen(tokenId: number) {
+    return await this.helper.nft.isTokenPropertiesFrozen(this.collectionId, tokenId);
+  }
+
   async setTokenPropertyPermissions(signer: TSigner, permissions: ITokenPropertyPermission[]) {
     return await this.helper.nft.setTokenPropertyPermissions(signer, this.collectionId, permissions);
   }
 
+  async setTokenPropertyValueTypes(signer: TSigner, valueTypes: {key: string, valueType?: TPropertyValueType}[]) {
+    return await this.helper.nft.setTokenPropertyValueTypes(signer, this.collectionId, valueTypes);
+  }
+
+  async grantTokenPropertyWrite(signer: TSigner, addressObj: ICrossAccountId, propertyKeys: string[]) {
+    return await this.helper.nft.grantTokenPropertyWrite(signer, this.collectionId, addressObj, propertyKeys);
+  }
+
+  async revokeTokenPropertyWrite(signer: TSigner, addressObj: ICrossAccountId, propertyKeys: string[]) {
+    return await this.helper.nft.revokeTokenPropertyWrite(signer, this.collectionId, addressObj, propertyKeys);
+  }
+
+  async getTokenPropertyWriteGrants() {
+    return await this.helper.nft.getTokenPropertyWriteGrants(this.collectionId);
+  }
+
   async nestToken(signer: TSigner, tokenId: number, toTokenObj: IToken) {
     return await this.helper.nft.nestToken(signer, {collectionId: this.collectionId, tokenId}, toTokenObj);
   }
@@ -3343,10 +3710,34 @@ export class UniqueRFTCollection extends UniqueBaseCollection {
     return await this.helper.rft.deleteTokenProperties(signer, this.collectionId, tokenId, propertyKeys);
   }
 
+  async freezeTokenProperties(signer: TSigner, tokenId: number) {
+    return await this.helper.rft.freezeTokenProperties(signer, this.collectionId, tokenId);
+  }
+
+  async isTokenPropertiesFrozen(tokenId: number) {
+    return await this.helper.rft.isTokenPropertiesFrozen(this.collectionId, tokenId);
+  }
+
   async setTokenPropertyPermissions(signer: TSigner, permissions: ITokenPropertyPermission[]) {
     return await this.helper.rft.setTokenPropertyPermissions(signer, this.collectionId, permissions);
   }
 
+  async setTokenPropertyValueTypes(signer: TSigner, valueTypes: {key: string, valueType?: TPropertyValueType}[]) {
+    return await this.helper.rft.setTokenPropertyValueTypes(signer, this.collectionId, valueTypes);
+  }
+
+  async grantTokenPropertyWrite(signer: TSigner, addressObj: ICrossAccountId, propertyKeys: string[]) {
+    return await this.helper.rft.grantTokenPropertyWrite(signer, this.collectionId, addressObj, propertyKeys);
+  }
+
+  async revokeTokenPropertyWrite(signer: TSigner, addressObj: ICrossAccountId, propertyKeys: string[]) {
+    return await this.helper.rft.revokeTokenPropertyWrite(signer, this.collectionId, addressObj, propertyKeys);
+  }
+
+  async getTokenPropertyWriteGrants() {
+    return await this.helper.rft.getTokenPropertyWriteGrants(this.collectionId);
+  }
+
   async nestToken(signer: TSigner, tokenId: number, toTokenObj: IToken) {
     return await this.helper.rft.nestToken(signer, {collectionId: this.collectionId, tokenId}, toTokenObj);
   }
@@ -3433,6 +3824,14 @@ export class UniqueBaseToken {
     return await this.collection.deleteTokenProperties(signer, this.tokenId, propertyKeys);
   }
 
+  async freezeProperties(signer: TSigner) {
+    return await this.collection.freezeTokenProperties(signer, this.tokenId);
+  }
+
+  async arePropertiesFrozen() {
+    return await this.collection.isTokenPropertiesFrozen(this.tokenId);
+  }
+
   async doesExist() {
     return await this.collection.doesTokenExist(this.tokenId);
   }
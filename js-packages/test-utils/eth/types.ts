@@ -37,6 +37,19 @@ export enum TokenPermissionField {
   CollectionAdmin
 }
 
+export enum PropertyValueKind {
+  Untyped,
+  U32,
+  U64,
+  U128,
+  Bool,
+  String,
+  Uri,
+  Address,
+  Bytes,
+  Enum
+}
+
 export enum CollectionLimitField {
   AccountTokenOwnership,
 	SponsoredDataSize,
@@ -73,6 +86,16 @@ export interface TokenPropertyPermission {
   key: string,
   permissions: PropertyPermission[],
 }
+export interface PropertyValueType {
+  kind: PropertyValueKind,
+  maxLength: number,
+  allowedValues: string[],
+}
+export interface TypedTokenPropertyPermission {
+  key: string,
+  permissions: PropertyPermission[],
+  valueType: PropertyValueType,
+}
 export interface CollectionNestingAndPermission {
   token_owner: boolean,
   collection_admin: boolean,
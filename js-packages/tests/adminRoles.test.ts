@@ -0,0 +1,150 @@
+﻿// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds, expect, itSub} from '@unique/test-utils/util.js';
+
+const ALL_ROLES = {
+  minter: true,
+  propertyEditor: true,
+  allowlistManager: true,
+  sponsorManager: true,
+  limitsManager: true,
+  tokenManager: true,
+};
+
+describe('Integration Test setCollectionAdminRoles(collection_id, admin, roles):', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([20n, 20n, 20n], donor);
+    });
+  });
+
+  itSub('Added admin has all the roles', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+
+    const admins = await collection.getAdminsWithRoles();
+    expect(admins).to.have.lengthOf(1);
+    expect(admins[0].admin).to.be.deep.equal({Substrate: bob.address});
+    expect(admins[0].roles).to.be.deep.equal(ALL_ROLES);
+  });
+
+  itSub('Minter admin can only mint', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {minter: true});
+
+    const [admin] = await collection.getAdminsWithRoles();
+    expect(admin.roles).to.be.deep.equal({
+      minter: true,
+      propertyEditor: false,
+      allowlistManager: false,
+      sponsorManager: false,
+      limitsManager: false,
+      tokenManager: false,
+    });
+
+    const token = await collection.mintToken(bob, {Substrate: charlie.address});
+    expect(await token.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+
+    await expect(collection.setProperties(bob, [{key: 'key', value: 'value'}])).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.addToAllowList(bob, {Substrate: charlie.address})).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setSponsor(bob, charlie.address)).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setLimits(bob, {accountTokenOwnershipLimit: 1})).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Admin without the minter role can\'t mint', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {propertyEditor: true, limitsManager: true});
+
+    await expect(collection.mintToken(bob)).to.be.rejectedWith(/common\.PublicMintingNotAllowed/);
+    await collection.setProperties(bob, [{key: 'key', value: 'value'}]);
+    await collection.setLimits(bob, {accountTokenOwnershipLimit: 1});
+    expect(await collection.getProperties(['key'])).to.be.deep.equal([{key: 'key', value: 'value'}]);
+  });
+
+  itSub('Only the token manager admin can operate on tokens of other owners', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      name: 'col', description: 'descr', tokenPrefix: 'COL',
+      limits: {ownerCanTransfer: true},
+      permissions: {nesting: {collectionAdmin: true}},
+    });
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {minter: true});
+    const token = await collection.mintToken(bob, {Substrate: charlie.address});
+    const target = await collection.mintToken(bob, {Substrate: charlie.address});
+    const nested = await collection.mintToken(bob, {Substrate: bob.address});
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.setTokenLock', [collection.collectionId, token.tokenId, true]))
+      .to.be.rejectedWith(/common\.NoPermission/);
+    await expect(token.approve(bob, {Substrate: bob.address})).to.be.rejectedWith(/common\.CantApproveMoreThanOwned/);
+    await expect(token.transferFrom(bob, {Substrate: charlie.address}, {Substrate: bob.address}))
+      .to.be.rejectedWith(/common\.ApprovedValueTooLow/);
+    await expect(token.burnFrom(bob, {Substrate: charlie.address})).to.be.rejectedWith(/common\.ApprovedValueTooLow/);
+    await expect(nested.nest(bob, target)).to.be.rejectedWith(/common\.UserIsNotAllowedToNest/);
+
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {tokenManager: true});
+    await nested.nest(bob, target);
+    await token.transferFrom(bob, {Substrate: charlie.address}, {Substrate: bob.address});
+    expect(await token.getOwner()).to.be.deep.equal({Substrate: bob.address});
+    expect(await nested.getTopmostOwner()).to.be.deep.equal({Substrate: charlie.address});
+  });
+
+  itSub('Re-added admin has all the roles', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {sponsorManager: true});
+    await collection.removeAdmin(alice, {Substrate: bob.address});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+
+    const [admin] = await collection.getAdminsWithRoles();
+    expect(admin.roles).to.be.deep.equal(ALL_ROLES);
+  });
+});
+
+describe('Negative Integration Test setCollectionAdminRoles(collection_id, admin, roles):', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([20n, 20n, 20n], donor);
+    });
+  });
+
+  itSub('Admin can\'t set roles', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.addAdmin(alice, {Substrate: charlie.address});
+
+    await expect(collection.setAdminRoles(bob, {Substrate: charlie.address}, {minter: true})).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setAdminRoles(bob, {Substrate: bob.address}, {minter: true})).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Can\'t set roles of a non-admin', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await expect(collection.setAdminRoles(alice, {Substrate: bob.address}, {minter: true})).to.be.rejectedWith(/common\.UserIsNotCollectionAdmin/);
+  });
+});
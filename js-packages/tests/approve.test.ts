@@ -539,6 +539,67 @@ describe('Normal user can approve other users to be wallet operator:', () => {
   });
 });
 
+describe('Approvals with deadline:', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  itSub('[nft] Approval can not be used after the deadline', async ({helper}) => {
+    const {collectionId} = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const {tokenId} = await helper.nft.mintToken(alice, {collectionId, owner: {Substrate: alice.address}});
+    const deadline = await helper.chain.getLatestBlockNumber() + 2;
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.approveWithDeadline', [{Substrate: bob.address}, collectionId, tokenId, 1, deadline]);
+    expect(await helper.nft.isTokenApproved(collectionId, tokenId, {Substrate: bob.address})).to.be.true;
+    expect((await helper.callRpc('api.rpc.unique.allowanceDeadline', [collectionId, {Substrate: alice.address}, {Substrate: bob.address}, tokenId])).toJSON()).to.be.equal(deadline);
+
+    await helper.wait.newBlocks(3);
+    expect(await helper.nft.isTokenApproved(collectionId, tokenId, {Substrate: bob.address})).to.be.false;
+    await expect(helper.nft.transferTokenFrom(bob, collectionId, tokenId, {Substrate: alice.address}, {Substrate: charlie.address})).to.be.rejectedWith('common.ApprovedValueTooLow');
+  });
+
+  itSub('[fungible] Approval can not be used after the deadline', async ({helper}) => {
+    const {collectionId} = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await helper.ft.mintTokens(alice, collectionId, 10n, {Substrate: alice.address});
+    const deadline = await helper.chain.getLatestBlockNumber() + 2;
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.approveWithDeadline', [{Substrate: bob.address}, collectionId, 0, 5, deadline]);
+    expect(await helper.ft.getTokenApprovedPieces(collectionId, 0, {Substrate: bob.address}, {Substrate: alice.address})).to.be.equal(5n);
+
+    await helper.wait.newBlocks(3);
+    expect(await helper.ft.getTokenApprovedPieces(collectionId, 0, {Substrate: bob.address}, {Substrate: alice.address})).to.be.equal(0n);
+    await expect(helper.ft.transferFrom(bob, collectionId, {Substrate: alice.address}, {Substrate: charlie.address}, 1n)).to.be.rejectedWith('common.ApprovedValueTooLow');
+  });
+
+  itSub('[nft] Operator status can not be used after the deadline', async ({helper}) => {
+    const {collectionId} = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const {tokenId} = await helper.nft.mintToken(alice, {collectionId, owner: {Substrate: alice.address}});
+    const deadline = await helper.chain.getLatestBlockNumber() + 2;
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setAllowanceForAllWithDeadline', [collectionId, {Substrate: bob.address}, deadline]);
+    expect(await helper.nft.allowanceForAll(collectionId, {Substrate: alice.address}, {Substrate: bob.address})).to.be.true;
+
+    await helper.wait.newBlocks(3);
+    expect(await helper.nft.allowanceForAll(collectionId, {Substrate: alice.address}, {Substrate: bob.address})).to.be.false;
+    await expect(helper.nft.transferTokenFrom(bob, collectionId, tokenId, {Substrate: alice.address}, {Substrate: charlie.address})).to.be.rejectedWith('common.ApprovedValueTooLow');
+  });
+
+  itSub('Approval with a passed deadline is rejected', async ({helper}) => {
+    const {collectionId} = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const {tokenId} = await helper.nft.mintToken(alice, {collectionId, owner: {Substrate: alice.address}});
+    const deadline = await helper.chain.getLatestBlockNumber() - 1;
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.approveWithDeadline', [{Substrate: bob.address}, collectionId, tokenId, 1, deadline])).to.be.rejectedWith('common.AllowanceDeadlinePassed');
+  });
+});
+
 describe('Administrator and collection owner do not need approval in order to execute TransferFrom (with owner_can_transfer_flag = true):', () => {
   let alice: IKeyringPair;
   let bob: IKeyringPair;
@@ -0,0 +1,147 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, usingPlaygrounds} from '@unique/test-utils/util.js';
+import type {UniqueHelper} from '@unique-nft/playgrounds/unique.js';
+
+describe('Marketplace auctions', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 1000n, 1000n], donor);
+    });
+  });
+
+  async function createAuction(helper: UniqueHelper, args: any[]) {
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.marketplace.createAuction', args);
+    const event = result.events.find(e => e.event.section === 'marketplace' && e.event.method === 'AuctionCreated');
+    return Number(event?.event.data[0].toString());
+  }
+
+  itSub('English auction goes to the highest bidder, outbid bids are refunded', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+    const nominal = helper.balance.getOneTokenNominal();
+
+    const auctionId = await createAuction(helper, [
+      collection.collectionId, nft.tokenId, 1, 0,
+      {English: {minBid: 10n * nominal, minIncrement: nominal}}, 3,
+    ]);
+    expect(await nft.getOwner()).to.not.be.deep.equal({Substrate: alice.address});
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.transfer', [{Substrate: bob.address}, collection.collectionId, nft.tokenId, 1]))
+      .to.be.rejected;
+
+    await helper.executeExtrinsic(bob, 'api.tx.marketplace.bid', [auctionId, 10n * nominal]);
+    await expect(helper.executeExtrinsic(charlie, 'api.tx.marketplace.bid', [auctionId, 10n * nominal + nominal / 2n]))
+      .to.be.rejectedWith('marketplace.BidTooLow');
+
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    const {result} = await helper.executeExtrinsic(charlie, 'api.tx.marketplace.bid', [auctionId, 12n * nominal]);
+    // The bid placed right before the end extends the auction
+    const extended = result.events.find(e => e.event.section === 'marketplace' && e.event.method === 'AuctionExtended');
+    expect(extended).to.not.be.undefined;
+    expect(await helper.balance.getSubstrate(bob.address)).to.be.equal(bobBalanceBefore + 10n * nominal);
+
+    const aliceBalanceBefore = await helper.balance.getSubstrate(alice.address);
+    await helper.wait.forParachainBlockNumber(BigInt(extended!.event.data[1].toString()));
+
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+    // 1% market fee
+    expect(await helper.balance.getSubstrate(alice.address)).to.be.equal(aliceBalanceBefore + 12n * nominal * 99n / 100n);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.bid', [auctionId, 20n * nominal]))
+      .to.be.rejectedWith('marketplace.AuctionNotFound');
+  });
+
+  itSub.ifWithPallets('RFT pieces can be bought at a Dutch auction', [Pallets.ReFungible], async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    const currency = await helper.ft.mintCollection(bob, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(bob, 1000n);
+
+    const auctionId = await createAuction(helper, [
+      collection.collectionId, rft.tokenId, 30, currency.collectionId,
+      {Dutch: {startPrice: 1000, endPrice: 100}}, 100,
+    ]);
+    expect(await rft.getBalance({Substrate: alice.address})).to.be.equal(70n);
+
+    await helper.wait.newBlocks(2);
+    const {result} = await helper.executeExtrinsic(bob, 'api.tx.marketplace.buyAuction', [auctionId, 1000]);
+    const settled = result.events.find(e => e.event.section === 'marketplace' && e.event.method === 'AuctionSettled');
+    const price = BigInt(settled!.event.data[2].toString());
+    expect(price < 1000n && price > 100n).to.be.true;
+
+    expect(await rft.getBalance({Substrate: bob.address})).to.be.equal(30n);
+    expect(await currency.getBalance({Substrate: bob.address})).to.be.equal(1000n - price);
+    await expect(helper.executeExtrinsic(charlie, 'api.tx.marketplace.buyAuction', [auctionId, 1000]))
+      .to.be.rejectedWith('marketplace.AuctionNotFound');
+  });
+
+  itSub('Auction without bids returns the token to the seller', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+
+    const auctionId = await createAuction(helper, [collection.collectionId, nft.tokenId, 1, 0, {Dutch: {startPrice: 10, endPrice: 1}}, 2]);
+    await helper.wait.newBlocks(3);
+
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: alice.address});
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.buyAuction', [auctionId, 10]))
+      .to.be.rejectedWith('marketplace.AuctionNotFound');
+  });
+
+  itSub('Auction can be cancelled only by the seller until the first bid', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const first = await collection.mintToken(alice);
+    const second = await collection.mintToken(alice);
+    const kind = {English: {minBid: 1, minIncrement: 1}};
+
+    const auctionId = await createAuction(helper, [collection.collectionId, first.tokenId, 1, 0, kind, 100]);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.cancelAuction', [auctionId]))
+      .to.be.rejectedWith('common.NoPermission');
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.cancelAuction', [auctionId]);
+    expect(await first.getOwner()).to.be.deep.equal({Substrate: alice.address});
+
+    const withBidId = await createAuction(helper, [collection.collectionId, second.tokenId, 1, 0, kind, 100]);
+    await helper.executeExtrinsic(bob, 'api.tx.marketplace.bid', [withBidId, helper.balance.getOneTokenNominal()]);
+    await expect(helper.executeExtrinsic(alice, 'api.tx.marketplace.cancelAuction', [withBidId]))
+      .to.be.rejectedWith('marketplace.AuctionHasBids');
+    expect(await second.getOwner()).to.not.be.deep.equal({Substrate: alice.address});
+  });
+
+  itSub('Negative: auction terms are checked', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.marketplace.createAuction', [collection.collectionId, nft.tokenId, 1, 0, {Dutch: {startPrice: 1, endPrice: 10}}, 10]))
+      .to.be.rejectedWith('marketplace.InvalidAuctionTerms');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.marketplace.createAuction', [collection.collectionId, nft.tokenId, 1, 0, {English: {minBid: 1, minIncrement: 1}}, 0]))
+      .to.be.rejectedWith('marketplace.InvalidAuctionTerms');
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.createAuction', [collection.collectionId, nft.tokenId, 1, 0, {English: {minBid: 1, minIncrement: 1}}, 10]))
+      .to.be.rejectedWith('common.TokenValueTooLow');
+
+    const auctionId = await createAuction(helper, [collection.collectionId, nft.tokenId, 1, 0, {Dutch: {startPrice: 10, endPrice: 1}}, 100]);
+    await expect(helper.executeExtrinsic(alice, 'api.tx.marketplace.bid', [auctionId, 10]))
+      .to.be.rejectedWith('common.NoPermission');
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.bid', [auctionId, 10]))
+      .to.be.rejectedWith('marketplace.NotEnglishAuction');
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.settleAuction', [auctionId]))
+      .to.be.rejectedWith('marketplace.AuctionNotEnded');
+  });
+});
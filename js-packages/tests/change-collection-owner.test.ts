@@ -102,6 +102,68 @@ describe('Integration Test changeCollectionOwner(collection_id, new_owner) speci
   });
 });
 
+describe('Integration Test two-step collection ownership transfer:', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  itSub('Proposed owner becomes the owner only after accepting', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    await collection.proposeOwner(alice, bob.address);
+    const proposed = await collection.getData();
+    expect(proposed?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(alice.address));
+    expect(helper.address.normalizeSubstrate(proposed?.raw.pendingOwner)).to.be.equal(helper.address.normalizeSubstrate(bob.address));
+
+    await collection.acceptOwnership(bob);
+    const accepted = await collection.getData();
+    expect(accepted?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(bob.address));
+    expect(accepted?.raw.pendingOwner).to.be.null;
+  });
+
+  itSub('Owner can cancel the proposed transfer', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    await collection.proposeOwner(alice, bob.address);
+    await collection.cancelOwnershipTransfer(alice);
+    expect((await collection.getData())?.raw.pendingOwner).to.be.null;
+
+    await expect(collection.acceptOwnership(bob)).to.be.rejectedWith(/common\.AcceptOwnershipFail/);
+    expect((await collection.getData())?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(alice.address));
+  });
+
+  itSub('Only the proposed owner can accept the ownership', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    await collection.proposeOwner(alice, bob.address);
+    await expect(collection.acceptOwnership(charlie)).to.be.rejectedWith(/common\.AcceptOwnershipFail/);
+    await expect(collection.acceptOwnership(alice)).to.be.rejectedWith(/common\.AcceptOwnershipFail/);
+
+    await collection.proposeOwner(alice, charlie.address);
+    await expect(collection.acceptOwnership(bob)).to.be.rejectedWith(/common\.AcceptOwnershipFail/);
+    await collection.acceptOwnership(charlie);
+    expect((await collection.getData())?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(charlie.address));
+  });
+
+  itSub('Only the owner can propose or cancel the transfer', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+
+    await expect(collection.proposeOwner(bob, bob.address)).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.cancelOwnershipTransfer(alice)).to.be.rejectedWith(/common\.OwnershipTransferNotProposed/);
+
+    await collection.proposeOwner(alice, charlie.address);
+    await expect(collection.cancelOwnershipTransfer(bob)).to.be.rejectedWith(/common\.NoPermission/);
+  });
+});
+
 describe('Negative Integration Test changeCollectionOwner(collection_id, new_owner):', () => {
   let alice: IKeyringPair;
   let bob: IKeyringPair;
@@ -0,0 +1,153 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds, expect, itSub, Pallets} from '@unique/test-utils/util.js';
+import {NON_EXISTENT_COLLECTION_ID} from '@unique-nft/playgrounds/types.js';
+
+const permission = {mutable: true, collectionAdmin: true, tokenOwner: false};
+
+const tokenPropertyPermissions = [
+  {key: 'background', permission},
+  {key: 'name', permission},
+  {key: 'level', permission, valueType: 'U32' as const},
+];
+
+describe('Integration Test: default token properties', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itSub.ifWithPallets(`[${testCase.mode}] Tokens inherit default properties`, testCase.requiredPallets, async ({helper}) => {
+      const collection = await helper[testCase.mode].mintCollection(alice, {tokenPropertyPermissions});
+      await collection.setDefaultTokenProperties(alice, [
+        {key: 'background', value: 'blue'},
+        {key: 'name', value: 'unnamed'},
+      ]);
+
+      const token = await helper[testCase.mode].mintToken(alice, {
+        collectionId: collection.collectionId, owner: {Substrate: bob.address}, pieces: 1n,
+        properties: [{key: 'name', value: 'first'}],
+      });
+
+      expect(await token.getProperties()).to.be.deep.equal([
+        {key: 'background', value: 'blue', inherited: true},
+        {key: 'name', value: 'first'},
+      ]);
+      expect(await token.getProperties(['background'])).to.be.deep.equal([
+        {key: 'background', value: 'blue', inherited: true},
+      ]);
+      expect((await token.getData())?.properties).to.be.deep.equal([
+        {key: 'background', value: 'blue', inherited: true},
+        {key: 'name', value: 'first'},
+      ]);
+    }));
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itSub.ifWithPallets(`[${testCase.mode}] Token-level writes override default properties`, testCase.requiredPallets, async ({helper}) => {
+      const collection = await helper[testCase.mode].mintCollection(alice, {tokenPropertyPermissions});
+      const token = await helper[testCase.mode].mintToken(alice, {
+        collectionId: collection.collectionId, owner: {Substrate: bob.address}, pieces: 1n,
+      });
+      await collection.setDefaultTokenProperties(alice, [{key: 'background', value: 'blue'}]);
+
+      await token.setProperties(alice, [{key: 'background', value: 'red'}]);
+      expect(await token.getProperties(['background'])).to.be.deep.equal([{key: 'background', value: 'red'}]);
+
+      await token.deleteProperties(alice, ['background']);
+      expect(await token.getProperties(['background'])).to.be.deep.equal([
+        {key: 'background', value: 'blue', inherited: true},
+      ]);
+
+      await collection.deleteDefaultTokenProperties(alice, ['background']);
+      expect(await token.getProperties(['background'])).to.be.deep.equal([]);
+    }));
+
+  itSub('Burnt tokens have no inherited properties', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await collection.setDefaultTokenProperties(alice, [{key: 'background', value: 'blue'}]);
+
+    await token.burn(bob);
+    expect(await token.getProperties()).to.be.deep.equal([]);
+  });
+
+  itSub('Admin with the property editor role can set default properties', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {propertyEditor: true});
+
+    await collection.setDefaultTokenProperties(bob, [{key: 'background', value: 'blue'}]);
+    await collection.deleteDefaultTokenProperties(bob, ['background']);
+  });
+});
+
+describe('Negative Integration Test: default token properties', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  itSub('Regular user can\'t set default properties', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+
+    await expect(collection.setDefaultTokenProperties(bob, [{key: 'background', value: 'blue'}])).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.deleteDefaultTokenProperties(bob, ['background'])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Admin without the property editor role can\'t set default properties', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {minter: true});
+
+    await expect(collection.setDefaultTokenProperties(bob, [{key: 'background', value: 'blue'}])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Can\'t set a default property not matching its value type', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+
+    await expect(collection.setDefaultTokenProperties(alice, [{key: 'level', value: 'high'}])).to.be.rejectedWith(/common\.PropertyValueTypeMismatch/);
+    await collection.setDefaultTokenProperties(alice, [{key: 'level', value: '42'}]);
+  });
+
+  itSub('Can\'t set default properties in a fungible collection', async ({helper}) => {
+    const collection = await helper.ft.mintCollection(alice);
+
+    await expect(collection.setDefaultTokenProperties(alice, [{key: 'background', value: 'blue'}])).to.be.rejectedWith(/common\.UnsupportedOperation/);
+  });
+
+  itSub('Can\'t set default properties in a non-existing collection', async ({helper}) => {
+    await expect(helper.collection.setDefaultTokenProperties(alice, NON_EXISTENT_COLLECTION_ID, [{key: 'background', value: 'blue'}])).to.be.rejectedWith(/common\.CollectionNotFound/);
+  });
+});
@@ -54,6 +54,37 @@ describe('integration test: ext. destroyCollection():', () => {
     await collection.burn(alice);
     expect(await collection.getData()).to.be.null;
   });
+  itSub('Collection with tokens is removed in the background', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      name: 'test',
+      description: 'test',
+      tokenPrefix: 'test',
+    });
+    const tokens = await collection.mintMultipleTokens(alice, Array(40).fill({owner: {Substrate: alice.address}}));
+
+    await collection.burn(alice);
+    expect(await collection.getData()).to.be.null;
+    const progress = await helper.callRpc('api.rpc.unique.collectionDestructionProgress', [collection.collectionId]);
+    expect(progress.isSome).to.be.true;
+
+    await helper.wait.newBlocks(3);
+    expect((await helper.callRpc('api.rpc.unique.collectionDestructionProgress', [collection.collectionId])).isNone).to.be.true;
+    for(const token of tokens) {
+      expect(await token.doesExist()).to.be.false;
+    }
+  });
+  itSub('Tokens nested in a destroyed collection are returned to the owner', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const collectionFT = await helper.ft.mintCollection(alice);
+    const parent = await collection.mintToken(alice);
+    await collectionFT.mint(alice, 10n, parent.nestingAccount());
+
+    await collection.burn(alice);
+    await helper.wait.newBlocks(3);
+
+    expect(await collectionFT.getBalance(parent.nestingAccount())).to.be.equal(0n);
+    expect(await collectionFT.getBalance({Substrate: alice.address})).to.be.equal(10n);
+  });
 });
 
 describe('(!negative test!) integration test: ext. destroyCollection():', () => {
@@ -108,13 +139,4 @@ describe('(!negative test!) integration test: ext. destroyCollection():', () =>
     });
     await expect(collection.burn(alice)).to.be.rejectedWith(/common\.NoPermission/);
   });
-  itSub('fails when a collection still has a token', async ({helper}) => {
-    const collection = await helper.nft.mintCollection(alice, {
-      name: 'test',
-      description: 'test',
-      tokenPrefix: 'test',
-    });
-    await collection.mintToken(alice, {Substrate: alice.address});
-    await expect(collection.burn(alice)).to.be.rejectedWith(/common\.CantDestroyNotEmptyCollection/);
-  });
 });
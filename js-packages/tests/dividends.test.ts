@@ -0,0 +1,146 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, usingPlaygrounds} from '@unique/test-utils/util.js';
+import type {UniqueHelper} from '@unique-nft/playgrounds/unique.js';
+
+describe('Dividends', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  async function distribute(helper: UniqueHelper, args: any[]) {
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.dividends.distribute', args);
+    const event = result.events.find(e => e.event.section === 'dividends' && e.event.method === 'DistributionCreated');
+    // The snapshot is taken in the background
+    await helper.wait.newBlocks(2);
+    return Number(event?.event.data[0].toString());
+  }
+
+  itSub('NFT holders claim funds in proportion to the number of their tokens', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    for(let i = 0; i < 3; i++) await collection.mintToken(alice, {Substrate: bob.address});
+    await collection.mintToken(alice, {Substrate: charlie.address});
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+
+    const distributionId = await distribute(helper, [collection.collectionId, null, currency.collectionId, 400]);
+    const distribution = (await helper.callRpc('api.rpc.unique.dividendDistribution', [distributionId])).toJSON() as any;
+    expect(distribution.snapshotTaken).to.be.true;
+    expect(distribution.totalShares).to.be.equal(4);
+    expect(await currency.getBalance({Substrate: alice.address})).to.be.equal(600n);
+
+    expect((await helper.callRpc('api.rpc.unique.claimableDividends', [distributionId, {Substrate: bob.address}])).toBigInt()).to.be.equal(300n);
+    expect((await helper.callRpc('api.rpc.unique.accountClaimableDividends', [{Substrate: charlie.address}])).toJSON())
+      .to.be.deep.equal([[distributionId, 100]]);
+
+    // Transfers after the snapshot don't change the shares
+    await collection.getTokenObject(4).transfer(charlie, {Substrate: bob.address});
+    await helper.executeExtrinsic(bob, 'api.tx.dividends.claim', [distributionId]);
+    await helper.executeExtrinsic(charlie, 'api.tx.dividends.claim', [distributionId]);
+    expect(await currency.getBalance({Substrate: bob.address})).to.be.equal(300n);
+    expect(await currency.getBalance({Substrate: charlie.address})).to.be.equal(100n);
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.dividends.claim', [distributionId]))
+      .to.be.rejectedWith('dividends.NothingToClaim');
+    expect((await helper.callRpc('api.rpc.unique.accountClaimableDividends', [{Substrate: bob.address}])).toJSON()).to.be.deep.equal([]);
+  });
+
+  itSub('Tokens can be transferred while the snapshot is pending', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    for(let i = 0; i < 3; i++) await collection.mintToken(alice);
+    await collection.mintToken(alice, {Substrate: bob.address});
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+
+    // The transfers are in the same block as the deposit, before the snapshot starts
+    const {result} = await helper.utility.batchAll(alice, [
+      helper.constructApiCall('api.tx.dividends.distribute', [collection.collectionId, null, currency.collectionId, 400]),
+      helper.constructApiCall('api.tx.unique.transfer', [{Substrate: charlie.address}, collection.collectionId, 1, 1]),
+      helper.constructApiCall('api.tx.unique.transfer', [{Substrate: bob.address}, collection.collectionId, 2, 1]),
+    ]);
+    const event = result.events.find(e => e.event.section === 'dividends' && e.event.method === 'DistributionCreated');
+    const distributionId = Number(event?.event.data[0].toString());
+    await helper.wait.newBlocks(2);
+
+    // The shares are taken from the balances at the time of the deposit
+    const distribution = (await helper.callRpc('api.rpc.unique.dividendDistribution', [distributionId])).toJSON() as any;
+    expect(distribution.snapshotTaken).to.be.true;
+    expect(distribution.totalShares).to.be.equal(4);
+    expect((await helper.callRpc('api.rpc.unique.claimableDividends', [distributionId, {Substrate: alice.address}])).toBigInt()).to.be.equal(300n);
+    expect((await helper.callRpc('api.rpc.unique.claimableDividends', [distributionId, {Substrate: bob.address}])).toBigInt()).to.be.equal(100n);
+    expect((await helper.callRpc('api.rpc.unique.claimableDividends', [distributionId, {Substrate: charlie.address}])).toBigInt()).to.be.equal(0n);
+    expect(await collection.getTokenObject(1).getOwner()).to.be.deep.equal({Substrate: charlie.address});
+  });
+
+  itSub.ifWithPallets('RFT token holders claim funds in proportion to their pieces', [Pallets.ReFungible], async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n, {Substrate: bob.address});
+    await collection.mintToken(alice, 100n, {Substrate: charlie.address});
+    await rft.transfer(bob, {Substrate: charlie.address}, 30n);
+    const nominal = helper.balance.getOneTokenNominal();
+
+    const distributionId = await distribute(helper, [collection.collectionId, rft.tokenId, 0, 10n * nominal]);
+    expect((await helper.callRpc('api.rpc.unique.claimableDividends', [distributionId, {Substrate: bob.address}])).toBigInt()).to.be.equal(7n * nominal);
+    expect((await helper.callRpc('api.rpc.unique.claimableDividends', [distributionId, {Substrate: charlie.address}])).toBigInt()).to.be.equal(3n * nominal);
+
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    const {result} = await helper.executeExtrinsic(bob, 'api.tx.dividends.claim', [distributionId]);
+    const claimed = result.events.find(e => e.event.section === 'dividends' && e.event.method === 'DividendsClaimed');
+    expect(BigInt(claimed!.event.data[2].toString())).to.be.equal(7n * nominal);
+    expect(await helper.balance.getSubstrate(bob.address) > bobBalanceBefore).to.be.true;
+  });
+
+  itSub('Negative: distribution terms are checked', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.dividends.distribute', [currency.collectionId, null, 0, 100]))
+      .to.be.rejectedWith('dividends.UnsupportedCollection');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.dividends.distribute', [collection.collectionId, nft.tokenId, 0, 100]))
+      .to.be.rejectedWith('dividends.UnsupportedCollection');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.dividends.distribute', [collection.collectionId, null, collection.collectionId, 100]))
+      .to.be.rejectedWith('dividends.UnsupportedCurrency');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.dividends.distribute', [collection.collectionId, null, currency.collectionId, 0]))
+      .to.be.rejectedWith('common.TokenValueTooLow');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.dividends.claim', [0xffffffff]))
+      .to.be.rejectedWith('dividends.DistributionNotFound');
+  });
+
+  itSub('Funds are returned to the payer, if the collection has no holders', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+    const snapshotFee = (helper.getApi().consts.dividends.snapshotFee as any).toBigInt();
+    const aliceBalanceBefore = await helper.balance.getSubstrate(alice.address);
+
+    const distributionId = await distribute(helper, [collection.collectionId, null, currency.collectionId, 400]);
+    expect(await currency.getBalance({Substrate: alice.address})).to.be.equal(1000n);
+    expect((await helper.callRpc('api.rpc.unique.dividendDistribution', [distributionId])).isNone).to.be.true;
+    // The snapshot fee isn't returned
+    expect(aliceBalanceBefore - await helper.balance.getSubstrate(alice.address) >= snapshotFee).to.be.true;
+  });
+});
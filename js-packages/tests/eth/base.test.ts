@@ -138,6 +138,18 @@ describe('ERC165 tests', () => {
     await checkInterface(helper, '0x42966c68', true, true);
   });
 
+  itEth('ERC2981 - 0x2a55205a - support', async ({helper}) => {
+    await checkInterface(helper, '0x2a55205a', true, true);
+  });
+
+  itEth('ERC5192 - 0xb45a3c0e - support', async ({helper}) => {
+    await checkInterface(helper, '0xb45a3c0e', true, true);
+  });
+
+  itEth('ERC4907 - 0xad092b5c - support', async ({helper}) => {
+    await checkInterface(helper, '0xad092b5c', true, true);
+  });
+
   itEth('ERC165 - 0x01ffc9a7 - support', async ({helper}) => {
     await checkInterface(helper, '0x01ffc9a7', true, true);
   });
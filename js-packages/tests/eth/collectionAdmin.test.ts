@@ -237,6 +237,66 @@ describe('Add collection admins', () => {
   });
 });
 
+describe('Collection admin roles', () => {
+  let donor: IKeyringPair;
+
+  before(async function() {
+    await usingEthPlaygrounds(async (_helper, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  itEth('Owner can restrict admin roles [cross]', async ({helper}) => {
+    const MINTER = 128;
+    const PROPERTY_EDITOR = 64;
+    const ALL_ROLES = 128 | 64 | 32 | 16 | 8;
+
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const admin = await helper.eth.createAccountWithBalance(donor);
+    const adminCross = helper.ethCrossAccount.fromAddress(admin);
+    const {collectionAddress, collectionId} = await helper.eth.createNFTCollection(owner, 'A', 'B', 'C');
+    const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+    const adminCollectionEvm = helper.eth.changeContractCaller(collectionEvm, admin);
+
+    expect(await collectionEvm.collectionAdminRolesCross.staticCall(adminCross)).to.be.eq(0n);
+    await (await collectionEvm.addCollectionAdminCross.send(adminCross)).wait(...waitParams);
+    expect(await collectionEvm.collectionAdminRolesCross.staticCall(adminCross)).to.be.eq(BigInt(ALL_ROLES));
+
+    await (await collectionEvm.setCollectionAdminRolesCross.send(adminCross, PROPERTY_EDITOR)).wait(...waitParams);
+    expect(await collectionEvm.collectionAdminRolesCross.staticCall(adminCross)).to.be.eq(BigInt(PROPERTY_EDITOR));
+    expect(await collectionEvm.isOwnerOrAdminCross.staticCall(adminCross)).to.be.true;
+
+    await expect(adminCollectionEvm.mint.send(admin)).to.be.rejected;
+    await (await adminCollectionEvm.setCollectionProperties.send([{key: 'key', value: Buffer.from('value')}])).wait(...waitParams);
+
+    await (await collectionEvm.setCollectionAdminRolesCross.send(adminCross, MINTER)).wait(...waitParams);
+    await (await adminCollectionEvm.mint.send(admin)).wait(...waitParams);
+    await expect(adminCollectionEvm.setCollectionProperties.send([{key: 'key', value: Buffer.from('other')}])).to.be.rejected;
+
+    const [[, roles]] = (await helper.callRpc('api.rpc.unique.adminlistWithRoles', [collectionId])).toJSON();
+    expect(roles).to.be.deep.equal({
+      minter: true,
+      propertyEditor: false,
+      allowlistManager: false,
+      sponsorManager: false,
+      limitsManager: false,
+      tokenManager: false,
+    });
+  });
+
+  itEth('(!negative tests!) Admin can\'t set admin roles [cross]', async ({helper}) => {
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const admin = await helper.eth.createAccountWithBalance(donor);
+    const adminCross = helper.ethCrossAccount.fromAddress(admin);
+    const {collectionAddress} = await helper.eth.createNFTCollection(owner, 'A', 'B', 'C');
+    const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+    await (await collectionEvm.addCollectionAdminCross.send(adminCross)).wait(...waitParams);
+
+    const adminCollectionEvm = helper.eth.changeContractCaller(collectionEvm, admin);
+    await expect(adminCollectionEvm.setCollectionAdminRolesCross.send(adminCross, 128)).to.be.rejected;
+  });
+});
+
 describe('Remove collection admins', () => {
   let donor: IKeyringPair;
 
@@ -502,4 +562,38 @@ describe('Change substrate owner tests', () => {
 
     expect(await collectionEvm.isOwnerOrAdminCross.staticCall(newOwnerCross)).to.be.false;
   });
+
+  itEth('Propose and accept owner [cross]', async ({helper}) => {
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const newOwner = await helper.eth.createAccountWithBalance(donor);
+    const newOwnerCross = helper.ethCrossAccount.fromAddress(newOwner);
+    const {collectionAddress, collectionId} = await helper.eth.createNFTCollection(owner, 'A', 'B', 'C');
+    const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+
+    await (await collectionEvm.proposeCollectionOwnerCross.send(newOwnerCross)).wait(...waitParams);
+    const pendingOwner = await collectionEvm.collectionPendingOwner.staticCall();
+    expect(helper.address.restoreCrossAccountFromBigInt(BigInt(pendingOwner.sub))).to.be.eq(helper.address.ethToSubstrate(newOwner, true));
+    expect(await collectionEvm.isOwnerOrAdminCross.staticCall(newOwnerCross)).to.be.false;
+
+    await (await (<Contract>collectionEvm.connect(newOwner)).acceptCollectionOwnership.send()).wait(...waitParams);
+    expect(await collectionEvm.isOwnerOrAdminCross.staticCall(newOwnerCross)).to.be.true;
+    expect((await collectionEvm.collectionPendingOwner.staticCall()).sub).to.be.eq(0n);
+    expect(await helper.collection.getData(collectionId))
+      .to.have.property('normalizedOwner').that.is.eq(helper.address.ethToSubstrate(newOwner));
+  });
+
+  itEth('(!negative tests!) accept owner by not proposed account [cross]', async ({helper}) => {
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const newOwner = await helper.eth.createAccountWithBalance(donor);
+    const other = await helper.eth.createAccountWithBalance(donor);
+    const {collectionAddress} = await helper.eth.createNFTCollection(owner, 'A', 'B', 'C');
+    const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+
+    await (await collectionEvm.proposeCollectionOwnerCross.send(helper.ethCrossAccount.fromAddress(newOwner))).wait(...waitParams);
+    await expect((<Contract>collectionEvm.connect(other)).acceptCollectionOwnership.send()).to.be.rejected;
+
+    await (await collectionEvm.cancelCollectionOwnershipTransfer.send()).wait(...waitParams);
+    await expect((<Contract>collectionEvm.connect(newOwner)).acceptCollectionOwnership.send()).to.be.rejected;
+    expect(await collectionEvm.isOwnerOrAdminCross.staticCall(helper.ethCrossAccount.fromAddress(owner))).to.be.true;
+  });
 });
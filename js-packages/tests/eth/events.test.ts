@@ -488,6 +488,33 @@ async function testTokenPropertySetAndDeleted(helper: EthUniqueHelper, mode: TCo
   unsubscribe();
 }
 
+async function testPermanentURI(helper: EthUniqueHelper, mode: TCollectionMode) {
+  const owner = await helper.eth.createAccountWithBalance(donor);
+  const {collectionAddress, collectionId} = await helper.eth.createCollection(owner, new CreateCollectionData('A', 'B', 'C', mode, 18)).send();
+  const collection = await helper.ethNativeContract.collection(collectionAddress, mode, owner);
+  const mintReceipt = await (await collection.mint.send(owner)).wait(...waitParams);
+  const tokenId = helper.eth.normalizeEvents(mintReceipt!).Transfer.args.tokenId;
+
+  await (await collection.addCollectionAdminCross.send(helper.ethCrossAccount.fromKeyringPair(donor))).wait(...waitParams);
+  await helper.collection.setProperties(donor, collectionId, [{key: 'URITemplate', value: 'https://example.com/{tokenId}.json'}]);
+
+  const ethEvents: any[] = [];
+  // eslint-disable-next-line @typescript-eslint/no-floating-promises
+  collection.on('PermanentURI', (value, id) => {
+    ethEvents.push({args: {value, id}});
+  });
+
+  const {unsubscribe, collectedEvents: subEvents} = await helper.subscribeEvents([{section: 'common', names: ['TokenPropertiesFrozen']}]);
+
+  await helper.nft.freezeTokenProperties(donor, collectionId, Number(tokenId));
+
+  await helper.wait.newBlocks(10);
+  expect(ethEvents).to.containSubset([{args: {value: `https://example.com/${tokenId}.json`, id: BigInt(tokenId)}}]);
+  expect(subEvents).to.containSubset([{method: 'TokenPropertiesFrozen'}]);
+
+  unsubscribe();
+}
+
 describe('[FT] Sync sub & eth events', () => {
   const mode: TCollectionMode = 'ft';
 
@@ -566,6 +593,10 @@ describe('[NFT] Sync sub & eth events', () => {
   itEth('CollectionChanged event for TokenPropertySet, TokenPropertyDeleted', async ({helper}) => {
     await testTokenPropertySetAndDeleted(helper, mode);
   });
+
+  itEth('PermanentURI event for TokenPropertiesFrozen', async ({helper}) => {
+    await testPermanentURI(helper, mode);
+  });
 });
 
 describe('[RFT] Sync sub & eth events', () => {
@@ -617,4 +648,8 @@ describe('[RFT] Sync sub & eth events', () => {
   itEth('CollectionChanged event for TokenPropertySet, TokenPropertyDeleted', async ({helper}) => {
     await testTokenPropertySetAndDeleted(helper, mode);
   });
+
+  itEth('PermanentURI event for TokenPropertiesFrozen', async ({helper}) => {
+    await testPermanentURI(helper, mode);
+  });
 });
@@ -498,6 +498,18 @@ describe('Fungible: Plain calls', () => {
     expect(await collectionEvm.balanceOfCross.staticCall(ownerCross)).to.be.eq(100n);
     expect(await collectionEvm.balanceOfCross.staticCall(otherCross)).to.be.eq(0n);
   });
+
+  itEth('Check cap()', async ({helper}) => {
+    const caller = await helper.eth.createAccountWithBalance(donor);
+    const collection = await helper.ft.mintCollection(alice, {});
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'ft', caller);
+
+    expect(await collectionEvm.cap.staticCall()).to.be.eq((1n << 128n) - 1n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 1000n]);
+    expect(await collectionEvm.cap.staticCall()).to.be.eq(1000n);
+  });
 });
 
 describe('Fungible: Fees', () => {
@@ -78,6 +78,12 @@ describe('Check ERC721 token URI for NFT', () => {
     const {contract, nextTokenId} = await setup(helper, 'BaseURI_', 'URISuffix', suffix);
     expect(await contract.tokenURI.staticCall(nextTokenId)).to.be.equal('BaseURI_' + suffix);
   });
+
+  itEth('TokenURI from URITemplate', async ({helper}) => {
+    const {contract, nextTokenId} = await setup(helper, 'BaseURI_', 'URISuffix', 'suffix');
+    await (await contract.setCollectionProperties.send([{key: 'URITemplate', value: Buffer.from('{baseURI}{tokenId}/{prop:URISuffix}.json')}])).wait(...waitParams);
+    expect(await contract.tokenURI.staticCall(nextTokenId)).to.be.equal(`BaseURI_${nextTokenId}/suffix.json`);
+  });
 });
 
 describe('NFT: Plain calls', () => {
@@ -791,6 +797,38 @@ describe('NFT: Plain calls', () => {
     }
   });
 
+  itEth('Can perform transferBatch()', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const receiverEth = await helper.eth.createAccountWithBalance(donor);
+    const receiverCrossEth = helper.ethCrossAccount.fromAddress(receiverEth);
+    const receiverCrossSub = helper.ethCrossAccount.fromKeyringPair(minter);
+
+    const first = await collection.mintToken(minter, {Ethereum: owner.address});
+    const second = await collection.mintToken(minter, {Ethereum: owner.address});
+    const foreign = await collection.mintToken(minter);
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+
+    // Nothing is transferred if one of the tokens is not owned
+    await expect(collectionEvm.transferBatch.staticCall([
+      {to: receiverCrossEth, tokenId: first.tokenId},
+      {to: receiverCrossEth, tokenId: foreign.tokenId},
+    ])).to.be.rejectedWith('NoPermission');
+
+    const transferTx = await collectionEvm.transferBatch.send([
+      {to: receiverCrossEth, tokenId: first.tokenId},
+      {to: receiverCrossSub, tokenId: second.tokenId},
+    ]);
+    const transferReceipt = await transferTx.wait(...waitParams);
+    expect(transferReceipt!.logs.length).to.be.equal(2);
+
+    expect(await first.getOwner()).to.deep.eq({Ethereum: receiverEth.address.toLowerCase()});
+    expect(await second.getOwner()).to.deep.eq({Substrate: minter.address});
+    expect(await collectionEvm.balanceOf.staticCall(owner.address)).to.equal(0n);
+  });
+
   ['transfer', 'transferCross'].map(testCase => itEth(`Cannot ${testCase} non-owned token`, async ({helper}) => {
     const sender = await helper.eth.createAccountWithBalance(donor);
     const tokenOwner = await helper.eth.createAccountWithBalance(donor);
@@ -828,6 +866,228 @@ describe('NFT: Plain calls', () => {
     }
   });
 
+  itEth('Can enumerate tokens with tokenByIndex() and tokenOfOwnerByIndex()', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const receiver = helper.eth.createAccount();
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+
+    const tokenIds = [];
+    for(let i = 0; i < 4; i++) {
+      const {tokenId} = await collection.mintToken(minter, {Ethereum: owner.address});
+      tokenIds.push(BigInt(tokenId));
+    }
+
+    await (await contract.burn.send(tokenIds[0])).wait(...waitParams);
+    await (await contract.transfer.send(receiver, tokenIds[1])).wait(...waitParams);
+
+    const enumerate = async (count: bigint, get: (index: bigint) => Promise<bigint>) => {
+      const tokens = [];
+      for(let i = 0n; i < count; i++) tokens.push(await get(i));
+      return tokens.sort((a, b) => (a < b ? -1 : 1));
+    };
+
+    const totalSupply = await contract.totalSupply.staticCall();
+    expect(totalSupply).to.equal(3n);
+    expect(await enumerate(totalSupply, i => contract.tokenByIndex.staticCall(i)))
+      .to.deep.equal([tokenIds[1], tokenIds[2], tokenIds[3]]);
+
+    const ownerBalance = await contract.balanceOf.staticCall(owner);
+    expect(await enumerate(ownerBalance, i => contract.tokenOfOwnerByIndex.staticCall(owner, i)))
+      .to.deep.equal([tokenIds[2], tokenIds[3]]);
+    expect(await contract.tokenOfOwnerByIndex.staticCall(receiver, 0n)).to.equal(tokenIds[1]);
+
+    await expect(contract.tokenByIndex.staticCall(totalSupply)).to.be.rejectedWith('index out of bounds');
+    await expect(contract.tokenOfOwnerByIndex.staticCall(receiver, 1n)).to.be.rejectedWith('index out of bounds');
+  });
+
+  itEth('Can perform safeTransferFrom()', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const owner = await helper.eth.createAccountWithBalance(donor);
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+
+    const deployReceiver = async (accept: boolean) => await helper.ethContract.deployByCode(owner, 'Receiver', `
+      // SPDX-License-Identifier: UNLICENSED
+      pragma solidity ^0.8.6;
+
+      contract Receiver {
+        event Received(address operator, address from, uint256 tokenId, bytes data);
+
+        function onERC721Received(address operator, address from, uint256 tokenId, bytes calldata data) external returns (bytes4) {
+          require(${accept}, "Receiver: rejected");
+          emit Received(operator, from, tokenId, data);
+          return this.onERC721Received.selector;
+        }
+      }
+    `);
+    const acceptingReceiver = await deployReceiver(true);
+    const rejectingReceiver = await deployReceiver(false);
+    const eoaReceiver = helper.eth.createAccount();
+
+    const {tokenId: firstToken} = await collection.mintToken(minter, {Ethereum: owner.address});
+    await (await contract['safeTransferFrom(address,address,uint256)'].send(owner, eoaReceiver, firstToken)).wait(...waitParams);
+    expect(await contract.ownerOf.staticCall(firstToken)).to.equal(eoaReceiver.address);
+
+    const {tokenId: secondToken} = await collection.mintToken(minter, {Ethereum: owner.address});
+    const data = '0x01020304';
+    const receipt = await (await contract['safeTransferFrom(address,address,uint256,bytes)'].send(owner, acceptingReceiver, secondToken, data)).wait(...waitParams);
+    const received = acceptingReceiver.interface.parseLog(receipt!.logs.find(log => log.address === acceptingReceiver.target)!)!;
+    expect(received.args.operator).to.equal(owner.address);
+    expect(received.args.from).to.equal(owner.address);
+    expect(received.args.tokenId).to.equal(BigInt(secondToken));
+    expect(received.args.data).to.equal(data);
+    expect(await contract.ownerOf.staticCall(secondToken)).to.equal(acceptingReceiver.target);
+
+    const {tokenId: thirdToken} = await collection.mintToken(minter, {Ethereum: owner.address});
+    await expect(contract['safeTransferFrom(address,address,uint256)'].send(owner, rejectingReceiver, thirdToken)).to.be.rejected;
+    expect(await contract.ownerOf.staticCall(thirdToken)).to.equal(owner.address);
+  });
+
+  itEth('Can set royalties and query royaltyInfo()', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const admin = await helper.eth.createAccountWithBalance(donor);
+    await collection.addAdmin(minter, {Ethereum: admin.address});
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'nft', admin);
+
+    const {tokenId: firstToken} = await collection.mintToken(minter, {Ethereum: admin.address});
+    const {tokenId: secondToken} = await collection.mintToken(minter, {Ethereum: admin.address});
+
+    const collectionReceiver = helper.eth.createAccount();
+    const tokenReceiver = helper.eth.createAccount();
+    const royalty = (receiver: string, basisPoints: number) => ({receiver: helper.ethCrossAccount.fromAddress(receiver), basisPoints});
+
+    let info = await contract.royaltyInfo.staticCall(firstToken, 10000n);
+    expect(info.receiver).to.equal('0x0000000000000000000000000000000000000000');
+    expect(info.royaltyAmount).to.equal(0n);
+
+    await (await contract.setCollectionRoyalty.send(royalty(collectionReceiver.address, 250))).wait(...waitParams);
+    await (await contract.setTokenRoyalty.send(secondToken, royalty(tokenReceiver.address, 1000))).wait(...waitParams);
+
+    info = await contract.royaltyInfo.staticCall(firstToken, 10000n);
+    expect(info.receiver).to.equal(collectionReceiver.address);
+    expect(info.royaltyAmount).to.equal(250n);
+    info = await contract.royaltyInfo.staticCall(secondToken, 10000n);
+    expect(info.receiver).to.equal(tokenReceiver.address);
+    expect(info.royaltyAmount).to.equal(1000n);
+
+    await (await contract.deleteTokenRoyalty.send(secondToken)).wait(...waitParams);
+    info = await contract.royaltyInfo.staticCall(secondToken, 10000n);
+    expect(info.receiver).to.equal(collectionReceiver.address);
+
+    await expect(contract.setCollectionRoyalty.send(royalty(collectionReceiver.address, 10001))).to.be.rejected;
+    const nonAdminContract = helper.eth.changeContractCaller(contract, await helper.eth.createAccountWithBalance(donor));
+    await expect(nonAdminContract.setCollectionRoyalty.send(royalty(collectionReceiver.address, 100))).to.be.rejected;
+  });
+
+  itEth('Can lock tokens against transfers', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const admin = await helper.eth.createAccountWithBalance(donor);
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const receiver = helper.eth.createAccount();
+    await collection.addAdmin(minter, {Ethereum: admin.address});
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const adminContract = await helper.ethNativeContract.collection(collectionAddress, 'nft', admin);
+    const ownerContract = helper.eth.changeContractCaller(adminContract, owner);
+
+    const {tokenId: lockedToken} = await collection.mintToken(minter, {Ethereum: owner.address});
+    const {tokenId: burntToken} = await collection.mintToken(minter, {Ethereum: owner.address});
+    expect(await adminContract.locked.staticCall(lockedToken)).to.be.false;
+
+    const lockReceipt = await (await adminContract.setLocked.send(lockedToken, true)).wait(...waitParams);
+    const lockEvents = helper.eth.normalizeEvents(lockReceipt!);
+    expect(lockEvents.Locked.address).to.be.equal(collectionAddress);
+    expect(lockEvents.Locked.args.tokenId).to.be.equal(lockedToken.toString());
+    await (await adminContract.setLocked.send(burntToken, true)).wait(...waitParams);
+
+    expect(await adminContract.locked.staticCall(lockedToken)).to.be.true;
+    await expect(ownerContract.transfer.send(receiver.address, lockedToken)).to.be.rejected;
+    await expect(ownerContract.setLocked.send(lockedToken, false)).to.be.rejected;
+
+    await (await ownerContract.burn.send(burntToken)).wait(...waitParams);
+    expect(await collection.doesTokenExist(burntToken)).to.be.false;
+
+    await (await adminContract.setLocked.send(lockedToken, false)).wait(...waitParams);
+    expect(await adminContract.locked.staticCall(lockedToken)).to.be.false;
+    await (await ownerContract.transfer.send(receiver.address, lockedToken)).wait(...waitParams);
+    expect(await adminContract.ownerOf.staticCall(lockedToken)).to.equal(receiver.address);
+  });
+
+  itEth('Can set token user, which is removed on transfer', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const operator = await helper.eth.createAccountWithBalance(donor);
+    const user = helper.eth.createAccount();
+    const receiver = helper.eth.createAccount();
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const ownerContract = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner);
+    const operatorContract = helper.eth.changeContractCaller(ownerContract, operator);
+
+    const {tokenId} = await collection.mintToken(minter, {Ethereum: owner.address});
+    expect(await ownerContract.userOf.staticCall(tokenId)).to.be.equal('0x0000000000000000000000000000000000000000');
+
+    const expires = BigInt(await helper.chain.getLatestBlockNumber()) + 1000n;
+    await expect(operatorContract.setUser.send(tokenId, user.address, expires)).to.be.rejected;
+    await (await ownerContract.setApprovalForAll.send(operator.address, true)).wait(...waitParams);
+
+    const receipt = await (await operatorContract.setUser.send(tokenId, user.address, expires)).wait(...waitParams);
+    const events = helper.eth.normalizeEvents(receipt!);
+    expect(events.UpdateUser.address).to.be.equal(collectionAddress);
+    expect(events.UpdateUser.args).to.be.like({
+      tokenId: tokenId.toString(),
+      user: user.address,
+      expires: expires.toString(),
+    });
+    expect(await ownerContract.userOf.staticCall(tokenId)).to.be.equal(user.address);
+    expect(await ownerContract.userExpires.staticCall(tokenId)).to.be.equal(expires);
+    expect((await collection.getToken(tokenId))?.user).to.be.not.null;
+
+    await (await ownerContract.transfer.send(receiver.address, tokenId)).wait(...waitParams);
+    expect(await ownerContract.userOf.staticCall(tokenId)).to.be.equal('0x0000000000000000000000000000000000000000');
+    expect(await ownerContract.userExpires.staticCall(tokenId)).to.be.equal(0n);
+    expect((await collection.getToken(tokenId))?.user).to.be.null;
+  });
+
+  itEth('Can freeze tokens and accounts', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(minter, {});
+    const admin = await helper.eth.createAccountWithBalance(donor);
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const receiver = helper.eth.createAccount();
+    await collection.addAdmin(minter, {Ethereum: admin.address});
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const adminContract = await helper.ethNativeContract.collection(collectionAddress, 'nft', admin);
+    const ownerContract = helper.eth.changeContractCaller(adminContract, owner);
+    const ownerCross = helper.ethCrossAccount.fromAddress(owner);
+
+    const {tokenId} = await collection.mintToken(minter, {Ethereum: owner.address});
+    expect(await adminContract.isTokenFrozen.staticCall(tokenId)).to.be.false;
+
+    await (await adminContract.freezeToken.send(tokenId)).wait(...waitParams);
+    expect(await adminContract.isTokenFrozen.staticCall(tokenId)).to.be.true;
+    await expect(ownerContract.transfer.send(receiver.address, tokenId)).to.be.rejected;
+    await expect(ownerContract.approve.send(receiver.address, tokenId)).to.be.rejected;
+    await expect(ownerContract.thawToken.send(tokenId)).to.be.rejected;
+    await (await adminContract.thawToken.send(tokenId)).wait(...waitParams);
+
+    await (await adminContract.freezeAccountCross.send(ownerCross)).wait(...waitParams);
+    expect(await adminContract.isAccountFrozenCross.staticCall(ownerCross)).to.be.true;
+    await expect(ownerContract.transfer.send(receiver.address, tokenId)).to.be.rejected;
+    await expect(ownerContract.approve.send(receiver.address, tokenId)).to.be.rejected;
+    await (await adminContract.thawAccountCross.send(ownerCross)).wait(...waitParams);
+    expect(await adminContract.isAccountFrozenCross.staticCall(ownerCross)).to.be.false;
+
+    await (await ownerContract.transfer.send(receiver.address, tokenId)).wait(...waitParams);
+    expect(await adminContract.ownerOf.staticCall(tokenId)).to.equal(receiver.address);
+  });
+
   itEth('Check ownerOfCross()', async ({helper}) => {
     const collection = await helper.nft.mintCollection(minter, {});
 
@@ -0,0 +1,147 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import {Signature, TypedDataEncoder, getBytes} from 'ethers';
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itEth, usingEthPlaygrounds, waitParams} from '@unique/test-utils/eth/util.js';
+import type {EthUniqueHelper} from '@unique/test-utils/eth/index.js';
+import {Pallets} from '@unique/test-utils/util.js';
+
+const PERMIT_TYPES = {
+  Permit: [
+    {name: 'owner', type: 'address'},
+    {name: 'spender', type: 'address'},
+    {name: 'value', type: 'uint256'},
+    {name: 'nonce', type: 'uint256'},
+    {name: 'deadline', type: 'uint256'},
+  ],
+};
+
+const PERMIT_CROSS_TYPES = {
+  PermitCross: [
+    {name: 'owner', type: 'CrossAddress'},
+    {name: 'spender', type: 'CrossAddress'},
+    {name: 'value', type: 'uint256'},
+    {name: 'nonce', type: 'uint256'},
+    {name: 'deadline', type: 'uint256'},
+  ],
+  CrossAddress: [
+    {name: 'eth', type: 'address'},
+    {name: 'sub', type: 'uint256'},
+  ],
+};
+
+describe('EIP-2612 permit', () => {
+  let donor: IKeyringPair;
+  let alice: IKeyringPair;
+
+  before(async function() {
+    await usingEthPlaygrounds(async (helper, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+      [alice] = await helper.arrange.createAccounts([100n], donor);
+    });
+  });
+
+  async function domain(helper: EthUniqueHelper, name: string, verifyingContract: string) {
+    const {chainId} = await helper.getWeb3().getNetwork();
+    return {name, version: '1', chainId, verifyingContract};
+  }
+
+  itEth('Fungible: permit() sets the allowance signed by the ethereum owner', async ({helper}) => {
+    const owner = helper.eth.createAccount();
+    const spender = await helper.eth.createAccountWithBalance(donor);
+    const collection = await helper.ft.mintCollection(alice, {name: 'Permit', description: 'descr', tokenPrefix: 'PRM'});
+    await collection.mint(alice, 200n, {Ethereum: owner.address});
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'ft', spender);
+    const permitDomain = await domain(helper, 'Permit', collectionAddress);
+    expect(await contract.DOMAIN_SEPARATOR.staticCall()).to.be.equal(TypedDataEncoder.hashDomain(permitDomain));
+    expect(await contract.nonces.staticCall(owner.address)).to.be.equal(0n);
+
+    const deadline = BigInt(Math.floor(Date.now() / 1000) + 3600);
+    const message = {owner: owner.address, spender: spender.address, value: 100n, nonce: 0n, deadline};
+    const {v, r, s} = Signature.from(await owner.signTypedData(permitDomain, PERMIT_TYPES, message));
+
+    await (await contract.permit.send(owner.address, spender.address, 100n, deadline, v, r, s)).wait(...waitParams);
+    expect(await contract.allowance.staticCall(owner.address, spender.address)).to.be.equal(100n);
+    expect(await contract.nonces.staticCall(owner.address)).to.be.equal(1n);
+
+    // The same signature can't be used twice
+    await expect(contract.permit.staticCall(owner.address, spender.address, 100n, deadline, v, r, s))
+      .to.be.rejectedWith('InvalidPermitSignature');
+  });
+
+  itEth('Fungible: permit() rejects expired permits', async ({helper}) => {
+    const owner = helper.eth.createAccount();
+    const spender = await helper.eth.createAccountWithBalance(donor);
+    const collection = await helper.ft.mintCollection(alice, {name: 'Permit', description: 'descr', tokenPrefix: 'PRM'});
+    await collection.mint(alice, 200n, {Ethereum: owner.address});
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'ft', spender);
+
+    const deadline = BigInt(Math.floor(Date.now() / 1000) - 3600);
+    const message = {owner: owner.address, spender: spender.address, value: 100n, nonce: 0n, deadline};
+    const permitDomain = await domain(helper, 'Permit', collectionAddress);
+    const {v, r, s} = Signature.from(await owner.signTypedData(permitDomain, PERMIT_TYPES, message));
+
+    await expect(contract.permit.staticCall(owner.address, spender.address, 100n, deadline, v, r, s))
+      .to.be.rejectedWith('PermitDeadlinePassed');
+  });
+
+  itEth('Fungible: permitCross() sets the allowance signed by the substrate owner', async ({helper}) => {
+    const spender = await helper.eth.createAccountWithBalance(donor);
+    const collection = await helper.ft.mintCollection(alice, {name: 'Permit', description: 'descr', tokenPrefix: 'PRM'});
+    await collection.mint(alice, 200n);
+
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'ft', spender);
+    const ownerCross = helper.ethCrossAccount.fromKeyringPair(alice);
+    const spenderCross = helper.ethCrossAccount.fromAddress(spender);
+
+    const deadline = BigInt(Math.floor(Date.now() / 1000) + 3600);
+    const message = {owner: ownerCross, spender: spenderCross, value: 100n, nonce: 0n, deadline};
+    const digest = TypedDataEncoder.hash(await domain(helper, 'Permit', collectionAddress), PERMIT_CROSS_TYPES, message);
+    const signature = alice.sign(getBytes(digest));
+
+    await (await contract.permitCross.send(ownerCross, spenderCross, 100n, deadline, signature)).wait(...waitParams);
+    expect(await contract.allowanceCross.staticCall(ownerCross, spenderCross)).to.be.equal(100n);
+
+    await (await contract.transferFromCross.send(ownerCross, spenderCross, 60n)).wait(...waitParams);
+    expect(await collection.getBalance({Ethereum: spender.address})).to.be.equal(60n);
+  });
+
+  itEth.ifWithPallets('Refungible: permit() sets the allowance of the token pieces', [Pallets.ReFungible], async ({helper}) => {
+    const owner = helper.eth.createAccount();
+    const spender = await helper.eth.createAccountWithBalance(donor);
+    const collection = await helper.rft.mintCollection(alice, {name: 'Permit', description: 'descr', tokenPrefix: 'PRM'});
+    const {tokenId} = await collection.mintToken(alice, 200n, {Ethereum: owner.address});
+
+    const tokenAddress = helper.ethAddress.fromTokenId(collection.collectionId, tokenId);
+    const contract = helper.ethNativeContract.rftToken(tokenAddress, spender);
+    const permitDomain = await domain(helper, 'Permit', tokenAddress);
+    expect(await contract.DOMAIN_SEPARATOR.staticCall()).to.be.equal(TypedDataEncoder.hashDomain(permitDomain));
+
+    const deadline = BigInt(Math.floor(Date.now() / 1000) + 3600);
+    const message = {owner: owner.address, spender: spender.address, value: 50n, nonce: 0n, deadline};
+    const {v, r, s} = Signature.from(await owner.signTypedData(permitDomain, PERMIT_TYPES, message));
+
+    await (await contract.permit.send(owner.address, spender.address, 50n, deadline, v, r, s)).wait(...waitParams);
+    expect(await contract.allowance.staticCall(owner.address, spender.address)).to.be.equal(50n);
+    expect(await contract.nonces.staticCall(owner.address)).to.be.equal(1n);
+  });
+});
@@ -781,6 +781,27 @@ describe('Refungible: Plain calls', () => {
     }
   });
 
+  itEth('tokenOfOwnerByIndex() skips tokens minted with zero pieces', async ({helper}) => {
+    const collection = await helper.rft.mintCollection(minter, {});
+    const owner = helper.eth.createAccount();
+    const zeroReceiver = helper.eth.createAccount();
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'rft', bob);
+
+    await helper.executeExtrinsic(minter, 'api.tx.unique.createMultipleItemsEx', [collection.collectionId, {
+      RefungibleMultipleOwners: {
+        users: new Map([
+          [JSON.stringify({Ethereum: owner.address}), 10],
+          [JSON.stringify({Ethereum: zeroReceiver.address}), 0],
+        ]),
+        properties: [],
+      },
+    }], true);
+
+    expect(await contract.tokenOfOwnerByIndex.staticCall(owner, 0n)).to.equal(1n);
+    await expect(contract.tokenOfOwnerByIndex.staticCall(zeroReceiver, 0n)).to.be.rejectedWith('index out of bounds');
+  });
+
   itEth('Check ownerOfCross()', async ({helper}) => {
     const collection = await helper.rft.mintCollection(minter, {});
 
@@ -83,6 +83,12 @@ describe('Check ERC721 token URI for ReFungible', () => {
     const {contract, nextTokenId} = await setup(helper, 'BaseURI_', 'URISuffix', suffix);
     expect(await contract.tokenURI.staticCall(nextTokenId)).to.be.equal('BaseURI_' + suffix);
   });
+
+  itEth('TokenURI from URITemplate', async ({helper}) => {
+    const {contract, nextTokenId} = await setup(helper, 'BaseURI_', 'URISuffix', 'suffix');
+    await (await contract.setCollectionProperties.send([{key: 'URITemplate', value: Buffer.from('{baseURI}{tokenId}/{prop:URISuffix}.json')}])).wait(...waitParams);
+    expect(await contract.tokenURI.staticCall(nextTokenId)).to.be.equal(`BaseURI_${nextTokenId}/suffix.json`);
+  });
 });
 
 describe('Refungible: Plain calls', () => {
@@ -20,7 +20,7 @@ import {itEth, usingEthPlaygrounds, expect, waitParams, hexlifyString} from '@un
 import type {ITokenPropertyPermission} from '@unique-nft/playgrounds/types.js';
 import {Pallets} from '@unique/test-utils/util.js';
 import {UniqueNFTCollection, UniqueNFToken, UniqueRFTCollection} from '@unique-nft/playgrounds/unique.js';
-import {CreateCollectionData, TokenPermissionField} from '@unique/test-utils/eth/types.js';
+import {CreateCollectionData, PropertyValueKind, TokenPermissionField} from '@unique/test-utils/eth/types.js';
 
 describe('EVM token properties', () => {
   let donor: IKeyringPair;
@@ -342,6 +342,88 @@ describe('EVM token properties', () => {
       expect(result.length).to.equal(0);
     }));
 
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itEth.ifWithPallets(`Can be written by granted account for ${testCase.mode}`, testCase.requiredPallets, async({helper}) => {
+      const caller = await helper.eth.createAccountWithBalance(donor);
+      const oracle = await helper.eth.createAccountWithBalance(donor);
+      const crossOracle = helper.ethCrossAccount.fromAddress(oracle);
+      const collection = await helper[testCase.mode].mintCollection(alice, {
+        tokenPropertyPermissions: [{
+          key: 'score',
+          permission: {
+            mutable: true,
+          },
+        }],
+      });
+      const token = await collection.mintToken(alice);
+      await collection.addAdmin(alice, {Ethereum: caller.address});
+
+      const address = helper.ethAddress.fromCollectionId(collection.collectionId);
+      const contract = await helper.ethNativeContract.collection(address, testCase.mode, caller);
+      const oracleContract = helper.eth.changeContractCaller(contract, oracle);
+
+      await expect(oracleContract.setProperties.send(token.tokenId, [{key: 'score', value: Buffer.from('10')}])).to.be.rejected;
+
+      await (await contract.grantTokenPropertyWriteCross.send(crossOracle, ['score'])).wait(...waitParams);
+      expect((await contract.tokenPropertyWriteGrantsCross.staticCall(crossOracle)).toArray()).to.be.deep.equal(['score']);
+
+      await (await oracleContract.setProperties.send(token.tokenId, [{key: 'score', value: Buffer.from('10')}])).wait(...waitParams);
+      expect(await token.getProperties(['score'])).to.be.deep.equal([{key: 'score', value: '10'}]);
+
+      await (await contract.revokeTokenPropertyWriteCross.send(crossOracle, ['score'])).wait(...waitParams);
+      expect((await contract.tokenPropertyWriteGrantsCross.staticCall(crossOracle)).toArray()).to.be.deep.equal([]);
+      await expect(oracleContract.setProperties.send(token.tokenId, [{key: 'score', value: Buffer.from('20')}])).to.be.rejected;
+    }));
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itEth.ifWithPallets(`Can set typed token property permissions for ${testCase.mode}`, testCase.requiredPallets, async({helper}) => {
+      const caller = await helper.eth.createAccountWithBalance(donor);
+      const collection = await helper[testCase.mode].mintCollection(alice);
+      const token = await collection.mintToken(alice);
+      await collection.addAdmin(alice, {Ethereum: caller.address});
+
+      const address = helper.ethAddress.fromCollectionId(collection.collectionId);
+      const contract = await helper.ethNativeContract.collection(address, testCase.mode, caller);
+      const permissions = [[TokenPermissionField.Mutable, true], [TokenPermissionField.CollectionAdmin, true]];
+
+      await (await contract.setTypedTokenPropertyPermissions.send([
+        ['level', permissions, [PropertyValueKind.U32, 0, []]],
+        ['rarity', permissions, [PropertyValueKind.Enum, 0, [Buffer.from('common'), Buffer.from('rare')]]],
+      ])).wait(...waitParams);
+
+      expect(await collection.getPropertyPermissions(['level', 'rarity'])).to.be.deep.equal([
+        {key: 'level', permission: {mutable: true, collectionAdmin: true, tokenOwner: false}, valueType: 'U32'},
+        {key: 'rarity', permission: {mutable: true, collectionAdmin: true, tokenOwner: false}, valueType: {Enum: ['common', 'rare']}},
+      ]);
+
+      const ethPermissions = await contract.typedTokenPropertyPermissions.staticCall();
+      expect(ethPermissions.toArray(/* deep */ true)).to.be.deep.equal([
+        ['level', [
+          [BigInt(TokenPermissionField.Mutable), true],
+          [BigInt(TokenPermissionField.TokenOwner), false],
+          [BigInt(TokenPermissionField.CollectionAdmin), true]],
+          [BigInt(PropertyValueKind.U32), 0n, []],
+        ],
+        ['rarity', [
+          [BigInt(TokenPermissionField.Mutable), true],
+          [BigInt(TokenPermissionField.TokenOwner), false],
+          [BigInt(TokenPermissionField.CollectionAdmin), true]],
+          [BigInt(PropertyValueKind.Enum), 0n, [hexlifyString('common'), hexlifyString('rare')]],
+        ],
+      ]);
+
+      await (await contract.setProperties.send(token.tokenId, [{key: 'level', value: Buffer.from('42')}])).wait(...waitParams);
+      await (await contract.setProperties.send(token.tokenId, [{key: 'rarity', value: Buffer.from('rare')}])).wait(...waitParams);
+      await expect(contract.setProperties.send(token.tokenId, [{key: 'level', value: Buffer.from('high')}])).to.be.rejected;
+      await expect(contract.setProperties.send(token.tokenId, [{key: 'rarity', value: Buffer.from('legendary')}])).to.be.rejected;
+    }));
+
   itEth('Can be read', async({helper}) => {
     const caller = helper.eth.createAccount();
     const collection = await helper.nft.mintCollection(alice, {
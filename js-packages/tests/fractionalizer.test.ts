@@ -0,0 +1,99 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, requirePalletsOrSkip, usingPlaygrounds} from '@unique/test-utils/util.js';
+
+describe('Fractionalizer', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async function() {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      requirePalletsOrSkip(this, helper, [Pallets.ReFungible]);
+
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 100n], donor);
+    });
+  });
+
+  itSub('NFT can be fractionalized and redeemed by the holder of all pieces', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+
+    await helper.executeExtrinsic(alice, 'api.tx.fractionalizer.fractionalize', [nftCollection.collectionId, nft.tokenId, 100]);
+    const [rftCollectionId, rftTokenId] = (await helper.callRpc('api.rpc.unique.rftOfNft', [nftCollection.collectionId, nft.tokenId])).toJSON() as [number, number];
+    expect((await helper.callRpc('api.rpc.unique.nftOfRft', [rftCollectionId, rftTokenId])).toJSON())
+      .to.be.deep.equal([nftCollection.collectionId, nft.tokenId]);
+
+    const rftCollection = helper.rft.getCollectionObject(rftCollectionId);
+    const rft = rftCollection.getTokenObject(rftTokenId);
+    expect(await rft.getBalance({Substrate: alice.address})).to.be.equal(100n);
+    expect(await nft.getOwner()).to.not.be.deep.equal({Substrate: alice.address});
+
+    await rft.transfer(alice, {Substrate: bob.address}, 60n);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.fractionalizer.redeem', [rftCollectionId, rftTokenId]))
+      .to.be.rejectedWith('fractionalizer.InsufficientPieces');
+    await rft.transfer(alice, {Substrate: bob.address}, 40n);
+    await helper.executeExtrinsic(bob, 'api.tx.fractionalizer.redeem', [rftCollectionId, rftTokenId]);
+
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: bob.address});
+    expect(await rft.doesExist()).to.be.false;
+    expect((await helper.callRpc('api.rpc.unique.rftOfNft', [nftCollection.collectionId, nft.tokenId])).isNone).to.be.true;
+    expect((await helper.callRpc('api.rpc.unique.nftOfRft', [rftCollectionId, rftTokenId])).isNone).to.be.true;
+  });
+
+  itSub('Pieces are minted in a collection nobody can manage', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+    const otherNft = await nftCollection.mintToken(alice, {Substrate: bob.address});
+
+    await helper.executeExtrinsic(alice, 'api.tx.fractionalizer.fractionalize', [nftCollection.collectionId, nft.tokenId, 100]);
+    await helper.executeExtrinsic(bob, 'api.tx.fractionalizer.fractionalize', [nftCollection.collectionId, otherNft.tokenId, 10]);
+    const [rftCollectionId, rftTokenId] = (await helper.callRpc('api.rpc.unique.rftOfNft', [nftCollection.collectionId, nft.tokenId])).toJSON() as [number, number];
+    const [otherRftCollectionId] = (await helper.callRpc('api.rpc.unique.rftOfNft', [nftCollection.collectionId, otherNft.tokenId])).toJSON() as [number, number];
+    expect(otherRftCollectionId).to.be.equal(rftCollectionId);
+
+    const rftCollection = helper.rft.getCollectionObject(rftCollectionId);
+    const data = await rftCollection.getData();
+    expect(data?.normalizedOwner).to.not.be.oneOf([alice.address, bob.address]);
+    expect(data?.admins).to.be.empty;
+
+    await expect(rftCollection.burn(alice)).to.be.rejectedWith('common.NoPermission');
+    await expect(helper.rft.burnToken(bob, rftCollectionId, rftTokenId, 100n)).to.be.rejected;
+    expect(await rftCollection.getTokenObject(rftTokenId).getBalance({Substrate: alice.address})).to.be.equal(100n);
+  });
+
+  itSub('Negative: fractionalization terms are checked', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rftCollection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+    const bobNft = await nftCollection.mintToken(alice, {Substrate: bob.address});
+    const rft = await rftCollection.mintToken(alice, 10n);
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.fractionalizer.fractionalize', [rftCollection.collectionId, rft.tokenId, 10]))
+      .to.be.rejectedWith('fractionalizer.NftCollectionExpected');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.fractionalizer.fractionalize', [nftCollection.collectionId, nft.tokenId, 0]))
+      .to.be.rejectedWith('common.TokenValueTooLow');
+    // Alice doesn't own the NFT of Bob
+    await expect(helper.executeExtrinsic(alice, 'api.tx.fractionalizer.fractionalize', [nftCollection.collectionId, bobNft.tokenId, 10]))
+      .to.be.rejectedWith('common.NoPermission');
+    expect(await bobNft.getOwner()).to.be.deep.equal({Substrate: bob.address});
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.fractionalizer.redeem', [rftCollection.collectionId, rft.tokenId]))
+      .to.be.rejectedWith('fractionalizer.TokenNotFractionalized');
+  });
+});
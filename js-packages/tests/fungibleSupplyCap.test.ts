@@ -0,0 +1,103 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, usingPlaygrounds} from '@unique/test-utils/util.js';
+
+describe('Fungible supply cap and mint authority', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    });
+  });
+
+  itSub('Total supply can\'t exceed the supply cap', async ({helper}) => {
+    const collection = await helper.ft.mintCollection(alice);
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 1000n]);
+    expect((await helper.callRpc('api.rpc.unique.supplyCap', [collection.collectionId])).unwrap().toBigInt()).to.be.equal(1000n);
+
+    await collection.mint(alice, 600n);
+    await collection.mint(alice, 400n, {Substrate: bob.address});
+    await expect(collection.mint(alice, 1n)).to.be.rejectedWith('fungible.SupplyCapExceeded');
+
+    await collection.burnTokens(bob, 100n);
+    await collection.mint(alice, 100n);
+    expect(await collection.getTotalPieces()).to.be.equal(1000n);
+  });
+
+  itSub('Supply cap can be raised, lowered and removed', async ({helper}) => {
+    const collection = await helper.ft.mintCollection(alice);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.setSupplyCap', [collection.collectionId, 1000n]))
+      .to.be.rejectedWith('common.NoPermission');
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 1000n]);
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 2000n]);
+    expect((await helper.callRpc('api.rpc.unique.supplyCap', [collection.collectionId])).unwrap().toBigInt()).to.be.equal(2000n);
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 500n]);
+    expect((await helper.callRpc('api.rpc.unique.supplyCap', [collection.collectionId])).unwrap().toBigInt()).to.be.equal(500n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, null]);
+    expect((await helper.callRpc('api.rpc.unique.supplyCap', [collection.collectionId])).isNone).to.be.true;
+    await collection.mint(alice, 1000n);
+
+    const nftCollection = await helper.nft.mintCollection(alice);
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [nftCollection.collectionId, 1000n]))
+      .to.be.rejectedWith('common.NotFungibleCollection');
+  });
+
+  itSub('Supply cap can\'t be lower than the total supply', async ({helper}) => {
+    const collection = await helper.ft.mintCollection(alice);
+    await collection.mint(alice, 600n);
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 599n]))
+      .to.be.rejectedWith('common.SupplyCapBelowTotalSupply');
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setSupplyCap', [collection.collectionId, 600n]);
+    await expect(collection.mint(alice, 1n)).to.be.rejectedWith('fungible.SupplyCapExceeded');
+  });
+
+  itSub('Only the mint authority can mint and it can burn from any holder', async ({helper}) => {
+    const collection = await helper.ft.mintCollection(alice);
+    await collection.mint(alice, 100n, {Substrate: charlie.address});
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.setMintAuthority', [collection.collectionId, {Substrate: bob.address}]))
+      .to.be.rejectedWith('common.NoPermission');
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setMintAuthority', [collection.collectionId, {Substrate: bob.address}]);
+    expect((await helper.callRpc('api.rpc.unique.mintAuthority', [collection.collectionId])).toJSON())
+      .to.be.deep.equal({substrate: bob.address});
+
+    await expect(collection.mint(alice, 100n)).to.be.rejectedWith('common.NoPermission');
+    await collection.mint(bob, 100n, {Substrate: charlie.address});
+    expect(await collection.getBalance({Substrate: charlie.address})).to.be.equal(200n);
+
+    await collection.burnTokensFrom(bob, {Substrate: charlie.address}, 150n);
+    expect(await collection.getBalance({Substrate: charlie.address})).to.be.equal(50n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setMintAuthority', [collection.collectionId, null]);
+    expect((await helper.callRpc('api.rpc.unique.mintAuthority', [collection.collectionId])).isNone).to.be.true;
+    await expect(collection.mint(bob, 100n)).to.be.rejectedWith('common.PublicMintingNotAllowed');
+    await collection.mint(alice, 100n);
+  });
+
+  itSub('Negative: only fungible collections have a mint authority', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice);
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.setMintAuthority', [collection.collectionId, {Substrate: bob.address}]))
+      .to.be.rejectedWith('common.NotFungibleCollection');
+  });
+});
@@ -0,0 +1,115 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, usingPlaygrounds} from '@unique/test-utils/util.js';
+
+describe('Marketplace', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 1000n, 100n], donor);
+    });
+  });
+
+  itSub('NFT can be bought for native currency, royalty and market fee are split off', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+    await helper.executeExtrinsic(alice, 'api.tx.unique.setCollectionRoyalty', [
+      collection.collectionId,
+      {receiver: {Substrate: charlie.address}, basisPoints: 1000},
+    ]);
+    const price = 100n * helper.balance.getOneTokenNominal();
+
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [collection.collectionId, nft.tokenId, 1, 0, price]);
+    const listing = (await helper.callRpc('api.rpc.unique.marketListing', [collection.collectionId, nft.tokenId, {Substrate: alice.address}])).unwrap();
+    expect(listing.amount.toBigInt()).to.be.equal(1n);
+    expect(listing.currency.toNumber()).to.be.equal(0);
+    expect(listing.price.toBigInt()).to.be.equal(price);
+
+    const aliceBalanceBefore = await helper.balance.getSubstrate(alice.address);
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await helper.executeExtrinsic(bob, 'api.tx.marketplace.buy', [collection.collectionId, nft.tokenId, {Substrate: alice.address}, price]);
+
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: bob.address});
+    // 10% royalty, then 1% market fee of the rest
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore + price / 10n);
+    expect(await helper.balance.getSubstrate(alice.address)).to.be.equal(aliceBalanceBefore + price * 891n / 1000n);
+    expect((await helper.callRpc('api.rpc.unique.marketListing', [collection.collectionId, nft.tokenId, {Substrate: alice.address}])).isNone).to.be.true;
+  });
+
+  itSub.ifWithPallets('RFT pieces can be bought for fungible tokens', [Pallets.ReFungible], async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    const currency = await helper.ft.mintCollection(bob, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(bob, 1000n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [collection.collectionId, rft.tokenId, 30, currency.collectionId, 500]);
+    await helper.executeExtrinsic(bob, 'api.tx.marketplace.buy', [collection.collectionId, rft.tokenId, {Substrate: alice.address}, 500]);
+
+    expect(await rft.getBalance({Substrate: bob.address})).to.be.equal(30n);
+    expect(await rft.getBalance({Substrate: alice.address})).to.be.equal(70n);
+    expect(await currency.getBalance({Substrate: alice.address})).to.be.equal(495n);
+    expect(await currency.getBalance({Substrate: bob.address})).to.be.equal(500n);
+  });
+
+  itSub('Listing is cancelled when the token is transferred', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [collection.collectionId, nft.tokenId, 1, 0, 1]);
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.unique.transfer', [{Substrate: charlie.address}, collection.collectionId, nft.tokenId, 1]);
+    expect(result.events.find(e => e.event.section === 'marketplace' && e.event.method === 'ListingCancelled')).to.not.be.undefined;
+
+    expect((await helper.callRpc('api.rpc.unique.marketListings', [collection.collectionId, nft.tokenId])).toJSON()).to.be.deep.equal([]);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.buy', [collection.collectionId, nft.tokenId, {Substrate: alice.address}, 1]))
+      .to.be.rejectedWith('marketplace.ListingNotFound');
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+  });
+
+  itSub('Listing can be cancelled only by the seller', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [collection.collectionId, nft.tokenId, 1, 0, 1]);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.cancelListing', [collection.collectionId, nft.tokenId]))
+      .to.be.rejectedWith('marketplace.ListingNotFound');
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.cancelListing', [collection.collectionId, nft.tokenId]);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.buy', [collection.collectionId, nft.tokenId, {Substrate: alice.address}, 1]))
+      .to.be.rejectedWith('marketplace.ListingNotFound');
+  });
+
+  itSub('Negative: listing terms are checked', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await collection.mintToken(alice);
+    const ftCollection = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.list', [collection.collectionId, nft.tokenId, 1, 0, 1]))
+      .to.be.rejectedWith('common.TokenValueTooLow');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [collection.collectionId, nft.tokenId, 1, collection.collectionId, 1]))
+      .to.be.rejectedWith('marketplace.UnsupportedCurrency');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [ftCollection.collectionId, 0, 1, 0, 1]))
+      .to.be.rejectedWith('marketplace.CollectionNotListable');
+
+    await helper.executeExtrinsic(alice, 'api.tx.marketplace.list', [collection.collectionId, nft.tokenId, 1, 0, 10]);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.marketplace.buy', [collection.collectionId, nft.tokenId, {Substrate: alice.address}, 9]))
+      .to.be.rejectedWith('marketplace.PriceTooHigh');
+  });
+});
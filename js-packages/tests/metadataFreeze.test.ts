@@ -0,0 +1,171 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds, expect, itSub, Pallets} from '@unique/test-utils/util.js';
+import {NON_EXISTENT_COLLECTION_ID} from '@unique-nft/playgrounds/types.js';
+
+const permission = {mutable: true, collectionAdmin: true, tokenOwner: true};
+
+const tokenPropertyPermissions = [
+  {key: 'URI', permission},
+  {key: 'level', permission},
+];
+
+describe('Integration Test: permanent metadata freeze', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itSub.ifWithPallets(`[${testCase.mode}] Frozen token properties can't be changed`, testCase.requiredPallets, async ({helper}) => {
+      const collection = await helper[testCase.mode].mintCollection(alice, {tokenPropertyPermissions});
+      const token = await helper[testCase.mode].mintToken(alice, {
+        collectionId: collection.collectionId, owner: {Substrate: bob.address}, pieces: 1n,
+        properties: [{key: 'URI', value: 'ipfs://token'}],
+      });
+      const otherToken = await helper[testCase.mode].mintToken(alice, {
+        collectionId: collection.collectionId, owner: {Substrate: bob.address}, pieces: 1n,
+      });
+
+      expect(await token.arePropertiesFrozen()).to.be.false;
+      await token.freezeProperties(alice);
+      expect(await token.arePropertiesFrozen()).to.be.true;
+      expect(await otherToken.arePropertiesFrozen()).to.be.false;
+
+      await expect(token.setProperties(alice, [{key: 'level', value: '1'}])).to.be.rejectedWith(/common\.TokenPropertiesAreFrozen/);
+      await expect(token.setProperties(bob, [{key: 'URI', value: 'ipfs://other'}])).to.be.rejectedWith(/common\.TokenPropertiesAreFrozen/);
+      await expect(token.deleteProperties(alice, ['URI'])).to.be.rejectedWith(/common\.TokenPropertiesAreFrozen/);
+      expect(await token.getProperties()).to.be.deep.equal([{key: 'URI', value: 'ipfs://token'}]);
+
+      await otherToken.setProperties(alice, [{key: 'level', value: '1'}]);
+    }));
+
+  itSub('Frozen token properties are removed on burn', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await token.freezeProperties(alice);
+
+    await token.burn(bob);
+    expect(await token.arePropertiesFrozen()).to.be.false;
+  });
+
+  itSub('Token URI sources can\'t be changed while a token is frozen', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions: [...tokenPropertyPermissions, {key: 'name', permission}],
+      properties: [
+        {key: 'URITemplate', value: '{baseURI}/{cid}/{prop:level}'},
+        {key: 'baseURI', value: 'https://example.com'},
+        {key: 'cid', value: 'abc'},
+      ],
+    });
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await token.freezeProperties(alice);
+
+    await expect(collection.setProperties(alice, [{key: 'URITemplate', value: '{prop:name}'}])).to.be.rejectedWith(/common\.TokenUriIsFrozen/);
+    await expect(collection.setProperties(alice, [{key: 'baseURI', value: 'https://other.com'}])).to.be.rejectedWith(/common\.TokenUriIsFrozen/);
+    await expect(collection.deleteProperties(alice, ['cid'])).to.be.rejectedWith(/common\.TokenUriIsFrozen/);
+    await expect(collection.setDefaultTokenProperties(alice, [{key: 'level', value: '1'}])).to.be.rejectedWith(/common\.TokenUriIsFrozen/);
+    await expect(collection.setDefaultTokenProperties(alice, [{key: 'URI', value: 'ipfs://other'}])).to.be.rejectedWith(/common\.TokenUriIsFrozen/);
+    await expect(collection.setTokenPropertyPermissions(alice, [{key: 'level', permission: {mutable: false, collectionAdmin: true, tokenOwner: false}}])).to.be.rejectedWith(/common\.TokenUriIsFrozen/);
+
+    // The properties the token URI doesn't depend on can still be changed
+    await collection.setProperties(alice, [{key: 'description', value: 'changed'}]);
+    await collection.setDefaultTokenProperties(alice, [{key: 'name', value: 'default'}]);
+    await collection.setTokenPropertyPermissions(alice, [{key: 'name', permission: {mutable: false, collectionAdmin: true, tokenOwner: false}}]);
+
+    // The sources are unlocked after the frozen token is burnt
+    await token.burn(bob);
+    await collection.setProperties(alice, [{key: 'baseURI', value: 'https://other.com'}]);
+    await collection.setDefaultTokenProperties(alice, [{key: 'level', value: '1'}]);
+  });
+
+  itSub('Frozen collection metadata can\'t be changed', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions,
+      properties: [{key: 'baseURI', value: 'https://example.com/'}],
+    });
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+
+    expect(await collection.isMetadataFrozen()).to.be.false;
+    await collection.freezeMetadata(alice);
+    expect(await collection.isMetadataFrozen()).to.be.true;
+
+    await expect(collection.setProperties(alice, [{key: 'baseURI', value: 'https://other.com/'}])).to.be.rejectedWith(/common\.CollectionMetadataIsFrozen/);
+    await expect(collection.deleteProperties(alice, ['baseURI'])).to.be.rejectedWith(/common\.CollectionMetadataIsFrozen/);
+    await expect(collection.setTokenPropertyPermissions(alice, [{key: 'level', permission: {mutable: false, collectionAdmin: true, tokenOwner: false}}])).to.be.rejectedWith(/common\.CollectionMetadataIsFrozen/);
+    await expect(collection.setDefaultTokenProperties(alice, [{key: 'level', value: '1'}])).to.be.rejectedWith(/common\.CollectionMetadataIsFrozen/);
+    expect(await collection.getProperties(['baseURI'])).to.be.deep.equal([{key: 'baseURI', value: 'https://example.com/'}]);
+
+    // Token properties are frozen separately
+    await token.setProperties(alice, [{key: 'level', value: '1'}]);
+  });
+});
+
+describe('Negative Integration Test: permanent metadata freeze', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  itSub('Token owner can\'t freeze token properties', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+
+    await expect(token.freezeProperties(bob)).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Admin can\'t freeze collection metadata', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.addAdmin(alice, {Substrate: bob.address});
+
+    await expect(collection.freezeMetadata(bob)).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Can\'t freeze already frozen metadata', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions});
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await token.freezeProperties(alice);
+    await collection.freezeMetadata(alice);
+
+    await expect(token.freezeProperties(alice)).to.be.rejectedWith(/common\.TokenPropertiesAreFrozen/);
+    await expect(collection.freezeMetadata(alice)).to.be.rejectedWith(/common\.CollectionMetadataIsFrozen/);
+  });
+
+  itSub('Can\'t freeze properties of a non-existing token', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice);
+
+    await expect(collection.freezeTokenProperties(alice, 1)).to.be.rejectedWith(/common\.TokenNotFound/);
+  });
+
+  itSub('Can\'t freeze metadata of a non-existing collection', async ({helper}) => {
+    await expect(helper.collection.freezeCollectionMetadata(alice, NON_EXISTENT_COLLECTION_ID)).to.be.rejectedWith(/common\.CollectionNotFound/);
+  });
+});
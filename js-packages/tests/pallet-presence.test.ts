@@ -50,6 +50,10 @@ const requiredPallets = [
   'xtokens',
   'maintenance',
   'messagequeue',
+  'marketplace',
+  'fractionalizer',
+  'dividends',
+  'rftbuyout',
 ];
 
 // Pallets that depend on consensus and governance configuration
@@ -0,0 +1,171 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds, expect, itSub, Pallets} from '@unique/test-utils/util.js';
+import type {ITokenPropertyPermission} from '@unique-nft/playgrounds/types.js';
+
+const permission = {mutable: true, collectionAdmin: true, tokenOwner: false};
+
+const typedPermissions: ITokenPropertyPermission[] = [
+  {key: 'level', permission, valueType: 'U32'},
+  {key: 'supply', permission, valueType: 'U128'},
+  {key: 'active', permission, valueType: 'Bool'},
+  {key: 'title', permission, valueType: 'String'},
+  {key: 'image', permission, valueType: 'Uri'},
+  {key: 'creator', permission, valueType: 'Address'},
+  {key: 'tag', permission, valueType: {Bytes: 4}},
+  {key: 'rarity', permission, valueType: {Enum: ['common', 'rare']}},
+  {key: 'notes', permission},
+];
+
+describe('Integration Test: typed token properties', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itSub.ifWithPallets(`[${testCase.mode}] Value types are returned by the RPC`, testCase.requiredPallets, async ({helper}) => {
+      const collection = await helper[testCase.mode].mintCollection(alice, {tokenPropertyPermissions: typedPermissions});
+
+      expect(await collection.getPropertyPermissions(['level', 'tag', 'rarity', 'notes'])).to.be.deep.equal([
+        {key: 'level', permission, valueType: 'U32'},
+        {key: 'tag', permission, valueType: {Bytes: 4}},
+        {key: 'rarity', permission, valueType: {Enum: ['common', 'rare']}},
+        {key: 'notes', permission},
+      ]);
+
+      const data = await collection.getData();
+      expect(data?.raw.tokenPropertyPermissions).to.have.deep.members(typedPermissions);
+    }));
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itSub.ifWithPallets(`[${testCase.mode}] Values matching the type can be written`, testCase.requiredPallets, async ({helper}) => {
+      const collection = await helper[testCase.mode].mintCollection(alice, {tokenPropertyPermissions: typedPermissions});
+      const properties = [
+        {key: 'level', value: '4294967295'},
+        {key: 'supply', value: '340282366920938463463374607431768211455'},
+        {key: 'active', value: 'true'},
+        {key: 'title', value: 'Château'},
+        {key: 'image', value: 'ipfs://QmSUzq95L5zUgwyZXM71Wj7bxk6UpHQThc1bSie4wfpnT5'},
+        {key: 'creator', value: '0x17c4e6453cc49aaaaeaca894e6d9683e00000001'},
+        {key: 'tag', value: '0xdeadbeef'},
+        {key: 'rarity', value: 'rare'},
+        {key: 'notes', value: 'anything goes'},
+      ];
+
+      const token = await helper[testCase.mode].mintToken(alice, {
+        collectionId: collection.collectionId, owner: {Substrate: bob.address}, pieces: 1n, properties,
+      });
+      expect(await token.getProperties(['level', 'rarity'])).to.be.deep.equal([
+        {key: 'level', value: '4294967295'},
+        {key: 'rarity', value: 'rare'},
+      ]);
+
+      await token.setProperties(alice, [{key: 'rarity', value: 'common'}]);
+      await token.deleteProperties(alice, ['level']);
+    }));
+
+  itSub('Changing the value type applies to the next writes', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions: typedPermissions});
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+
+    // Permissions set without the value types keep the declared ones
+    await collection.setTokenPropertyPermissions(alice, [{key: 'level', permission}]);
+    expect(await collection.getPropertyPermissions(['level'])).to.be.deep.equal([{key: 'level', permission, valueType: 'U32'}]);
+
+    await collection.setTokenPropertyValueTypes(alice, [{key: 'level'}]);
+    expect(await collection.getPropertyPermissions(['level'])).to.be.deep.equal([{key: 'level', permission}]);
+    await token.setProperties(alice, [{key: 'level', value: 'high'}]);
+
+    await collection.setTokenPropertyValueTypes(alice, [{key: 'level', valueType: 'U64'}]);
+    await expect(token.setProperties(alice, [{key: 'level', value: 'higher'}])).to.be.rejectedWith(/common\.PropertyValueTypeMismatch/);
+    await token.setProperties(alice, [{key: 'level', value: '18446744073709551615'}]);
+  });
+});
+
+describe('Negative Integration Test: typed token properties', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  [
+    {key: 'level', value: '4294967296'},
+    {key: 'level', value: '-1'},
+    {key: 'level', value: ''},
+    {key: 'supply', value: '1e18'},
+    {key: 'active', value: 'True'},
+    {key: 'title', value: '0xff'},
+    {key: 'image', value: 'QmSUzq95L5zUgwyZXM71Wj7bxk6UpHQThc1bSie4wfpnT5'},
+    {key: 'image', value: 'https://example.com/a b'},
+    {key: 'creator', value: '0x17c4e6453cc49aaaaeaca894e6d9683e000000'},
+    {key: 'tag', value: '0xdeadbeef00'},
+    {key: 'rarity', value: 'legendary'},
+  ].map(property =>
+    itSub(`Can't write "${property.value}" to the ${property.key} property`, async ({helper}) => {
+      const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions: typedPermissions});
+      const token = await collection.mintToken(alice, {Substrate: bob.address});
+
+      await expect(token.setProperties(alice, [property])).to.be.rejectedWith(/common\.PropertyValueTypeMismatch/);
+      await expect(collection.mintToken(alice, {Substrate: bob.address}, [property])).to.be.rejectedWith(/common\.PropertyValueTypeMismatch/);
+    }));
+
+  itSub('Can\'t declare too many allowed values', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions: [{key: 'rarity', permission}]});
+    const values = Array.from({length: 17}, (_, i) => `value${i}`);
+
+    await expect(collection.setTokenPropertyValueTypes(alice, [{key: 'rarity', valueType: {Enum: values}}])).to.be.rejected;
+  });
+
+  itSub('Can\'t declare the type of a key without a permission', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice);
+
+    await expect(collection.setTokenPropertyValueTypes(alice, [{key: 'level', valueType: 'U32'}])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Can\'t change the type of an immutable key', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions: [{key: 'level', permission: {...permission, mutable: false}, valueType: 'U32'}],
+    });
+
+    await expect(collection.setTokenPropertyValueTypes(alice, [{key: 'level', valueType: 'U64'}])).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setTokenPropertyValueTypes(alice, [{key: 'level'}])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Non-admin can\'t declare value types', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {tokenPropertyPermissions: [{key: 'level', permission}]});
+
+    await expect(collection.setTokenPropertyValueTypes(bob, [{key: 'level', valueType: 'U32'}])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+});
@@ -0,0 +1,155 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, requirePalletsOrSkip, usingPlaygrounds} from '@unique/test-utils/util.js';
+
+const MAX_TEST_NOTICE_PERIOD = 100n;
+
+describe('RFT buyout', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async function() {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      requirePalletsOrSkip(this, helper, [Pallets.ReFungible]);
+
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  itSub('Buyout can be cancelled by the buyer during the notice period', async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    await rft.transfer(alice, {Substrate: bob.address}, 40n);
+    const aliceBalanceBefore = await helper.balance.getSubstrate(alice.address);
+
+    await helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, 0, 10n]);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.rftBuyout.cancelBuyout', [collection.collectionId, rft.tokenId]))
+      .to.be.rejectedWith('common.NoPermission');
+    await helper.executeExtrinsic(alice, 'api.tx.rftBuyout.cancelBuyout', [collection.collectionId, rft.tokenId]);
+
+    expect((await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).isNone).to.be.true;
+    // Only the fees are spent, the payment is returned
+    expect(aliceBalanceBefore - await helper.balance.getSubstrate(alice.address) < helper.balance.getOneTokenNominal()).to.be.true;
+    expect(await rft.getBalance({Substrate: bob.address})).to.be.equal(40n);
+  });
+
+  itSub('Negative: buyout terms are checked', async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    const nft = await nftCollection.mintToken(alice);
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, 0, 1]))
+      .to.be.rejectedWith('rftBuyout.BuyoutThresholdNotReached');
+    await rft.transfer(alice, {Substrate: bob.address}, 50n);
+    await expect(helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, 0, 1]))
+      .to.be.rejectedWith('rftBuyout.BuyoutThresholdNotReached');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [nftCollection.collectionId, nft.tokenId, 0, 1]))
+      .to.be.rejectedWith('rftBuyout.RftCollectionExpected');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, nftCollection.collectionId, 1]))
+      .to.be.rejectedWith('rftBuyout.UnsupportedCurrency');
+
+    await rft.transfer(bob, {Substrate: alice.address}, 10n);
+    await helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, 0, 1]);
+    await expect(helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, 0, 1]))
+      .to.be.rejectedWith('rftBuyout.BuyoutAlreadyExists');
+    await expect(helper.executeExtrinsic(bob, 'api.tx.rftBuyout.settleBuyout', [collection.collectionId, rft.tokenId]))
+      .to.be.rejectedWith('rftBuyout.BuyoutNotEnded');
+  });
+});
+
+describe('RFT buyout settlement', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async function() {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      requirePalletsOrSkip(this, helper, [Pallets.ReFungible]);
+      // The notice period lasts for days, unless the node is built with the buyout-test-timings feature
+      if((helper.getApi().consts.rftBuyout.noticePeriod as any).toBigInt() > MAX_TEST_NOTICE_PERIOD) this.skip();
+
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  itSub('Pieces of the other holders are bought out after the notice period', async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    await rft.transfer(alice, {Substrate: bob.address}, 30n);
+    await rft.transfer(alice, {Substrate: charlie.address}, 10n);
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, currency.collectionId, 5]);
+    const buyout = (await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).toJSON() as any;
+    expect(buyout.buyer).to.be.deep.equal({substrate: alice.address});
+    expect(buyout.deposit).to.be.equal(200);
+    expect(await currency.getBalance({Substrate: alice.address})).to.be.equal(800n);
+
+    await helper.wait.forParachainBlockNumber(BigInt(buyout.end) + 2n);
+
+    expect(await rft.getBalance({Substrate: alice.address})).to.be.equal(100n);
+    expect(await currency.getBalance({Substrate: bob.address})).to.be.equal(150n);
+    expect(await currency.getBalance({Substrate: charlie.address})).to.be.equal(50n);
+    expect((await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).isNone).to.be.true;
+  });
+
+  itSub('Buyout is cancelled, if the payment does not cover the pieces of the other holders', async ({helper}) => {
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    await rft.transfer(alice, {Substrate: bob.address}, 40n);
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, currency.collectionId, 5]);
+    const buyout = (await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).toJSON() as any;
+    // The payment covers only the pieces held by Bob at the start
+    await rft.transfer(alice, {Substrate: charlie.address}, 10n);
+
+    await helper.wait.forParachainBlockNumber(BigInt(buyout.end) + 2n);
+
+    expect((await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).isNone).to.be.true;
+    expect(await rft.getBalance({Substrate: bob.address})).to.be.equal(40n);
+    expect(await rft.getBalance({Substrate: charlie.address})).to.be.equal(10n);
+    expect(await currency.getBalance({Substrate: alice.address})).to.be.equal(1000n);
+  });
+
+  itSub('Buyout is cancelled, if the vault holds some of the pieces', async ({helper}) => {
+    const vault = helper.arrange.calculatePalletAddress('unq/rbuy');
+    const collection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await collection.mintToken(alice, 100n);
+    await rft.transfer(alice, {Substrate: bob.address}, 30n);
+    await rft.transfer(alice, {Substrate: vault}, 10n);
+    const currency = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await currency.mint(alice, 1000n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.rftBuyout.startBuyout', [collection.collectionId, rft.tokenId, currency.collectionId, 5]);
+    const buyout = (await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).toJSON() as any;
+
+    await helper.wait.forParachainBlockNumber(BigInt(buyout.end) + 2n);
+
+    expect((await helper.callRpc('api.rpc.unique.rftBuyout', [collection.collectionId, rft.tokenId])).isNone).to.be.true;
+    expect(await rft.getBalance({Substrate: bob.address})).to.be.equal(30n);
+    expect(await rft.getBalance({Substrate: vault})).to.be.equal(10n);
+    expect(await currency.getBalance({Substrate: alice.address})).to.be.equal(1000n);
+  });
+});
@@ -0,0 +1,79 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, usingPlaygrounds} from '@unique/test-utils/util.js';
+import {CrossAccountId} from '@unique-nft/playgrounds/unique.js';
+
+describe('Integration Test: Recursive burning', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([200n, 50n], donor);
+    });
+  });
+
+  itSub('Burns the token together with the whole nesting tree', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const collectionFT = await helper.ft.mintCollection(alice);
+    const root = await collection.mintToken(alice);
+    const child = await collection.mintToken(alice, root.nestingAccount());
+    const grandchild = await collection.mintToken(alice, child.nestingAccount());
+    await collectionFT.mint(alice, 10n, child.nestingAccount());
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.burnRecursively', [collection.collectionId, root.tokenId, true]);
+
+    expect(await root.doesExist()).to.be.false;
+    expect(await child.doesExist()).to.be.false;
+    expect(await grandchild.doesExist()).to.be.false;
+    expect(await collectionFT.getBalance(child.nestingAccount())).to.be.equal(0n);
+    expect(await collectionFT.getTotalPieces()).to.be.equal(0n);
+  });
+
+  itSub('Returns the nested tokens to the owner', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const collectionFT = await helper.ft.mintCollection(alice);
+    const root = await collection.mintToken(alice);
+    const child = await collection.mintToken(alice, root.nestingAccount());
+    const grandchild = await collection.mintToken(alice, child.nestingAccount());
+    await collectionFT.mint(alice, 10n, root.nestingAccount());
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.burnRecursively', [collection.collectionId, root.tokenId, false]);
+
+    expect(await root.doesExist()).to.be.false;
+    expect(await child.getOwner()).to.be.deep.equal({Substrate: alice.address});
+    // Only the direct children are returned, the deeper ones stay nested
+    expect(await grandchild.getOwner()).to.be.deep.equal(CrossAccountId.toLowerCase(child.nestingAccount()));
+    expect(await collectionFT.getBalance({Substrate: alice.address})).to.be.equal(10n);
+  });
+
+  itSub('Negative: only the owner can burn the token recursively', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const root = await collection.mintToken(alice);
+    const child = await collection.mintToken(alice, root.nestingAccount());
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.burnRecursively', [collection.collectionId, root.tokenId, true]))
+      .to.be.rejectedWith('common.NoPermission');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.burnRecursively', [collection.collectionId, child.tokenId, true]))
+      .to.be.rejectedWith('common.NoPermission');
+
+    expect(await root.doesExist()).to.be.true;
+    expect(await child.getOwner()).to.be.deep.equal(CrossAccountId.toLowerCase(root.nestingAccount()));
+  });
+});
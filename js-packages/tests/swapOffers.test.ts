@@ -0,0 +1,138 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, usingPlaygrounds} from '@unique/test-utils/util.js';
+
+describe('Swap offers', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  itSub('NFT can be swapped for fungible tokens', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+    const ftCollection = await helper.ft.mintCollection(bob, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await ftCollection.mint(bob, 100n);
+
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.unique.createSwapOffer', [
+      [{collection: nftCollection.collectionId, token: nft.tokenId, amount: 1}],
+      [{collection: ftCollection.collectionId, token: 0, amount: 10}],
+      null,
+      null,
+    ]);
+    const event = result.events.find(e => e.event.section === 'unique' && e.event.method === 'SwapOfferCreated');
+    const offerId = Number(event?.event.data[0].toString());
+    expect(await nft.getOwner()).to.not.be.deep.equal({Substrate: alice.address});
+
+    await helper.executeExtrinsic(bob, 'api.tx.unique.acceptSwapOffer', [offerId]);
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: bob.address});
+    expect(await ftCollection.getBalance({Substrate: alice.address})).to.be.equal(10n);
+    expect(await ftCollection.getBalance({Substrate: bob.address})).to.be.equal(90n);
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.acceptSwapOffer', [offerId])).to.be.rejectedWith('unique.SwapOfferNotFound');
+  });
+
+  itSub('NFT can be swapped for native currency', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+    const price = 10n * helper.balance.getOneTokenNominal();
+
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.unique.createSwapOffer', [
+      [{collection: nftCollection.collectionId, token: nft.tokenId, amount: 1}],
+      [{collection: 0, token: 0, amount: price}],
+      {Substrate: bob.address},
+      null,
+    ]);
+    const event = result.events.find(e => e.event.section === 'unique' && e.event.method === 'SwapOfferCreated');
+    const offerId = Number(event?.event.data[0].toString());
+
+    await expect(helper.executeExtrinsic(charlie, 'api.tx.unique.acceptSwapOffer', [offerId])).to.be.rejectedWith('common.NoPermission');
+
+    const aliceBalanceBefore = await helper.balance.getSubstrate(alice.address);
+    await helper.executeExtrinsic(bob, 'api.tx.unique.acceptSwapOffer', [offerId]);
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: bob.address});
+    expect(await helper.balance.getSubstrate(alice.address)).to.be.equal(aliceBalanceBefore + price);
+  });
+
+  itSub('Cancelled offer returns the assets and can not be accepted', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.unique.createSwapOffer', [
+      [{collection: nftCollection.collectionId, token: nft.tokenId, amount: 1}],
+      [{collection: 0, token: 0, amount: 1}],
+      null,
+      null,
+    ]);
+    const event = result.events.find(e => e.event.section === 'unique' && e.event.method === 'SwapOfferCreated');
+    const offerId = Number(event?.event.data[0].toString());
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.cancelSwapOffer', [offerId])).to.be.rejectedWith('common.NoPermission');
+    await helper.executeExtrinsic(alice, 'api.tx.unique.cancelSwapOffer', [offerId]);
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: alice.address});
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.acceptSwapOffer', [offerId])).to.be.rejectedWith('unique.SwapOfferNotFound');
+  });
+
+  itSub('Escrowed assets can not be frozen or locked', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.unique.createSwapOffer', [
+      [{collection: nftCollection.collectionId, token: nft.tokenId, amount: 1}],
+      [{collection: 0, token: 0, amount: 1}],
+      null,
+      null,
+    ]);
+    const event = result.events.find(e => e.event.section === 'unique' && e.event.method === 'SwapOfferCreated');
+    const offerId = Number(event?.event.data[0].toString());
+    const escrow = await nft.getOwner();
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.freezeToken', [nftCollection.collectionId, nft.tokenId])).to.be.rejectedWith('common.AssetIsEscrowed');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.setTokenLock', [nftCollection.collectionId, nft.tokenId, true])).to.be.rejectedWith('common.AssetIsEscrowed');
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.freezeAccount', [nftCollection.collectionId, escrow])).to.be.rejectedWith('common.AssetIsEscrowed');
+
+    await helper.executeExtrinsic(bob, 'api.tx.unique.acceptSwapOffer', [offerId]);
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: bob.address});
+  });
+
+  itSub('Expired offer can not be accepted, but can be cancelled by anyone', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+    const expires = await helper.chain.getLatestBlockNumber() + 2;
+
+    const {result} = await helper.executeExtrinsic(alice, 'api.tx.unique.createSwapOffer', [
+      [{collection: nftCollection.collectionId, token: nft.tokenId, amount: 1}],
+      [{collection: 0, token: 0, amount: 1}],
+      null,
+      expires,
+    ]);
+    const event = result.events.find(e => e.event.section === 'unique' && e.event.method === 'SwapOfferCreated');
+    const offerId = Number(event?.event.data[0].toString());
+
+    await helper.wait.newBlocks(3);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.acceptSwapOffer', [offerId])).to.be.rejectedWith('unique.SwapOfferExpired');
+    await helper.executeExtrinsic(bob, 'api.tx.unique.cancelSwapOffer', [offerId]);
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: alice.address});
+  });
+});
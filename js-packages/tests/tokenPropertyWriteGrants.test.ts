@@ -0,0 +1,129 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds, expect, itSub} from '@unique/test-utils/util.js';
+import type {UniqueHelper} from '@unique-nft/playgrounds/unique.js';
+import {NON_EXISTENT_COLLECTION_ID} from '@unique-nft/playgrounds/types.js';
+
+describe('Integration Test grantTokenPropertyWrite(collection_id, account, keys):', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let oracle: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, oracle] = await helper.arrange.createAccounts([50n, 20n, 20n], donor);
+    });
+  });
+
+  const mintCollection = async (helper: UniqueHelper) => await helper.nft.mintCollection(alice, {
+    name: 'col', description: 'descr', tokenPrefix: 'COL',
+    tokenPropertyPermissions: [
+      {key: 'score', permission: {mutable: true, collectionAdmin: false, tokenOwner: false}},
+      {key: 'name', permission: {mutable: true, collectionAdmin: false, tokenOwner: true}},
+      {key: 'seal', permission: {mutable: false, collectionAdmin: true, tokenOwner: false}},
+    ],
+  });
+
+  itSub('Granted account can write only the granted token properties', async ({helper}) => {
+    const collection = await mintCollection(helper);
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+
+    await expect(token.setProperties(oracle, [{key: 'score', value: '10'}])).to.be.rejectedWith(/common\.NoPermission/);
+
+    await collection.grantTokenPropertyWrite(alice, {Substrate: oracle.address}, ['score']);
+    expect(await collection.getTokenPropertyWriteGrants()).to.be.deep.equal([
+      {account: {Substrate: oracle.address}, key: 'score'},
+    ]);
+
+    await token.setProperties(oracle, [{key: 'score', value: '10'}]);
+    await token.deleteProperties(oracle, ['score']);
+    await token.setProperties(oracle, [{key: 'score', value: '42'}]);
+    expect(await token.getProperties(['score'])).to.be.deep.equal([{key: 'score', value: '42'}]);
+
+    await expect(token.setProperties(oracle, [{key: 'name', value: 'oracle'}])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Granted account can\'t change immutable token properties once set', async ({helper}) => {
+    const collection = await mintCollection(helper);
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await collection.grantTokenPropertyWrite(alice, {Substrate: oracle.address}, ['seal']);
+
+    await token.setProperties(oracle, [{key: 'seal', value: 'first'}]);
+    await expect(token.setProperties(oracle, [{key: 'seal', value: 'second'}])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Revoked account can\'t write token properties', async ({helper}) => {
+    const collection = await mintCollection(helper);
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await collection.grantTokenPropertyWrite(alice, {Substrate: oracle.address}, ['score', 'name']);
+
+    await collection.revokeTokenPropertyWrite(alice, {Substrate: oracle.address}, ['score']);
+    expect(await collection.getTokenPropertyWriteGrants()).to.be.deep.equal([
+      {account: {Substrate: oracle.address}, key: 'name'},
+    ]);
+    await expect(token.setProperties(oracle, [{key: 'score', value: '10'}])).to.be.rejectedWith(/common\.NoPermission/);
+    await token.setProperties(oracle, [{key: 'name', value: 'oracle'}]);
+
+    await collection.revokeTokenPropertyWrite(alice, {Substrate: oracle.address}, ['name']);
+    expect(await collection.getTokenPropertyWriteGrants()).to.be.deep.equal([]);
+  });
+});
+
+describe('Negative Integration Test grantTokenPropertyWrite(collection_id, account, keys):', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let oracle: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, oracle] = await helper.arrange.createAccounts([20n, 20n, 20n], donor);
+    });
+  });
+
+  itSub('Regular user can\'t grant token property write', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    await expect(collection.grantTokenPropertyWrite(bob, {Substrate: oracle.address}, ['score'])).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.revokeTokenPropertyWrite(bob, {Substrate: oracle.address}, ['score'])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Admin without the property editor role can\'t grant token property write', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, {minter: true});
+
+    await expect(collection.grantTokenPropertyWrite(bob, {Substrate: oracle.address}, ['score'])).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Can\'t grant token property write of keys without permissions or with invalid keys', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      name: 'col', description: 'descr', tokenPrefix: 'COL',
+      tokenPropertyPermissions: [{key: 'score', permission: {mutable: true, collectionAdmin: false, tokenOwner: false}}],
+    });
+
+    await expect(collection.grantTokenPropertyWrite(alice, {Substrate: oracle.address}, ['name'])).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.grantTokenPropertyWrite(alice, {Substrate: oracle.address}, ['sc@re'])).to.be.rejectedWith(/common\.InvalidCharacterInPropertyKey/);
+    expect(await collection.getTokenPropertyWriteGrants()).to.be.deep.equal([]);
+  });
+
+  itSub('Can\'t grant token property write in a non-existing collection', async ({helper}) => {
+    await expect(helper.nft.grantTokenPropertyWrite(alice, NON_EXISTENT_COLLECTION_ID, {Substrate: oracle.address}, ['score'])).to.be.rejectedWith(/common\.CollectionNotFound/);
+  });
+});
@@ -0,0 +1,134 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds, expect, itSub, Pallets} from '@unique/test-utils/util.js';
+
+const permission = {mutable: true, collectionAdmin: true, tokenOwner: false};
+
+const tokenPropertyPermissions = [
+  {key: 'URI', permission},
+  {key: 'URISuffix', permission},
+  {key: 'level', permission},
+];
+
+describe('Integration Test: token URI', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([100n, 10n], donor);
+    });
+  });
+
+  [
+    {mode: 'nft' as const, requiredPallets: []},
+    {mode: 'rft' as const, requiredPallets: [Pallets.ReFungible]},
+  ].map(testCase =>
+    itSub.ifWithPallets(`[${testCase.mode}] Token URI is rendered from the collection template`, testCase.requiredPallets, async ({helper}) => {
+      const collection = await helper[testCase.mode].mintCollection(alice, {
+        tokenPropertyPermissions,
+        properties: [
+          {key: 'baseURI', value: 'https://example.com'},
+          {key: 'cid', value: 'QmSUzq95L5zUgwyZXM71Wj7bxk6UpHQThc1bSie4wfpnT5'},
+          {key: 'URITemplate', value: '{baseURI}/{tokenId}.json'},
+        ],
+      });
+      const token = await helper[testCase.mode].mintToken(alice, {
+        collectionId: collection.collectionId, owner: {Substrate: bob.address}, pieces: 1n,
+      });
+
+      expect(await helper[testCase.mode].getTokenUri(collection.collectionId, token.tokenId))
+        .to.be.equal(`https://example.com/${token.tokenId}.json`);
+
+      await collection.setProperties(alice, [{key: 'URITemplate', value: 'ipfs://{cid}/{prop:level}'}]);
+      expect(await helper[testCase.mode].getTokenUri(collection.collectionId, token.tokenId))
+        .to.be.equal('ipfs://QmSUzq95L5zUgwyZXM71Wj7bxk6UpHQThc1bSie4wfpnT5/');
+
+      await token.setProperties(alice, [{key: 'level', value: '7'}]);
+      expect(await helper[testCase.mode].getTokenUri(collection.collectionId, token.tokenId))
+        .to.be.equal('ipfs://QmSUzq95L5zUgwyZXM71Wj7bxk6UpHQThc1bSie4wfpnT5/7');
+    }));
+
+  itSub('Template uses the default token properties', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions,
+      properties: [{key: 'URITemplate', value: 'https://example.com/levels/{prop:level}'}],
+    });
+    const token = await collection.mintToken(alice, {Substrate: bob.address});
+    await collection.setDefaultTokenProperties(alice, [{key: 'level', value: '1'}]);
+
+    expect(await helper.nft.getTokenUri(collection.collectionId, token.tokenId)).to.be.equal('https://example.com/levels/1');
+  });
+
+  itSub('Token URI property takes precedence over the template', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions,
+      properties: [{key: 'URITemplate', value: 'https://example.com/{tokenId}'}],
+    });
+    const token = await collection.mintToken(alice, {Substrate: bob.address}, [{key: 'URI', value: 'ipfs://token'}]);
+
+    expect(await helper.nft.getTokenUri(collection.collectionId, token.tokenId)).to.be.equal('ipfs://token');
+  });
+
+  itSub('Token URI falls back to baseURI and URISuffix without a template', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions,
+      properties: [{key: 'baseURI', value: 'https://example.com/'}],
+    });
+    const token = await collection.mintToken(alice, {Substrate: bob.address}, [{key: 'URISuffix', value: 'token.json'}]);
+    const otherToken = await collection.mintToken(alice, {Substrate: bob.address});
+
+    expect(await helper.nft.getTokenUri(collection.collectionId, token.tokenId)).to.be.equal('https://example.com/token.json');
+    expect(await helper.nft.getTokenUri(collection.collectionId, otherToken.tokenId)).to.be.equal('https://example.com/');
+  });
+
+  itSub('Token URI rendered from the template can\'t be too long', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      tokenPropertyPermissions,
+      properties: [{key: 'URITemplate', value: '{prop:level}'.repeat(40)}],
+    });
+    const token = await collection.mintToken(alice, {Substrate: bob.address}, [{key: 'level', value: 'x'.repeat(1000)}]);
+
+    await expect(helper.nft.getTokenUri(collection.collectionId, token.tokenId)).to.be.rejected;
+  });
+
+  itSub('Non-existing token has no URI', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      properties: [{key: 'URITemplate', value: 'https://example.com/{tokenId}'}],
+    });
+
+    expect(await helper.nft.getTokenUri(collection.collectionId, 1)).to.be.null;
+  });
+
+  itSub('Can\'t create a collection with an invalid template', async ({helper}) => {
+    await expect(helper.nft.mintCollection(alice, {
+      properties: [{key: 'URITemplate', value: 'https://example.com/{tokenId'}],
+    })).to.be.rejectedWith(/common\.InvalidTokenUriTemplate/);
+  });
+
+  itSub('Can\'t set an invalid template', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice);
+
+    for(const template of ['https://example.com/{tokenId', 'https://example.com/tokenId}', '{{tokenId}}', '{prop:}', '{}', '{base URI}']) {
+      await expect(collection.setProperties(alice, [{key: 'URITemplate', value: template}]))
+        .to.be.rejectedWith(/common\.InvalidTokenUriTemplate/);
+    }
+    expect(await collection.getProperties(['URITemplate'])).to.be.empty;
+  });
+});
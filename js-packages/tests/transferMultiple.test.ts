@@ -0,0 +1,99 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, usingPlaygrounds} from '@unique/test-utils/util.js';
+
+describe('Integration Test transferMultiple and transferFromMultiple', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 10n, 10n], donor);
+    });
+  });
+
+  itSub('Tokens of different collections are transferred in one call', async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const [first, second] = [await nftCollection.mintToken(alice), await nftCollection.mintToken(alice)];
+    const ftCollection = await helper.ft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'}, 0);
+    await ftCollection.mint(alice, 100n);
+
+    await helper.executeExtrinsic(alice, 'api.tx.unique.transferMultiple', [[
+      {collection: nftCollection.collectionId, token: first.tokenId, recipient: {Substrate: bob.address}, amount: 1},
+      {collection: nftCollection.collectionId, token: second.tokenId, recipient: {Substrate: charlie.address}, amount: 1},
+      {collection: ftCollection.collectionId, token: 0, recipient: {Substrate: bob.address}, amount: 30},
+      {collection: ftCollection.collectionId, token: 0, recipient: {Substrate: charlie.address}, amount: 20},
+    ]]);
+
+    expect(await first.getOwner()).to.be.deep.equal({Substrate: bob.address});
+    expect(await second.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+    expect(await ftCollection.getBalance({Substrate: alice.address})).to.be.equal(50n);
+    expect(await ftCollection.getBalance({Substrate: bob.address})).to.be.equal(30n);
+    expect(await ftCollection.getBalance({Substrate: charlie.address})).to.be.equal(20n);
+  });
+
+  itSub.ifWithPallets('Approved tokens are transferred on behalf of the owner', [Pallets.ReFungible], async ({helper}) => {
+    const nftCollection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const nft = await nftCollection.mintToken(alice);
+    const rftCollection = await helper.rft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const rft = await rftCollection.mintToken(alice, 100n);
+    await nft.approve(alice, {Substrate: bob.address});
+    await rft.approve(alice, {Substrate: bob.address}, 40n);
+
+    await helper.executeExtrinsic(bob, 'api.tx.unique.transferFromMultiple', [{Substrate: alice.address}, [
+      {collection: nftCollection.collectionId, token: nft.tokenId, recipient: {Substrate: charlie.address}, amount: 1},
+      {collection: rftCollection.collectionId, token: rft.tokenId, recipient: {Substrate: charlie.address}, amount: 40},
+    ]]);
+
+    expect(await nft.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+    expect(await rft.getBalance({Substrate: charlie.address})).to.be.equal(40n);
+    expect(await rft.getBalance({Substrate: alice.address})).to.be.equal(60n);
+  });
+
+  itSub('Negative: no transfer is applied if one of them fails', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const own = await collection.mintToken(alice);
+    const foreign = await collection.mintToken(alice, {Substrate: charlie.address});
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.transferMultiple', [[
+      {collection: collection.collectionId, token: own.tokenId, recipient: {Substrate: bob.address}, amount: 1},
+      {collection: collection.collectionId, token: foreign.tokenId, recipient: {Substrate: bob.address}, amount: 1},
+    ]])).to.be.rejectedWith('common.NoPermission');
+
+    expect(await own.getOwner()).to.be.deep.equal({Substrate: alice.address});
+    expect(await foreign.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+    await expect(helper.executeExtrinsic(alice, 'api.tx.unique.transferMultiple', [[]]))
+      .to.be.rejectedWith('unique.EmptyArgument');
+  });
+
+  itSub('Negative: transferFromMultiple requires approval of each token', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+    const approved = await collection.mintToken(alice);
+    const notApproved = await collection.mintToken(alice);
+    await approved.approve(alice, {Substrate: bob.address});
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.unique.transferFromMultiple', [{Substrate: alice.address}, [
+      {collection: collection.collectionId, token: approved.tokenId, recipient: {Substrate: bob.address}, amount: 1},
+      {collection: collection.collectionId, token: notApproved.tokenId, recipient: {Substrate: bob.address}, amount: 1},
+    ]])).to.be.rejectedWith('common.ApprovedValueTooLow');
+
+    expect(await approved.getOwner()).to.be.deep.equal({Substrate: alice.address});
+  });
+});
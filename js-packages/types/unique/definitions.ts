@@ -80,14 +80,19 @@ export default {
       'Vec<UpDataStructsProperty>',
     ),
     tokenProperties: fun(
-      'Get token properties, optionally limited to the provided keys',
+      'Get token properties, optionally limited to the provided keys, flagging the ones inherited from the collection defaults',
       [collectionParam, tokenParam, propertyKeysParam],
-      'Vec<UpDataStructsProperty>',
+      'Vec<UpDataStructsRpcTokenProperty>',
     ),
     propertyPermissions: fun(
       'Get property permissions, optionally limited to the provided keys',
       [collectionParam, propertyKeysParam],
-      'Vec<UpDataStructsPropertyKeyPermission>',
+      'Vec<UpDataStructsRpcPropertyKeyPermission>',
+    ),
+    propertyWriteGrants: fun(
+      'Get the list of accounts granted to write token properties, together with the property keys',
+      [collectionParam],
+      'Vec<UpDataStructsPropertyWriteGrant>',
     ),
 
     constMetadata: fun(
@@ -133,6 +138,11 @@ export default {
       [collectionParam],
       'Vec<PalletEvmAccountBasicCrossAccountIdRepr>',
     ),
+    adminlistWithRoles: fun(
+      'Get the list of admin accounts of a collection together with their roles',
+      [collectionParam],
+      `Vec<(${CROSS_ACCOUNT_ID_TYPE}, UpDataStructsRpcCollectionAdminRoles)>`,
+    ),
     allowlist: fun(
       'Get the list of accounts allowed to operate within a collection',
       [collectionParam],
@@ -180,5 +190,100 @@ export default {
       [collectionParam, crossAccountParam('owner'), crossAccountParam('operator')],
       'Option<bool>',
     ),
+    royalty: fun(
+      'Get the EIP-2981 royalty of a token, falling back to the collection royalty',
+      [collectionParam, {name: 'tokenId', type: 'Option<u32>', isOptional: true}],
+      'Option<UpDataStructsRoyalty>',
+    ),
+    tokenFrozen: fun(
+      'Check if a token is frozen',
+      [collectionParam, tokenParam],
+      'bool',
+    ),
+    accountFrozen: fun(
+      'Check if an account is frozen within a collection',
+      [collectionParam, crossAccountParam()],
+      'bool',
+    ),
+    allowanceDeadline: fun(
+      'Get the block after which the allowance can no longer be used, if it has a deadline',
+      [collectionParam, crossAccountParam('sender'), crossAccountParam('spender'), tokenParam],
+      'Option<u64>',
+    ),
+    allowanceForAllDeadline: fun(
+      'Get the block after which the operator approval can no longer be used, if it has a deadline',
+      [collectionParam, crossAccountParam('owner'), crossAccountParam('operator')],
+      'Option<u64>',
+    ),
+    marketListing: fun(
+      'Get the marketplace listing of a token by the seller',
+      [collectionParam, tokenParam, crossAccountParam('seller')],
+      'Option<UpDataStructsMarketListing>',
+    ),
+    marketListings: fun(
+      'Get all marketplace listings of a token',
+      [collectionParam, tokenParam],
+      `Vec<(${CROSS_ACCOUNT_ID_TYPE}, UpDataStructsMarketListing)>`,
+    ),
+    collectionDestructionProgress: fun(
+      'Get the progress of the collection destruction, if it is being destroyed',
+      [collectionParam],
+      'Option<UpDataStructsCollectionDestruction>',
+    ),
+    tokenUri: fun(
+      'Get the URI of a token, resolved the same way as the ERC-721 tokenURI method',
+      [collectionParam, tokenParam],
+      'Option<Text>',
+    ),
+    tokenPropertiesFrozen: fun(
+      'Check if the token properties are permanently frozen',
+      [collectionParam, tokenParam],
+      'bool',
+    ),
+    collectionMetadataFrozen: fun(
+      'Check if the collection metadata is permanently frozen',
+      [collectionParam],
+      'bool',
+    ),
+    rftOfNft: fun(
+      'Get the RFT token linked to a fractionalized NFT',
+      [collectionParam, tokenParam],
+      'Option<(u32, u32)>',
+    ),
+    nftOfRft: fun(
+      'Get the fractionalized NFT linked to an RFT token',
+      [collectionParam, tokenParam],
+      'Option<(u32, u32)>',
+    ),
+    rftBuyout: fun(
+      'Get the buyout of an RFT token',
+      [collectionParam, tokenParam],
+      'Option<UpDataStructsRftBuyout>',
+    ),
+    dividendDistribution: fun(
+      'Get a dividend distribution',
+      [{name: 'distribution', type: 'u32'}],
+      'Option<UpDataStructsDividendDistribution>',
+    ),
+    claimableDividends: fun(
+      'Get the dividends, which an account can claim from a distribution',
+      [{name: 'distribution', type: 'u32'}, crossAccountParam()],
+      'u128',
+    ),
+    accountClaimableDividends: fun(
+      'Get all the distributions, from which an account can claim dividends, with the claimable amounts',
+      [crossAccountParam()],
+      'Vec<(u32, u128)>',
+    ),
+    supplyCap: fun(
+      'Get the supply cap of a fungible collection, if it is set',
+      [collectionParam],
+      'Option<u128>',
+    ),
+    mintAuthority: fun(
+      'Get the mint authority of a fungible collection, if it is set',
+      [collectionParam],
+      `Option<${CROSS_ACCOUNT_ID_TYPE}>`,
+    ),
   },
 };
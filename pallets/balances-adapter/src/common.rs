@@ -12,7 +12,7 @@ use pallet_balances::{weights::SubstrateWeight as BalancesWeight, WeightInfo};
 use pallet_common::{
 	erc::CrossAccountId, CommonCollectionOperations, CommonWeightInfo, Error as CommonError,
 };
-use up_data_structs::{budget::Budget, TokenId};
+use up_data_structs::{budget::Budget, TokenId, TokenUser};
 
 use crate::{Config, NativeFungibleHandle, Pallet};
 
@@ -73,6 +73,14 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	fn force_repair_item() -> Weight {
 		Weight::default()
 	}
+
+	fn set_token_lock() -> Weight {
+		Weight::default()
+	}
+
+	fn set_token_user() -> Weight {
+		Weight::default()
+	}
 }
 
 /// Implementation of `CommonCollectionOperations` for `FungibleHandle`. It wraps FungibleHandle Pallet
@@ -116,6 +124,16 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		fail!(<CommonError<T>>::UnsupportedOperation);
 	}
 
+	fn burn_item_recursively(
+		&self,
+		_sender: <T>::CrossAccountId,
+		_token: TokenId,
+		_burn_children: bool,
+		_budget: &dyn up_data_structs::budget::Budget,
+	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation);
+	}
+
 	fn set_collection_properties(
 		&self,
 		_sender: <T>::CrossAccountId,
@@ -172,6 +190,14 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		fail!(<CommonError<T>>::UnsupportedOperation);
 	}
 
+	fn set_token_property_value_types(
+		&self,
+		_sender: &<T>::CrossAccountId,
+		_value_types: Vec<up_data_structs::PropertyKeyValueType>,
+	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation);
+	}
+
 	fn transfer(
 		&self,
 		sender: <T>::CrossAccountId,
@@ -194,6 +220,7 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		_spender: <T>::CrossAccountId,
 		_token: TokenId,
 		_amount: u128,
+		_deadline: Option<u64>,
 	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
 		fail!(<CommonError<T>>::UnsupportedOperation);
 	}
@@ -205,6 +232,7 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		_to: <T>::CrossAccountId,
 		_token: TokenId,
 		_amount: u128,
+		_deadline: Option<u64>,
 	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
 		fail!(<CommonError<T>>::UnsupportedOperation);
 	}
@@ -340,6 +368,15 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		0
 	}
 
+	fn allowance_deadline(
+		&self,
+		_sender: <T>::CrossAccountId,
+		_spender: <T>::CrossAccountId,
+		_token: TokenId,
+	) -> Option<u64> {
+		None
+	}
+
 	fn xcm_extensions(&self) -> Option<&dyn pallet_common::XcmExtensions<T>> {
 		Some(self)
 	}
@@ -349,6 +386,7 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		_owner: <T>::CrossAccountId,
 		_operator: <T>::CrossAccountId,
 		_approve: bool,
+		_deadline: Option<u64>,
 	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
 		fail!(<CommonError<T>>::UnsupportedOperation);
 	}
@@ -361,12 +399,46 @@ impl<T: Config> CommonCollectionOperations<T> for NativeFungibleHandle<T> {
 		false
 	}
 
+	fn allowance_for_all_deadline(
+		&self,
+		_owner: <T>::CrossAccountId,
+		_operator: <T>::CrossAccountId,
+	) -> Option<u64> {
+		None
+	}
+
 	fn repair_item(
 		&self,
 		_token: TokenId,
 	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
 		fail!(<CommonError<T>>::UnsupportedOperation);
 	}
+
+	fn set_token_lock(
+		&self,
+		_sender: <T>::CrossAccountId,
+		_token: TokenId,
+		_locked: bool,
+	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation);
+	}
+
+	fn token_locked(&self, _token: TokenId) -> bool {
+		false
+	}
+
+	fn set_token_user(
+		&self,
+		_sender: <T>::CrossAccountId,
+		_token: TokenId,
+		_user: Option<TokenUser<<T>::CrossAccountId>>,
+	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation);
+	}
+
+	fn token_user(&self, _token: TokenId) -> Option<TokenUser<<T>::CrossAccountId>> {
+		None
+	}
 }
 
 impl<T: Config> pallet_common::XcmExtensions<T> for NativeFungibleHandle<T> {
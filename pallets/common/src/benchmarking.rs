@@ -31,11 +31,17 @@ use sp_runtime::{traits::Zero, DispatchError};
 use sp_std::vec::Vec;
 use up_data_structs::{
 	AccessMode, CollectionId, CollectionMode, CollectionPermissions, CreateCollectionData,
-	NestingPermissions, Property, PropertyKey, PropertyValue, MAX_COLLECTION_DESCRIPTION_LENGTH,
-	MAX_COLLECTION_NAME_LENGTH, MAX_PROPERTIES_PER_ITEM, MAX_TOKEN_PREFIX_LENGTH,
+	NestingPermissions, Property, PropertyEnumValue, PropertyKey, PropertyKeyPermission,
+	PropertyKeyValueType, PropertyPermission, PropertyValue, PropertyValueType,
+	MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_COLLECTION_NAME_LENGTH, MAX_PROPERTIES_PER_ITEM,
+	MAX_PROPERTY_ENUM_VALUES, MAX_PROPERTY_ENUM_VALUE_LENGTH, MAX_PROPERTY_VALUE_LENGTH,
+	MAX_TOKEN_PREFIX_LENGTH,
 };
 
-use crate::{BenchmarkPropertyWriter, CollectionHandle, CollectionIssuer, Config, Pallet};
+use crate::{
+	erc::static_property::key, BenchmarkPropertyWriter, CollectionHandle, CollectionIssuer, Config,
+	FrozenTokenPropertiesCount, Pallet,
+};
 
 const SEED: u32 = 1;
 
@@ -198,6 +204,96 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_default_token_properties(
+		b: Linear<0, MAX_PROPERTIES_PER_ITEM>,
+	) -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let props = (0..b)
+			.map(|p| Property {
+				key: property_key(p as usize),
+				value: property_value(),
+			})
+			.collect::<Vec<_>>();
+
+		#[block]
+		{
+			<Pallet<T>>::set_default_token_properties(&collection, &owner, props.into_iter())?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn delete_default_token_properties(
+		b: Linear<0, MAX_PROPERTIES_PER_ITEM>,
+	) -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let props = (0..MAX_PROPERTIES_PER_ITEM)
+			.map(|p| Property {
+				key: property_key(p as usize),
+				value: property_value(),
+			})
+			.collect::<Vec<_>>();
+		<Pallet<T>>::set_default_token_properties(&collection, &owner, props.into_iter())?;
+		let keys = (0..b).map(|p| property_key(p as usize)).collect::<Vec<_>>();
+
+		#[block]
+		{
+			<Pallet<T>>::delete_default_token_properties(&collection, &owner, keys.into_iter())?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_token_property_value_types(
+		a: Linear<1, MAX_PROPERTIES_PER_ITEM>,
+		b: Linear<0, MAX_PROPERTY_ENUM_VALUES>,
+	) -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let perms = (0..a)
+			.map(|k| PropertyKeyPermission {
+				key: property_key(k as usize),
+				permission: PropertyPermission {
+					mutable: false,
+					collection_admin: true,
+					token_owner: false,
+				},
+			})
+			.collect::<Vec<_>>();
+		<Pallet<T>>::set_token_property_permissions(&collection, &owner, perms)?;
+		let values = (0..b)
+			.map(|v| {
+				let mut value = create_data::<MAX_PROPERTY_ENUM_VALUE_LENGTH>();
+				value[0] = v as u8;
+				PropertyEnumValue(value)
+			})
+			.collect::<Vec<_>>();
+		let value_types = (0..a)
+			.map(|k| PropertyKeyValueType {
+				key: property_key(k as usize),
+				value_type: Some(PropertyValueType::Enum(values.clone().try_into().unwrap())),
+			})
+			.collect::<Vec<_>>();
+
+		#[block]
+		{
+			<Pallet<T>>::set_token_property_value_types(&collection, &owner, value_types)?;
+		}
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn check_accesslist() -> Result<(), BenchmarkError> {
 		bench_init! {
@@ -245,4 +341,41 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn load_frozen_token_uri_keys() -> Result<(), BenchmarkError> {
+		#[cfg(not(feature = "std"))]
+		use alloc::format;
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+
+		// The worst case: the URI template consists of distinct token property placeholders only
+		let mut template = Vec::new();
+		for i in 0.. {
+			let placeholder = format!("{{prop:{i}}}");
+			if template.len() + placeholder.len() > MAX_PROPERTY_VALUE_LENGTH as usize {
+				break;
+			}
+			template.extend_from_slice(placeholder.as_bytes());
+		}
+		<Pallet<T>>::set_collection_properties(
+			&collection,
+			&owner,
+			[Property {
+				key: key::uri_template(),
+				value: template.try_into().unwrap(),
+			}]
+			.into_iter(),
+		)?;
+		<FrozenTokenPropertiesCount<T>>::insert(collection.id, 1);
+
+		#[block]
+		{
+			<Pallet<T>>::frozen_token_uri_keys(collection.id);
+		}
+
+		Ok(())
+	}
 }
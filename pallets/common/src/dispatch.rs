@@ -9,9 +9,11 @@ use frame_support::{
 };
 use sp_runtime::DispatchError;
 use sp_weights::Weight;
-use up_data_structs::{CollectionId, CreateCollectionData};
+use up_data_structs::{CollectionId, CollectionMode, CreateCollectionData};
 
-use crate::{pallet::Config, CollectionIssuer, CommonCollectionOperations};
+use crate::{
+	helpers::StorageCleaner, pallet::Config, CollectionIssuer, CommonCollectionOperations,
+};
 
 // TODO: move to benchmarking
 /// Price of [`dispatch_tx`] call with noop `call` argument
@@ -86,6 +88,18 @@ pub trait CollectionDispatch<T: Config> {
 	/// * `handle` - Collection handle.
 	fn destroy(sender: T::CrossAccountId, collection_id: CollectionId) -> DispatchResult;
 
+	/// Remove the remaining storage of the destroyed collection, as far as `cleaner` allows.
+	/// Returns `true` once there is nothing left to remove.
+	///
+	/// * `collection_id` - ID of the destroyed collection.
+	/// * `mode` - Type of the destroyed collection.
+	/// * `cleaner` - Weight-bounded storage remover.
+	fn clear_destroyed(
+		collection_id: CollectionId,
+		mode: &CollectionMode,
+		cleaner: &mut StorageCleaner<T>,
+	) -> bool;
+
 	/// Get a specialized collection from the handle.
 	///
 	/// * `handle` - Collection handle.
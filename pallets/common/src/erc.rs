@@ -16,6 +16,7 @@
 
 //! This module contains the implementation of pallet methods for evm.
 
+use evm_coder::AbiEncode;
 pub use pallet_evm::{
 	account::CrossAccountId, PrecompileHandle, PrecompileOutput, PrecompileResult,
 };
@@ -25,16 +26,18 @@ use pallet_evm_coder_substrate::{
 	execution::{Error, PreDispatch, Result},
 	frontier_contract, solidity_interface,
 	types::*,
-	ToLog,
+	SubCall, SubstrateRecorder, ToLog,
 };
+use sp_core::U256;
 use sp_std::vec;
 use up_data_structs::{
-	CollectionMode, CollectionPermissions, OwnerRestrictedSet, Property, SponsoringRateLimit,
-	SponsorshipState,
+	CollectionAdminRoles, CollectionMode, CollectionPermissions, OwnerRestrictedSet, Property,
+	SponsoringRateLimit, SponsorshipState,
 };
 
 use crate::{
-	eth, weights::WeightInfo, CollectionHandle, CollectionProperties, Config, Pallet, SelfWeightOf,
+	dispatch::CollectionDispatch, eth, set_collection_properties_weight, CollectionHandle,
+	CollectionProperties, Config, Pallet, PendingCollectionOwner,
 };
 
 frontier_contract! {
@@ -69,6 +72,54 @@ pub enum CollectionHelpersEvents {
 	},
 }
 
+/// Events of the permanently frozen metadata, emitted by the collection contract.
+#[derive(ToLog)]
+pub enum CollectionMetadataEvents {
+	/// The token metadata has been permanently frozen,
+	/// see https://docs.opensea.io/docs/metadata-standards#freezing-metadata
+	PermanentURI {
+		/// Token URI at the moment of the freeze.
+		value: String,
+
+		/// Token ID.
+		#[indexed]
+		id: U256,
+	},
+}
+
+/// Selector of `onERC721Received(address,address,uint256,bytes)`,
+/// which should be returned by contract accepting the token.
+pub const ERC721_RECEIVED: [u8; 4] = [0x15, 0x0b, 0x7a, 0x02];
+
+/// Check that `to` accepts the token, if it is a contract.
+///
+/// Schedules `onERC721Received` call, which is performed after the transfer, reverting it
+/// if receiver contract doesn't return [`ERC721_RECEIVED`].
+/// See https://eips.ethereum.org/EIPS/eip-721 `safeTransferFrom`.
+pub fn check_on_erc721_received<T: Config>(
+	recorder: &SubstrateRecorder<T>,
+	operator: Address,
+	from: Address,
+	to: Address,
+	token_id: U256,
+	data: Bytes,
+) -> Result<()> {
+	recorder.consume_store_reads(1)?;
+	if !<pallet_evm::AccountCodes<T>>::contains_key(to) {
+		return Ok(());
+	}
+
+	let mut expected_output = vec![0; 32];
+	expected_output[..4].copy_from_slice(&ERC721_RECEIVED);
+	recorder.schedule_sub_call(SubCall {
+		target: to,
+		input: (operator, from, token_id, data).abi_encode_call(ERC721_RECEIVED),
+		expected_output,
+		error: "ERC721: transfer to non ERC721Receiver implementer",
+	});
+	Ok(())
+}
+
 /// Does not always represent a full collection, for RFT it is either
 /// collection (Implementing ERC721), or specific collection token (Implementing ERC20).
 pub trait CommonEvmHandler {
@@ -80,7 +131,7 @@ pub trait CommonEvmHandler {
 }
 
 /// @title A contract that allows you to work with collections.
-#[solidity_interface(name = Collection, enum(derive(PreDispatch)), enum_attr(weight))]
+#[solidity_interface(name = Collection, events(CollectionMetadataEvents), enum(derive(PreDispatch)), enum_attr(weight))]
 impl<T: Config> CollectionHandle<T>
 where
 	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
@@ -90,7 +141,7 @@ where
 	/// @param key Property key.
 	/// @param value Propery value.
 	#[solidity(hide)]
-	#[weight(<SelfWeightOf<T>>::set_collection_properties(1))]
+	#[weight(set_collection_properties_weight::<T>(1))]
 	fn set_collection_property(&mut self, caller: Caller, key: String, value: Bytes) -> Result<()> {
 		let caller = T::CrossAccountId::from_eth(caller);
 		let key = <Vec<u8>>::from(key)
@@ -105,7 +156,7 @@ where
 	/// Set collection properties.
 	///
 	/// @param properties Vector of properties key/value pair.
-	#[weight(<SelfWeightOf<T>>::set_collection_properties(properties.len() as u32))]
+	#[weight(set_collection_properties_weight::<T>(properties.len() as u32))]
 	fn set_collection_properties(
 		&mut self,
 		caller: Caller,
@@ -126,7 +177,7 @@ where
 	///
 	/// @param key Property key.
 	#[solidity(hide)]
-	#[weight(<SelfWeightOf<T>>::set_collection_properties(1))]
+	#[weight(set_collection_properties_weight::<T>(1))]
 	fn delete_collection_property(&mut self, caller: Caller, key: String) -> Result<()> {
 		let caller = T::CrossAccountId::from_eth(caller);
 		let key = <Vec<u8>>::from(key)
@@ -139,7 +190,7 @@ where
 	/// Delete collection properties.
 	///
 	/// @param keys Properties keys.
-	#[weight(<SelfWeightOf<T>>::set_collection_properties(keys.len() as u32))]
+	#[weight(set_collection_properties_weight::<T>(keys.len() as u32))]
 	fn delete_collection_properties(&mut self, caller: Caller, keys: Vec<String>) -> Result<()> {
 		let caller = T::CrossAccountId::from_eth(caller);
 		let keys = keys
@@ -725,6 +776,221 @@ where
 		self.change_owner(caller, new_owner)
 			.map_err(dispatch_to_evm::<T>)
 	}
+
+	/// Get the royalty of the collection.
+	///
+	/// @return Royalty receiver and amount in basis points. If the collection has no royalty, zeroed royalty is returned.
+	fn collection_royalty(&self) -> Result<eth::Royalty> {
+		self.consume_store_reads(1)?;
+
+		Ok(<Pallet<T>>::collection_royalty(self.id)
+			.map(|royalty| eth::Royalty::from_sub::<T>(&royalty))
+			.unwrap_or_default())
+	}
+
+	/// Set the royalty of the collection, used by EIP-2981 `royaltyInfo` for tokens without their own royalty.
+	///
+	/// @param royalty Royalty receiver and amount in basis points, 10000 is 100%.
+	#[weight(set_collection_properties_weight::<T>(1))]
+	fn set_collection_royalty(&mut self, caller: Caller, royalty: eth::Royalty) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let royalty = royalty.into_sub::<T>()?;
+
+		<Pallet<T>>::set_collection_royalty(self, &caller, Some(royalty))
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Remove the royalty of the collection.
+	#[weight(set_collection_properties_weight::<T>(1))]
+	fn delete_collection_royalty(&mut self, caller: Caller) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+
+		<Pallet<T>>::set_collection_royalty(self, &caller, None).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Set the royalty of the token, overriding the royalty of the collection.
+	///
+	/// @param tokenId Id of the token.
+	/// @param royalty Royalty receiver and amount in basis points, 10000 is 100%.
+	#[weight(set_collection_properties_weight::<T>(1))]
+	fn set_token_royalty(
+		&mut self,
+		caller: Caller,
+		token_id: U256,
+		royalty: eth::Royalty,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token_id = token_id.try_into()?;
+		let royalty = royalty.into_sub::<T>()?;
+
+		let dispatch = T::CollectionDispatch::dispatch(self.id).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_token_royalty(self, dispatch.as_dyn(), &caller, token_id, Some(royalty))
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Remove the royalty of the token, so the royalty of the collection applies to it.
+	///
+	/// @param tokenId Id of the token.
+	#[weight(set_collection_properties_weight::<T>(1))]
+	fn delete_token_royalty(&mut self, caller: Caller, token_id: U256) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token_id = token_id.try_into()?;
+
+		let dispatch = T::CollectionDispatch::dispatch(self.id).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_token_royalty(self, dispatch.as_dyn(), &caller, token_id, None)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Check whether the token is frozen.
+	///
+	/// @param tokenId Id of the token.
+	fn is_token_frozen(&self, token_id: U256) -> Result<bool> {
+		self.consume_store_reads(1)?;
+
+		let token_id = token_id.try_into()?;
+		Ok(<Pallet<T>>::token_frozen(self.id, token_id))
+	}
+
+	/// Freeze the token, blocking its transfers, approvals and nesting under it.
+	///
+	/// @param tokenId Id of the token.
+	fn freeze_token(&mut self, caller: Caller, token_id: U256) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token_id = token_id.try_into()?;
+
+		let dispatch = T::CollectionDispatch::dispatch(self.id).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::toggle_token_freeze(self, dispatch.as_dyn(), &caller, token_id, true)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Thaw the frozen token.
+	///
+	/// @param tokenId Id of the token.
+	fn thaw_token(&mut self, caller: Caller, token_id: U256) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token_id = token_id.try_into()?;
+
+		let dispatch = T::CollectionDispatch::dispatch(self.id).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::toggle_token_freeze(self, dispatch.as_dyn(), &caller, token_id, false)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Check whether the account is frozen in the collection.
+	///
+	/// @param account Account cross address to check.
+	fn is_account_frozen_cross(&self, account: eth::CrossAddress) -> Result<bool> {
+		self.consume_store_reads(1)?;
+
+		let account = account.into_sub_cross_account::<T>()?;
+		Ok(<Pallet<T>>::account_frozen(self.id, account))
+	}
+
+	/// Freeze the account in the collection, blocking transfers and approvals of its tokens.
+	///
+	/// @param account Account cross address to freeze.
+	fn freeze_account_cross(&mut self, caller: Caller, account: eth::CrossAddress) -> Result<()> {
+		self.consume_store_writes(1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let account = account.into_sub_cross_account::<T>()?;
+		<Pallet<T>>::toggle_account_freeze(self, &caller, &account, true)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Thaw the frozen account in the collection.
+	///
+	/// @param account Account cross address to thaw.
+	fn thaw_account_cross(&mut self, caller: Caller, account: eth::CrossAddress) -> Result<()> {
+		self.consume_store_writes(1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let account = account.into_sub_cross_account::<T>()?;
+		<Pallet<T>>::toggle_account_freeze(self, &caller, &account, false)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Propose a new owner of the collection.
+	///
+	/// @dev Ownership is transferred only after the proposed owner accepts it with `acceptCollectionOwnership`.
+	/// @param newOwner Cross account address of the proposed owner.
+	fn propose_collection_owner_cross(
+		&mut self,
+		caller: Caller,
+		new_owner: eth::CrossAddress,
+	) -> Result<()> {
+		self.consume_store_writes(1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let new_owner = new_owner.into_sub_cross_account::<T>()?;
+		self.propose_owner(&caller, new_owner)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Accept the proposed ownership of the collection.
+	///
+	/// @dev The caller must be proposed as the owner with `proposeCollectionOwnerCross`.
+	fn accept_collection_ownership(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 2)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		self.accept_ownership(&caller).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Cancel the proposed transfer of the collection ownership.
+	fn cancel_collection_ownership_transfer(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		self.cancel_ownership_transfer(&caller)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Get the proposed owner of the collection.
+	///
+	/// @return Cross address of the proposed owner. If there is no pending ownership transfer, zero address is returned.
+	fn collection_pending_owner(&self) -> Result<eth::CrossAddress> {
+		self.consume_store_reads(1)?;
+
+		Ok(<PendingCollectionOwner<T>>::get(self.id)
+			.map(|owner| eth::CrossAddress::from_sub::<T>(&owner))
+			.unwrap_or_default())
+	}
+
+	/// Set the roles of the collection admin.
+	///
+	/// @dev Admins added without explicitly set roles have all of them.
+	/// @param admin Cross account administrator address.
+	/// @param roles New roles of the administrator.
+	fn set_collection_admin_roles_cross(
+		&mut self,
+		caller: Caller,
+		admin: eth::CrossAddress,
+		roles: CollectionAdminRoles,
+	) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let admin = admin.into_sub_cross_account::<T>()?;
+		<Pallet<T>>::set_admin_roles(self, &caller, &admin, roles).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Get the roles of the collection admin.
+	///
+	/// @param admin Cross account administrator address.
+	/// @return Roles of the administrator. The owner has all the roles, other accounts have none.
+	fn collection_admin_roles_cross(
+		&self,
+		admin: eth::CrossAddress,
+	) -> Result<CollectionAdminRoles> {
+		self.consume_store_reads(2)?;
+
+		let admin = admin.into_sub_cross_account::<T>()?;
+		Ok(self.admin_roles(&admin).unwrap_or_default())
+	}
 }
 
 /// Contains static property keys and values.
@@ -754,6 +1020,11 @@ pub mod static_property {
 			property_key_from_bytes(b"URISuffix").expect(EXPECT_CONVERT_ERROR)
 		}
 
+		/// Key "URITemplate".
+		pub fn uri_template() -> up_data_structs::PropertyKey {
+			property_key_from_bytes(b"URITemplate").expect(EXPECT_CONVERT_ERROR)
+		}
+
 		/// Key "parentNft".
 		pub fn parent_nft() -> up_data_structs::PropertyKey {
 			property_key_from_bytes(b"parentNft").expect(EXPECT_CONVERT_ERROR)
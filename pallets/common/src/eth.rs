@@ -24,7 +24,8 @@ use evm_coder::{
 };
 pub use pallet_evm::{account::CrossAccountId, Config};
 use pallet_evm_coder_substrate::execution::Error;
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
 use sp_std::vec;
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
@@ -141,6 +142,15 @@ impl CrossAddress {
 			Err("All fields of cross account is non zeroed".into())
 		}
 	}
+
+	/// EIP-712 hash of the `CrossAddress` struct.
+	fn eip712_hash(&self) -> H256 {
+		let mut data = Vec::with_capacity(3 * 32);
+		data.extend_from_slice(&keccak_256(b"CrossAddress(address eth,uint256 sub)"));
+		data.extend_from_slice(H256::from(self.eth).as_bytes());
+		data.extend_from_slice(&self.sub.to_big_endian());
+		H256(keccak_256(&data))
+	}
 }
 
 /// Type of tokens in collection
@@ -516,6 +526,193 @@ impl TokenPropertyPermission {
 	}
 }
 
+/// Ethereum representation of the kind of [`up_data_structs::PropertyValueType`].
+#[derive(AbiCoder, Copy, Clone, Default, Debug)]
+#[repr(u8)]
+pub enum PropertyValueKind {
+	/// Any value is allowed.
+	#[default]
+	Untyped,
+
+	/// Decimal representation of `uint32`.
+	U32,
+
+	/// Decimal representation of `uint64`.
+	U64,
+
+	/// Decimal representation of `uint128`.
+	U128,
+
+	/// `true` or `false`.
+	Bool,
+
+	/// UTF-8 string.
+	String,
+
+	/// URI with a scheme.
+	Uri,
+
+	/// Address, 20 bytes.
+	Address,
+
+	/// Bytes with maximal length.
+	Bytes,
+
+	/// One of the allowed values.
+	Enum,
+}
+
+/// Ethereum representation of [`up_data_structs::PropertyValueType`].
+#[derive(Debug, Default, AbiCoder)]
+pub struct PropertyValueType {
+	/// Kind of the property value.
+	kind: PropertyValueKind,
+	/// Maximal length of the value, used by [`PropertyValueKind::Bytes`].
+	max_length: u32,
+	/// Allowed values, used by [`PropertyValueKind::Enum`].
+	allowed_values: Vec<evm_coder::types::Bytes>,
+}
+
+impl PropertyValueType {
+	/// Make [`PropertyValueType`] from optional [`up_data_structs::PropertyValueType`].
+	pub fn from_value_type(value_type: Option<up_data_structs::PropertyValueType>) -> Self {
+		use up_data_structs::PropertyValueType as Type;
+
+		let kind = match &value_type {
+			None => PropertyValueKind::Untyped,
+			Some(Type::U32) => PropertyValueKind::U32,
+			Some(Type::U64) => PropertyValueKind::U64,
+			Some(Type::U128) => PropertyValueKind::U128,
+			Some(Type::Bool) => PropertyValueKind::Bool,
+			Some(Type::String) => PropertyValueKind::String,
+			Some(Type::Uri) => PropertyValueKind::Uri,
+			Some(Type::Address) => PropertyValueKind::Address,
+			Some(Type::Bytes(_)) => PropertyValueKind::Bytes,
+			Some(Type::Enum(_)) => PropertyValueKind::Enum,
+		};
+		let max_length = match &value_type {
+			Some(Type::Bytes(max_length)) => *max_length,
+			_ => 0,
+		};
+		let allowed_values = match value_type {
+			Some(Type::Enum(values)) => values
+				.into_iter()
+				.map(|value| evm_coder::types::Bytes(value.0.into_inner()))
+				.collect(),
+			_ => Vec::new(),
+		};
+
+		Self {
+			kind,
+			max_length,
+			allowed_values,
+		}
+	}
+
+	/// Make optional [`up_data_structs::PropertyValueType`] from [`PropertyValueType`].
+	pub fn into_value_type(self) -> Result<Option<up_data_structs::PropertyValueType>, Error> {
+		use up_data_structs::PropertyValueType as Type;
+
+		Ok(Some(match self.kind {
+			PropertyValueKind::Untyped => return Ok(None),
+			PropertyValueKind::U32 => Type::U32,
+			PropertyValueKind::U64 => Type::U64,
+			PropertyValueKind::U128 => Type::U128,
+			PropertyValueKind::Bool => Type::Bool,
+			PropertyValueKind::String => Type::String,
+			PropertyValueKind::Uri => Type::Uri,
+			PropertyValueKind::Address => Type::Address,
+			PropertyValueKind::Bytes => Type::Bytes(self.max_length),
+			PropertyValueKind::Enum => Type::Enum(
+				self.allowed_values
+					.into_iter()
+					.map(|value| {
+						value
+							.0
+							.try_into()
+							.map(up_data_structs::PropertyEnumValue)
+							.map_err(|_| "too long allowed value")
+					})
+					.collect::<Result<Vec<_>, _>>()?
+					.try_into()
+					.map_err(|_| "too many allowed values")?,
+			),
+		}))
+	}
+}
+
+/// Ethereum representation of Token Property Permissions with the type of property values.
+#[derive(Debug, Default, AbiCoder)]
+pub struct TypedTokenPropertyPermission {
+	/// Token property key.
+	key: evm_coder::types::String,
+	/// Token property permissions.
+	permissions: Vec<PropertyPermission>,
+	/// Type of the token property values.
+	value_type: PropertyValueType,
+}
+
+impl From<up_data_structs::RpcPropertyKeyPermission> for TypedTokenPropertyPermission {
+	fn from(value: up_data_structs::RpcPropertyKeyPermission) -> Self {
+		let up_data_structs::RpcPropertyKeyPermission {
+			key,
+			permission,
+			value_type,
+		} = value;
+		let key = evm_coder::types::String::from_utf8(key.into_inner())
+			.expect("Stored key must be valid");
+		Self {
+			key,
+			permissions: PropertyPermission::into_vec(permission),
+			value_type: PropertyValueType::from_value_type(value_type),
+		}
+	}
+}
+
+impl TypedTokenPropertyPermission {
+	/// Number of the allowed values of an enum type, zero for other types.
+	pub fn enum_values_num(&self) -> u32 {
+		match self.value_type.kind {
+			PropertyValueKind::Enum => self.value_type.allowed_values.len() as u32,
+			_ => 0,
+		}
+	}
+
+	/// Convert vector of [`TypedTokenPropertyPermission`] into vectors of
+	/// [`up_data_structs::PropertyKeyPermission`] and [`up_data_structs::PropertyKeyValueType`].
+	pub fn into_property_key_permissions(
+		permissions: Vec<TypedTokenPropertyPermission>,
+	) -> Result<
+		(
+			Vec<up_data_structs::PropertyKeyPermission>,
+			Vec<up_data_structs::PropertyKeyValueType>,
+		),
+		Error,
+	> {
+		let mut perms = Vec::new();
+		let mut value_types = Vec::new();
+
+		for TypedTokenPropertyPermission {
+			key,
+			permissions,
+			value_type,
+		} in permissions
+		{
+			let key: up_data_structs::PropertyKey =
+				key.into_bytes().try_into().map_err(|_| "too long key")?;
+			value_types.push(up_data_structs::PropertyKeyValueType {
+				key: key.clone(),
+				value_type: value_type.into_value_type()?,
+			});
+			perms.push(up_data_structs::PropertyKeyPermission {
+				key,
+				permission: PropertyPermission::from_vec(permissions),
+			});
+		}
+		Ok((perms, value_types))
+	}
+}
+
 /// Data for creation token with uri.
 #[derive(Debug, AbiCoder)]
 pub struct TokenUri {
@@ -526,6 +723,16 @@ pub struct TokenUri {
 	pub uri: String,
 }
 
+/// Single transfer of a batch.
+#[derive(Debug, Default, AbiCoder)]
+pub struct TokenTransfer {
+	/// Recipient of the token.
+	pub to: CrossAddress,
+
+	/// Id of the transferred token.
+	pub token_id: U256,
+}
+
 /// Nested collections and permissions
 #[derive(Debug, Default, AbiCoder)]
 pub struct CollectionNestingAndPermission {
@@ -633,3 +840,150 @@ impl From<AccessMode> for up_data_structs::AccessMode {
 		}
 	}
 }
+
+/// Ethereum representation of [`up_data_structs::Royalty`].
+#[derive(Debug, Default, AbiCoder)]
+pub struct Royalty {
+	/// Account receiving the royalty.
+	pub receiver: CrossAddress,
+	/// Royalty amount in basis points of the sale price, 10000 is 100%.
+	pub basis_points: u32,
+}
+
+impl Royalty {
+	/// Create [`Royalty`] from the substrate representation.
+	pub fn from_sub<T>(royalty: &up_data_structs::Royalty<T::CrossAccountId>) -> Self
+	where
+		T: pallet_evm::Config,
+		T::AccountId: AsRef<[u8; 32]>,
+	{
+		Self {
+			receiver: CrossAddress::from_sub_cross_account::<T>(&royalty.receiver),
+			basis_points: royalty.basis_points.into(),
+		}
+	}
+
+	/// Convert to the substrate representation.
+	pub fn into_sub<T>(self) -> Result<up_data_structs::Royalty<T::CrossAccountId>, Error>
+	where
+		T: pallet_evm::Config,
+		T::AccountId: From<[u8; 32]>,
+	{
+		Ok(up_data_structs::Royalty {
+			receiver: self.receiver.into_sub_cross_account::<T>()?,
+			basis_points: self
+				.basis_points
+				.try_into()
+				.map_err(|_| "royalty is too high")?,
+		})
+	}
+}
+
+/// Result of EIP-2981 `royaltyInfo`.
+#[derive(Debug, Default, AbiCoder)]
+pub struct RoyaltyInfo {
+	/// Address of who should be sent the royalty payment.
+	pub receiver: Address,
+	/// The royalty payment amount for the sale price.
+	pub royalty_amount: U256,
+}
+
+impl RoyaltyInfo {
+	/// Create [`RoyaltyInfo`] for the `sale_price`.
+	pub fn new<T: pallet_evm::Config>(
+		royalty: &up_data_structs::Royalty<T::CrossAccountId>,
+		sale_price: U256,
+	) -> Self {
+		Self {
+			receiver: *royalty.receiver.as_eth(),
+			royalty_amount: royalty.amount(sale_price),
+		}
+	}
+}
+
+/// Half of the order of the secp256k1 curve.
+/// Signatures with a greater `s` are malleable and rejected, as in EIP-2.
+const SECP256K1_HALF_ORDER: U256 = U256([
+	0xdfe92f46681b20a0,
+	0x5d576e7357a4501d,
+	0xffffffffffffffff,
+	0x7fffffffffffffff,
+]);
+
+/// EIP-712 domain separator of an ERC-20 contract supporting EIP-2612 permits.
+pub fn permit_domain_separator(name: &str, chain_id: u64, contract: Address) -> H256 {
+	let mut data = Vec::with_capacity(5 * 32);
+	data.extend_from_slice(&keccak_256(
+		b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+	));
+	data.extend_from_slice(&keccak_256(name.as_bytes()));
+	data.extend_from_slice(&keccak_256(b"1"));
+	data.extend_from_slice(&U256::from(chain_id).to_big_endian());
+	data.extend_from_slice(H256::from(contract).as_bytes());
+	H256(keccak_256(&data))
+}
+
+/// EIP-712 hash of the EIP-2612 `Permit` struct.
+pub fn permit_hash(
+	owner: Address,
+	spender: Address,
+	value: U256,
+	nonce: u64,
+	deadline: U256,
+) -> H256 {
+	let mut data = Vec::with_capacity(6 * 32);
+	data.extend_from_slice(&keccak_256(
+		b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)",
+	));
+	data.extend_from_slice(H256::from(owner).as_bytes());
+	data.extend_from_slice(H256::from(spender).as_bytes());
+	data.extend_from_slice(&value.to_big_endian());
+	data.extend_from_slice(&U256::from(nonce).to_big_endian());
+	data.extend_from_slice(&deadline.to_big_endian());
+	H256(keccak_256(&data))
+}
+
+/// EIP-712 hash of the `PermitCross` struct, the permit for cross addresses.
+pub fn permit_cross_hash(
+	owner: &CrossAddress,
+	spender: &CrossAddress,
+	value: U256,
+	nonce: u64,
+	deadline: U256,
+) -> H256 {
+	let mut data = Vec::with_capacity(6 * 32);
+	data.extend_from_slice(&keccak_256(
+		concat!(
+			"PermitCross(CrossAddress owner,CrossAddress spender,uint256 value,uint256 nonce,",
+			"uint256 deadline)CrossAddress(address eth,uint256 sub)",
+		)
+		.as_bytes(),
+	));
+	data.extend_from_slice(owner.eip712_hash().as_bytes());
+	data.extend_from_slice(spender.eip712_hash().as_bytes());
+	data.extend_from_slice(&value.to_big_endian());
+	data.extend_from_slice(&U256::from(nonce).to_big_endian());
+	data.extend_from_slice(&deadline.to_big_endian());
+	H256(keccak_256(&data))
+}
+
+/// EIP-712 digest of the permit, which is signed by the owner.
+pub fn permit_digest(domain_separator: H256, permit_hash: H256) -> [u8; 32] {
+	let mut data = Vec::with_capacity(2 + 2 * 32);
+	data.extend_from_slice(b"\x19\x01");
+	data.extend_from_slice(domain_separator.as_bytes());
+	data.extend_from_slice(permit_hash.as_bytes());
+	keccak_256(&data)
+}
+
+/// Recover the Ethereum address, which signed the `digest`.
+///
+/// The `signature` is `r`, `s` and `v` concatenated.
+pub fn recover_signer(digest: &[u8; 32], signature: &[u8]) -> Option<Address> {
+	let signature: &[u8; 65] = signature.try_into().ok()?;
+	if U256::from_big_endian(&signature[32..64]) > SECP256K1_HALF_ORDER {
+		return None;
+	}
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(signature, digest).ok()?;
+	Some(H160::from(H256(keccak_256(&public))))
+}
@@ -2,11 +2,25 @@
 //!
 //! The module contains helpers.
 //!
+use core::marker::PhantomData;
+
 use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 	pallet_prelude::DispatchResultWithPostInfo,
+	traits::Get,
 	weights::Weight,
 };
+use sp_io::MultiRemovalResults;
+use sp_runtime::Perbill;
+use sp_weights::WeightMeter;
+
+/// Share of the block weight spent on each step of a multi-block migration.
+const MIGRATION_STEP_SHARE: Perbill = Perbill::from_percent(10);
+
+/// Weight available to a single step of a multi-block migration, run in `on_initialize`.
+pub fn migration_step_limit<T: frame_system::Config>() -> Weight {
+	MIGRATION_STEP_SHARE * T::BlockWeights::get().max_block
+}
 
 /// Add weight for a `DispatchResultWithPostInfo`
 ///
@@ -28,3 +42,71 @@ pub fn add_weight_to_post_info(target: &mut DispatchResultWithPostInfo, addition
 		_ => {}
 	}
 }
+
+/// Bounded removal of the storage of a destroyed collection.
+///
+/// Every removed storage entry consumes the weight of a single read and write from the meter.
+pub struct StorageCleaner<'a, T: frame_system::Config> {
+	meter: &'a mut WeightMeter,
+	removed: u32,
+	_phantom: PhantomData<T>,
+}
+
+impl<'a, T: frame_system::Config> StorageCleaner<'a, T> {
+	/// Create a cleaner consuming the weight from `meter`.
+	pub fn new(meter: &'a mut WeightMeter) -> Self {
+		Self {
+			meter,
+			removed: 0,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Number of storage entries removed by this cleaner.
+	pub fn removed(&self) -> u32 {
+		self.removed
+	}
+
+	fn entry_weight() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	/// Number of storage entries which can still be removed.
+	pub fn limit(&self) -> u32 {
+		self.meter
+			.remaining()
+			.checked_div_per_component(&Self::entry_weight())
+			.unwrap_or(u64::MAX)
+			.min(u32::MAX.into()) as u32
+	}
+
+	/// Account the removal of a single storage entry, and an `extra` weight spent along with it.
+	///
+	/// Returns `false` if there is not enough weight left, in which case nothing is consumed.
+	pub fn remove_entry(&mut self, extra: Weight) -> bool {
+		if self
+			.meter
+			.try_consume(Self::entry_weight().saturating_add(extra))
+			.is_err()
+		{
+			return false;
+		}
+		self.removed = self.removed.saturating_add(1);
+		true
+	}
+
+	/// Clear a storage prefix with `clear`, which receives the maximum number of entries to remove.
+	///
+	/// Returns `true` once there are no entries left under the prefix.
+	pub fn clear_prefix(&mut self, clear: impl FnOnce(u32) -> MultiRemovalResults) -> bool {
+		let limit = self.limit();
+		if limit == 0 {
+			return false;
+		}
+		let result = clear(limit);
+		self.meter
+			.consume(Self::entry_weight().saturating_mul(result.loops.into()));
+		self.removed = self.removed.saturating_add(result.unique);
+		result.maybe_cursor.is_none()
+	}
+}
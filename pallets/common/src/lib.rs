@@ -48,14 +48,22 @@
 //!
 //! **Collection administrator** - For a collection, you can set administrators who have the right
 //! to most actions on the collection.
+//!
+//! **Collection administrator roles** - The rights of an administrator can be restricted to
+//! minting, editing properties, managing the allow list, the sponsor or the limits,
+//! see [`CollectionAdminRoles`].
 
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{
+	boxed::Box,
+	string::{String, ToString},
+};
 use core::{
+	cell::RefCell,
 	marker::PhantomData,
 	ops::{Deref, DerefMut},
 	slice::from_ref,
@@ -69,29 +77,40 @@ use frame_support::{
 	traits::{
 		fungible::{Balanced, Debt, Inspect},
 		tokens::{Imbalance, Precision, Preservation},
-		Get,
+		Get, Time,
 	},
-	transactional,
+	transactional, PalletId,
 };
 pub use pallet::*;
-use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
+use pallet_evm::{account::CrossAccountId, GasWeightMapping, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::{SubstrateRecorder, WithRecorder};
-use sp_core::H160;
-use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, DispatchResult};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{sr25519, H160, H256, U256};
+use sp_runtime::{
+	traits::{AccountIdConversion, UniqueSaturatedInto, Zero},
+	ArithmeticError, DispatchError, DispatchResult,
+};
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec,
+};
 use sp_weights::Weight;
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, AccessMode, Collection, CollectionId,
-	CollectionLimits, CollectionMode, CollectionPermissions,
+	budget::Budget, mapping::TokenAddressMapping, AccessMode, Collection, CollectionAdminRoles,
+	CollectionDestruction, CollectionId, CollectionLimits, CollectionMode, CollectionPermissions,
 	CollectionProperties as CollectionPropertiesT, CollectionStats, CreateCollectionData,
-	CreateItemData, CreateItemExData, PhantomType, PropertiesError, PropertiesPermissionMap,
-	Property, PropertyKey, PropertyKeyPermission, PropertyPermission, PropertyScope, PropertyValue,
-	RpcCollection, RpcCollectionFlags, SponsoringRateLimit, SponsorshipState, TokenChild,
-	TokenData, TokenId, TokenOwnerError, TokenProperties, TrySetProperty, COLLECTION_ADMINS_LIMIT,
-	COLLECTION_NUMBER_LIMIT, CUSTOM_DATA_LIMIT, FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
-	MAX_SPONSOR_TIMEOUT, MAX_TOKEN_OWNERSHIP, MAX_TOKEN_PREFIX_LENGTH,
-	NFT_SPONSOR_TRANSFER_TIMEOUT, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
+	CreateItemData, CreateItemExData, PhantomType, Properties, PropertiesError, PropertiesMap,
+	PropertiesPermissionMap, PropertiesValueTypeMap, Property, PropertyKey, PropertyKeyPermission,
+	PropertyKeyValueType, PropertyPermission, PropertyScope, PropertyValue, PropertyWriteGrant,
+	PropertyWriteGrantKeys, Royalty, RpcCollection, RpcCollectionAdminRoles, RpcCollectionFlags,
+	RpcPropertyKeyPermission, RpcTokenProperty, SponsoringRateLimit, SponsorshipState, TokenChild,
+	TokenData, TokenId, TokenOwnerError, TokenProperties, TokenUser, TrySetProperty,
+	COLLECTION_ADMINS_LIMIT, COLLECTION_NUMBER_LIMIT, CUSTOM_DATA_LIMIT,
+	FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT, MAX_SPONSOR_TIMEOUT, MAX_TOKEN_OWNERSHIP,
+	MAX_TOKEN_PREFIX_LENGTH, MAX_TOKEN_URI_LENGTH, NFT_SPONSOR_TRANSFER_TIMEOUT,
+	REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT, ROYALTY_BASIS_POINTS_DENOMINATOR, ROYALTY_PROPERTY_KEY,
 };
 use up_pov_estimate_rpc::PovInfo;
 
@@ -104,6 +123,7 @@ pub mod helpers;
 #[allow(missing_docs)]
 pub mod weights;
 
+use helpers::StorageCleaner;
 use weights::WeightInfo;
 
 /// Weight info.
@@ -202,7 +222,7 @@ impl<T: Config> CollectionHandle<T> {
 		sponsor: T::AccountId,
 	) -> DispatchResult {
 		self.check_is_internal()?;
-		self.check_is_owner_or_admin(sender)?;
+		self.check_has_admin_role(sender, |roles| roles.sponsor_manager)?;
 
 		self.collection.sponsorship = SponsorshipState::Unconfirmed(sponsor.clone());
 
@@ -269,7 +289,7 @@ impl<T: Config> CollectionHandle<T> {
 	/// Remove collection sponsor.
 	pub fn remove_sponsor(&mut self, sender: &T::CrossAccountId) -> DispatchResult {
 		self.check_is_internal()?;
-		self.check_is_owner_or_admin(sender)?;
+		self.check_has_admin_role(sender, |roles| roles.sponsor_manager)?;
 
 		self.collection.sponsorship = SponsorshipState::Disabled;
 
@@ -355,16 +375,65 @@ impl<T: Config> CollectionHandle<T> {
 		Ok(())
 	}
 
+	/// Returns the roles of the `user` if it is the owner or administrator of the collection.
+	///
+	/// The owner and administrators without explicitly set roles have all of them.
+	pub fn admin_roles(&self, user: &T::CrossAccountId) -> Option<CollectionAdminRoles> {
+		if *user.as_sub() == self.owner {
+			return Some(CollectionAdminRoles::all());
+		}
+		if !<IsAdmin<T>>::get((self.id, user)) {
+			return None;
+		}
+		Some(<AdminRoles<T>>::get((self.id, user)).unwrap_or_else(CollectionAdminRoles::all))
+	}
+
+	/// Returns **true** if the `user` is the owner of the collection
+	/// or its administrator having the `role`.
+	pub fn has_admin_role(
+		&self,
+		user: &T::CrossAccountId,
+		role: impl FnOnce(CollectionAdminRoles) -> bool,
+	) -> bool {
+		self.admin_roles(user).map_or(false, role)
+	}
+
+	/// Checks if the `user` is the owner of the collection or its administrator having the `role`.
+	pub fn check_has_admin_role(
+		&self,
+		user: &T::CrossAccountId,
+		role: impl FnOnce(CollectionAdminRoles) -> bool,
+	) -> DispatchResult {
+		ensure!(self.has_admin_role(user, role), <Error<T>>::NoPermission);
+		Ok(())
+	}
+
 	/// Returns **true** if
-	/// * the `user`is a collection owner or admin
+	/// * the `user`is a collection owner or admin with the token manager role
 	/// * the collection limits allow the owner/admins to transfer/burn any collection token
 	pub fn ignores_token_restrictions(&self, user: &T::CrossAccountId) -> bool {
-		self.limits.owner_can_transfer() && self.is_owner_or_admin(user)
+		self.limits.owner_can_transfer() && self.has_admin_role(user, |roles| roles.token_manager)
+	}
+
+	/// Get the mint authority of the fungible collection, if it is set.
+	pub fn mint_authority(&self) -> Option<T::CrossAccountId> {
+		<MintAuthority<T>>::get(self.id)
+	}
+
+	/// Get the supply cap of the fungible collection, the maximum `u128` value if it is not set.
+	pub fn supply_cap(&self) -> u128 {
+		<SupplyCap<T>>::get(self.id).unwrap_or(u128::MAX)
+	}
+
+	/// Returns **true** if the `user` is the mint authority of the collection.
+	pub fn is_mint_authority(&self, user: &T::CrossAccountId) -> bool {
+		self.mint_authority()
+			.map_or(false, |authority| authority.conv_eq(user))
 	}
 
 	/// Return **true** if `user` does not have enough token parts, and he can ignore such restrictions.
 	pub fn ignores_owned_amount(&self, user: &T::CrossAccountId) -> bool {
-		self.limits.owner_can_transfer() && self.is_owner_or_admin(user)
+		self.limits.owner_can_transfer() && self.has_admin_role(user, |roles| roles.token_manager)
 	}
 
 	/// Checks if the user is in the allow list. If not [Error::AddressNotInAllowlist] returns.
@@ -376,9 +445,45 @@ impl<T: Config> CollectionHandle<T> {
 		Ok(())
 	}
 
+	/// Checks if the user is not frozen in the collection. If it is [Error::AccountIsFrozen] returns.
+	pub fn check_account_not_frozen(&self, user: &T::CrossAccountId) -> DispatchResult {
+		ensure!(
+			!<FrozenAccounts<T>>::get((self.id, user)),
+			<Error<T>>::AccountIsFrozen
+		);
+		Ok(())
+	}
+
+	/// Checks if the token is not frozen. If it is [Error::TokenIsFrozen] returns.
+	pub fn check_token_not_frozen(&self, token: TokenId) -> DispatchResult {
+		ensure!(
+			!<FrozenTokens<T>>::get((self.id, token)),
+			<Error<T>>::TokenIsFrozen
+		);
+		Ok(())
+	}
+
+	/// Checks if the token properties are not frozen. If they are [Error::TokenPropertiesAreFrozen] returns.
+	pub fn check_token_properties_not_frozen(&self, token: TokenId) -> DispatchResult {
+		ensure!(
+			!<FrozenTokenProperties<T>>::get((self.id, token)),
+			<Error<T>>::TokenPropertiesAreFrozen
+		);
+		Ok(())
+	}
+
+	/// Checks if the collection metadata is not frozen. If it is [Error::CollectionMetadataIsFrozen] returns.
+	pub fn check_metadata_not_frozen(&self) -> DispatchResult {
+		ensure!(
+			!<FrozenCollectionMetadata<T>>::get(self.id),
+			<Error<T>>::CollectionMetadataIsFrozen
+		);
+		Ok(())
+	}
+
 	/// Changes collection owner to another account
 	/// #### Store read/writes
-	/// 1 writes
+	/// 2 writes
 	pub fn change_owner(
 		&mut self,
 		caller: T::CrossAccountId,
@@ -387,6 +492,7 @@ impl<T: Config> CollectionHandle<T> {
 		self.check_is_internal()?;
 		self.check_is_owner(&caller)?;
 		self.collection.owner = new_owner.as_sub().clone();
+		<PendingCollectionOwner<T>>::remove(self.id);
 
 		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnerChanged(
 			self.id,
@@ -401,6 +507,82 @@ impl<T: Config> CollectionHandle<T> {
 
 		self.save()
 	}
+
+	/// Propose `new_owner` as the owner of the collection.
+	///
+	/// In order for the ownership to be transferred, it must be accepted by the new owner
+	/// through [`Self::accept_ownership`]. Replaces the previously proposed owner, if any.
+	pub fn propose_owner(
+		&self,
+		caller: &T::CrossAccountId,
+		new_owner: T::CrossAccountId,
+	) -> DispatchResult {
+		self.check_is_internal()?;
+		self.check_is_owner(caller)?;
+
+		<PendingCollectionOwner<T>>::insert(self.id, new_owner.as_sub());
+
+		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnershipTransferProposed(
+			self.id,
+			new_owner.as_sub().clone(),
+		));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(self.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+		Ok(())
+	}
+
+	/// Accept the ownership of the collection.
+	///
+	/// Before accepting the ownership, the user must be proposed as the owner of the collection
+	/// via [`Self::propose_owner`].
+	pub fn accept_ownership(&mut self, sender: &T::CrossAccountId) -> DispatchResult {
+		self.check_is_internal()?;
+		ensure!(
+			<PendingCollectionOwner<T>>::get(self.id).as_ref() == Some(sender.as_sub()),
+			Error::<T>::AcceptOwnershipFail
+		);
+
+		<PendingCollectionOwner<T>>::remove(self.id);
+		self.collection.owner = sender.as_sub().clone();
+
+		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnerChanged(
+			self.id,
+			sender.as_sub().clone(),
+		));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(self.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		self.save()
+	}
+
+	/// Cancel the proposed transfer of the collection ownership.
+	pub fn cancel_ownership_transfer(&self, caller: &T::CrossAccountId) -> DispatchResult {
+		self.check_is_internal()?;
+		self.check_is_owner(caller)?;
+		ensure!(
+			<PendingCollectionOwner<T>>::contains_key(self.id),
+			Error::<T>::OwnershipTransferNotProposed
+		);
+
+		<PendingCollectionOwner<T>>::remove(self.id);
+
+		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnershipTransferCancelled(self.id));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(self.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+		Ok(())
+	}
 }
 
 #[frame_support::pallet]
@@ -408,7 +590,7 @@ pub mod pallet {
 
 	use dispatch::CollectionDispatch;
 	use frame_support::{
-		pallet_prelude::*, storage::Key, traits::StorageVersion, Blake2_128Concat,
+		pallet_prelude::*, storage::Key, traits::StorageVersion, Blake2_128Concat, Twox64Concat,
 	};
 	use scale_info::TypeInfo;
 	use up_data_structs::{mapping::TokenAddressMapping, TokenId};
@@ -450,6 +632,13 @@ pub mod pallet {
 
 		/// Mapper for token addresses to [`CrossAccountId`].
 		type CrossTokenAddressMapping: TokenAddressMapping<Self::CrossAccountId>;
+
+		/// Handler notified when token pieces leave their owner.
+		type OnTokenTransfer: OnTokenTransfer<Self::CrossAccountId>;
+
+		/// The swap escrow id, used for deriving the account holding assets of open swap offers.
+		#[pallet::constant]
+		type SwapPalletId: Get<PalletId>;
 	}
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -506,6 +695,8 @@ pub mod pallet {
 		),
 
 		/// New collection was destroyed
+		///
+		/// Fired once all of the collection storage is removed.
 		CollectionDestroyed(
 			/// Globally unique identifier of collection.
 			CollectionId,
@@ -692,6 +883,154 @@ pub mod pallet {
 			/// ID of the affected collection.
 			CollectionId,
 		),
+
+		/// Token was frozen.
+		TokenFrozen(
+			/// ID of the affected collection.
+			CollectionId,
+			/// ID of the frozen token.
+			TokenId,
+		),
+
+		/// Token was thawed.
+		TokenThawed(
+			/// ID of the affected collection.
+			CollectionId,
+			/// ID of the thawed token.
+			TokenId,
+		),
+
+		/// Account was frozen in the collection.
+		AccountFrozen(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Address of the frozen account.
+			T::CrossAccountId,
+		),
+
+		/// Account was thawed in the collection.
+		AccountThawed(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Address of the thawed account.
+			T::CrossAccountId,
+		),
+
+		/// Token transfer lock was set or removed.
+		TokenLockChanged(
+			/// ID of the affected collection.
+			CollectionId,
+			/// ID of the affected token.
+			TokenId,
+			/// Is the token locked against transfers?
+			bool,
+		),
+
+		/// ERC-4907 user of the token was set or removed.
+		TokenUserChanged(
+			/// ID of the affected collection.
+			CollectionId,
+			/// ID of the affected token.
+			TokenId,
+			/// New user of the token with its expiry block, if any.
+			Option<TokenUser<T::CrossAccountId>>,
+		),
+
+		/// Collection was destroyed, its tokens are being removed in the background.
+		CollectionDestructionStarted(
+			/// ID of the destroyed collection.
+			CollectionId,
+		),
+
+		/// Transfer of the collection ownership was proposed, awaiting acceptance by the new owner.
+		CollectionOwnershipTransferProposed(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Proposed owner address.
+			T::AccountId,
+		),
+
+		/// Proposed transfer of the collection ownership was cancelled.
+		CollectionOwnershipTransferCancelled(
+			/// ID of the affected collection.
+			CollectionId,
+		),
+
+		/// Roles of a collection admin were set.
+		CollectionAdminRolesSet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Admin address.
+			T::CrossAccountId,
+			/// New roles of the admin.
+			CollectionAdminRoles,
+		),
+
+		/// Account was granted to write a token property.
+		TokenPropertyWriteGranted(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Address of the granted account.
+			T::CrossAccountId,
+			/// Key of the token property.
+			PropertyKey,
+		),
+
+		/// Grant to write a token property was revoked from an account.
+		TokenPropertyWriteRevoked(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Address of the account.
+			T::CrossAccountId,
+			/// Key of the token property.
+			PropertyKey,
+		),
+
+		/// The default token property of the collection has been added or edited.
+		DefaultTokenPropertySet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// The property that was set.
+			PropertyKey,
+		),
+
+		/// The default token property of the collection has been deleted.
+		DefaultTokenPropertyDeleted(
+			/// ID of the affected collection.
+			CollectionId,
+			/// The property that was deleted.
+			PropertyKey,
+		),
+
+		/// The token properties have been permanently frozen.
+		TokenPropertiesFrozen(
+			/// ID of the collection to which the token belongs.
+			CollectionId,
+			/// ID of the affected token.
+			TokenId,
+		),
+
+		/// The collection metadata has been permanently frozen.
+		CollectionMetadataFrozen(
+			/// ID of the affected collection.
+			CollectionId,
+		),
+
+		/// Mint authority of the fungible collection was set or removed.
+		MintAuthoritySet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// The new mint authority, if any.
+			Option<T::CrossAccountId>,
+		),
+
+		/// Supply cap of the fungible collection was set or removed.
+		SupplyCapSet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// The new supply cap, if any.
+			Option<u128>,
+		),
 	}
 
 	#[pallet::error]
@@ -792,6 +1131,61 @@ pub mod pallet {
 
 		/// Not Fungible item data used to mint in Fungible collection.
 		NotFungibleDataUsedToMintFungibleCollectionToken,
+
+		/// Royalty can not be more than 10000 basis points (100%).
+		RoyaltyTooHigh,
+
+		/// The token is locked and can not be transferred.
+		TokenTransferLocked,
+
+		/// The token is frozen by the collection owner or admin.
+		TokenIsFrozen,
+
+		/// The account is frozen in the collection by the collection owner or admin.
+		AccountIsFrozen,
+
+		/// The deadline of the approval has already passed.
+		AllowanceDeadlinePassed,
+
+		/// Tried to accept the ownership of a collection without being proposed as its owner.
+		AcceptOwnershipFail,
+
+		/// There is no proposed transfer of the collection ownership.
+		OwnershipTransferNotProposed,
+
+		/// Tried to grant an account to write more token property keys than allowed.
+		PropertyWriteGrantLimitExceeded,
+
+		/// Token property value doesn't match the type declared for its key.
+		PropertyValueTypeMismatch,
+
+		/// Token URI template of the collection has an unbalanced brace or an invalid placeholder,
+		/// or the token URI rendered from it is too long.
+		InvalidTokenUriTemplate,
+
+		/// Token properties are permanently frozen and can't be changed.
+		TokenPropertiesAreFrozen,
+
+		/// Collection metadata is permanently frozen and can't be changed.
+		CollectionMetadataIsFrozen,
+
+		/// Supply cap and mint authority are only supported by fungible collections.
+		NotFungibleCollection,
+
+		/// Supply cap can't be lower than the current total supply of the collection.
+		SupplyCapBelowTotalSupply,
+
+		/// The permit deadline has passed.
+		PermitDeadlinePassed,
+
+		/// The permit isn't signed by the owner, or was already used.
+		InvalidPermitSignature,
+
+		/// Assets held in the swap escrow can't be frozen or locked.
+		AssetIsEscrowed,
+
+		/// The property is used in the URI of a token with frozen properties and can't be changed.
+		TokenUriIsFrozen,
 	}
 
 	/// Storage of the count of created collections. Essentially contains the last collection ID.
@@ -803,6 +1197,24 @@ pub mod pallet {
 	pub type DestroyedCollectionCount<T> =
 		StorageValue<Value = CollectionId, QueryKind = ValueQuery>;
 
+	/// Destroyed collections, whose storage is still being removed.
+	#[pallet::storage]
+	pub type DestroyingCollections<T> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = CollectionId,
+		Value = CollectionDestruction,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Proposed owners of collections, who have not accepted the ownership yet.
+	#[pallet::storage]
+	pub type PendingCollectionOwner<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = <T as frame_system::Config>::AccountId,
+		QueryKind = OptionQuery,
+	>;
+
 	/// Storage of collection info.
 	#[pallet::storage]
 	pub type CollectionById<T> = StorageMap<
@@ -832,6 +1244,25 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Storage of token property value types of a collection.
+	#[pallet::storage]
+	pub type CollectionPropertyValueTypes<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = PropertiesValueTypeMap,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Storage of default token properties of a collection,
+	/// used for the tokens which have no own value for the property.
+	#[pallet::storage]
+	pub type DefaultTokenProperties<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = TokenProperties,
+		QueryKind = ValueQuery,
+	>;
+
 	/// Storage of the amount of collection admins.
 	#[pallet::storage]
 	pub type AdminAmount<T> = StorageMap<
@@ -852,6 +1283,19 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Roles of collection admins with restricted rights.
+	///
+	/// Admins without an entry have all the roles.
+	#[pallet::storage]
+	pub type AdminRoles<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = CollectionAdminRoles,
+		QueryKind = OptionQuery,
+	>;
+
 	/// Allowlisted collection users.
 	#[pallet::storage]
 	pub type Allowlist<T: Config> = StorageNMap<
@@ -863,23 +1307,120 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
-	/// Not used by code, exists only to provide some types to metadata.
+	/// Tokens frozen by the collection owner or admins.
 	#[pallet::storage]
-	pub type DummyStorageValue<T: Config> = StorageValue<
-		Value = (
-			CollectionStats,
-			CollectionId,
-			TokenId,
-			TokenChild,
-			PhantomType<(
-				TokenData<T::CrossAccountId>,
-				RpcCollection<T::AccountId>,
-				// PoV Estimate Info
-				PovInfo,
-			)>,
-		),
-		QueryKind = OptionQuery,
-	>;
+	pub type FrozenTokens<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Accounts whose holdings in a collection are frozen by the collection owner or admins.
+	#[pallet::storage]
+	pub type FrozenAccounts<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Token property keys which accounts are granted to write regardless of property permissions.
+	#[pallet::storage]
+	pub type TokenPropertyWriteGrants<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = PropertyWriteGrantKeys,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Tokens whose properties are permanently frozen.
+	#[pallet::storage]
+	pub type FrozenTokenProperties<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Number of tokens whose properties are permanently frozen in a collection.
+	///
+	/// While there are such tokens, the properties their URIs are derived from can't be changed.
+	#[pallet::storage]
+	pub type FrozenTokenPropertiesCount<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = u32,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Collections whose properties, token property permissions and default token properties
+	/// are permanently frozen.
+	#[pallet::storage]
+	pub type FrozenCollectionMetadata<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// The only accounts allowed to mint tokens of fungible collections.
+	///
+	/// The mint authority can also burn tokens of any holder without an allowance.
+	#[pallet::storage]
+	pub type MintAuthority<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = <T as Config>::CrossAccountId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Maximum total supply of fungible collections.
+	///
+	/// Collections without a supply cap are unlimited.
+	#[pallet::storage]
+	pub type SupplyCap<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = u128,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Nonces of the next EIP-2612 permits, signed by the token owners.
+	///
+	/// Fungible collections use the default token ID.
+	#[pallet::storage]
+	pub type PermitNonces<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Twox64Concat, TokenId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = u64,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Not used by code, exists only to provide some types to metadata.
+	#[pallet::storage]
+	pub type DummyStorageValue<T: Config> = StorageValue<
+		Value = (
+			CollectionStats,
+			CollectionId,
+			TokenId,
+			TokenChild,
+			PhantomType<(
+				TokenData<T::CrossAccountId>,
+				RpcCollection<T::AccountId>,
+				RpcCollectionAdminRoles,
+				PropertyWriteGrant<T::CrossAccountId>,
+				// PoV Estimate Info
+				PovInfo,
+			)>,
+		),
+		QueryKind = OptionQuery,
+	>;
 }
 
 enum LazyValueState<'a, T> {
@@ -961,9 +1502,12 @@ fn check_token_permissions<T: Config>(
 	is_collection_admin: &mut LazyValue<bool>,
 	is_token_owner: &mut LazyValue<Result<bool, DispatchError>>,
 	is_token_exist: &mut LazyValue<bool>,
+	is_write_granted: impl FnOnce() -> bool,
 ) -> DispatchResult {
+	// Accounts granted to write the property don't need the admin or owner permission.
 	if !(collection_admin_permitted && *is_collection_admin.value()
-		|| token_owner_permitted && (*is_token_owner.value())?)
+		|| token_owner_permitted && (*is_token_owner.value())?
+		|| is_write_granted())
 	{
 		fail!(<Error<T>>::NoPermission);
 	}
@@ -1003,6 +1547,33 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// Get a vector of collection admins together with their roles.
+	pub fn adminlist_with_roles(
+		collection: CollectionId,
+	) -> Vec<(T::CrossAccountId, CollectionAdminRoles)> {
+		<IsAdmin<T>>::iter_prefix((collection,))
+			.map(|(admin, _)| {
+				let roles = <AdminRoles<T>>::get((collection, &admin))
+					.unwrap_or_else(CollectionAdminRoles::all);
+				(admin, roles)
+			})
+			.collect()
+	}
+
+	/// Get a vector of token property write grants given to accounts in the collection.
+	pub fn token_property_write_grants(
+		collection: CollectionId,
+	) -> Vec<PropertyWriteGrant<T::CrossAccountId>> {
+		<TokenPropertyWriteGrants<T>>::iter_prefix((collection,))
+			.flat_map(|(account, keys)| {
+				keys.into_iter().map(move |key| PropertyWriteGrant {
+					account: account.clone(),
+					key,
+				})
+			})
+			.collect()
+	}
+
 	/// Get a vector of users allowed to mint tokens.
 	pub fn allowlist(collection: CollectionId) -> Vec<T::CrossAccountId> {
 		<Allowlist<T>>::iter_prefix((collection,))
@@ -1015,6 +1586,128 @@ impl<T: Config> Pallet<T> {
 		<Allowlist<T>>::get((collection, user))
 	}
 
+	/// The account holding assets of open swap offers.
+	pub fn swap_escrow() -> T::CrossAccountId {
+		T::CrossAccountId::from_sub(T::SwapPalletId::get().into_account_truncating())
+	}
+
+	/// Checks that no pieces of the `token` are held in the swap escrow,
+	/// which must always be able to release them. If they are [Error::AssetIsEscrowed] returns.
+	pub fn check_token_not_escrowed(
+		tokens: &dyn CommonCollectionOperations<T>,
+		token: TokenId,
+	) -> DispatchResult {
+		ensure!(
+			tokens.balance(Self::swap_escrow(), token) == 0,
+			<Error<T>>::AssetIsEscrowed
+		);
+		Ok(())
+	}
+
+	/// Is `token` frozen in `collection`.
+	pub fn token_frozen(collection: CollectionId, token: TokenId) -> bool {
+		<FrozenTokens<T>>::get((collection, token))
+	}
+
+	/// Are the properties of `token` permanently frozen in `collection`.
+	pub fn token_properties_frozen(collection: CollectionId, token: TokenId) -> bool {
+		<FrozenTokenProperties<T>>::get((collection, token))
+	}
+
+	/// Is the metadata of `collection` permanently frozen.
+	pub fn collection_metadata_frozen(collection: CollectionId) -> bool {
+		<FrozenCollectionMetadata<T>>::get(collection)
+	}
+
+	/// Is `user` frozen in `collection`.
+	pub fn account_frozen(collection: CollectionId, user: T::CrossAccountId) -> bool {
+		<FrozenAccounts<T>>::get((collection, user))
+	}
+
+	/// Current block number, used to measure approval deadlines and token user expiries.
+	pub fn block_number() -> u64 {
+		<frame_system::Pallet<T>>::block_number().unique_saturated_into()
+	}
+
+	/// Has the approval `deadline` passed? Approvals without deadline never expire.
+	pub fn deadline_passed(deadline: Option<u64>) -> bool {
+		deadline.is_some_and(|deadline| deadline < Self::block_number())
+	}
+
+	/// Checks that the approval `deadline` hasn't passed yet.
+	pub fn check_deadline(deadline: Option<u64>) -> DispatchResult {
+		ensure!(
+			!Self::deadline_passed(deadline),
+			<Error<T>>::AllowanceDeadlinePassed
+		);
+		Ok(())
+	}
+
+	/// Nonce of the next permit signed by the `owner` of the token.
+	pub fn permit_nonce(
+		collection: CollectionId,
+		token: TokenId,
+		owner: &T::CrossAccountId,
+	) -> u64 {
+		<PermitNonces<T>>::get((collection, token, owner))
+	}
+
+	/// EIP-712 domain separator of the ERC-20 `contract` named `name`.
+	pub fn permit_domain_separator(name: &str, contract: H160) -> H256 {
+		eth::permit_domain_separator(name, <T as pallet_evm::Config>::ChainId::get(), contract)
+	}
+
+	/// Weight of [`Self::use_permit`].
+	pub fn use_permit_weight() -> Weight {
+		// Signature check costs the same as the `ecrecover` precompile.
+		<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(3000, true)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
+	/// Check the EIP-2612 permit signed by the `owner` and use up its nonce.
+	///
+	/// Ethereum owners sign the permit with ECDSA, and Substrate owners sign it with sr25519.
+	///
+	/// - `permit_hash`: EIP-712 hash of the permit with the given nonce.
+	/// - `deadline`: Timestamp in seconds, after which the permit is no longer valid.
+	pub fn use_permit(
+		collection: CollectionId,
+		token: TokenId,
+		owner: &T::CrossAccountId,
+		domain_separator: H256,
+		permit_hash: impl FnOnce(u64) -> H256,
+		deadline: U256,
+		signature: &[u8],
+	) -> DispatchResult {
+		let now: u128 = <T as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+		ensure!(
+			deadline >= U256::from(now / 1000),
+			<Error<T>>::PermitDeadlinePassed
+		);
+
+		let nonce = Self::permit_nonce(collection, token, owner);
+		let digest = eth::permit_digest(domain_separator, permit_hash(nonce));
+		let signed = if owner.is_canonical_substrate() {
+			let public = sr25519::Public::try_from(owner.as_sub().encode().as_slice());
+			let signature = sr25519::Signature::try_from(signature);
+			match (public, signature) {
+				(Ok(public), Ok(signature)) => {
+					sp_io::crypto::sr25519_verify(&signature, &digest, &public)
+				}
+				_ => false,
+			}
+		} else {
+			eth::recover_signer(&digest, signature).as_ref() == Some(owner.as_eth())
+		};
+		ensure!(signed, <Error<T>>::InvalidPermitSignature);
+		let next_nonce = nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+		// =========
+
+		<PermitNonces<T>>::insert((collection, token, owner), next_nonce);
+		Ok(())
+	}
+
 	/// Get statistics of collections.
 	pub fn collection_stats() -> CollectionStats {
 		let created = <CreatedCollectionCount<T>>::get();
@@ -1069,9 +1762,14 @@ impl<T: Config> Pallet<T> {
 			flags,
 		} = <CollectionById<T>>::get(collection)?;
 
+		let value_types = <CollectionPropertyValueTypes<T>>::get(collection);
 		let token_property_permissions = <CollectionPropertyPermissions<T>>::get(collection)
 			.into_iter()
-			.map(|(key, permission)| PropertyKeyPermission { key, permission })
+			.map(|(key, permission)| RpcPropertyKeyPermission {
+				value_type: value_types.get(&key).cloned(),
+				key,
+				permission,
+			})
 			.collect();
 
 		let properties = <CollectionProperties<T>>::get(collection)
@@ -1102,6 +1800,7 @@ impl<T: Config> Pallet<T> {
 				foreign: flags.foreign,
 				erc721metadata: flags.erc721metadata,
 			},
+			pending_owner: <PendingCollectionOwner<T>>::get(collection),
 		})
 	}
 }
@@ -1218,6 +1917,11 @@ impl<T: Config> Pallet<T> {
 		collection_properties
 			.try_set_from_iter(data.properties.into_iter())
 			.map_err(<Error<T>>::from)?;
+		if let Some(template) =
+			collection_properties.get(&erc::static_property::key::uri_template())
+		{
+			Self::check_token_uri_template(template)?;
+		}
 
 		CollectionProperties::<T>::insert(id, collection_properties);
 
@@ -1262,6 +1966,9 @@ impl<T: Config> Pallet<T> {
 
 	/// Destroy collection.
 	///
+	/// The collection stops being accessible right away, while its remaining storage
+	/// is registered in [`DestroyingCollections`] to be removed in bounded chunks.
+	///
 	/// * `collection` - Collection handler.
 	/// * `sender` - The owner or administrator of the collection.
 	pub fn destroy_collection(
@@ -1285,18 +1992,56 @@ impl<T: Config> Pallet<T> {
 		<CollectionById<T>>::remove(collection.id);
 		<AdminAmount<T>>::remove(collection.id);
 		let _ = <IsAdmin<T>>::clear_prefix((collection.id,), u32::MAX, None);
+		let _ = <AdminRoles<T>>::clear_prefix((collection.id,), u32::MAX, None);
 		let _ = <Allowlist<T>>::clear_prefix((collection.id,), u32::MAX, None);
 		<CollectionProperties<T>>::remove(collection.id);
+		<CollectionPropertyValueTypes<T>>::remove(collection.id);
+		<DefaultTokenProperties<T>>::remove(collection.id);
+		<FrozenCollectionMetadata<T>>::remove(collection.id);
+		<FrozenTokenPropertiesCount<T>>::remove(collection.id);
+		<PendingCollectionOwner<T>>::remove(collection.id);
+		<MintAuthority<T>>::remove(collection.id);
+		<SupplyCap<T>>::remove(collection.id);
+		<DestroyingCollections<T>>::insert(
+			collection.id,
+			CollectionDestruction {
+				mode: collection.mode.clone(),
+				removed_entries: 0,
+			},
+		);
+		Ok(())
+	}
+
+	/// Remove the storage of the destroyed collection kept by this pallet.
+	///
+	/// Returns `true` once there is nothing left to remove.
+	pub fn clear_destroyed_collection(
+		collection_id: CollectionId,
+		cleaner: &mut StorageCleaner<T>,
+	) -> bool {
+		cleaner.clear_prefix(|limit| <FrozenTokens<T>>::clear_prefix((collection_id,), limit, None))
+			&& cleaner.clear_prefix(|limit| {
+				<FrozenAccounts<T>>::clear_prefix((collection_id,), limit, None)
+			}) && cleaner.clear_prefix(|limit| {
+			<TokenPropertyWriteGrants<T>>::clear_prefix((collection_id,), limit, None)
+		}) && cleaner.clear_prefix(|limit| {
+			<FrozenTokenProperties<T>>::clear_prefix((collection_id,), limit, None)
+		}) && cleaner
+			.clear_prefix(|limit| <PermitNonces<T>>::clear_prefix((collection_id,), limit, None))
+	}
+
+	/// Finish the destruction of the collection, once all of its storage is removed.
+	pub fn finish_collection_destruction(collection_id: CollectionId) {
+		<DestroyingCollections<T>>::remove(collection_id);
 
-		<Pallet<T>>::deposit_event(Event::CollectionDestroyed(collection.id));
+		<Pallet<T>>::deposit_event(Event::CollectionDestroyed(collection_id));
 
 		<PalletEvm<T>>::deposit_log(
 			erc::CollectionHelpersEvents::CollectionDestroyed {
-				collection_id: eth::collection_id_to_address(collection.id),
+				collection_id: eth::collection_id_to_address(collection_id),
 			}
 			.to_log(T::ContractAddress::get()),
 		);
-		Ok(())
 	}
 
 	/// This function sets or removes a collection properties according to
@@ -1313,13 +2058,24 @@ impl<T: Config> Pallet<T> {
 		sender: &T::CrossAccountId,
 		properties_updates: impl Iterator<Item = (PropertyKey, Option<PropertyValue>)>,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		collection.check_metadata_not_frozen()?;
 
+		let frozen_uri_keys = Self::frozen_token_uri_keys(collection.id);
 		let mut stored_properties = <CollectionProperties<T>>::get(collection.id);
 
 		for (key, value) in properties_updates {
+			ensure!(
+				!frozen_uri_keys.collection.contains(&key),
+				<Error<T>>::TokenUriIsFrozen
+			);
+
 			match value {
 				Some(value) => {
+					if key == erc::static_property::key::uri_template() {
+						Self::check_token_uri_template(&value)?;
+					}
+
 					stored_properties
 						.try_set(key.clone(), value)
 						.map_err(<Error<T>>::from)?;
@@ -1351,17 +2107,81 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// This function sets or removes default token properties of a collection
+	/// the same way [`Self::modify_collection_properties`] does for the collection properties.
+	///
+	/// Values are checked against the value types declared for their keys.
+	#[transactional]
+	fn modify_default_token_properties(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		properties_updates: impl Iterator<Item = (PropertyKey, Option<PropertyValue>)>,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		ensure!(
+			!matches!(collection.mode, CollectionMode::Fungible(_)),
+			<Error<T>>::UnsupportedOperation
+		);
+		collection.check_metadata_not_frozen()?;
+
+		let frozen_uri_keys = Self::frozen_token_uri_keys(collection.id);
+		let value_types = <CollectionPropertyValueTypes<T>>::get(collection.id);
+		let mut stored_properties = <DefaultTokenProperties<T>>::get(collection.id);
+
+		for (key, value) in properties_updates {
+			ensure!(
+				!frozen_uri_keys.token.contains(&key),
+				<Error<T>>::TokenUriIsFrozen
+			);
+
+			match value {
+				Some(value) => {
+					if let Some(value_type) = value_types.get(&key) {
+						ensure!(
+							value_type.check(&value),
+							<Error<T>>::PropertyValueTypeMismatch
+						);
+					}
+
+					stored_properties
+						.try_set(key.clone(), value)
+						.map_err(<Error<T>>::from)?;
+
+					Self::deposit_event(Event::DefaultTokenPropertySet(collection.id, key));
+				}
+				None => {
+					stored_properties.remove(&key).map_err(<Error<T>>::from)?;
+
+					Self::deposit_event(Event::DefaultTokenPropertyDeleted(collection.id, key));
+				}
+			}
+
+			<PalletEvm<T>>::deposit_log(
+				erc::CollectionHelpersEvents::CollectionChanged {
+					collection_id: eth::collection_id_to_address(collection.id),
+				}
+				.to_log(T::ContractAddress::get()),
+			);
+		}
+
+		<DefaultTokenProperties<T>>::set(collection.id, stored_properties);
+
+		Ok(())
+	}
+
 	/// Sets or unsets the approval of a given operator.
 	///
 	/// The `operator` is allowed to transfer all token pieces of the `owner` on their behalf.
 	/// - `owner`: Token owner
 	/// - `operator`: Operator
 	/// - `approve`: Should operator status be granted or revoked?
+	/// - `deadline`: Last block in which the granted operator status can be used.
 	pub fn set_allowance_for_all(
 		collection: &CollectionHandle<T>,
 		owner: &T::CrossAccountId,
 		operator: &T::CrossAccountId,
 		approve: bool,
+		deadline: Option<u64>,
 		set_allowance: impl FnOnce(),
 		log: evm_coder::ethereum::Log,
 	) -> DispatchResult {
@@ -1369,6 +2189,10 @@ impl<T: Config> Pallet<T> {
 			collection.check_allowlist(owner)?;
 			collection.check_allowlist(operator)?;
 		}
+		collection.check_account_not_frozen(owner)?;
+		if approve {
+			Self::check_deadline(deadline)?;
+		}
 
 		Self::ensure_correct_receiver(operator)?;
 
@@ -1478,78 +2302,306 @@ impl<T: Config> Pallet<T> {
 		Self::modify_collection_properties(collection, sender, property_keys.map(|key| (key, None)))
 	}
 
-	/// Set collection propetry permission without any checks.
-	///
-	/// Used for migrations.
+	/// Set default token properties of the collection.
 	///
 	/// * `collection` - Collection handler.
-	/// * `property_permissions` - Property permissions.
-	pub fn set_property_permission_unchecked(
-		collection: CollectionId,
-		property_permission: PropertyKeyPermission,
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `properties` - The properties to set.
+	pub fn set_default_token_properties(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		properties: impl Iterator<Item = Property>,
 	) -> DispatchResult {
-		<CollectionPropertyPermissions<T>>::try_mutate(collection, |permissions| {
-			permissions.try_set(property_permission.key, property_permission.permission)
-		})
-		.map_err(<Error<T>>::from)?;
-		Ok(())
+		Self::modify_default_token_properties(
+			collection,
+			sender,
+			properties.map(|property| (property.key, Some(property.value))),
+		)
 	}
 
-	/// Set collection property permission.
+	/// Delete default token properties of the collection.
 	///
 	/// * `collection` - Collection handler.
 	/// * `sender` - The owner or administrator of the collection.
-	/// * `property_permission` - Property permission.
-	pub fn set_property_permission(
+	/// * `property_keys` - The properties to delete.
+	pub fn delete_default_token_properties(
 		collection: &CollectionHandle<T>,
 		sender: &T::CrossAccountId,
-		property_permission: PropertyKeyPermission,
+		property_keys: impl Iterator<Item = PropertyKey>,
 	) -> DispatchResult {
-		Self::set_scoped_property_permission(
+		Self::modify_default_token_properties(
 			collection,
 			sender,
-			PropertyScope::None,
-			property_permission,
+			property_keys.map(|key| (key, None)),
 		)
 	}
 
-	/// Set collection property permission with scope.
+	/// Get the default token property of the collection.
+	///
+	/// * `collection_id` - ID of the collection.
+	/// * `key` - Key of the property.
+	pub fn default_token_property(
+		collection_id: CollectionId,
+		key: &PropertyKey,
+	) -> Option<PropertyValue> {
+		<DefaultTokenProperties<T>>::get(collection_id)
+			.get(key)
+			.cloned()
+	}
+
+	/// Get the royalty of the collection.
+	///
+	/// * `collection_id` - ID of the collection.
+	pub fn collection_royalty(collection_id: CollectionId) -> Option<Royalty<T::CrossAccountId>> {
+		Self::read_royalty(&<CollectionProperties<T>>::get(collection_id))
+	}
+
+	/// Get the effective royalty of the token: its own royalty if set,
+	/// otherwise the royalty of the collection.
+	///
+	/// * `collection_id` - ID of the collection to which the token belongs.
+	/// * `tokens` - Implementation of the collection operations.
+	/// * `token_id` - ID of the token.
+	pub fn token_royalty(
+		collection_id: CollectionId,
+		tokens: &dyn CommonCollectionOperations<T>,
+		token_id: TokenId,
+	) -> Option<Royalty<T::CrossAccountId>> {
+		tokens
+			.get_token_properties_raw(token_id)
+			.and_then(|properties| Self::read_royalty(&properties))
+			.or_else(|| Self::collection_royalty(collection_id))
+	}
+
+	/// Set or remove the royalty of the collection.
 	///
 	/// * `collection` - Collection handler.
 	/// * `sender` - The owner or administrator of the collection.
-	/// * `scope` - Property scope.
-	/// * `property_permission` - Property permission.
-	pub fn set_scoped_property_permission(
+	/// * `royalty` - The royalty to set, `None` to remove it.
+	pub fn set_collection_royalty(
 		collection: &CollectionHandle<T>,
 		sender: &T::CrossAccountId,
-		scope: PropertyScope,
-		property_permission: PropertyKeyPermission,
+		royalty: Option<Royalty<T::CrossAccountId>>,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
-
-		let all_permissions = CollectionPropertyPermissions::<T>::get(collection.id);
-		let current_permission = all_permissions.get(&property_permission.key);
-		if matches![
-			current_permission,
-			Some(PropertyPermission { mutable: false, .. })
-		] {
-			return Err(<Error<T>>::NoPermission.into());
-		}
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		collection.check_metadata_not_frozen()?;
+		Self::check_royalty(collection, royalty.as_ref())?;
 
-		CollectionPropertyPermissions::<T>::try_mutate(collection.id, |permissions| {
-			let property_permission = property_permission.clone();
-			permissions.try_scoped_set(
-				scope,
-				property_permission.key,
-				property_permission.permission,
-			)
+		let mut stored_properties = <CollectionProperties<T>>::get(collection.id);
+		let is_set = royalty.is_some();
+		let key = Self::write_royalty(&mut stored_properties, royalty)?;
+
+		// =========
+
+		<CollectionProperties<T>>::set(collection.id, stored_properties);
+
+		if is_set {
+			Self::deposit_event(Event::CollectionPropertySet(collection.id, key));
+		} else {
+			Self::deposit_event(Event::CollectionPropertyDeleted(collection.id, key));
+		}
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
+	/// Set or remove the royalty of the token, overriding the royalty of the collection.
+	///
+	/// * `collection` - Collection handler.
+	/// * `tokens` - Implementation of the collection operations.
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `token_id` - ID of the token.
+	/// * `royalty` - The royalty to set, `None` to fall back to the collection royalty.
+	pub fn set_token_royalty(
+		collection: &CollectionHandle<T>,
+		tokens: &dyn CommonCollectionOperations<T>,
+		sender: &T::CrossAccountId,
+		token_id: TokenId,
+		royalty: Option<Royalty<T::CrossAccountId>>,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		Self::check_royalty(collection, royalty.as_ref())?;
+		ensure!(tokens.token_exists(token_id), <Error<T>>::TokenNotFound);
+		collection.check_token_properties_not_frozen(token_id)?;
+
+		let mut stored_properties = tokens
+			.get_token_properties_raw(token_id)
+			.unwrap_or_default();
+		let is_set = royalty.is_some();
+		let key = Self::write_royalty(&mut stored_properties, royalty)?;
+
+		// =========
+
+		tokens.set_token_properties_raw(token_id, stored_properties);
+
+		if is_set {
+			Self::deposit_event(Event::TokenPropertySet(collection.id, token_id, key));
+		} else {
+			Self::deposit_event(Event::TokenPropertyDeleted(collection.id, token_id, key));
+		}
+
+		Ok(())
+	}
+
+	fn check_royalty(
+		collection: &CollectionHandle<T>,
+		royalty: Option<&Royalty<T::CrossAccountId>>,
+	) -> DispatchResult {
+		ensure!(
+			!matches!(collection.mode, CollectionMode::Fungible(_)),
+			<Error<T>>::UnsupportedOperation
+		);
+		if let Some(royalty) = royalty {
+			ensure!(
+				royalty.basis_points <= ROYALTY_BASIS_POINTS_DENOMINATOR,
+				<Error<T>>::RoyaltyTooHigh
+			);
+			Self::ensure_correct_receiver(&royalty.receiver)?;
+		}
+		Ok(())
+	}
+
+	fn read_royalty<const S: u32>(
+		properties: &Properties<S>,
+	) -> Option<Royalty<T::CrossAccountId>> {
+		properties
+			.get_scoped(
+				PropertyScope::Royalty,
+				PropertyKey::truncate_from(ROYALTY_PROPERTY_KEY.to_vec()),
+			)
+			.and_then(|value| Royalty::decode(&mut value.as_slice()).ok())
+	}
+
+	/// Writes the royalty into the reserved property, returns the scoped key of the property.
+	fn write_royalty<const S: u32>(
+		properties: &mut Properties<S>,
+		royalty: Option<Royalty<T::CrossAccountId>>,
+	) -> Result<PropertyKey, DispatchError> {
+		let key = PropertyKey::truncate_from(ROYALTY_PROPERTY_KEY.to_vec());
+		let scoped_key = PropertyScope::Royalty
+			.apply(key.clone())
+			.map_err(<Error<T>>::from)?;
+
+		match royalty {
+			Some(royalty) => properties
+				.try_scoped_set(
+					PropertyScope::Royalty,
+					key,
+					PropertyValue::truncate_from(royalty.encode()),
+				)
+				.map(|_| ()),
+			None => properties
+				.try_scoped_remove(PropertyScope::Royalty, key)
+				.map(|_| ()),
+		}
+		.map_err(<Error<T>>::from)?;
+
+		Ok(scoped_key)
+	}
+
+	/// Set collection propetry permission without any checks.
+	///
+	/// Used for migrations.
+	///
+	/// * `collection` - Collection handler.
+	/// * `property_permissions` - Property permissions.
+	pub fn set_property_permission_unchecked(
+		collection: CollectionId,
+		property_permission: PropertyKeyPermission,
+	) -> DispatchResult {
+		<CollectionPropertyPermissions<T>>::try_mutate(collection, |permissions| {
+			permissions.try_set(property_permission.key, property_permission.permission)
 		})
 		.map_err(<Error<T>>::from)?;
+		Ok(())
+	}
+
+	/// Set collection property permission.
+	///
+	/// * `collection` - Collection handler.
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `property_permission` - Property permission.
+	pub fn set_property_permission(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		property_permission: PropertyKeyPermission,
+	) -> DispatchResult {
+		Self::set_scoped_property_permission(
+			collection,
+			sender,
+			PropertyScope::None,
+			property_permission,
+		)
+	}
+
+	/// Set collection property permission with scope.
+	///
+	/// * `collection` - Collection handler.
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `scope` - Property scope.
+	/// * `property_permission` - Property permission.
+	pub fn set_scoped_property_permission(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		scope: PropertyScope,
+		property_permission: PropertyKeyPermission,
+	) -> DispatchResult {
+		Self::set_scoped_token_property_permissions(
+			collection,
+			sender,
+			scope,
+			vec![property_permission],
+		)
+	}
+
+	/// Set or remove the types of token property values.
+	///
+	/// A type can be declared only for a key with a property permission, and can't be changed
+	/// once declared if the permission isn't mutable.
+	/// Values already stored in tokens aren't checked against the new type.
+	///
+	/// * `collection` - Collection handler.
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `value_types` - Types of the property values, `None` removes the type.
+	#[transactional]
+	pub fn set_token_property_value_types(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		value_types: Vec<PropertyKeyValueType>,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		collection.check_metadata_not_frozen()?;
+
+		let frozen_uri_keys = Self::frozen_token_uri_keys(collection.id);
+		let permissions = CollectionPropertyPermissions::<T>::get(collection.id);
+		for PropertyKeyValueType { key, value_type } in value_types {
+			ensure!(
+				!frozen_uri_keys.token.contains(&key),
+				<Error<T>>::TokenUriIsFrozen
+			);
+			let permission = permissions.get(&key).ok_or(<Error<T>>::NoPermission)?;
+
+			<CollectionPropertyValueTypes<T>>::try_mutate(collection.id, |value_types| {
+				ensure!(
+					permission.mutable || value_types.get(&key).is_none(),
+					<Error<T>>::NoPermission
+				);
+				match value_type {
+					Some(value_type) => value_types.try_set(key.clone(), value_type),
+					None => value_types.try_scoped_remove(PropertyScope::None, key.clone()),
+				}
+				.map_err(<Error<T>>::from)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::PropertyPermissionSet(collection.id, key));
+		}
 
-		Self::deposit_event(Event::PropertyPermissionSet(
-			collection.id,
-			property_permission.key,
-		));
 		<PalletEvm<T>>::deposit_log(
 			erc::CollectionHelpersEvents::CollectionChanged {
 				collection_id: eth::collection_id_to_address(collection.id),
@@ -1579,130 +2631,720 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	/// Set token property permission with scope.
+	/// Set token property permission with scope.
+	///
+	/// * `collection` - Collection handler.
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `scope` - Property scope.
+	/// * `property_permissions` - Property permissions.
+	#[transactional]
+	pub fn set_scoped_token_property_permissions(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		scope: PropertyScope,
+		property_permissions: Vec<PropertyKeyPermission>,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		collection.check_metadata_not_frozen()?;
+
+		let frozen_uri_keys = Self::frozen_token_uri_keys(collection.id);
+
+		for property_permission in property_permissions {
+			// Scoped keys can't be used in the token URI template.
+			ensure!(
+				scope != PropertyScope::None
+					|| !frozen_uri_keys.token.contains(&property_permission.key),
+				<Error<T>>::TokenUriIsFrozen
+			);
+
+			let all_permissions = CollectionPropertyPermissions::<T>::get(collection.id);
+			let current_permission = all_permissions.get(&property_permission.key);
+			if matches![
+				current_permission,
+				Some(PropertyPermission { mutable: false, .. })
+			] {
+				return Err(<Error<T>>::NoPermission.into());
+			}
+
+			CollectionPropertyPermissions::<T>::try_mutate(collection.id, |permissions| {
+				let property_permission = property_permission.clone();
+				permissions.try_scoped_set(
+					scope,
+					property_permission.key,
+					property_permission.permission,
+				)
+			})
+			.map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::PropertyPermissionSet(
+				collection.id,
+				property_permission.key,
+			));
+			<PalletEvm<T>>::deposit_log(
+				erc::CollectionHelpersEvents::CollectionChanged {
+					collection_id: eth::collection_id_to_address(collection.id),
+				}
+				.to_log(T::ContractAddress::get()),
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Grant or revoke the right to write token properties to an account.
+	///
+	/// A granted account can write the properties with the `keys` of any token in the collection,
+	/// regardless of the `collection_admin` and `token_owner` property permissions.
+	/// Immutable properties still can't be changed once they are set.
+	/// Only keys having a token property permission can be granted.
+	///
+	/// * `collection` - Collection handler.
+	/// * `sender` - The owner or administrator of the collection.
+	/// * `account` - Account whose right is changed.
+	/// * `keys` - Keys of the token properties.
+	/// * `granted` - Whether the right is granted or revoked.
+	pub fn set_token_property_write_grants(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		account: &T::CrossAccountId,
+		keys: Vec<PropertyKey>,
+		granted: bool,
+	) -> DispatchResult {
+		collection.check_is_internal()?;
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+
+		let permissions = <CollectionPropertyPermissions<T>>::get(collection.id);
+		let mut grants = <TokenPropertyWriteGrants<T>>::get((collection.id, account));
+		for key in &keys {
+			if granted {
+				<PropertiesMap<PropertyValue>>::check_property_key(key)
+					.map_err(<Error<T>>::from)?;
+				ensure!(permissions.contains_key(key), <Error<T>>::NoPermission);
+				grants
+					.try_insert(key.clone())
+					.map_err(|_| <Error<T>>::PropertyWriteGrantLimitExceeded)?;
+			} else {
+				grants.remove(key);
+			}
+		}
+
+		// =========
+
+		if grants.is_empty() {
+			<TokenPropertyWriteGrants<T>>::remove((collection.id, account));
+		} else {
+			<TokenPropertyWriteGrants<T>>::insert((collection.id, account), grants);
+		}
+
+		for key in keys {
+			Self::deposit_event(if granted {
+				Event::<T>::TokenPropertyWriteGranted(collection.id, account.clone(), key)
+			} else {
+				Event::<T>::TokenPropertyWriteRevoked(collection.id, account.clone(), key)
+			});
+		}
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
+	/// Get collection property.
+	pub fn get_collection_property(
+		collection_id: CollectionId,
+		key: &PropertyKey,
+	) -> Option<PropertyValue> {
+		Self::collection_properties(collection_id).get(key).cloned()
+	}
+
+	/// Get the URI of the token, the same as returned by the ERC-721 `tokenURI` method:
+	/// * the token `URI` property, if it is not empty,
+	/// * otherwise the collection `URITemplate` property rendered for the token, if not empty,
+	/// * otherwise the collection `baseURI` property followed by the token `URISuffix` property,
+	/// or an empty string if the collection has no `baseURI`.
+	///
+	/// The template may contain placeholders in braces:
+	/// * `{tokenId}` - decimal ID of the token,
+	/// * `{prop:<key>}` - value of the token property with the key `<key>`,
+	/// * `{<key>}` - value of the collection property with the key `<key>`, e.g. `{baseURI}`.
+	///
+	/// Absent properties are substituted with an empty string.
+	/// Fails with [`Error::InvalidTokenUriTemplate`] if the rendered URI is longer
+	/// than [`MAX_TOKEN_URI_LENGTH`].
+	///
+	/// * `collection_id` - ID of the collection to which the token belongs.
+	/// * `tokens` - Implementation of the collection operations.
+	/// * `token_id` - ID of the token.
+	pub fn token_uri(
+		collection_id: CollectionId,
+		tokens: &dyn CommonCollectionOperations<T>,
+		token_id: TokenId,
+	) -> Result<String, DispatchError> {
+		use erc::static_property::key;
+
+		let collection_properties = Self::collection_properties(collection_id);
+		let collection_property = |key: &PropertyKey| {
+			collection_properties
+				.get(key)
+				.map(|value| String::from_utf8_lossy(value).into_owned())
+				.unwrap_or_default()
+		};
+		let token_property = |key: &PropertyKey| {
+			tokens
+				.token_property(token_id, key)
+				.map(|value| String::from_utf8_lossy(&value).into_owned())
+				.unwrap_or_default()
+		};
+
+		let uri = token_property(&key::url());
+		if !uri.is_empty() {
+			return Ok(uri);
+		}
+
+		let template = collection_property(&key::uri_template());
+		if !template.is_empty() {
+			return Self::render_token_uri_template(
+				&template,
+				token_id,
+				&collection_property,
+				&token_property,
+			);
+		}
+
+		let base_uri = collection_property(&key::base_uri());
+		if base_uri.is_empty() {
+			return Ok(base_uri);
+		}
+
+		Ok(base_uri + &token_property(&key::suffix()))
+	}
+
+	/// Check that the value of the `URITemplate` collection property is a valid template:
+	/// every placeholder is closed and contains either `tokenId` or a valid property key.
+	///
+	/// Returns the number of placeholders in the template.
+	pub fn check_token_uri_template(template: &[u8]) -> Result<u32, DispatchError> {
+		let template =
+			core::str::from_utf8(template).map_err(|_| <Error<T>>::InvalidTokenUriTemplate)?;
+		Self::render_token_uri_template(
+			template,
+			TokenId(0),
+			|_| String::new(),
+			|_| String::new(),
+		)?;
+
+		Ok(Self::token_uri_template_placeholders(template))
+	}
+
+	/// Get the number of placeholders in a valid token URI template.
+	pub fn token_uri_template_placeholders(template: &str) -> u32 {
+		template.matches('{').count() as u32
+	}
+
+	/// Get the number of placeholders in the `URITemplate` property of the collection.
+	pub fn collection_token_uri_placeholders(collection_id: CollectionId) -> u32 {
+		Self::collection_properties(collection_id)
+			.get(&erc::static_property::key::uri_template())
+			.map(|template| {
+				Self::token_uri_template_placeholders(&String::from_utf8_lossy(template))
+			})
+			.unwrap_or_default()
+	}
+
+	/// Get the keys of the collection properties and of the token properties the token URIs
+	/// of the collection are derived from, if the collection has tokens with frozen properties.
+	///
+	/// These are the `URITemplate` and `baseURI` collection properties, the `URI` and `URISuffix`
+	/// token properties, and the properties used in the placeholders of the template.
+	/// Changing them, their default values or their permissions would change the URI
+	/// of a frozen token.
+	fn frozen_token_uri_keys(collection_id: CollectionId) -> TokenUriKeys {
+		use erc::static_property::key;
+
+		if <FrozenTokenPropertiesCount<T>>::get(collection_id) == 0 {
+			return TokenUriKeys::default();
+		}
+
+		let collection_keys = RefCell::new(BTreeSet::from([key::uri_template(), key::base_uri()]));
+		let token_keys = RefCell::new(BTreeSet::from([key::url(), key::suffix()]));
+		let template = Self::collection_properties(collection_id)
+			.get(&key::uri_template())
+			.cloned()
+			.unwrap_or_default();
+		if let Ok(template) = core::str::from_utf8(&template) {
+			// The template is checked when it is set, so rendering only fails on a too long URI,
+			// which can't happen with empty values.
+			let _ = Self::render_token_uri_template(
+				template,
+				TokenId(0),
+				|key| {
+					collection_keys.borrow_mut().insert(key.clone());
+					String::new()
+				},
+				|key| {
+					token_keys.borrow_mut().insert(key.clone());
+					String::new()
+				},
+			);
+		}
+
+		TokenUriKeys {
+			collection: collection_keys.into_inner(),
+			token: token_keys.into_inner(),
+		}
+	}
+
+	/// Forget the freeze of the properties of the burned `token`.
+	pub fn remove_token_properties_freeze(collection_id: CollectionId, token: TokenId) {
+		if <FrozenTokenProperties<T>>::take((collection_id, token)) {
+			<FrozenTokenPropertiesCount<T>>::mutate(collection_id, |count| {
+				*count = count.saturating_sub(1)
+			});
+		}
+	}
+
+	fn render_token_uri_template(
+		template: &str,
+		token_id: TokenId,
+		collection_property: impl Fn(&PropertyKey) -> String,
+		token_property: impl Fn(&PropertyKey) -> String,
+	) -> Result<String, DispatchError> {
+		let to_key = |key: &str| -> Result<PropertyKey, DispatchError> {
+			let key: PropertyKey = key
+				.as_bytes()
+				.to_vec()
+				.try_into()
+				.map_err(|_| <Error<T>>::InvalidTokenUriTemplate)?;
+			<PropertiesMap<PropertyValue>>::check_property_key(&key)
+				.map_err(|_| <Error<T>>::InvalidTokenUriTemplate)?;
+			Ok(key)
+		};
+		let check_literal = |literal: &str| {
+			ensure!(!literal.contains('}'), <Error<T>>::InvalidTokenUriTemplate);
+			Ok::<_, DispatchError>(())
+		};
+		// Placeholders may repeat, so the URI can be much longer than the template.
+		let push = |uri: &mut String, part: &str| {
+			ensure!(
+				uri.len() + part.len() <= MAX_TOKEN_URI_LENGTH as usize,
+				<Error<T>>::InvalidTokenUriTemplate
+			);
+			uri.push_str(part);
+			Ok::<_, DispatchError>(())
+		};
+
+		let mut uri = String::new();
+		let mut rest = template;
+
+		while let Some(start) = rest.find('{') {
+			check_literal(&rest[..start])?;
+			push(&mut uri, &rest[..start])?;
+			rest = &rest[start + 1..];
+
+			let end = rest.find('}').ok_or(<Error<T>>::InvalidTokenUriTemplate)?;
+			let placeholder = &rest[..end];
+			rest = &rest[end + 1..];
+
+			let value = match placeholder.strip_prefix("prop:") {
+				_ if placeholder == "tokenId" => token_id.0.to_string(),
+				Some(key) => token_property(&to_key(key)?),
+				None => collection_property(&to_key(placeholder)?),
+			};
+			push(&mut uri, &value)?;
+		}
+		check_literal(rest)?;
+		push(&mut uri, rest)?;
+
+		Ok(uri)
+	}
+
+	/// Convert byte vector to property key vector.
+	pub fn bytes_keys_to_property_keys(
+		keys: Vec<Vec<u8>>,
+	) -> Result<Vec<PropertyKey>, DispatchError> {
+		keys.into_iter()
+			.map(|key| -> Result<PropertyKey, DispatchError> {
+				key.try_into()
+					.map_err(|_| <Error<T>>::PropertyKeyIsTooLong.into())
+			})
+			.collect::<Result<Vec<PropertyKey>, DispatchError>>()
+	}
+
+	/// Get properties according to given keys.
+	pub fn filter_collection_properties(
+		collection_id: CollectionId,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Result<Vec<Property>, DispatchError> {
+		let properties = Self::collection_properties(collection_id);
+
+		let properties = keys
+			.map(|keys| {
+				keys.into_iter()
+					.filter_map(|key| {
+						properties.get(&key).map(|value| Property {
+							key,
+							value: value.clone(),
+						})
+					})
+					.collect()
+			})
+			.unwrap_or_else(|| {
+				properties
+					.into_iter()
+					.map(|(key, value)| Property { key, value })
+					.collect()
+			});
+
+		Ok(properties)
+	}
+
+	/// Get token properties according to given keys, falling back to the default
+	/// token properties of the collection for the keys the token has no own value for.
+	///
+	/// * `collection_id` - ID of the collection to which the token belongs.
+	/// * `own_properties` - Own properties of the token.
+	/// * `keys` - Keys of the properties to get, all of them if `None`.
+	pub fn filter_token_properties(
+		collection_id: CollectionId,
+		own_properties: TokenProperties,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Vec<Property> {
+		let default_properties = <DefaultTokenProperties<T>>::get(collection_id);
+
+		keys.map(|keys| {
+			keys.into_iter()
+				.filter_map(|key| {
+					own_properties
+						.get(&key)
+						.or_else(|| default_properties.get(&key))
+						.map(|value| Property {
+							value: value.clone(),
+							key,
+						})
+				})
+				.collect()
+		})
+		.unwrap_or_else(|| {
+			let mut properties: BTreeMap<_, _> = default_properties.into_iter().collect();
+			properties.extend(own_properties);
+
+			properties
+				.into_iter()
+				.map(|(key, value)| Property { key, value })
+				.collect()
+		})
+	}
+
+	/// Get token properties according to given keys, marking the ones
+	/// inherited from the default token properties of the collection.
+	///
+	/// * `tokens` - Implementation of the collection operations.
+	/// * `token_id` - ID of the token.
+	/// * `keys` - Keys of the properties to get, all of them if `None`.
+	pub fn rpc_token_properties(
+		tokens: &dyn CommonCollectionOperations<T>,
+		token_id: TokenId,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Vec<RpcTokenProperty> {
+		let own_properties = tokens
+			.get_token_properties_raw(token_id)
+			.unwrap_or_default();
+
+		tokens
+			.token_properties(token_id, keys)
+			.into_iter()
+			.map(|Property { key, value }| RpcTokenProperty {
+				inherited: own_properties.get(&key).is_none(),
+				key,
+				value,
+			})
+			.collect()
+	}
+
+	/// Get property permissions according to given keys.
+	pub fn filter_property_permissions(
+		collection_id: CollectionId,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Result<Vec<RpcPropertyKeyPermission>, DispatchError> {
+		let permissions = Self::property_permissions(collection_id);
+		let value_types = <CollectionPropertyValueTypes<T>>::get(collection_id);
+
+		let key_permissions = keys
+			.map(|keys| {
+				keys.into_iter()
+					.filter_map(|key| {
+						permissions
+							.get(&key)
+							.map(|permission| RpcPropertyKeyPermission {
+								value_type: value_types.get(&key).cloned(),
+								permission: permission.clone(),
+								key,
+							})
+					})
+					.collect()
+			})
+			.unwrap_or_else(|| {
+				permissions
+					.into_iter()
+					.map(|(key, permission)| RpcPropertyKeyPermission {
+						value_type: value_types.get(&key).cloned(),
+						key,
+						permission,
+					})
+					.collect()
+			});
+
+		Ok(key_permissions)
+	}
+
+	/// Toggle `user` participation in the `collection`'s allow list.
+	/// #### Store read/writes
+	/// 1 writes
+	pub fn toggle_allowlist(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		user: &T::CrossAccountId,
+		allowed: bool,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.allowlist_manager)?;
+
+		// =========
+
+		if allowed {
+			<Allowlist<T>>::insert((collection.id, user), true);
+			Self::deposit_event(Event::<T>::AllowListAddressAdded(
+				collection.id,
+				user.clone(),
+			));
+		} else {
+			<Allowlist<T>>::remove((collection.id, user));
+			Self::deposit_event(Event::<T>::AllowListAddressRemoved(
+				collection.id,
+				user.clone(),
+			));
+		}
+
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
+	/// Freeze or thaw the `token`, blocking its transfers, approvals and nesting under it.
+	/// #### Store read/writes
+	/// 1 reads, 1 writes
+	pub fn toggle_token_freeze(
+		collection: &CollectionHandle<T>,
+		tokens: &dyn CommonCollectionOperations<T>,
+		sender: &T::CrossAccountId,
+		token: TokenId,
+		frozen: bool,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.allowlist_manager)?;
+		ensure!(
+			!matches!(collection.mode, CollectionMode::Fungible(_)),
+			<Error<T>>::FungibleItemsHaveNoId
+		);
+		ensure!(tokens.token_exists(token), <Error<T>>::TokenNotFound);
+		if frozen {
+			Self::check_token_not_escrowed(tokens, token)?;
+		}
+
+		// =========
+
+		if frozen {
+			<FrozenTokens<T>>::insert((collection.id, token), true);
+			Self::deposit_event(Event::<T>::TokenFrozen(collection.id, token));
+		} else {
+			<FrozenTokens<T>>::remove((collection.id, token));
+			Self::deposit_event(Event::<T>::TokenThawed(collection.id, token));
+		}
+
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
+	/// Freeze or thaw the `user` in the `collection`, blocking transfers and approvals of its holdings.
+	/// #### Store read/writes
+	/// 1 writes
+	pub fn toggle_account_freeze(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		user: &T::CrossAccountId,
+		frozen: bool,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.allowlist_manager)?;
+		ensure!(
+			!frozen || *user != Self::swap_escrow(),
+			<Error<T>>::AssetIsEscrowed
+		);
+
+		// =========
+
+		if frozen {
+			<FrozenAccounts<T>>::insert((collection.id, user), true);
+			Self::deposit_event(Event::<T>::AccountFrozen(collection.id, user.clone()));
+		} else {
+			<FrozenAccounts<T>>::remove((collection.id, user));
+			Self::deposit_event(Event::<T>::AccountThawed(collection.id, user.clone()));
+		}
+
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
+	/// Permanently freeze the properties of the `token`.
+	///
+	/// Frozen properties can't be set or deleted by anyone, regardless of the property permissions.
+	/// While the collection has tokens with frozen properties, the collection properties,
+	/// default token properties and token property permissions the token URI is derived from
+	/// can't be changed either, see [`Self::frozen_token_uri_keys`].
+	/// Emits the `PermanentURI` event with the token URI at the moment of the freeze.
+	/// #### Store read/writes
+	/// 5 reads, 2 writes
+	pub fn freeze_token_properties(
+		collection: &CollectionHandle<T>,
+		tokens: &dyn CommonCollectionOperations<T>,
+		sender: &T::CrossAccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.property_editor)?;
+		ensure!(
+			!matches!(collection.mode, CollectionMode::Fungible(_)),
+			<Error<T>>::FungibleItemsHaveNoId
+		);
+		ensure!(tokens.token_exists(token), <Error<T>>::TokenNotFound);
+		collection.check_token_properties_not_frozen(token)?;
+
+		let uri = Self::token_uri(collection.id, tokens, token)?;
+
+		// =========
+
+		<FrozenTokenProperties<T>>::insert((collection.id, token), true);
+		<FrozenTokenPropertiesCount<T>>::mutate(collection.id, |count| {
+			*count = count.saturating_add(1)
+		});
+		Self::deposit_event(Event::<T>::TokenPropertiesFrozen(collection.id, token));
+
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionMetadataEvents::PermanentURI {
+				value: uri,
+				id: token.into(),
+			}
+			.to_log(eth::collection_id_to_address(collection.id)),
+		);
+
+		Ok(())
+	}
+
+	/// Permanently freeze the metadata of the `collection`.
 	///
-	/// * `collection` - Collection handler.
-	/// * `sender` - The owner or administrator of the collection.
-	/// * `scope` - Property scope.
-	/// * `property_permissions` - Property permissions.
-	#[transactional]
-	pub fn set_scoped_token_property_permissions(
+	/// The collection properties, royalty, token property permissions
+	/// and default token properties can't be changed after the freeze.
+	/// #### Store read/writes
+	/// 1 reads, 1 writes
+	pub fn freeze_collection_metadata(
 		collection: &CollectionHandle<T>,
 		sender: &T::CrossAccountId,
-		scope: PropertyScope,
-		property_permissions: Vec<PropertyKeyPermission>,
 	) -> DispatchResult {
-		for prop_pemission in property_permissions {
-			Self::set_scoped_property_permission(collection, sender, scope, prop_pemission)?;
-		}
+		collection.check_is_internal()?;
+		collection.check_is_owner(sender)?;
+		collection.check_metadata_not_frozen()?;
 
-		Ok(())
-	}
+		// =========
 
-	/// Get collection property.
-	pub fn get_collection_property(
-		collection_id: CollectionId,
-		key: &PropertyKey,
-	) -> Option<PropertyValue> {
-		Self::collection_properties(collection_id).get(key).cloned()
-	}
+		<FrozenCollectionMetadata<T>>::insert(collection.id, true);
+		Self::deposit_event(Event::<T>::CollectionMetadataFrozen(collection.id));
 
-	/// Convert byte vector to property key vector.
-	pub fn bytes_keys_to_property_keys(
-		keys: Vec<Vec<u8>>,
-	) -> Result<Vec<PropertyKey>, DispatchError> {
-		keys.into_iter()
-			.map(|key| -> Result<PropertyKey, DispatchError> {
-				key.try_into()
-					.map_err(|_| <Error<T>>::PropertyKeyIsTooLong.into())
-			})
-			.collect::<Result<Vec<PropertyKey>, DispatchError>>()
-	}
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
 
-	/// Get properties according to given keys.
-	pub fn filter_collection_properties(
-		collection_id: CollectionId,
-		keys: Option<Vec<PropertyKey>>,
-	) -> Result<Vec<Property>, DispatchError> {
-		let properties = Self::collection_properties(collection_id);
+		Ok(())
+	}
 
-		let properties = keys
-			.map(|keys| {
-				keys.into_iter()
-					.filter_map(|key| {
-						properties.get(&key).map(|value| Property {
-							key,
-							value: value.clone(),
-						})
-					})
-					.collect()
-			})
-			.unwrap_or_else(|| {
-				properties
-					.into_iter()
-					.map(|(key, value)| Property { key, value })
-					.collect()
-			});
+	/// Set the mint authority of the fungible collection.
+	///
+	/// Once set, only the `authority` can mint tokens of the collection,
+	/// and it can burn tokens of any holder. `None` returns minting to the collection admins.
+	/// #### Store read/writes
+	/// 1 writes
+	pub fn set_mint_authority(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		authority: Option<T::CrossAccountId>,
+	) -> DispatchResult {
+		collection.check_is_internal()?;
+		collection.check_is_owner(sender)?;
+		ensure!(
+			matches!(collection.mode, CollectionMode::Fungible(_)),
+			<Error<T>>::NotFungibleCollection
+		);
 
-		Ok(properties)
-	}
+		// =========
 
-	/// Get property permissions according to given keys.
-	pub fn filter_property_permissions(
-		collection_id: CollectionId,
-		keys: Option<Vec<PropertyKey>>,
-	) -> Result<Vec<PropertyKeyPermission>, DispatchError> {
-		let permissions = Self::property_permissions(collection_id);
+		<MintAuthority<T>>::set(collection.id, authority.clone());
+		Self::deposit_event(Event::<T>::MintAuthoritySet(collection.id, authority));
 
-		let key_permissions = keys
-			.map(|keys| {
-				keys.into_iter()
-					.filter_map(|key| {
-						permissions
-							.get(&key)
-							.map(|permission| PropertyKeyPermission {
-								key,
-								permission: permission.clone(),
-							})
-					})
-					.collect()
-			})
-			.unwrap_or_else(|| {
-				permissions
-					.into_iter()
-					.map(|(key, permission)| PropertyKeyPermission { key, permission })
-					.collect()
-			});
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
 
-		Ok(key_permissions)
+		Ok(())
 	}
 
-	/// Toggle `user` participation in the `collection`'s allow list.
+	/// Set the supply cap of the fungible collection.
+	///
+	/// The cap can't be lower than the current total supply. `None` removes the cap.
 	/// #### Store read/writes
-	/// 1 writes
-	pub fn toggle_allowlist(
+	/// 1 reads, 1 writes
+	pub fn set_supply_cap(
 		collection: &CollectionHandle<T>,
+		tokens: &dyn CommonCollectionOperations<T>,
 		sender: &T::CrossAccountId,
-		user: &T::CrossAccountId,
-		allowed: bool,
+		cap: Option<u128>,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
+		collection.check_is_internal()?;
+		collection.check_is_owner(sender)?;
+		ensure!(
+			matches!(collection.mode, CollectionMode::Fungible(_)),
+			<Error<T>>::NotFungibleCollection
+		);
+		if let Some(cap) = cap {
+			let total_supply = tokens.total_pieces(TokenId::default()).unwrap_or(0);
+			ensure!(cap >= total_supply, <Error<T>>::SupplyCapBelowTotalSupply);
+		}
 
 		// =========
 
-		if allowed {
-			<Allowlist<T>>::insert((collection.id, user), true);
-			Self::deposit_event(Event::<T>::AllowListAddressAdded(
-				collection.id,
-				user.clone(),
-			));
-		} else {
-			<Allowlist<T>>::remove((collection.id, user));
-			Self::deposit_event(Event::<T>::AllowListAddressRemoved(
-				collection.id,
-				user.clone(),
-			));
-		}
+		<SupplyCap<T>>::set(collection.id, cap);
+		Self::deposit_event(Event::<T>::SupplyCapSet(collection.id, cap));
 
 		<PalletEvm<T>>::deposit_log(
 			erc::CollectionHelpersEvents::CollectionChanged {
@@ -1757,6 +3399,7 @@ impl<T: Config> Pallet<T> {
 		} else {
 			<AdminAmount<T>>::insert(collection.id, amount.saturating_sub(1));
 			<IsAdmin<T>>::remove((collection.id, user));
+			<AdminRoles<T>>::remove((collection.id, user));
 
 			Self::deposit_event(Event::<T>::CollectionAdminRemoved(
 				collection.id,
@@ -1774,6 +3417,47 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Set the roles of the `user` administrating the `collection`.
+	///
+	/// An admin with all the roles is stored without explicitly set roles.
+	/// #### Store read/writes
+	/// 1 read, 1 write
+	pub fn set_admin_roles(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		user: &T::CrossAccountId,
+		roles: CollectionAdminRoles,
+	) -> DispatchResult {
+		collection.check_is_internal()?;
+		collection.check_is_owner(sender)?;
+		ensure!(
+			<IsAdmin<T>>::get((collection.id, user)),
+			<Error<T>>::UserIsNotCollectionAdmin
+		);
+
+		// =========
+
+		if roles == CollectionAdminRoles::all() {
+			<AdminRoles<T>>::remove((collection.id, user));
+		} else {
+			<AdminRoles<T>>::insert((collection.id, user), roles);
+		}
+
+		Self::deposit_event(Event::<T>::CollectionAdminRolesSet(
+			collection.id,
+			user.clone(),
+			roles,
+		));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
 	/// Update collection limits.
 	pub fn update_limits(
 		user: &T::CrossAccountId,
@@ -1781,7 +3465,7 @@ impl<T: Config> Pallet<T> {
 		new_limit: CollectionLimits,
 	) -> DispatchResult {
 		collection.check_is_internal()?;
-		collection.check_is_owner_or_admin(user)?;
+		collection.check_has_admin_role(user, |roles| roles.limits_manager)?;
 
 		collection.limits =
 			Self::clamp_limits(collection.mode.clone(), &collection.limits, new_limit)?;
@@ -1850,7 +3534,7 @@ impl<T: Config> Pallet<T> {
 		new_permission: CollectionPermissions,
 	) -> DispatchResult {
 		collection.check_is_internal()?;
-		collection.check_is_owner_or_admin(user)?;
+		collection.check_has_admin_role(user, |roles| roles.limits_manager)?;
 		collection.permissions = Self::clamp_permissions(
 			collection.mode.clone(),
 			&collection.permissions,
@@ -1916,6 +3600,15 @@ pub trait CommonWeightInfo<CrossAccountId> {
 	/// The weight of the burning item.
 	fn burn_item() -> Weight;
 
+	/// The price of burning a token recursively, visiting at most `max_nodes` tokens.
+	///
+	/// Every visited token is either burned or transferred back to the caller.
+	fn burn_recursively(max_nodes: u32) -> Weight {
+		Self::burn_item()
+			.saturating_add(Self::transfer())
+			.saturating_mul(max_nodes.max(1) as u64)
+	}
+
 	/// Property setting weight.
 	///
 	/// * `amount`- The number of properties to set.
@@ -1965,6 +3658,12 @@ pub trait CommonWeightInfo<CrossAccountId> {
 
 	/// The price of repairing an item.
 	fn force_repair_item() -> Weight;
+
+	/// The price of locking or unlocking a token.
+	fn set_token_lock() -> Weight;
+
+	/// The price of setting or removing the ERC-4907 user of a token.
+	fn set_token_user() -> Weight;
 }
 
 /// Weight info extension trait for refungible pallet.
@@ -2031,6 +3730,22 @@ pub trait CommonCollectionOperations<T: Config> {
 		amount: u128,
 	) -> DispatchResultWithPostInfo;
 
+	/// Burn token together with the tokens nested in it.
+	///
+	/// Fungible and refungible collections burn the whole balance of the `sender`.
+	///
+	/// * `sender` - The user who owns the token.
+	/// * `token` - Token id that will burned.
+	/// * `burn_children` - Whether nested tokens are burned too, or returned to the `sender`.
+	/// * `budget` - A budget limiting the number of visited tokens.
+	fn burn_item_recursively(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		burn_children: bool,
+		budget: &dyn Budget,
+	) -> DispatchResultWithPostInfo;
+
 	/// Set collection properties.
 	///
 	/// * `sender` - Must be either the owner of the collection or its admin.
@@ -2108,6 +3823,16 @@ pub trait CommonCollectionOperations<T: Config> {
 		property_permissions: Vec<PropertyKeyPermission>,
 	) -> DispatchResultWithPostInfo;
 
+	/// Set types of token property values.
+	///
+	/// * `sender` - Must be either the owner of the collection or its admin.
+	/// * `value_types` - Types of the property values to be set.
+	fn set_token_property_value_types(
+		&self,
+		sender: &T::CrossAccountId,
+		value_types: Vec<PropertyKeyValueType>,
+	) -> DispatchResultWithPostInfo;
+
 	/// Transfer amount of token pieces.
 	///
 	/// * `sender` - Donor user.
@@ -2130,12 +3855,14 @@ pub trait CommonCollectionOperations<T: Config> {
 	/// * `spender` - The user to whom the rights are granted.
 	/// * `token` - The token to which access is granted.
 	/// * `amount` - The amount of pieces that another user can dispose of.
+	/// * `deadline` - Last block in which the access can be used, `None` if it never expires.
 	fn approve(
 		&self,
 		sender: T::CrossAccountId,
 		spender: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo;
 
 	/// Grant access to another account to transfer parts of the token owned by the calling user's eth mirror via [Self::transfer_from].
@@ -2145,6 +3872,7 @@ pub trait CommonCollectionOperations<T: Config> {
 	/// * `to` - The user to whom the rights are granted.
 	/// * `token` - The token to which access is granted.
 	/// * `amount` - The amount of pieces that another user can dispose of.
+	/// * `deadline` - Last block in which the access can be used, `None` if it never expires.
 	fn approve_from(
 		&self,
 		sender: T::CrossAccountId,
@@ -2152,6 +3880,7 @@ pub trait CommonCollectionOperations<T: Config> {
 		to: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo;
 
 	/// Send parts of a token owned by another user.
@@ -2295,6 +4024,18 @@ pub trait CommonCollectionOperations<T: Config> {
 		token: TokenId,
 	) -> u128;
 
+	/// Get the deadline of the access granted via [`Self::approve`], if it has one.
+	///
+	/// * `sender` - Owner of the token.
+	/// * `spender` - Trusted user.
+	/// * `token` - The token for which to get the value.
+	fn allowance_deadline(
+		&self,
+		sender: T::CrossAccountId,
+		spender: T::CrossAccountId,
+		token: TokenId,
+	) -> Option<u64>;
+
 	/// Get extension for RFT collection.
 	fn refungible_extensions(&self) -> Option<&dyn RefungibleExtensions<T>> {
 		None
@@ -2309,18 +4050,64 @@ pub trait CommonCollectionOperations<T: Config> {
 	/// * `owner` - Token owner
 	/// * `operator` - Operator
 	/// * `approve` - Should operator status be granted or revoked?
+	/// * `deadline` - Last block in which the operator status can be used, `None` if it never expires.
 	fn set_allowance_for_all(
 		&self,
 		owner: T::CrossAccountId,
 		operator: T::CrossAccountId,
 		approve: bool,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo;
 
 	/// Tells whether the given `owner` approves the `operator`.
 	fn allowance_for_all(&self, owner: T::CrossAccountId, operator: T::CrossAccountId) -> bool;
 
+	/// Get the deadline of the operator status of the `operator`, if it has one.
+	fn allowance_for_all_deadline(
+		&self,
+		owner: T::CrossAccountId,
+		operator: T::CrossAccountId,
+	) -> Option<u64>;
+
 	/// Repairs a possibly broken item.
 	fn repair_item(&self, token: TokenId) -> DispatchResultWithPostInfo;
+
+	/// Lock or unlock the token against transfers.
+	///
+	/// Locked tokens can still be burnt by their owners.
+	///
+	/// * `sender` - Must be either the owner of the collection or its admin.
+	/// * `token` - The token to lock or unlock.
+	/// * `locked` - Should the token be locked?
+	fn set_token_lock(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		locked: bool,
+	) -> DispatchResultWithPostInfo;
+
+	/// Tells whether the token is locked against transfers.
+	///
+	/// * `token` - Id token to check.
+	fn token_locked(&self, token: TokenId) -> bool;
+
+	/// Set or remove the ERC-4907 user of the token.
+	///
+	/// * `sender` - Must be either the owner of the token, its approved spender
+	///   or an operator approved for all of the owner's tokens.
+	/// * `token` - The token whose user is changed.
+	/// * `user` - New user of the token with its expiry block, `None` removes the user.
+	fn set_token_user(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		user: Option<TokenUser<T::CrossAccountId>>,
+	) -> DispatchResultWithPostInfo;
+
+	/// Get the ERC-4907 user of the token, if it has an unexpired one.
+	///
+	/// * `token` - Id token to check.
+	fn token_user(&self, token: TokenId) -> Option<TokenUser<T::CrossAccountId>>;
 }
 
 /// Extension for RFT collection.
@@ -2454,6 +4241,62 @@ where
 	) -> DispatchResult;
 }
 
+/// Hook for pallets, which keep state bound to the current token owner.
+pub trait OnTokenTransfer<CrossAccountId> {
+	/// Called before some pieces of the `token` are transferred from the `from` account
+	/// to the `to` account. The transfer fails, if an error is returned.
+	///
+	/// Invoked inside of the transfer, so the implementation should be cheap.
+	fn check_token_transfer(
+		_collection: CollectionId,
+		_token: TokenId,
+		_from: &CrossAccountId,
+		_to: &CrossAccountId,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called after some pieces of the `token` were transferred or burnt from the `from` account.
+	///
+	/// Invoked inside of the transfer, so the implementation should be cheap.
+	fn on_token_transfer(collection: CollectionId, token: TokenId, from: &CrossAccountId);
+
+	/// Called before the balance of the `holder` in the `token` changes, i.e. before
+	/// the pieces of the `token` are transferred, minted, burnt or repartitioned.
+	///
+	/// Invoked inside of the balance change, so the implementation should be cheap.
+	fn before_balance_change(_collection: CollectionId, _token: TokenId, _holder: &CrossAccountId) {
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<CrossAccountId> OnTokenTransfer<CrossAccountId> for Tuple {
+	fn check_token_transfer(
+		collection: CollectionId,
+		token: TokenId,
+		from: &CrossAccountId,
+		to: &CrossAccountId,
+	) -> DispatchResult {
+		for_tuples!( #(
+			Tuple::check_token_transfer(collection, token, from, to)?;
+		)* );
+
+		Ok(())
+	}
+
+	fn on_token_transfer(collection: CollectionId, token: TokenId, from: &CrossAccountId) {
+		for_tuples!( #(
+			Tuple::on_token_transfer(collection, token, from);
+		)* );
+	}
+
+	fn before_balance_change(collection: CollectionId, token: TokenId, holder: &CrossAccountId) {
+		for_tuples!( #(
+			Tuple::before_balance_change(collection, token, holder);
+		)* );
+	}
+}
+
 /// Merge [`DispatchResult`] with [`Weight`] into [`DispatchResultWithPostInfo`].
 ///
 /// Used for [`CommonCollectionOperations`] implementations and flexible enough to do so.
@@ -2504,6 +4347,9 @@ where
 		properties_updates: impl Iterator<Item = (PropertyKey, Option<PropertyValue>)>,
 		log: evm_coder::ethereum::Log,
 	) -> DispatchResult {
+		self.collection
+			.check_token_properties_not_frozen(token_id)?;
+
 		for (key, value) in properties_updates {
 			let permission = self
 				.collection_lazy_info
@@ -2534,11 +4380,29 @@ where
 					&mut self.collection_lazy_info.is_collection_admin,
 					&mut token_lazy_info.is_token_owner,
 					&mut token_lazy_info.is_token_exist,
+					|| {
+						self.collection_lazy_info
+							.write_grants
+							.value()
+							.contains(&key)
+					},
 				)?,
 			}
 
 			match value {
 				Some(value) => {
+					if let Some(value_type) = self
+						.collection_lazy_info
+						.property_value_types
+						.value()
+						.get(&key)
+					{
+						ensure!(
+							value_type.check(&value),
+							<Error<T>>::PropertyValueTypeMismatch
+						);
+					}
+
 					token_lazy_info
 						.stored_properties
 						.value_mut()
@@ -2585,6 +4449,8 @@ where
 pub struct PropertyWriterLazyCollectionInfo<'a> {
 	is_collection_admin: LazyValue<'a, bool>,
 	property_permissions: LazyValue<'a, PropertiesPermissionMap>,
+	property_value_types: LazyValue<'a, PropertiesValueTypeMap>,
+	write_grants: LazyValue<'a, PropertyWriteGrantKeys>,
 }
 
 /// A helper structure for the [`PropertyWriter`] that holds
@@ -2626,10 +4492,18 @@ impl<T: Config> NewTokenPropertyWriter<T> {
 		PropertyWriter {
 			collection,
 			collection_lazy_info: PropertyWriterLazyCollectionInfo {
-				is_collection_admin: LazyValue::new(|| collection.is_owner_or_admin(sender)),
+				is_collection_admin: LazyValue::new(|| {
+					collection.has_admin_role(sender, |roles| roles.minter)
+				}),
 				property_permissions: LazyValue::new(|| {
 					<Pallet<T>>::property_permissions(collection.id)
 				}),
+				property_value_types: LazyValue::new(|| {
+					<CollectionPropertyValueTypes<T>>::get(collection.id)
+				}),
+				write_grants: LazyValue::new(|| {
+					<TokenPropertyWriteGrants<T>>::get((collection.id, sender))
+				}),
 			},
 			_phantom: PhantomData,
 		}
@@ -2689,10 +4563,18 @@ impl<T: Config> ExistingTokenPropertyWriter<T> {
 		PropertyWriter {
 			collection,
 			collection_lazy_info: PropertyWriterLazyCollectionInfo {
-				is_collection_admin: LazyValue::new(|| collection.is_owner_or_admin(sender)),
+				is_collection_admin: LazyValue::new(|| {
+					collection.has_admin_role(sender, |roles| roles.property_editor)
+				}),
 				property_permissions: LazyValue::new(|| {
 					<Pallet<T>>::property_permissions(collection.id)
 				}),
+				property_value_types: LazyValue::new(|| {
+					<CollectionPropertyValueTypes<T>>::get(collection.id)
+				}),
+				write_grants: LazyValue::new(|| {
+					<TokenPropertyWriteGrants<T>>::get((collection.id, sender))
+				}),
 			},
 			_phantom: PhantomData,
 		}
@@ -2767,12 +4649,17 @@ impl<T: Config> BenchmarkPropertyWriter<T> {
 	where
 		Handle: Deref<Target = CollectionHandle<T>>,
 	{
-		let is_collection_admin = collection_handle.is_owner_or_admin(sender);
+		let is_collection_admin =
+			collection_handle.has_admin_role(sender, |roles| roles.property_editor);
 		let property_permissions = <Pallet<T>>::property_permissions(collection_handle.id);
+		let property_value_types = <CollectionPropertyValueTypes<T>>::get(collection_handle.id);
+		let write_grants = <TokenPropertyWriteGrants<T>>::get((collection_handle.id, sender));
 
 		PropertyWriterLazyCollectionInfo {
 			is_collection_admin: LazyValue::new(move || is_collection_admin),
 			property_permissions: LazyValue::new(move || property_permissions),
+			property_value_types: LazyValue::new(move || property_value_types),
+			write_grants: LazyValue::new(move || write_grants),
 		}
 	}
 
@@ -2855,6 +4742,34 @@ pub fn write_token_properties_total_weight<T: Config, I: Fn(u32) -> Weight>(
 	weight
 }
 
+/// Keys of the properties the token URIs of a collection are derived from.
+#[derive(Default)]
+struct TokenUriKeys {
+	/// Keys of the collection properties.
+	collection: BTreeSet<PropertyKey>,
+	/// Keys of the token properties.
+	token: BTreeSet<PropertyKey>,
+}
+
+/// Computes the weight of setting collection properties.
+/// * `amount` - The number of properties to set or delete.
+pub fn set_collection_properties_weight<T: Config>(amount: u32) -> Weight {
+	<SelfWeightOf<T>>::set_collection_properties(amount)
+		.saturating_add(<SelfWeightOf<T>>::load_frozen_token_uri_keys())
+}
+
+/// Computes the weight of setting token property value types.
+/// * `enum_values_nums` - The number of allowed values of each type,
+///   zero for non-enum types.
+pub fn set_token_property_value_types_weight<T: Config>(
+	enum_values_nums: impl ExactSizeIterator<Item = u32>,
+) -> Weight {
+	let amount = enum_values_nums.len() as u32;
+	let enum_values = enum_values_nums.max().unwrap_or(0);
+	<SelfWeightOf<T>>::set_token_property_value_types(amount, enum_values)
+		.saturating_add(<SelfWeightOf<T>>::load_frozen_token_uri_keys())
+}
+
 #[cfg(any(feature = "tests", test))]
 #[allow(missing_docs)]
 pub mod tests {
@@ -2929,6 +4844,7 @@ pub mod tests {
 			is_collection_admin,
 			check_token_ownership,
 			check_token_existence,
+			|| false,
 		)
 	}
 }
@@ -34,8 +34,12 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_common.
 pub trait WeightInfo {
 	fn set_collection_properties(b: u32, ) -> Weight;
+	fn set_default_token_properties(b: u32, ) -> Weight;
+	fn delete_default_token_properties(b: u32, ) -> Weight;
+	fn set_token_property_value_types(a: u32, b: u32, ) -> Weight;
 	fn check_accesslist() -> Weight;
 	fn property_writer_load_collection_info() -> Weight;
+	fn load_frozen_token_uri_keys() -> Weight;
 }
 
 /// Weights for pallet_common using the Substrate node and recommended hardware.
@@ -55,6 +59,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Common::FrozenCollectionMetadata` (r:1 w:0)
+	/// Proof: `Common::FrozenCollectionMetadata` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyValueTypes` (r:1 w:0)
+	/// Proof: `Common::CollectionPropertyValueTypes` (`max_values`: None, `max_size`: Some(50454), added: 52929, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DefaultTokenProperties` (r:1 w:1)
+	/// Proof: `Common::DefaultTokenProperties` (`max_values`: None, `max_size`: Some(32800), added: 35275, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 64]`.
+	fn set_default_token_properties(b: u32, ) -> Weight {
+		Weight::from_parts(14_000_000, 90700)
+			.saturating_add(Weight::from_parts(35_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::FrozenCollectionMetadata` (r:1 w:0)
+	/// Proof: `Common::FrozenCollectionMetadata` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyValueTypes` (r:1 w:0)
+	/// Proof: `Common::CollectionPropertyValueTypes` (`max_values`: None, `max_size`: Some(50454), added: 52929, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DefaultTokenProperties` (r:1 w:1)
+	/// Proof: `Common::DefaultTokenProperties` (`max_values`: None, `max_size`: Some(32800), added: 35275, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 64]`.
+	fn delete_default_token_properties(b: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 90700)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::FrozenCollectionMetadata` (r:1 w:0)
+	/// Proof: `Common::FrozenCollectionMetadata` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyPermissions` (r:1 w:0)
+	/// Proof: `Common::CollectionPropertyPermissions` (`max_values`: None, `max_size`: Some(16726), added: 19201, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyValueTypes` (r:1 w:1)
+	/// Proof: `Common::CollectionPropertyValueTypes` (`max_values`: None, `max_size`: Some(50454), added: 52929, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 64]`.
+	/// The range of component `b` is `[0, 16]`.
+	fn set_token_property_value_types(a: u32, b: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 53919)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Common::Allowlist` (r:1 w:0)
 	/// Proof: `Common::Allowlist` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn check_accesslist() -> Weight {
@@ -77,6 +122,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(11_552_000, 20191)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Common::FrozenTokenPropertiesCount` (r:1 w:0)
+	/// Proof: `Common::FrozenTokenPropertiesCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionProperties` (r:1 w:0)
+	/// Proof: `Common::CollectionProperties` (`max_values`: None, `max_size`: Some(40992), added: 43467, mode: `MaxEncodedLen`)
+	fn load_frozen_token_uri_keys() -> Weight {
+		Weight::from_parts(1_850_000_000, 45966)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -95,6 +148,47 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Common::FrozenCollectionMetadata` (r:1 w:0)
+	/// Proof: `Common::FrozenCollectionMetadata` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyValueTypes` (r:1 w:0)
+	/// Proof: `Common::CollectionPropertyValueTypes` (`max_values`: None, `max_size`: Some(50454), added: 52929, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DefaultTokenProperties` (r:1 w:1)
+	/// Proof: `Common::DefaultTokenProperties` (`max_values`: None, `max_size`: Some(32800), added: 35275, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 64]`.
+	fn set_default_token_properties(b: u32, ) -> Weight {
+		Weight::from_parts(14_000_000, 90700)
+			.saturating_add(Weight::from_parts(35_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::FrozenCollectionMetadata` (r:1 w:0)
+	/// Proof: `Common::FrozenCollectionMetadata` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyValueTypes` (r:1 w:0)
+	/// Proof: `Common::CollectionPropertyValueTypes` (`max_values`: None, `max_size`: Some(50454), added: 52929, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DefaultTokenProperties` (r:1 w:1)
+	/// Proof: `Common::DefaultTokenProperties` (`max_values`: None, `max_size`: Some(32800), added: 35275, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 64]`.
+	fn delete_default_token_properties(b: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 90700)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::FrozenCollectionMetadata` (r:1 w:0)
+	/// Proof: `Common::FrozenCollectionMetadata` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyPermissions` (r:1 w:0)
+	/// Proof: `Common::CollectionPropertyPermissions` (`max_values`: None, `max_size`: Some(16726), added: 19201, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionPropertyValueTypes` (r:1 w:1)
+	/// Proof: `Common::CollectionPropertyValueTypes` (`max_values`: None, `max_size`: Some(50454), added: 52929, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 64]`.
+	/// The range of component `b` is `[0, 16]`.
+	fn set_token_property_value_types(a: u32, b: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 53919)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Common::Allowlist` (r:1 w:0)
 	/// Proof: `Common::Allowlist` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn check_accesslist() -> Weight {
@@ -117,5 +211,13 @@ impl WeightInfo for () {
 		Weight::from_parts(11_552_000, 20191)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Common::FrozenTokenPropertiesCount` (r:1 w:0)
+	/// Proof: `Common::FrozenTokenPropertiesCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionProperties` (r:1 w:0)
+	/// Proof: `Common::CollectionProperties` (`max_values`: None, `max_size`: Some(40992), added: 43467, mode: `MaxEncodedLen`)
+	fn load_frozen_token_uri_keys() -> Weight {
+		Weight::from_parts(1_850_000_000, 45966)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
 
@@ -0,0 +1,173 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::v2::{account, benchmarks, BenchmarkError, Linear};
+use frame_support::traits::{fungible::Balanced, tokens::Precision};
+use frame_system::RawOrigin;
+use pallet_common::{dispatch::CollectionDispatch, CollectionIssuer, Config as CommonConfig};
+use sp_runtime::traits::UniqueSaturatedFrom;
+use up_data_structs::{
+	budget::Unlimited, CreateCollectionData, CreateFungibleData, CreateItemData,
+	CreateReFungibleData,
+};
+
+use super::*;
+
+const SEED: u32 = 1;
+const PIECES: u128 = 1_000;
+const AMOUNT: u128 = 1_000_000;
+const MAX_HOLDERS: u32 = 100;
+
+fn cross_account<T: Config>(name: &'static str) -> T::CrossAccountId {
+	T::CrossAccountId::from_sub(account(name, 0, SEED))
+}
+
+fn origin<T: Config>(account: &T::CrossAccountId) -> OriginFor<T> {
+	RawOrigin::Signed(account.as_sub().clone()).into()
+}
+
+fn create_collection<T: Config>(
+	owner: &T::CrossAccountId,
+	mode: CollectionMode,
+) -> Result<CollectionId, DispatchError> {
+	let _ = <T as CommonConfig>::Currency::deposit(
+		owner.as_sub(),
+		T::CollectionCreationPrice::get(),
+		Precision::Exact,
+	)?;
+	T::CollectionDispatch::create(
+		owner.clone(),
+		CollectionIssuer::User(owner.clone()),
+		CreateCollectionData {
+			mode,
+			..Default::default()
+		},
+	)
+}
+
+fn mint<T: Config>(
+	collection_id: CollectionId,
+	owner: &T::CrossAccountId,
+	to: &T::CrossAccountId,
+	data: CreateItemData,
+) -> DispatchResult {
+	T::CollectionDispatch::dispatch(collection_id)?
+		.as_dyn()
+		.create_item(owner.clone(), to.clone(), data, &Unlimited)
+		.map(|_| ())
+		.map_err(|error| error.error)
+}
+
+/// Mints an RFT token to the `minter`, and gives a single piece of it to each of the `holders`.
+/// Creates a fungible collection, in which the `payer` can pay [`AMOUNT`],
+/// and gives the `payer` enough native currency for the snapshot fee.
+///
+/// Returns IDs of the RFT collection and of the currency collection.
+fn prepare_distribution<T: Config>(
+	payer: &T::CrossAccountId,
+	holders: u32,
+) -> Result<(CollectionId, CollectionId), DispatchError> {
+	let minter = cross_account::<T>("minter");
+	let collection_id = create_collection::<T>(&minter, CollectionMode::ReFungible)?;
+	mint::<T>(
+		collection_id,
+		&minter,
+		&minter,
+		CreateItemData::ReFungible(CreateReFungibleData {
+			pieces: PIECES,
+			properties: Default::default(),
+		}),
+	)?;
+	for i in 0..holders {
+		let holder = T::CrossAccountId::from_sub(account("holder", i, SEED));
+		<PalletStructure<T>>::transfer_as_owner(collection_id, TokenId(1), &minter, &holder, 1)?;
+	}
+
+	let currency = create_collection::<T>(payer, CollectionMode::Fungible(0))?;
+	mint::<T>(
+		currency,
+		payer,
+		payer,
+		CreateItemData::Fungible(CreateFungibleData { value: AMOUNT }),
+	)?;
+	let _ = <T as CommonConfig>::Currency::deposit(
+		payer.as_sub(),
+		UniqueSaturatedFrom::unique_saturated_from(T::SnapshotFee::get().saturating_mul(2)),
+		Precision::Exact,
+	)?;
+
+	Ok((collection_id, currency))
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn distribute() -> Result<(), BenchmarkError> {
+		let payer = cross_account::<T>("payer");
+		let (collection_id, currency) = prepare_distribution::<T>(&payer, 0)?;
+
+		#[extrinsic_call]
+		_(
+			origin::<T>(&payer),
+			collection_id,
+			Some(TokenId(1)),
+			currency,
+			AMOUNT,
+		);
+
+		assert!(<SnapshotCursors<T>>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim() -> Result<(), BenchmarkError> {
+		let payer = cross_account::<T>("payer");
+		let (collection_id, currency) = prepare_distribution::<T>(&payer, 0)?;
+		let distribution_id = <Pallet<T>>::create_distribution(
+			&payer,
+			collection_id,
+			Some(TokenId(1)),
+			currency,
+			AMOUNT,
+		)?;
+		<Pallet<T>>::take_snapshot(distribution_id, Vec::new(), &mut WeightMeter::new());
+		let minter = cross_account::<T>("minter");
+
+		#[extrinsic_call]
+		_(origin::<T>(&minter), distribution_id);
+
+		assert!(!<Shares<T>>::contains_key(&minter, distribution_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn take_snapshot(b: Linear<0, MAX_HOLDERS>) -> Result<(), BenchmarkError> {
+		let payer = cross_account::<T>("payer");
+		let (collection_id, currency) = prepare_distribution::<T>(&payer, b)?;
+		let distribution_id =
+			<Pallet<T>>::create_distribution(&payer, collection_id, None, currency, AMOUNT)?;
+
+		#[block]
+		{
+			<Pallet<T>>::take_snapshot(distribution_id, Vec::new(), &mut WeightMeter::new());
+		}
+
+		assert!(!<SnapshotCursors<T>>::contains_key(distribution_id));
+		Ok(())
+	}
+}
@@ -0,0 +1,702 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Dividends pallet
+//!
+//! The pallet distributes funds to the holders of the tokens of a collection
+//! in proportion to their balances.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//! - [`Error`]
+//! - [`Event`]
+//!
+//! ## Overview
+//!
+//! A payer deposits funds in the native currency or in a fungible collection
+//! for the holders of an NFT collection, of an RFT collection or of a single RFT token.
+//! The funds are kept in the vault account of the pallet. The payer is also charged
+//! [`SnapshotFee`](Config::SnapshotFee) in the native currency, which goes to the treasury.
+//!
+//! After the deposit, the pallet takes a snapshot of the holder balances
+//! in the background with the weight left in the blocks:
+//!
+//! - for an NFT collection, the share of a holder is the number of their tokens;
+//! - for an RFT collection, the share of a holder is the sum of their pieces of all the tokens;
+//! - for an RFT token, the share of a holder is the number of their pieces.
+//!
+//! Large collections are snapshotted over several blocks, during which the tokens
+//! can still be transferred, minted and burnt. The snapshot is consistent with the balances
+//! at the time of the deposit: if a balance changes before the snapshot reaches it,
+//! the balance before the first change is recorded (see [`OnTokenTransfer`] implementation).
+//!
+//! Once the snapshot is taken, each holder can claim their part of the funds.
+//! The claimed amount is rounded down, and the rounding remainder stays in the vault.
+//! If the collection has no holders, the funds are returned to the payer.
+//!
+//! ### Dispatchable Functions
+//!
+//! - [`distribute`][`Pallet::distribute`] - deposit funds for the holders of a collection.
+//! - [`claim`][`Pallet::claim`] - claim the share of the deposited funds.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+use alloc::vec::Vec;
+
+use frame_support::{
+	defensive, dispatch::DispatchResult, ensure, pallet_prelude::*, storage::PrefixIterator,
+	transactional, weights::WeightMeter, PalletId,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_common::{CollectionById, CollectionHandle, Error as CommonError, OnTokenTransfer};
+use pallet_evm::account::CrossAccountId;
+use pallet_nonfungible::AccountBalance as NonfungibleAccountBalance;
+use pallet_refungible::{Balance as RefungibleBalance, TotalSupply};
+use pallet_structure::Pallet as PalletStructure;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, traits::AccountIdConversion,
+	ArithmeticError, Rounding,
+};
+use up_data_structs::{
+	CollectionId, CollectionMode, DistributionId, DividendDistribution, TokenId,
+};
+use weights::WeightInfo;
+
+/// Maximum length of the raw storage key, from which the snapshot continues.
+pub const MAX_SNAPSHOT_CURSOR_LENGTH: u32 = 256;
+
+pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
+
+/// Raw storage key of a snapshotted balance.
+pub type RawBalanceKey = BoundedVec<u8, ConstU32<MAX_SNAPSHOT_CURSOR_LENGTH>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_common::Config
+		+ pallet_structure::Config
+		+ pallet_nonfungible::Config
+		+ pallet_refungible::Config
+	{
+		/// Events compatible with [`frame_system::Config::Event`].
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The vault id, used for deriving the account holding the deposited funds.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Fee in the native currency, which the payer pays to the treasury
+		/// for taking the snapshot of the holder balances.
+		#[pallet::constant]
+		type SnapshotFee: Get<u128>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Funds were deposited for the holders of a collection.
+		DistributionCreated(
+			/// ID of the distribution.
+			DistributionId,
+			/// ID of the collection.
+			CollectionId,
+			/// ID of the RFT token, if the funds are only for its holders.
+			Option<TokenId>,
+			/// Payer of the funds.
+			T::CrossAccountId,
+			/// ID of the currency collection, 0 for the native currency.
+			CollectionId,
+			/// Deposited funds.
+			u128,
+		),
+
+		/// Snapshot of the holder balances was taken, and the funds can be claimed.
+		SnapshotTaken(
+			/// ID of the distribution.
+			DistributionId,
+			/// Sum of the snapshotted balances.
+			u128,
+		),
+
+		/// Collection had no holders, and the funds were returned to the payer.
+		DistributionRefunded(
+			/// ID of the distribution.
+			DistributionId,
+		),
+
+		/// Snapshot of the holder balances couldn't be taken, and the funds were returned
+		/// to the payer.
+		SnapshotFailed(
+			/// ID of the distribution.
+			DistributionId,
+		),
+
+		/// Holder claimed their share of the funds.
+		DividendsClaimed(
+			/// ID of the distribution.
+			DistributionId,
+			/// Holder, who received the funds.
+			T::CrossAccountId,
+			/// Claimed funds.
+			u128,
+		),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Funds can only be distributed to the holders of NFT and RFT collections.
+		UnsupportedCollection,
+		/// Funds can only be paid in the native currency or in a fungible collection.
+		UnsupportedCurrency,
+		/// No distribution with the given ID.
+		DistributionNotFound,
+		/// Snapshot of the holder balances isn't taken yet.
+		SnapshotInProgress,
+		/// Account has no share in the distribution, or has already claimed it.
+		NothingToClaim,
+	}
+
+	/// Number of the created distributions, used as the ID of the next one.
+	#[pallet::storage]
+	pub type DistributionCount<T: Config> =
+		StorageValue<Value = DistributionId, QueryKind = ValueQuery>;
+
+	/// Distributions, which aren't refunded.
+	#[pallet::storage]
+	pub type Distributions<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = DistributionId,
+		Value = DividendDistribution<T::CrossAccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Snapshotted balances of the holders, which haven't claimed their share yet.
+	///
+	/// * **Key1** - Holder.
+	/// * **Key2** - Distribution ID.
+	/// * **Value** - Share of the holder.
+	#[pallet::storage]
+	pub type Shares<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = T::CrossAccountId,
+		Hasher2 = Twox64Concat,
+		Key2 = DistributionId,
+		Value = u128,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Distributions, whose snapshots are being taken in [`Hooks::on_idle`].
+	///
+	/// * **Key** - Distribution ID.
+	/// * **Value** - Raw storage key of the last snapshotted balance, empty at the start.
+	#[pallet::storage]
+	pub type SnapshotCursors<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = DistributionId,
+		Value = RawBalanceKey,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Distributions, whose snapshots are being taken, by the snapshotted collection.
+	///
+	/// * **Key1** - Collection ID.
+	/// * **Key2** - ID of the RFT token, or `None` for the whole collection.
+	/// * **Key3** - Distribution ID.
+	/// * **Value** - Mode of the collection.
+	#[pallet::storage]
+	pub type PendingSnapshots<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Twox64Concat, Option<TokenId>>,
+			Key<Twox64Concat, DistributionId>,
+		),
+		Value = CollectionMode,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Balances, which changed before the snapshot reached them,
+	/// and whose shares were recorded at the first change.
+	///
+	/// * **Key1** - Distribution ID.
+	/// * **Key2** - Raw storage key of the balance.
+	#[pallet::storage]
+	pub type RecordedBalances<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox64Concat,
+		Key1 = DistributionId,
+		Hasher2 = Twox64Concat,
+		Key2 = RawBalanceKey,
+		Value = (),
+		QueryKind = OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			while meter
+				.try_consume(<SelfWeightOf<T>>::take_snapshot(0))
+				.is_ok()
+			{
+				let Some((distribution_id, cursor)) = <SnapshotCursors<T>>::iter().next() else {
+					break;
+				};
+				if !Self::take_snapshot(distribution_id, cursor.into_inner(), &mut meter) {
+					break;
+				}
+			}
+			meter.consumed()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deposit funds for the holders of the tokens of a collection.
+		///
+		/// The funds can be claimed once the snapshot of the holder balances
+		/// at the time of the deposit is taken. The payer is charged
+		/// [`SnapshotFee`](Config::SnapshotFee) for taking the snapshot.
+		///
+		/// # Permissions
+		///
+		/// * Anyone.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the NFT or RFT collection.
+		/// * `token_id`: ID of the RFT token, if the funds are only for its holders.
+		/// * `currency`: ID of the fungible collection, in which the funds are paid,
+		/// or 0 for the native currency.
+		/// * `amount`: Deposited funds.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<SelfWeightOf<T>>::distribute())]
+		pub fn distribute(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: Option<TokenId>,
+			currency: CollectionId,
+			amount: u128,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::create_distribution(&sender, collection_id, token_id, currency, amount)
+				.map(|_| ())
+		}
+
+		/// Claim the share of the funds of a distribution.
+		///
+		/// # Permissions
+		///
+		/// * Holder, whose balance was snapshotted.
+		///
+		/// # Arguments
+		///
+		/// * `distribution_id`: ID of the distribution.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<SelfWeightOf<T>>::claim())]
+		pub fn claim(origin: OriginFor<T>, distribution_id: DistributionId) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::claim_dividends(&sender, distribution_id).map(|_| ())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get the distribution.
+	pub fn distribution(
+		distribution_id: DistributionId,
+	) -> Option<DividendDistribution<T::CrossAccountId>> {
+		<Distributions<T>>::get(distribution_id)
+	}
+
+	/// Get the funds, which the holder can claim from the distribution.
+	pub fn claimable(holder: &T::CrossAccountId, distribution_id: DistributionId) -> u128 {
+		let Some(distribution) = <Distributions<T>>::get(distribution_id) else {
+			return 0;
+		};
+		if !distribution.snapshot_taken {
+			return 0;
+		}
+		Self::payout(&distribution, <Shares<T>>::get(holder, distribution_id)).unwrap_or_default()
+	}
+
+	/// Get all the distributions, from which the holder can claim funds, with the claimable funds.
+	pub fn claimable_distributions(holder: &T::CrossAccountId) -> Vec<(DistributionId, u128)> {
+		<Shares<T>>::iter_key_prefix(holder)
+			.map(|distribution_id| (distribution_id, Self::claimable(holder, distribution_id)))
+			.filter(|(_, amount)| *amount > 0)
+			.collect()
+	}
+
+	/// Account holding the deposited funds.
+	pub fn vault() -> T::CrossAccountId {
+		T::CrossAccountId::from_sub(T::PalletId::get().into_account_truncating())
+	}
+
+	/// Deposit funds for the holders of the collection. See [`Pallet::distribute`].
+	#[transactional]
+	pub fn create_distribution(
+		payer: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: Option<TokenId>,
+		currency: CollectionId,
+		amount: u128,
+	) -> Result<DistributionId, DispatchError> {
+		let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+		match (&collection.mode, token_id) {
+			(CollectionMode::NFT, None) | (CollectionMode::ReFungible, None) => {}
+			(CollectionMode::ReFungible, Some(token_id)) => ensure!(
+				<TotalSupply<T>>::get((collection_id, token_id)) > 0,
+				<CommonError<T>>::TokenNotFound
+			),
+			_ => return Err(<Error<T>>::UnsupportedCollection.into()),
+		}
+		Self::check_currency(currency)?;
+		ensure!(amount > 0, <CommonError<T>>::TokenValueTooLow);
+
+		let distribution_id = <DistributionCount<T>>::get();
+		let next_id = distribution_id
+			.checked_add(1)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// =========
+
+		<PalletStructure<T>>::pay(currency, payer, &Self::vault(), amount)?;
+		let treasury = T::CrossAccountId::from_sub(T::TreasuryAccountId::get());
+		<PalletStructure<T>>::pay(CollectionId(0), payer, &treasury, T::SnapshotFee::get())?;
+		<DistributionCount<T>>::put(next_id);
+		<Distributions<T>>::insert(
+			distribution_id,
+			DividendDistribution {
+				payer: payer.clone(),
+				collection: collection_id,
+				token: token_id,
+				currency,
+				amount,
+				total_shares: 0,
+				claimed: 0,
+				snapshot_taken: false,
+			},
+		);
+		<SnapshotCursors<T>>::insert(distribution_id, BoundedVec::default());
+		<PendingSnapshots<T>>::insert(
+			(collection_id, token_id, distribution_id),
+			collection.mode.clone(),
+		);
+
+		Self::deposit_event(Event::DistributionCreated(
+			distribution_id,
+			collection_id,
+			token_id,
+			payer.clone(),
+			currency,
+			amount,
+		));
+		Ok(distribution_id)
+	}
+
+	/// Claim the share of the funds of the distribution. See [`Pallet::claim`].
+	#[transactional]
+	pub fn claim_dividends(
+		holder: &T::CrossAccountId,
+		distribution_id: DistributionId,
+	) -> Result<u128, DispatchError> {
+		let mut distribution =
+			<Distributions<T>>::get(distribution_id).ok_or(<Error<T>>::DistributionNotFound)?;
+		ensure!(distribution.snapshot_taken, <Error<T>>::SnapshotInProgress);
+		let share = <Shares<T>>::get(holder, distribution_id);
+		let amount = Self::payout(&distribution, share)?;
+		ensure!(amount > 0, <Error<T>>::NothingToClaim);
+
+		// =========
+
+		<Shares<T>>::remove(holder, distribution_id);
+		distribution.claimed = distribution
+			.claimed
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		<PalletStructure<T>>::pay(distribution.currency, &Self::vault(), holder, amount)?;
+		<Distributions<T>>::insert(distribution_id, distribution);
+
+		Self::deposit_event(Event::DividendsClaimed(
+			distribution_id,
+			holder.clone(),
+			amount,
+		));
+		Ok(amount)
+	}
+
+	/// Continues the snapshot of the distribution, as long as the meter allows.
+	///
+	/// Returns whether the snapshot is finished.
+	fn take_snapshot(
+		distribution_id: DistributionId,
+		cursor: Vec<u8>,
+		meter: &mut WeightMeter,
+	) -> bool {
+		let Some(mut distribution) = <Distributions<T>>::get(distribution_id) else {
+			<SnapshotCursors<T>>::remove(distribution_id);
+			return true;
+		};
+		let collection_id = distribution.collection;
+		let mode = <CollectionById<T>>::get(collection_id).map(|collection| collection.mode);
+
+		let cursor = match (mode, distribution.token) {
+			(Some(CollectionMode::NFT), _) => Self::snapshot_balances(
+				distribution_id,
+				&mut distribution,
+				<NonfungibleAccountBalance<T>>::iter_prefix((collection_id,)),
+				cursor,
+				meter,
+				|holder, tokens| (holder, tokens.into()),
+			),
+			(Some(CollectionMode::ReFungible), Some(token_id)) => Self::snapshot_balances(
+				distribution_id,
+				&mut distribution,
+				<RefungibleBalance<T>>::iter_prefix((collection_id, token_id)),
+				cursor,
+				meter,
+				|holder, pieces| (holder, pieces),
+			),
+			(Some(CollectionMode::ReFungible), None) => Self::snapshot_balances(
+				distribution_id,
+				&mut distribution,
+				<RefungibleBalance<T>>::iter_prefix((collection_id,)),
+				cursor,
+				meter,
+				|(_token_id, holder), pieces| (holder, pieces),
+			),
+			// The collection is destroyed, the snapshot stops with the recorded shares
+			_ => None,
+		};
+
+		match cursor.map(BoundedVec::try_from) {
+			Some(Ok(cursor)) => {
+				<SnapshotCursors<T>>::insert(distribution_id, cursor);
+				<Distributions<T>>::insert(distribution_id, distribution);
+				return false;
+			}
+			Some(Err(_)) => {
+				defensive!("snapshot cursor exceeds MAX_SNAPSHOT_CURSOR_LENGTH");
+				Self::finish_snapshot(distribution_id, &distribution, meter);
+				Self::refund(distribution_id, &distribution);
+				Self::deposit_event(Event::SnapshotFailed(distribution_id));
+				return true;
+			}
+			None => {}
+		};
+
+		Self::finish_snapshot(distribution_id, &distribution, meter);
+		if distribution.total_shares == 0 {
+			Self::refund(distribution_id, &distribution);
+			Self::deposit_event(Event::DistributionRefunded(distribution_id));
+		} else {
+			distribution.snapshot_taken = true;
+			let total_shares = distribution.total_shares;
+			<Distributions<T>>::insert(distribution_id, distribution);
+			Self::deposit_event(Event::SnapshotTaken(distribution_id, total_shares));
+		}
+		true
+	}
+
+	/// Removes the snapshot cursor of the distribution, and the balances recorded
+	/// before the snapshot reached them.
+	fn finish_snapshot(
+		distribution_id: DistributionId,
+		distribution: &DividendDistribution<T::CrossAccountId>,
+		meter: &mut WeightMeter,
+	) {
+		<SnapshotCursors<T>>::remove(distribution_id);
+		<PendingSnapshots<T>>::remove((
+			distribution.collection,
+			distribution.token,
+			distribution_id,
+		));
+		// Only the balances removed before the snapshot reached them are left,
+		// their removal is paid by the balance changes, which recorded them
+		let removed = <RecordedBalances<T>>::clear_prefix(distribution_id, u32::MAX, None);
+		meter.consume(T::DbWeight::get().writes(removed.unique.into()));
+	}
+
+	/// Removes the distribution, and returns the funds to the payer.
+	fn refund(
+		distribution_id: DistributionId,
+		distribution: &DividendDistribution<T::CrossAccountId>,
+	) {
+		<Distributions<T>>::remove(distribution_id);
+		if let Err(e) = <PalletStructure<T>>::pay(
+			distribution.currency,
+			&Self::vault(),
+			&distribution.payer,
+			distribution.amount,
+		) {
+			log::warn!("failed to refund distribution {}: {:?}", distribution_id, e);
+		}
+	}
+
+	/// Records the shares of the holders, starting after the cursor, as long as the meter allows.
+	///
+	/// Returns the cursor to continue from, or `None`, if all the balances are snapshotted.
+	fn snapshot_balances<K, V>(
+		distribution_id: DistributionId,
+		distribution: &mut DividendDistribution<T::CrossAccountId>,
+		mut balances: PrefixIterator<(K, V)>,
+		cursor: Vec<u8>,
+		meter: &mut WeightMeter,
+		share: impl Fn(K, V) -> (T::CrossAccountId, u128),
+	) -> Option<Vec<u8>> {
+		if !cursor.is_empty() {
+			balances.set_last_raw_key(cursor);
+		}
+		while meter.try_consume(Self::snapshot_balance_weight()).is_ok() {
+			let Some((key, value)) = balances.next() else {
+				return None;
+			};
+			if <RecordedBalances<T>>::take(distribution_id, Self::raw_key(balances.last_raw_key()))
+				.is_some()
+			{
+				// The balance changed since the deposit, and was already recorded
+				continue;
+			}
+			let (holder, shares) = share(key, value);
+			Self::add_shares(distribution, distribution_id, holder, shares);
+		}
+		Some(balances.last_raw_key().to_vec())
+	}
+
+	/// Records the balance of the holder before it changes, unless the snapshot
+	/// already reached it, or it was already recorded.
+	fn record_balance(
+		distribution_id: DistributionId,
+		mode: CollectionMode,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		holder: &T::CrossAccountId,
+	) {
+		let (key, shares) = match mode {
+			CollectionMode::NFT => (
+				<NonfungibleAccountBalance<T>>::hashed_key_for((collection_id, holder)),
+				<NonfungibleAccountBalance<T>>::get((collection_id, holder)).into(),
+			),
+			_ => (
+				<RefungibleBalance<T>>::hashed_key_for((collection_id, token_id, holder)),
+				<RefungibleBalance<T>>::get((collection_id, token_id, holder)),
+			),
+		};
+		let cursor = <SnapshotCursors<T>>::get(distribution_id).unwrap_or_default();
+		// The snapshot goes in the order of the raw keys, an empty cursor means it hasn't started
+		if !cursor.is_empty() && key.as_slice() <= cursor.as_slice() {
+			return;
+		}
+		let key = Self::raw_key(&key);
+		if <RecordedBalances<T>>::contains_key(distribution_id, &key) {
+			return;
+		}
+		let Some(mut distribution) = <Distributions<T>>::get(distribution_id) else {
+			return;
+		};
+
+		<RecordedBalances<T>>::insert(distribution_id, key, ());
+		if shares > 0 {
+			Self::add_shares(&mut distribution, distribution_id, holder.clone(), shares);
+			<Distributions<T>>::insert(distribution_id, distribution);
+		}
+	}
+
+	fn add_shares(
+		distribution: &mut DividendDistribution<T::CrossAccountId>,
+		distribution_id: DistributionId,
+		holder: T::CrossAccountId,
+		shares: u128,
+	) {
+		if shares == 0 {
+			return;
+		}
+		<Shares<T>>::mutate(holder, distribution_id, |holder_shares| {
+			*holder_shares = holder_shares.saturating_add(shares)
+		});
+		distribution.total_shares = distribution.total_shares.saturating_add(shares);
+	}
+
+	fn raw_key(key: &[u8]) -> RawBalanceKey {
+		// Raw keys of the balances are much shorter than the limit
+		RawBalanceKey::truncate_from(key.to_vec())
+	}
+
+	fn payout(
+		distribution: &DividendDistribution<T::CrossAccountId>,
+		share: u128,
+	) -> Result<u128, DispatchError> {
+		if share == 0 {
+			return Ok(0);
+		}
+		multiply_by_rational_with_rounding(
+			distribution.amount,
+			share,
+			distribution.total_shares,
+			Rounding::Down,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	fn check_currency(currency: CollectionId) -> DispatchResult {
+		ensure!(
+			<PalletStructure<T>>::is_currency(currency)?,
+			<Error<T>>::UnsupportedCurrency
+		);
+		Ok(())
+	}
+
+	/// Weight of snapshotting a single balance in [`Hooks::on_idle`].
+	fn snapshot_balance_weight() -> Weight {
+		<SelfWeightOf<T>>::take_snapshot(1).saturating_sub(<SelfWeightOf<T>>::take_snapshot(0))
+	}
+}
+
+/// Keeps the snapshots consistent with the balances at the time of the deposit.
+///
+/// Before a balance, which the snapshot hasn't reached yet, changes for the first time,
+/// its current value is recorded, and the snapshot skips it later.
+impl<T: Config> OnTokenTransfer<T::CrossAccountId> for Pallet<T> {
+	fn on_token_transfer(_collection: CollectionId, _token: TokenId, _from: &T::CrossAccountId) {}
+
+	fn before_balance_change(collection: CollectionId, token: TokenId, holder: &T::CrossAccountId) {
+		for token_id in [None, Some(token)] {
+			for (distribution_id, mode) in
+				<PendingSnapshots<T>>::iter_prefix((collection, token_id))
+			{
+				Self::record_balance(distribution_id, mode, collection, token, holder);
+			}
+		}
+	}
+}
@@ -0,0 +1,143 @@
+//! Weights for pallet_dividends
+//!
+//! The values are estimates which haven't been measured yet,
+//! replace them by running `make bench-dividends`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_dividends.
+pub trait WeightInfo {
+	fn distribute() -> Weight;
+	fn claim() -> Weight;
+	fn take_snapshot(b: u32, ) -> Weight;
+}
+
+/// Weights for pallet_dividends using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Common::CollectionById` (r:3 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Refungible::TotalSupply` (r:1 w:0)
+	/// Proof: `Refungible::TotalSupply` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::DistributionCount` (r:1 w:1)
+	/// Proof: `Dividends::DistributionCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Fungible::Balance` (r:2 w:2)
+	/// Proof: `Fungible::Balance` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::Distributions` (r:0 w:1)
+	/// Proof: `Dividends::Distributions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::SnapshotCursors` (r:0 w:1)
+	/// Proof: `Dividends::SnapshotCursors` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::PendingSnapshots` (r:0 w:1)
+	/// Proof: `Dividends::PendingSnapshots` (`max_values`: None, `max_size`: Some(39), added: 2514, mode: `MaxEncodedLen`)
+	fn distribute() -> Weight {
+		Weight::from_parts(45_000_000, 23329)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Dividends::Distributions` (r:1 w:1)
+	/// Proof: `Dividends::Distributions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::Shares` (r:1 w:1)
+	/// Proof: `Dividends::Shares` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Fungible::Balance` (r:2 w:2)
+	/// Proof: `Fungible::Balance` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		Weight::from_parts(30_000_000, 13573)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Dividends::Distributions` (r:1 w:1)
+	/// Proof: `Dividends::Distributions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Refungible::Balance` (r:2 w:0)
+	/// Proof: `Refungible::Balance` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::RecordedBalances` (r:1 w:1)
+	/// Proof: `Dividends::RecordedBalances` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::Shares` (r:1 w:1)
+	/// Proof: `Dividends::Shares` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::SnapshotCursors` (r:0 w:1)
+	/// Proof: `Dividends::SnapshotCursors` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::PendingSnapshots` (r:0 w:1)
+	/// Proof: `Dividends::PendingSnapshots` (`max_values`: None, `max_size`: Some(39), added: 2514, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 100]`.
+	fn take_snapshot(b: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 16350)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 7869).saturating_mul(b.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Common::CollectionById` (r:3 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Refungible::TotalSupply` (r:1 w:0)
+	/// Proof: `Refungible::TotalSupply` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::DistributionCount` (r:1 w:1)
+	/// Proof: `Dividends::DistributionCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Fungible::Balance` (r:2 w:2)
+	/// Proof: `Fungible::Balance` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::Distributions` (r:0 w:1)
+	/// Proof: `Dividends::Distributions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::SnapshotCursors` (r:0 w:1)
+	/// Proof: `Dividends::SnapshotCursors` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::PendingSnapshots` (r:0 w:1)
+	/// Proof: `Dividends::PendingSnapshots` (`max_values`: None, `max_size`: Some(39), added: 2514, mode: `MaxEncodedLen`)
+	fn distribute() -> Weight {
+		Weight::from_parts(45_000_000, 23329)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Dividends::Distributions` (r:1 w:1)
+	/// Proof: `Dividends::Distributions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::Shares` (r:1 w:1)
+	/// Proof: `Dividends::Shares` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Fungible::Balance` (r:2 w:2)
+	/// Proof: `Fungible::Balance` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		Weight::from_parts(30_000_000, 13573)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Dividends::Distributions` (r:1 w:1)
+	/// Proof: `Dividends::Distributions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Refungible::Balance` (r:2 w:0)
+	/// Proof: `Refungible::Balance` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::RecordedBalances` (r:1 w:1)
+	/// Proof: `Dividends::RecordedBalances` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::Shares` (r:1 w:1)
+	/// Proof: `Dividends::Shares` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::SnapshotCursors` (r:0 w:1)
+	/// Proof: `Dividends::SnapshotCursors` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Dividends::PendingSnapshots` (r:0 w:1)
+	/// Proof: `Dividends::PendingSnapshots` (`max_values`: None, `max_size`: Some(39), added: 2514, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 100]`.
+	fn take_snapshot(b: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 16350)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 7869).saturating_mul(b.into()))
+	}
+}
@@ -25,6 +25,7 @@ use alloc::format;
 use core::marker::PhantomData;
 
 use execution::PreDispatch;
+use fp_evm::{Context, ExitReason};
 use frame_support::{
 	ensure, pallet_prelude::DispatchError, sp_runtime::ModuleError, traits::PalletInfo,
 };
@@ -33,7 +34,7 @@ use pallet_evm::{
 	PrecompileOutput, PrecompileResult,
 };
 use parity_scale_codec::Decode;
-use sp_core::{Get, H160};
+use sp_core::{Get, H160, U256};
 use sp_std::cell::RefCell;
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
@@ -94,10 +95,27 @@ impl<T: Config> budget::Budget for GasCallsBudget<'_, T> {
 	}
 }
 
+/// Call to other contract, which is performed after precompile method execution succeeds,
+/// i.e. ERC721 `onERC721Received` acceptance check.
+///
+/// If called contract reverts or returns anything other than `expected_output`,
+/// the whole precompile call is reverted.
+pub struct SubCall {
+	/// Called contract.
+	pub target: H160,
+	/// ABI-encoded call input.
+	pub input: Vec<u8>,
+	/// Output, which called contract should return for the precompile call to succeed.
+	pub expected_output: Vec<u8>,
+	/// Revert reason used when called contract returns unexpected output.
+	pub error: &'static str,
+}
+
 #[derive(Default)]
 pub struct SubstrateRecorder<T: Config> {
 	initial_gas: u64,
 	gas_limit: RefCell<u64>,
+	sub_calls: RefCell<Vec<SubCall>>,
 	_phantom: PhantomData<*const T>,
 }
 
@@ -106,10 +124,19 @@ impl<T: Config> SubstrateRecorder<T> {
 		Self {
 			initial_gas: gas_limit,
 			gas_limit: RefCell::new(gas_limit),
+			sub_calls: RefCell::new(Vec::new()),
 			_phantom: PhantomData,
 		}
 	}
 
+	/// Schedule call to other contract, see [`SubCall`].
+	///
+	/// Scheduled calls are only performed when method is called via precompile,
+	/// and are paid from the gas left after the method execution.
+	pub fn schedule_sub_call(&self, call: SubCall) {
+		self.sub_calls.borrow_mut().push(call);
+	}
+
 	pub fn gas_left(&self) -> u64 {
 		*self.gas_limit.borrow()
 	}
@@ -173,7 +200,7 @@ impl<T: Config> SubstrateRecorder<T> {
 		// We ignore error here, as it should not occur, as we have our own bookkeeping of gas
 		let _ = handle.record_cost(self.initial_gas - self.gas_left());
 		Some(match result {
-			Ok(Some(v)) => Ok(PrecompileOutput {
+			Ok(Some(v)) => self.perform_sub_calls(handle).map(|()| PrecompileOutput {
 				exit_status: ExitSucceed::Returned,
 				output: v,
 			}),
@@ -187,6 +214,51 @@ impl<T: Config> SubstrateRecorder<T> {
 		})
 	}
 
+	/// Perform calls scheduled with [`Self::schedule_sub_call`].
+	///
+	/// Gas used by the calls is recorded by the handle itself.
+	fn perform_sub_calls(
+		&self,
+		handle: &mut impl PrecompileHandle,
+	) -> Result<(), PrecompileFailure> {
+		let caller = handle.code_address();
+		for call in self.sub_calls.take() {
+			let (reason, output) = handle.call(
+				call.target,
+				None,
+				call.input,
+				Some(self.gas_left()),
+				false,
+				&Context {
+					address: call.target,
+					caller,
+					apparent_value: U256::zero(),
+				},
+			);
+			match reason {
+				ExitReason::Succeed(_) if output == call.expected_output => {}
+				ExitReason::Succeed(_) => {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: (call.error,)
+							.abi_encode_call(evm_coder::fn_selector!(Error(string))),
+					})
+				}
+				ExitReason::Revert(exit_status) => {
+					return Err(PrecompileFailure::Revert {
+						exit_status,
+						output,
+					})
+				}
+				ExitReason::Error(e) => return Err(e.into()),
+				ExitReason::Fatal(exit_status) => {
+					return Err(PrecompileFailure::Fatal { exit_status })
+				}
+			}
+		}
+		Ok(())
+	}
+
 	/// Consume gas for reading.
 	pub fn consume_store_reads(&self, reads: u64) -> execution::Result<()> {
 		self.consume_gas(T::GasWeightMapping::weight_to_gas(Weight::from_parts(
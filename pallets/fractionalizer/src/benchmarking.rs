@@ -0,0 +1,111 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::v2::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{fungible::Balanced, tokens::Precision};
+use frame_system::RawOrigin;
+use pallet_common::Config as CommonConfig;
+use up_data_structs::{budget::Unlimited, CreateNftData};
+
+use super::*;
+
+const SEED: u32 = 1;
+const PIECES: u128 = 100;
+
+fn cross_account<T: Config>(name: &'static str) -> T::CrossAccountId {
+	T::CrossAccountId::from_sub(account(name, 0, SEED))
+}
+
+fn origin<T: Config>(account: &T::CrossAccountId) -> OriginFor<T> {
+	RawOrigin::Signed(account.as_sub().clone()).into()
+}
+
+fn create_collection<T: Config>(
+	owner: &T::CrossAccountId,
+	mode: CollectionMode,
+) -> Result<CollectionId, DispatchError> {
+	let _ = <T as CommonConfig>::Currency::deposit(
+		owner.as_sub(),
+		T::CollectionCreationPrice::get(),
+		Precision::Exact,
+	)?;
+	T::CollectionDispatch::create(
+		owner.clone(),
+		CollectionIssuer::User(owner.clone()),
+		CreateCollectionData {
+			mode,
+			..Default::default()
+		},
+	)
+}
+
+fn mint<T: Config>(
+	collection_id: CollectionId,
+	owner: &T::CrossAccountId,
+	to: &T::CrossAccountId,
+	data: CreateItemData,
+) -> DispatchResult {
+	T::CollectionDispatch::dispatch(collection_id)?
+		.as_dyn()
+		.create_item(owner.clone(), to.clone(), data, &Unlimited)
+		.map(|_| ())
+		.map_err(|error| error.error)
+}
+
+/// Creates an NFT collection and mints a token owned by `owner` in it.
+fn create_nft<T: Config>(owner: &T::CrossAccountId) -> Result<CollectionId, DispatchError> {
+	let collection_id = create_collection::<T>(owner, CollectionMode::NFT)?;
+	mint::<T>(
+		collection_id,
+		owner,
+		owner,
+		CreateItemData::NFT(CreateNftData::default()),
+	)?;
+	Ok(collection_id)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn fractionalize() -> Result<(), BenchmarkError> {
+		let owner = cross_account::<T>("owner");
+		let collection_id = create_nft::<T>(&owner)?;
+		// The worst case: the pieces collection is created by the first fractionalization
+		assert!(<PiecesCollection<T>>::get().is_none());
+
+		#[extrinsic_call]
+		_(origin::<T>(&owner), collection_id, TokenId(1), PIECES);
+
+		assert!(<NftToRft<T>>::contains_key((collection_id, TokenId(1))));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn redeem() -> Result<(), BenchmarkError> {
+		let owner = cross_account::<T>("owner");
+		let collection_id = create_nft::<T>(&owner)?;
+		let (rft_collection, rft_token) =
+			<Pallet<T>>::fractionalize_token(&owner, collection_id, TokenId(1), PIECES)?;
+
+		#[extrinsic_call]
+		_(origin::<T>(&owner), rft_collection, rft_token);
+
+		assert!(!<NftToRft<T>>::contains_key((collection_id, TokenId(1))));
+		Ok(())
+	}
+}
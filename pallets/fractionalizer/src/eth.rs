@@ -0,0 +1,192 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! Implementation of Fractionalizer contract.
+
+use core::marker::PhantomData;
+
+use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::*, AbiCoder};
+use frame_support::traits::Get;
+use pallet_common::eth::{collection_id_to_address, map_eth_to_id};
+use pallet_evm::{account::CrossAccountId, OnMethodCall, PrecompileHandle, PrecompileResult};
+use pallet_evm_coder_substrate::{
+	dispatch_to_evm,
+	execution::{PreDispatch, Result},
+	frontier_contract, SubstrateRecorder, WithRecorder,
+};
+use up_data_structs::{CollectionId, TokenId};
+
+use crate::{weights::WeightInfo, Config, Pallet, SelfWeightOf};
+
+frontier_contract! {
+	macro_rules! Fractionalizer_result {...}
+	impl<T: Config> Contract for Fractionalizer<T> {...}
+}
+
+/// Token of a Unique collection.
+#[derive(Debug, Default, AbiCoder)]
+pub struct Token {
+	/// Address of the collection.
+	pub collection: Address,
+	/// ID of the token.
+	pub token_id: U256,
+}
+
+impl From<(CollectionId, TokenId)> for Token {
+	fn from((collection, token): (CollectionId, TokenId)) -> Self {
+		Self {
+			collection: collection_id_to_address(collection),
+			token_id: token.into(),
+		}
+	}
+}
+
+/// See [`FractionalizerCall`]
+pub struct Fractionalizer<T: Config>(SubstrateRecorder<T>);
+impl<T: Config> WithRecorder<T> for Fractionalizer<T> {
+	fn recorder(&self) -> &SubstrateRecorder<T> {
+		&self.0
+	}
+
+	fn into_recorder(self) -> SubstrateRecorder<T> {
+		self.0
+	}
+}
+
+fn map_collection(collection: &Address) -> Result<CollectionId> {
+	map_eth_to_id(collection).ok_or_else(|| "not a collection address".into())
+}
+
+/// @title Vault splitting NFTs into refungible tokens
+#[solidity_interface(name = Fractionalizer, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> Fractionalizer<T>
+where
+	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
+	/// @notice Lock the NFT in the vault and mint the linked RFT token in the pieces collection.
+	/// @dev All the pieces of the minted token go to the caller.
+	/// @param nftCollection Address of the NFT collection.
+	/// @param nftTokenId ID of the fractionalized NFT.
+	/// @param pieces Number of the pieces of the minted RFT token.
+	/// @return Minted RFT token.
+	#[weight(<SelfWeightOf<T>>::fractionalize())]
+	fn fractionalize(
+		&mut self,
+		caller: Caller,
+		nft_collection: Address,
+		nft_token_id: U256,
+		pieces: U256,
+	) -> Result<Token> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let pieces = pieces.try_into().map_err(|_| "pieces overflow")?;
+
+		<Pallet<T>>::fractionalize_token(
+			&caller,
+			map_collection(&nft_collection)?,
+			nft_token_id.try_into()?,
+			pieces,
+		)
+		.map(Token::from)
+		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Burn all the pieces of the RFT token and get the linked NFT back.
+	/// @param rftCollection Address of the RFT collection.
+	/// @param rftTokenId ID of the RFT token linked to the NFT.
+	#[weight(<SelfWeightOf<T>>::redeem())]
+	fn redeem(
+		&mut self,
+		caller: Caller,
+		rft_collection: Address,
+		rft_token_id: U256,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+
+		<Pallet<T>>::redeem_token(
+			&caller,
+			map_collection(&rft_collection)?,
+			rft_token_id.try_into()?,
+		)
+		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Get the RFT collection owned by the vault, in which the pieces are minted.
+	/// @return Address of the pieces collection, or zero address if no NFT was fractionalized yet.
+	fn pieces_collection(&self) -> Result<Address> {
+		self.recorder().consume_sload()?;
+
+		Ok(<Pallet<T>>::pieces_collection()
+			.map(collection_id_to_address)
+			.unwrap_or_default())
+	}
+
+	/// @notice Get the RFT token linked to the fractionalized NFT.
+	/// @param collection Address of the NFT collection.
+	/// @param tokenId ID of the NFT.
+	/// @return Linked RFT token.
+	fn rft_of_nft(&self, collection: Address, token_id: U256) -> Result<Token> {
+		self.recorder().consume_sload()?;
+
+		<Pallet<T>>::rft_of_nft(map_collection(&collection)?, token_id.try_into()?)
+			.map(Token::from)
+			.ok_or_else(|| "token is not fractionalized".into())
+	}
+
+	/// @notice Get the fractionalized NFT linked to the RFT token.
+	/// @param collection Address of the RFT collection.
+	/// @param tokenId ID of the RFT token.
+	/// @return Linked NFT.
+	fn nft_of_rft(&self, collection: Address, token_id: U256) -> Result<Token> {
+		self.recorder().consume_sload()?;
+
+		<Pallet<T>>::nft_of_rft(map_collection(&collection)?, token_id.try_into()?)
+			.map(Token::from)
+			.ok_or_else(|| "token is not fractionalized".into())
+	}
+}
+
+/// Implements [`OnMethodCall`], which delegates call to [`Fractionalizer`]
+pub struct FractionalizerOnMethodCall<T: Config>(PhantomData<*const T>);
+impl<T: Config> OnMethodCall<T> for FractionalizerOnMethodCall<T>
+where
+	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
+	fn is_reserved(contract: &sp_core::H160) -> bool {
+		contract == &<T as Config>::ContractAddress::get()
+	}
+
+	fn is_used(contract: &sp_core::H160) -> bool {
+		contract == &<T as Config>::ContractAddress::get()
+	}
+
+	fn call(handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		if handle.code_address() != <T as Config>::ContractAddress::get() {
+			return None;
+		}
+
+		let fractionalizer =
+			Fractionalizer::<T>(SubstrateRecorder::<T>::new(handle.remaining_gas()));
+		pallet_evm_coder_substrate::call(handle, fractionalizer)
+	}
+
+	fn get_code(contract: &sp_core::H160) -> Option<Vec<u8>> {
+		(contract == &<T as Config>::ContractAddress::get())
+			.then(|| include_bytes!("./stubs/Fractionalizer.raw").to_vec())
+	}
+}
+
+generate_stubgen!(fractionalizer_impl, FractionalizerCall<()>, true);
+generate_stubgen!(fractionalizer_iface, FractionalizerCall<()>, false);
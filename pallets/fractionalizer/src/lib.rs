@@ -0,0 +1,382 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Fractionalizer pallet
+//!
+//! The pallet splits NFTs into refungible tokens and reassembles them back.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//! - [`Error`]
+//! - [`Event`]
+//!
+//! ## Overview
+//!
+//! An NFT owner fractionalizes the token into a chosen number of pieces. The NFT is moved
+//! to the vault account of the pallet, and a new RFT token with all the pieces is minted
+//! to the owner in the pieces collection. The NFT keeps its collection, ID and history,
+//! and the link between the NFT and the RFT token is kept until the NFT is redeemed.
+//!
+//! The pieces collection is an RFT collection owned by the vault, created on the first
+//! fractionalization. It has no admins, so only the holders can burn the pieces,
+//! and nobody can destroy the collection.
+//!
+//! Anyone holding all the pieces of the linked RFT token can redeem it:
+//! the RFT token is burnt, and the NFT is moved from the vault to the redeemer.
+//!
+//! ### Dispatchable Functions
+//!
+//! - [`fractionalize`][`Pallet::fractionalize`] - lock an NFT and mint the linked RFT token.
+//! - [`redeem`][`Pallet::redeem`] - burn the whole RFT token and get the NFT back.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use frame_support::{
+	dispatch::DispatchResult, ensure, pallet_prelude::*, storage::Key, transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_common::{
+	dispatch::{dispatch_tx, CollectionDispatch},
+	CollectionHandle, CollectionIssuer, Error as CommonError,
+};
+use pallet_evm::account::CrossAccountId;
+use pallet_structure::{Pallet as PalletStructure, TRANSFER_NESTING_BUDGET};
+use sp_runtime::{traits::AccountIdConversion, DispatchError};
+use up_data_structs::{
+	budget, CollectionDescription, CollectionId, CollectionMode, CollectionName,
+	CollectionTokenPrefix, CreateCollectionData, CreateItemData, CreateReFungibleData, TokenId,
+};
+use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod eth;
+pub mod weights;
+
+pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_common::Config
+		+ pallet_structure::Config
+		+ pallet_evm_coder_substrate::Config
+	{
+		/// Events compatible with [`frame_system::Config::Event`].
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Address under which the Fractionalizer contract would be available.
+		#[pallet::constant]
+		type ContractAddress: Get<sp_core::H160>;
+
+		/// The vault id, used for deriving the account holding fractionalized NFTs.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// NFT was moved to the vault, and the linked RFT token was minted.
+		Fractionalized(
+			/// ID of the NFT collection.
+			CollectionId,
+			/// ID of the fractionalized NFT.
+			TokenId,
+			/// ID of the RFT collection.
+			CollectionId,
+			/// ID of the minted RFT token.
+			TokenId,
+			/// Owner of the NFT, who received all the pieces.
+			T::CrossAccountId,
+			/// Number of the minted pieces.
+			u128,
+		),
+
+		/// RFT token was burnt, and the linked NFT was returned from the vault.
+		Redeemed(
+			/// ID of the NFT collection.
+			CollectionId,
+			/// ID of the redeemed NFT.
+			TokenId,
+			/// ID of the RFT collection.
+			CollectionId,
+			/// ID of the burnt RFT token.
+			TokenId,
+			/// Holder of all the pieces, who received the NFT.
+			T::CrossAccountId,
+		),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only tokens of NFT collections can be fractionalized.
+		NftCollectionExpected,
+		/// RFT token isn't linked to a fractionalized NFT.
+		TokenNotFractionalized,
+		/// Redeemer doesn't hold all the pieces of the RFT token.
+		InsufficientPieces,
+	}
+
+	/// RFT collection owned by the vault, in which the pieces of the fractionalized NFTs are minted.
+	#[pallet::storage]
+	pub type PiecesCollection<T: Config> =
+		StorageValue<Value = CollectionId, QueryKind = OptionQuery>;
+
+	/// Links of the fractionalized NFTs to their RFT tokens.
+	#[pallet::storage]
+	pub type NftToRft<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = (CollectionId, TokenId),
+		QueryKind = OptionQuery,
+	>;
+
+	/// Links of the RFT tokens to their fractionalized NFTs.
+	#[pallet::storage]
+	pub type RftToNft<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = (CollectionId, TokenId),
+		QueryKind = OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock an NFT in the vault and mint the linked RFT token in the pieces collection.
+		///
+		/// All the pieces of the minted token go to the owner of the NFT.
+		///
+		/// # Permissions
+		///
+		/// * Owner of the NFT.
+		///
+		/// # Arguments
+		///
+		/// * `nft_collection`: ID of the NFT collection.
+		/// * `nft_token`: ID of the fractionalized NFT.
+		/// * `pieces`: Number of the pieces of the minted RFT token.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<SelfWeightOf<T>>::fractionalize())]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			nft_collection: CollectionId,
+			nft_token: TokenId,
+			pieces: u128,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::fractionalize_token(&sender, nft_collection, nft_token, pieces).map(|_| ())
+		}
+
+		/// Burn all the pieces of the RFT token and get the linked NFT back from the vault.
+		///
+		/// # Permissions
+		///
+		/// * Holder of all the pieces of the RFT token.
+		///
+		/// # Arguments
+		///
+		/// * `rft_collection`: ID of the RFT collection.
+		/// * `rft_token`: ID of the RFT token linked to the NFT.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<SelfWeightOf<T>>::redeem())]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			rft_collection: CollectionId,
+			rft_token: TokenId,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::redeem_token(&sender, rft_collection, rft_token)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get the RFT token linked to the fractionalized NFT.
+	pub fn rft_of_nft(
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> Option<(CollectionId, TokenId)> {
+		<NftToRft<T>>::get((collection_id, token_id))
+	}
+
+	/// Get the fractionalized NFT linked to the RFT token.
+	pub fn nft_of_rft(
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> Option<(CollectionId, TokenId)> {
+		<RftToNft<T>>::get((collection_id, token_id))
+	}
+
+	/// Account holding the fractionalized NFTs.
+	pub fn vault() -> T::CrossAccountId {
+		T::CrossAccountId::from_sub(T::PalletId::get().into_account_truncating())
+	}
+
+	/// Get the RFT collection owned by the vault, in which the pieces are minted.
+	pub fn pieces_collection() -> Option<CollectionId> {
+		<PiecesCollection<T>>::get()
+	}
+
+	/// Lock the NFT and mint the linked RFT token. See [`Pallet::fractionalize`].
+	///
+	/// Returns the IDs of the pieces collection and of the minted RFT token.
+	#[transactional]
+	pub fn fractionalize_token(
+		owner: &T::CrossAccountId,
+		nft_collection: CollectionId,
+		nft_token: TokenId,
+		pieces: u128,
+	) -> Result<(CollectionId, TokenId), DispatchError> {
+		let collection = <CollectionHandle<T>>::try_get(nft_collection)?;
+		ensure!(
+			collection.mode == CollectionMode::NFT,
+			<Error<T>>::NftCollectionExpected
+		);
+		ensure!(pieces > 0, <CommonError<T>>::TokenValueTooLow);
+
+		// =========
+
+		let rft_collection = Self::get_or_create_pieces_collection()?;
+		<PalletStructure<T>>::transfer_as_owner(
+			nft_collection,
+			nft_token,
+			owner,
+			&Self::vault(),
+			1,
+		)?;
+		let data = CreateItemData::ReFungible(CreateReFungibleData {
+			pieces,
+			properties: Default::default(),
+		});
+		let budget = budget::Value::new(TRANSFER_NESTING_BUDGET);
+		dispatch_tx::<T, _>(rft_collection, |d| {
+			d.create_item(Self::vault(), owner.clone(), data, &budget)
+		})
+		.map_err(|error| error.error)?;
+		let rft_token = T::CollectionDispatch::dispatch(rft_collection)?
+			.as_dyn()
+			.last_token_id();
+
+		<NftToRft<T>>::insert((nft_collection, nft_token), (rft_collection, rft_token));
+		<RftToNft<T>>::insert((rft_collection, rft_token), (nft_collection, nft_token));
+
+		Self::deposit_event(Event::Fractionalized(
+			nft_collection,
+			nft_token,
+			rft_collection,
+			rft_token,
+			owner.clone(),
+			pieces,
+		));
+		Ok((rft_collection, rft_token))
+	}
+
+	fn get_or_create_pieces_collection() -> Result<CollectionId, DispatchError> {
+		if let Some(collection_id) = <PiecesCollection<T>>::get() {
+			return Ok(collection_id);
+		}
+
+		let name: CollectionName = "Fractionalized NFTs"
+			.encode_utf16()
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("name length < max name length; qed");
+		let description: CollectionDescription = "Pieces of the NFTs locked in the vault"
+			.encode_utf16()
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("description length < max description length; qed");
+		let token_prefix: CollectionTokenPrefix = b"FRAC"
+			.to_vec()
+			.try_into()
+			.expect("prefix length < max prefix length; qed");
+
+		let collection_id = T::CollectionDispatch::create(
+			Self::vault(),
+			CollectionIssuer::Internals,
+			CreateCollectionData {
+				name,
+				description,
+				token_prefix,
+				mode: CollectionMode::ReFungible,
+				..Default::default()
+			},
+		)?;
+		<PiecesCollection<T>>::put(collection_id);
+		Ok(collection_id)
+	}
+
+	/// Burn the RFT token and return the linked NFT. See [`Pallet::redeem`].
+	#[transactional]
+	pub fn redeem_token(
+		redeemer: &T::CrossAccountId,
+		rft_collection: CollectionId,
+		rft_token: TokenId,
+	) -> DispatchResult {
+		let (nft_collection, nft_token) = <RftToNft<T>>::get((rft_collection, rft_token))
+			.ok_or(<Error<T>>::TokenNotFractionalized)?;
+		let dispatch = T::CollectionDispatch::dispatch(rft_collection)?;
+		let pieces = dispatch
+			.as_dyn()
+			.total_pieces(rft_token)
+			.ok_or(<CommonError<T>>::TokenNotFound)?;
+		ensure!(
+			dispatch.as_dyn().balance(redeemer.clone(), rft_token) == pieces,
+			<Error<T>>::InsufficientPieces
+		);
+
+		// =========
+
+		dispatch_tx::<T, _>(rft_collection, |d| {
+			d.burn_item(redeemer.clone(), rft_token, pieces)
+		})
+		.map_err(|error| error.error)?;
+		<RftToNft<T>>::remove((rft_collection, rft_token));
+		<NftToRft<T>>::remove((nft_collection, nft_token));
+		<PalletStructure<T>>::transfer_as_owner(
+			nft_collection,
+			nft_token,
+			&Self::vault(),
+			redeemer,
+			1,
+		)?;
+
+		Self::deposit_event(Event::Redeemed(
+			nft_collection,
+			nft_token,
+			rft_collection,
+			rft_token,
+			redeemer.clone(),
+		));
+		Ok(())
+	}
+}
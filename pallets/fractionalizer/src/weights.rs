@@ -0,0 +1,46 @@
+//! Weights for pallet_fractionalizer
+//!
+//! The values are estimates which haven't been measured yet,
+//! replace them by running `make bench-fractionalizer`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_fractionalizer.
+pub trait WeightInfo {
+	fn fractionalize() -> Weight;
+	fn redeem() -> Weight;
+}
+
+/// Weights for pallet_fractionalizer using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn fractionalize() -> Weight {
+		Weight::from_parts(140_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	fn redeem() -> Weight {
+		Weight::from_parts(90_000_000, 9000)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn fractionalize() -> Weight {
+		Weight::from_parts(140_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	fn redeem() -> Weight {
+		Weight::from_parts(90_000_000, 9000)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+}
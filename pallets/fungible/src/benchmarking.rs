@@ -132,7 +132,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance(&collection, &sender, &spender, 100)?;
+			<Pallet<T>>::set_allowance(&collection, &sender, &spender, 100, None)?;
 		}
 
 		Ok(())
@@ -150,7 +150,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance_from(&collection, &sender, &owner_eth, &spender, 100)?;
+			<Pallet<T>>::set_allowance_from(&collection, &sender, &owner_eth, &spender, 100, None)?;
 		}
 
 		Ok(())
@@ -163,7 +163,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; spender: cross_sub;
 		};
 		<Pallet<T>>::create_item(&collection, &owner, (sender.clone(), 200), &Unlimited)?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &spender, 200)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &spender, 200, None)?;
 
 		#[block]
 		{
@@ -196,7 +196,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; burner: cross_sub;
 		};
 		<Pallet<T>>::create_item(&collection, &owner, (sender.clone(), 200), &Unlimited)?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &burner, 200)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &burner, 200, None)?;
 
 		#[block]
 		{
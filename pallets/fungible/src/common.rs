@@ -21,18 +21,19 @@ use pallet_common::{
 	weights::WeightInfo as _, with_weight, CommonCollectionOperations, CommonWeightInfo,
 	Error as CommonError, SelfWeightOf as PalletCommonWeightOf, XcmExtensions,
 };
+use pallet_structure::Error as StructureError;
 use sp_runtime::{ArithmeticError, DispatchError};
 use sp_std::vec;
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
 use up_data_structs::{
 	budget::Budget, CollectionId, CreateItemData, CreateItemExData, Property, PropertyKey,
-	PropertyKeyPermission, PropertyValue, TokenId, TokenOwnerError,
+	PropertyKeyPermission, PropertyKeyValueType, PropertyValue, TokenId, TokenOwnerError,
+	TokenUser,
 };
 
 use crate::{
-	weights::WeightInfo, Allowance, Balance, Config, Error, FungibleHandle, Pallet, SelfWeightOf,
-	TotalSupply,
+	weights::WeightInfo, Balance, Config, Error, FungibleHandle, Pallet, SelfWeightOf, TotalSupply,
 };
 
 pub struct CommonWeights<T: Config>(PhantomData<T>);
@@ -56,7 +57,7 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	}
 
 	fn set_collection_properties(amount: u32) -> Weight {
-		<pallet_common::SelfWeightOf<T>>::set_collection_properties(amount)
+		pallet_common::set_collection_properties_weight::<T>(amount)
 	}
 
 	fn set_token_properties(_amount: u32) -> Weight {
@@ -99,6 +100,16 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	fn force_repair_item() -> Weight {
 		Weight::zero()
 	}
+
+	fn set_token_lock() -> Weight {
+		// Error
+		Weight::zero()
+	}
+
+	fn set_token_user() -> Weight {
+		// Error
+		Weight::zero()
+	}
 }
 
 /// Implementation of `CommonCollectionOperations` for `FungibleHandle`. It wraps FungibleHandle Pallete
@@ -180,6 +191,26 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		)
 	}
 
+	fn burn_item_recursively(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		_burn_children: bool,
+		budget: &dyn Budget,
+	) -> DispatchResultWithPostInfo {
+		ensure!(
+			token == TokenId::default(),
+			<CommonError<T>>::FungibleItemsHaveNoId
+		);
+		ensure!(budget.consume(), <StructureError<T>>::BreadthLimit);
+
+		let amount = <Balance<T>>::get((self.id, &sender));
+		with_weight(
+			<Pallet<T>>::burn(self, &sender, amount),
+			<CommonWeights<T>>::burn_item(),
+		)
+	}
+
 	fn transfer(
 		&self,
 		from: T::CrossAccountId,
@@ -202,6 +233,7 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		spender: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		ensure!(
 			token == TokenId::default(),
@@ -209,7 +241,7 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		);
 
 		with_weight(
-			<Pallet<T>>::set_allowance(self, &sender, &spender, amount),
+			<Pallet<T>>::set_allowance(self, &sender, &spender, amount, deadline),
 			<CommonWeights<T>>::approve(),
 		)
 	}
@@ -221,6 +253,7 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		to: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		ensure!(
 			token == TokenId::default(),
@@ -228,7 +261,7 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		);
 
 		with_weight(
-			<Pallet<T>>::set_allowance_from(self, &sender, &from, &to, amount),
+			<Pallet<T>>::set_allowance_from(self, &sender, &from, &to, amount, deadline),
 			<CommonWeights<T>>::approve_from(),
 		)
 	}
@@ -313,6 +346,14 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		fail!(<Error<T>>::SettingPropertiesNotAllowed)
 	}
 
+	fn set_token_property_value_types(
+		&self,
+		_sender: &T::CrossAccountId,
+		_value_types: Vec<PropertyKeyValueType>,
+	) -> DispatchResultWithPostInfo {
+		fail!(<Error<T>>::SettingPropertiesNotAllowed)
+	}
+
 	fn delete_token_properties(
 		&self,
 		_sender: T::CrossAccountId,
@@ -427,7 +468,19 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		if token != TokenId::default() {
 			return 0;
 		}
-		<Allowance<T>>::get((self.id, sender, spender))
+		<Pallet<T>>::allowance(self, &sender, &spender)
+	}
+
+	fn allowance_deadline(
+		&self,
+		sender: T::CrossAccountId,
+		spender: T::CrossAccountId,
+		token: TokenId,
+	) -> Option<u64> {
+		if token != TokenId::default() {
+			return None;
+		}
+		<Pallet<T>>::allowance_deadline(self, &sender, &spender)
 	}
 
 	fn total_pieces(&self, token: TokenId) -> Option<u128> {
@@ -446,6 +499,7 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		_owner: T::CrossAccountId,
 		_operator: T::CrossAccountId,
 		_approve: bool,
+		_deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		fail!(<Error<T>>::SettingAllowanceForAllNotAllowed)
 	}
@@ -454,10 +508,44 @@ impl<T: Config> CommonCollectionOperations<T> for FungibleHandle<T> {
 		false
 	}
 
+	fn allowance_for_all_deadline(
+		&self,
+		_owner: T::CrossAccountId,
+		_operator: T::CrossAccountId,
+	) -> Option<u64> {
+		None
+	}
+
 	/// Repairs a possibly broken item.
 	fn repair_item(&self, _token: TokenId) -> DispatchResultWithPostInfo {
 		fail!(<Error<T>>::FungibleTokensAreAlwaysValid)
 	}
+
+	fn set_token_lock(
+		&self,
+		_sender: T::CrossAccountId,
+		_token: TokenId,
+		_locked: bool,
+	) -> DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation)
+	}
+
+	fn token_locked(&self, _token: TokenId) -> bool {
+		false
+	}
+
+	fn set_token_user(
+		&self,
+		_sender: T::CrossAccountId,
+		_token: TokenId,
+		_user: Option<TokenUser<T::CrossAccountId>>,
+	) -> DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation)
+	}
+
+	fn token_user(&self, _token: TokenId) -> Option<TokenUser<T::CrossAccountId>> {
+		None
+	}
 }
 
 impl<T: Config> XcmExtensions<T> for FungibleHandle<T> {
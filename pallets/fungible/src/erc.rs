@@ -22,8 +22,8 @@ use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
 use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::*, AbiCoder, ToLog};
 use pallet_common::{
 	erc::{CollectionCall, CommonEvmHandler, PrecompileResult},
-	eth::CrossAddress,
-	CollectionHandle, CommonWeightInfo as _,
+	eth::{self, collection_id_to_address, CrossAddress},
+	CollectionHandle, CommonWeightInfo as _, Pallet as PalletCommon,
 };
 use pallet_evm::{account::CrossAccountId, PrecompileHandle};
 use pallet_evm_coder_substrate::{
@@ -32,11 +32,11 @@ use pallet_evm_coder_substrate::{
 	frontier_contract, SubstrateRecorder,
 };
 use pallet_structure::{weights::WeightInfo as _, SelfWeightOf as StructureWeight};
-use sp_core::{Get, U256};
-use up_data_structs::{budget::Budget, CollectionMode};
+use sp_core::{Get, H256, U256};
+use up_data_structs::{budget::Budget, CollectionMode, TokenId};
 
 use crate::{
-	common::CommonWeights, weights::WeightInfo, Allowance, Balance, Config, FungibleHandle, Pallet,
+	common::CommonWeights, weights::WeightInfo, Balance, Config, FungibleHandle, Pallet,
 	SelfWeightOf, TotalSupply,
 };
 
@@ -69,6 +69,13 @@ pub struct AmountForAddress {
 	amount: U256,
 }
 
+/// Recipient and amount of a single transfer of a batch.
+#[derive(AbiCoder, Debug)]
+pub struct AmountForCrossAddress {
+	to: CrossAddress,
+	amount: U256,
+}
+
 fn nesting_budget<T: Config>(recorder: &SubstrateRecorder<T>) -> impl Budget + '_ {
 	recorder.weight_calls_budget(<StructureWeight<T>>::find_parent())
 }
@@ -142,7 +149,7 @@ impl<T: Config> FungibleHandle<T> {
 		let spender = T::CrossAccountId::from_eth(spender);
 		let amount = amount.try_into().map_err(|_| "amount overflow")?;
 
-		<Pallet<T>>::set_allowance(self, &caller, &spender, amount)
+		<Pallet<T>>::set_allowance(self, &caller, &spender, amount, None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
 	}
@@ -151,7 +158,7 @@ impl<T: Config> FungibleHandle<T> {
 		let owner = T::CrossAccountId::from_eth(owner);
 		let spender = T::CrossAccountId::from_eth(spender);
 
-		Ok(<Allowance<T>>::get((self.id, owner, spender)).into())
+		Ok(<Pallet<T>>::allowance(self, &owner, &spender).into())
 	}
 }
 
@@ -170,6 +177,12 @@ impl<T: Config> FungibleHandle<T> {
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
 	}
+
+	/// @notice Maximum total supply of the tokens.
+	/// @dev Returns the maximum `uint128` value if the supply is not capped.
+	fn cap(&self) -> Result<U256> {
+		Ok(self.supply_cap().into())
+	}
 }
 
 #[solidity_interface(name = ERC20UniqueExtensions, enum(derive(PreDispatch)), enum_attr(weight))]
@@ -185,7 +198,7 @@ where
 		let owner = owner.into_sub_cross_account::<T>()?;
 		let spender = spender.into_sub_cross_account::<T>()?;
 
-		Ok(<Allowance<T>>::get((self.id, owner, spender)).into())
+		Ok(<Pallet<T>>::allowance(self, &owner, &spender).into())
 	}
 
 	/// @notice A description for the collection.
@@ -217,7 +230,7 @@ where
 		let spender = spender.into_sub_cross_account::<T>()?;
 		let amount = amount.try_into().map_err(|_| "amount overflow")?;
 
-		<Pallet<T>>::set_allowance(self, &caller, &spender, amount)
+		<Pallet<T>>::set_allowance(self, &caller, &spender, amount, None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
 	}
@@ -328,6 +341,27 @@ where
 		Ok(true)
 	}
 
+	/// Transfer tokens to multiple accounts.
+	/// Either all transfers succeed, or none of them is applied.
+	/// @param transfers array of pairs of recipient cross address and amount
+	#[weight(<CommonWeights<T>>::transfer().saturating_mul(transfers.len() as u64))]
+	fn transfer_batch(
+		&mut self,
+		caller: Caller,
+		transfers: Vec<AmountForCrossAddress>,
+	) -> Result<bool> {
+		let caller = T::CrossAccountId::from_eth(caller);
+
+		for AmountForCrossAddress { to, amount } in transfers {
+			let to = to.into_sub_cross_account::<T>()?;
+			let amount = amount.try_into().map_err(|_| "amount overflow")?;
+
+			<Pallet<T>>::transfer(self, &caller, &to, amount, &nesting_budget(&self.recorder))
+				.map_err(|e| dispatch_to_evm::<T>(e.error))?;
+		}
+		Ok(true)
+	}
+
 	/// @notice Returns collection helper contract address
 	fn collection_helper_address(&self) -> Result<Address> {
 		Ok(T::ContractAddress::get())
@@ -341,6 +375,107 @@ where
 		let balance = <Balance<T>>::get((self.id, owner.into_sub_cross_account::<T>()?));
 		Ok(balance.into())
 	}
+
+	/// @notice Set the allowance of `spender` over the tokens of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `PermitCross` struct.
+	///  Ethereum owners sign it with ECDSA (`r`, `s` and `v` concatenated),
+	///  Substrate owners sign it with sr25519.
+	/// @param owner Cross address, which signed the permit.
+	/// @param spender Cross address, which will spend the tokens.
+	/// @param value Amount of tokens the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param signature Signature of the owner.
+	#[weight(<SelfWeightOf<T>>::approve().saturating_add(<PalletCommon<T>>::use_permit_weight()))]
+	fn permit_cross(
+		&mut self,
+		owner: CrossAddress,
+		spender: CrossAddress,
+		value: U256,
+		deadline: U256,
+		signature: Bytes,
+	) -> Result<()> {
+		let owner_cross = owner.into_sub_cross_account::<T>()?;
+		let spender_cross = spender.into_sub_cross_account::<T>()?;
+		let amount = value.try_into().map_err(|_| "amount overflow")?;
+
+		<PalletCommon<T>>::use_permit(
+			self.id,
+			TokenId::default(),
+			&owner_cross,
+			self.permit_domain_separator()?,
+			|nonce| eth::permit_cross_hash(&owner, &spender, value, nonce, deadline),
+			deadline,
+			&signature.0,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_allowance(self, &owner_cross, &spender_cross, amount, None)
+			.map_err(dispatch_to_evm::<T>)
+	}
+}
+
+/// @title ERC-20 extension for approvals by signature, see EIP-2612.
+#[solidity_interface(name = ERC20Permit, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleHandle<T> {
+	/// @notice Set the allowance of `spender` over the tokens of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `Permit` struct.
+	/// @param owner Address, which signed the permit.
+	/// @param spender Address, which will spend the tokens.
+	/// @param value Amount of tokens the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param v Recovery ID of the signature.
+	/// @param r First half of the signature.
+	/// @param s Second half of the signature.
+	#[weight(<SelfWeightOf<T>>::approve().saturating_add(<PalletCommon<T>>::use_permit_weight()))]
+	#[allow(clippy::too_many_arguments)]
+	fn permit(
+		&mut self,
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> Result<()> {
+		let owner_cross = T::CrossAccountId::from_eth(owner);
+		let spender_cross = T::CrossAccountId::from_eth(spender);
+		let amount = value.try_into().map_err(|_| "amount overflow")?;
+
+		<PalletCommon<T>>::use_permit(
+			self.id,
+			TokenId::default(),
+			&owner_cross,
+			self.permit_domain_separator()?,
+			|nonce| eth::permit_hash(owner, spender, value, nonce, deadline),
+			deadline,
+			&[r.as_bytes(), s.as_bytes(), &[v]].concat(),
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_allowance(self, &owner_cross, &spender_cross, amount, None)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Nonce of the next permit signed by the `owner`.
+	fn nonces(&self, owner: Address) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		let owner = T::CrossAccountId::from_eth(owner);
+		Ok(<PalletCommon<T>>::permit_nonce(self.id, TokenId::default(), &owner).into())
+	}
+
+	/// @notice EIP-712 domain separator of the permits.
+	#[solidity(rename_selector = "DOMAIN_SEPARATOR")]
+	fn domain_separator(&self) -> Result<H256> {
+		self.permit_domain_separator()
+	}
+}
+
+impl<T: Config> FungibleHandle<T> {
+	fn permit_domain_separator(&self) -> Result<H256> {
+		Ok(<PalletCommon<T>>::permit_domain_separator(
+			&self.name()?,
+			collection_id_to_address(self.id),
+		))
+	}
 }
 
 #[solidity_interface(
@@ -348,6 +483,7 @@ where
 	is(
 		ERC20,
 		ERC20Mintable,
+		ERC20Permit,
 		ERC20UniqueExtensions,
 		Collection(via(common_mut returns CollectionHandle<T>)),
 	),
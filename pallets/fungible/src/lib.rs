@@ -84,9 +84,11 @@ use evm_coder::ToLog;
 use frame_support::{dispatch::PostDispatchInfo, ensure, pallet_prelude::*};
 pub use pallet::*;
 use pallet_common::{
-	eth::collection_id_to_address, helpers::add_weight_to_post_info,
-	weights::WeightInfo as CommonWeightInfo, Error as CommonError, Event as CommonEvent,
-	Pallet as PalletCommon, SelfWeightOf as PalletCommonWeightOf,
+	eth::collection_id_to_address,
+	helpers::{add_weight_to_post_info, StorageCleaner},
+	weights::WeightInfo as CommonWeightInfo,
+	Error as CommonError, Event as CommonEvent, Pallet as PalletCommon,
+	SelfWeightOf as PalletCommonWeightOf,
 };
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::WithRecorder;
@@ -133,6 +135,8 @@ pub mod pallet {
 		SettingAllowanceForAllNotAllowed,
 		/// Only a fungible collection could be possibly broken; any fungible token is valid.
 		FungibleTokensAreAlwaysValid,
+		/// Minting would exceed the supply cap of the collection.
+		SupplyCapExceeded,
 	}
 
 	#[pallet::config]
@@ -172,6 +176,18 @@ pub mod pallet {
 		Value = u128,
 		QueryKind = ValueQuery,
 	>;
+
+	/// Deadlines of the [`Allowance`]s, after which they can no longer be used.
+	#[pallet::storage]
+	pub type AllowanceDeadline<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128, T::CrossAccountId>,       // Owner
+			Key<Blake2_128Concat, T::CrossAccountId>, // Spender
+		),
+		Value = u64,
+		QueryKind = OptionQuery,
+	>;
 }
 
 /// Wrapper around untyped collection handle, asserting inner collection is of fungible type.
@@ -213,24 +229,33 @@ impl<T: Config> Deref for FungibleHandle<T> {
 /// Pallet implementation for fungible assets
 impl<T: Config> Pallet<T> {
 	/// Destroys a collection.
+	///
+	/// The balances are removed later by [`Self::clear_destroyed_collection`].
 	pub fn destroy_collection(
 		collection: FungibleHandle<T>,
 		sender: &T::CrossAccountId,
 	) -> DispatchResult {
-		let id = collection.id;
+		PalletCommon::destroy_collection(collection.0, sender)
+	}
 
-		if Self::collection_has_tokens(id) {
-			return Err(<CommonError<T>>::CantDestroyNotEmptyCollection.into());
+	/// Removes the balances of the destroyed collection, as far as `cleaner` allows.
+	/// Returns `true` once there is nothing left to remove.
+	pub fn clear_destroyed_collection(id: CollectionId, cleaner: &mut StorageCleaner<T>) -> bool {
+		while let Some((owner, _)) = <Balance<T>>::iter_prefix((id,)).next() {
+			if !cleaner.remove_entry(T::DbWeight::get().reads_writes(1, 1)) {
+				return false;
+			}
+			<Balance<T>>::remove((id, &owner));
+			<PalletStructure<T>>::unnest_destroyed(&owner, id, TokenId::default());
 		}
 
-		// =========
-
-		PalletCommon::destroy_collection(collection.0, sender)?;
-
-		<TotalSupply<T>>::remove(id);
-		let _ = <Balance<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <Allowance<T>>::clear_prefix((id,), u32::MAX, None);
-		Ok(())
+		let done = cleaner.clear_prefix(|limit| <Allowance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <AllowanceDeadline<T>>::clear_prefix((id,), limit, None));
+		if done {
+			<TotalSupply<T>>::remove(id);
+		}
+		done
 	}
 
 	/// Add properties to the collection.
@@ -255,11 +280,6 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	/// Checks if collection has tokens. Return `true` if it has.
-	fn collection_has_tokens(collection_id: CollectionId) -> bool {
-		<TotalSupply<T>>::get(collection_id) != 0
-	}
-
 	/// Burns the specified amount of the token. If the token balance
 	/// or total supply is less than the given value,
 	/// it will return [DispatchError].
@@ -341,6 +361,8 @@ impl<T: Config> Pallet<T> {
 			<CommonError<T>>::TransferNotAllowed,
 		);
 
+		collection.check_account_not_frozen(from)?;
+
 		let mut actual_weight = <SelfWeightOf<T>>::transfer_raw();
 
 		if collection.permissions.access() == AccessMode::AllowList {
@@ -414,7 +436,9 @@ impl<T: Config> Pallet<T> {
 		data: BTreeMap<T::CrossAccountId, u128>,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		if !collection.is_owner_or_admin(depositor) {
+		if let Some(authority) = collection.mint_authority() {
+			ensure!(authority.conv_eq(depositor), <CommonError<T>>::NoPermission);
+		} else if !collection.has_admin_role(depositor, |roles| roles.minter) {
 			ensure!(
 				collection.permissions.mint_mode(),
 				<CommonError<T>>::PublicMintingNotAllowed
@@ -433,6 +457,10 @@ impl<T: Config> Pallet<T> {
 				acc.checked_add(v)
 			})
 			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			total_supply <= collection.supply_cap(),
+			<Error<T>>::SupplyCapExceeded
+		);
 
 		for (to, _) in data.iter() {
 			<PalletStructure<T>>::check_nesting(
@@ -491,6 +519,7 @@ impl<T: Config> Pallet<T> {
 	) {
 		if amount == 0 {
 			<Allowance<T>>::remove((collection.id, owner, spender));
+			<AllowanceDeadline<T>>::remove((collection.id, owner, spender));
 		} else {
 			<Allowance<T>>::insert((collection.id, owner, spender), amount);
 		}
@@ -518,16 +547,19 @@ impl<T: Config> Pallet<T> {
 	/// - `owner`: Owner of tokens that sets the allowance.
 	/// - `spender`: Recipient of the allowance rights.
 	/// - `amount`: Amount of tokens the spender is allowed to `transfer` or `burn`.
+	/// - `deadline`: Last block in which the spender can use the allowance.
 	pub fn set_allowance(
 		collection: &FungibleHandle<T>,
 		owner: &T::CrossAccountId,
 		spender: &T::CrossAccountId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(owner)?;
 			collection.check_allowlist(spender)?;
 		}
+		collection.check_account_not_frozen(owner)?;
 
 		if <Balance<T>>::get((collection.id, owner)) < amount {
 			ensure!(
@@ -535,10 +567,14 @@ impl<T: Config> Pallet<T> {
 				<CommonError<T>>::CantApproveMoreThanOwned
 			);
 		}
+		<PalletCommon<T>>::check_deadline(deadline)?;
 
 		// =========
 
 		Self::set_allowance_unchecked(collection, owner, spender, amount);
+		if amount > 0 {
+			<AllowanceDeadline<T>>::set((collection.id, owner, spender), deadline);
+		}
 		Ok(())
 	}
 
@@ -549,12 +585,14 @@ impl<T: Config> Pallet<T> {
 	/// - `from`: Owner's eth mirror.
 	/// - `to`: Recipient of the allowance rights.
 	/// - `amount`: Amount of tokens the spender is allowed to `transfer` or `burn`.
+	/// - `deadline`: Last block in which the spender can use the allowance.
 	pub fn set_allowance_from(
 		collection: &FungibleHandle<T>,
 		sender: &T::CrossAccountId,
 		from: &T::CrossAccountId,
 		to: &T::CrossAccountId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(sender)?;
@@ -566,18 +604,24 @@ impl<T: Config> Pallet<T> {
 			sender.conv_eq(from),
 			<CommonError<T>>::AddressIsNotEthMirror
 		);
+		collection.check_account_not_frozen(from)?;
 
 		if <Balance<T>>::get((collection.id, from)) < amount {
 			ensure!(
 				collection.limits.owner_can_transfer()
-					&& (collection.is_owner_or_admin(sender) || collection.is_owner_or_admin(from)),
+					&& (collection.has_admin_role(sender, |roles| roles.token_manager)
+						|| collection.has_admin_role(from, |roles| roles.token_manager)),
 				<CommonError<T>>::CantApproveMoreThanOwned
 			);
 		}
+		<PalletCommon<T>>::check_deadline(deadline)?;
 
 		// =========
 
 		Self::set_allowance_unchecked(collection, from, to, amount);
+		if amount > 0 {
+			<AllowanceDeadline<T>>::set((collection.id, from, to), deadline);
+		}
 		Ok(())
 	}
 
@@ -637,7 +681,30 @@ impl<T: Config> Pallet<T> {
 		spender: &T::CrossAccountId,
 		amount: u128,
 	) -> Option<u128> {
-		<Allowance<T>>::get((collection.id, from, spender)).checked_sub(amount)
+		Self::allowance(collection, from, spender).checked_sub(amount)
+	}
+
+	/// Get the amount of tokens the `spender` is allowed to `transfer` or `burn`.
+	/// Expired allowances are treated as zero.
+	pub fn allowance(
+		collection: &FungibleHandle<T>,
+		owner: &T::CrossAccountId,
+		spender: &T::CrossAccountId,
+	) -> u128 {
+		let deadline = <AllowanceDeadline<T>>::get((collection.id, owner, spender));
+		if <PalletCommon<T>>::deadline_passed(deadline) {
+			return 0;
+		}
+		<Allowance<T>>::get((collection.id, owner, spender))
+	}
+
+	/// Get the deadline of the allowance set by the `owner` for the `spender`, if it has one.
+	pub fn allowance_deadline(
+		collection: &FungibleHandle<T>,
+		owner: &T::CrossAccountId,
+		spender: &T::CrossAccountId,
+	) -> Option<u64> {
+		<AllowanceDeadline<T>>::get((collection.id, owner, spender))
 	}
 
 	/// Transfer fungible tokens from one account to another.
@@ -652,6 +719,7 @@ impl<T: Config> Pallet<T> {
 		amount: u128,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResultWithPostInfo {
+		collection.check_account_not_frozen(spender)?;
 		let allowance = Self::check_allowed(collection, spender, from, amount, nesting_budget)?;
 
 		// =========
@@ -674,7 +742,7 @@ impl<T: Config> Pallet<T> {
 	/// Burn fungible tokens from the account.
 	///
 	/// Same as the [`burn`][`Pallet::burn`] but spender doesn't need to be an owner of the tokens. The `from` should
-	/// set allowance for the spender to burn tokens.
+	/// set allowance for the spender to burn tokens, unless the spender is the mint authority of the collection.
 	/// See [`set_allowance`][`Pallet::set_allowance`] for more details.
 	pub fn burn_from(
 		collection: &FungibleHandle<T>,
@@ -683,7 +751,11 @@ impl<T: Config> Pallet<T> {
 		amount: u128,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		let allowance = Self::check_allowed(collection, spender, from, amount, nesting_budget)?;
+		let allowance = if collection.is_mint_authority(spender) {
+			None
+		} else {
+			Self::check_allowed(collection, spender, from, amount, nesting_budget)?
+		};
 
 		// =========
 
@@ -697,7 +769,8 @@ impl<T: Config> Pallet<T> {
 	/// Creates fungible token.
 	///
 	/// The sender should be the owner/admin of the collection or collection should be configured
-	/// to allow public minting.
+	/// to allow public minting. If the collection has a mint authority, only it can mint tokens.
+	/// The total supply can't exceed the supply cap of the collection.
 	///
 	/// - `data`: Contains user who will become the owners of the tokens and amount
 	///   of tokens he will receive.
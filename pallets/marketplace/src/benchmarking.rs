@@ -0,0 +1,333 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::v2::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{fungible::Balanced, tokens::Precision};
+use frame_system::RawOrigin;
+use pallet_common::{CollectionIssuer, Config as CommonConfig};
+use up_data_structs::{
+	budget::Unlimited, CreateCollectionData, CreateFungibleData, CreateItemData, CreateNftData,
+	Royalty,
+};
+
+use super::*;
+
+const SEED: u32 = 1;
+const PRICE: u128 = 1_000_000;
+const DURATION: u64 = 100;
+
+fn cross_account<T: Config>(name: &'static str) -> T::CrossAccountId {
+	T::CrossAccountId::from_sub(account(name, 0, SEED))
+}
+
+fn origin<T: Config>(account: &T::CrossAccountId) -> OriginFor<T> {
+	RawOrigin::Signed(account.as_sub().clone()).into()
+}
+
+fn create_collection<T: Config>(
+	owner: &T::CrossAccountId,
+	mode: CollectionMode,
+) -> Result<CollectionId, DispatchError> {
+	let _ = <T as CommonConfig>::Currency::deposit(
+		owner.as_sub(),
+		T::CollectionCreationPrice::get(),
+		Precision::Exact,
+	)?;
+	T::CollectionDispatch::create(
+		owner.clone(),
+		CollectionIssuer::User(owner.clone()),
+		CreateCollectionData {
+			mode,
+			..Default::default()
+		},
+	)
+}
+
+fn mint<T: Config>(
+	collection_id: CollectionId,
+	owner: &T::CrossAccountId,
+	to: &T::CrossAccountId,
+	data: CreateItemData,
+) -> DispatchResult {
+	let dispatch = T::CollectionDispatch::dispatch(collection_id)?;
+	dispatch
+		.as_dyn()
+		.create_item(owner.clone(), to.clone(), data, &Unlimited)
+		.map(|_| ())
+		.map_err(|error| error.error)
+}
+
+/// Creates an NFT with a royalty owned by `seller`, and a fungible collection,
+/// in which each of the `buyers` can pay the price twice.
+///
+/// Returns IDs of the NFT collection and of the currency collection.
+fn prepare_sale<T: Config>(
+	seller: &T::CrossAccountId,
+	buyers: &[&T::CrossAccountId],
+) -> Result<(CollectionId, CollectionId), DispatchError> {
+	let owner = cross_account::<T>("owner");
+	let collection_id = create_collection::<T>(&owner, CollectionMode::NFT)?;
+	<PalletCommon<T>>::set_collection_royalty(
+		&<CollectionHandle<T>>::try_get(collection_id)?,
+		&owner,
+		Some(Royalty {
+			receiver: owner.clone(),
+			basis_points: 1000,
+		}),
+	)?;
+	mint::<T>(
+		collection_id,
+		&owner,
+		seller,
+		CreateItemData::NFT(CreateNftData::default()),
+	)?;
+
+	let currency = create_collection::<T>(&owner, CollectionMode::Fungible(0))?;
+	for buyer in buyers {
+		mint::<T>(
+			currency,
+			&owner,
+			buyer,
+			CreateItemData::Fungible(CreateFungibleData { value: PRICE * 2 }),
+		)?;
+	}
+
+	Ok((collection_id, currency))
+}
+
+fn set_block_number<T: Config>(block: u64) {
+	<frame_system::Pallet<T>>::set_block_number(block.unique_saturated_into());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn list() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[])?;
+
+		#[extrinsic_call]
+		_(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			PRICE,
+		);
+
+		assert!(<Listings<T>>::contains_key((
+			collection_id,
+			TokenId(1),
+			&seller
+		)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_listing() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[])?;
+		<Pallet<T>>::list(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			PRICE,
+		)?;
+
+		#[extrinsic_call]
+		_(origin::<T>(&seller), collection_id, TokenId(1));
+
+		assert!(!<Listings<T>>::contains_key((
+			collection_id,
+			TokenId(1),
+			&seller
+		)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn buy() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let buyer = cross_account::<T>("buyer");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[&buyer])?;
+		<Pallet<T>>::list(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			PRICE,
+		)?;
+
+		#[extrinsic_call]
+		_(
+			origin::<T>(&buyer),
+			collection_id,
+			TokenId(1),
+			seller.clone(),
+			PRICE,
+		);
+
+		assert!(!<Listings<T>>::contains_key((
+			collection_id,
+			TokenId(1),
+			&seller
+		)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_auction() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[])?;
+
+		#[extrinsic_call]
+		_(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			AuctionKind::English {
+				min_bid: PRICE / 2,
+				min_increment: 1,
+			},
+			DURATION,
+		);
+
+		assert!(<Auctions<T>>::contains_key(1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bid() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let previous_bidder = cross_account::<T>("previous_bidder");
+		let bidder = cross_account::<T>("bidder");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[&previous_bidder, &bidder])?;
+		<Pallet<T>>::create_auction(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			AuctionKind::English {
+				min_bid: PRICE / 2,
+				min_increment: 1,
+			},
+			DURATION,
+		)?;
+		let auction_id = <AuctionCount<T>>::get();
+		<Pallet<T>>::bid(origin::<T>(&previous_bidder), auction_id, PRICE / 2)?;
+		// The worst case: the previous bid is refunded, and the auction is extended
+		let end = <Auctions<T>>::get(auction_id)
+			.ok_or(BenchmarkError::Stop("auction not created"))?
+			.end;
+		set_block_number::<T>(end - 1);
+
+		#[extrinsic_call]
+		_(origin::<T>(&bidder), auction_id, PRICE);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn buy_auction() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let buyer = cross_account::<T>("buyer");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[&buyer])?;
+		<Pallet<T>>::create_auction(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			AuctionKind::Dutch {
+				start_price: PRICE,
+				end_price: PRICE / 2,
+			},
+			DURATION,
+		)?;
+		let auction_id = <AuctionCount<T>>::get();
+
+		#[extrinsic_call]
+		_(origin::<T>(&buyer), auction_id, PRICE);
+
+		assert!(!<Auctions<T>>::contains_key(auction_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_auction() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[])?;
+		<Pallet<T>>::create_auction(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			AuctionKind::English {
+				min_bid: PRICE / 2,
+				min_increment: 1,
+			},
+			DURATION,
+		)?;
+		let auction_id = <AuctionCount<T>>::get();
+
+		#[extrinsic_call]
+		_(origin::<T>(&seller), auction_id);
+
+		assert!(!<Auctions<T>>::contains_key(auction_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn settle_auction() -> Result<(), BenchmarkError> {
+		let seller = cross_account::<T>("seller");
+		let bidder = cross_account::<T>("bidder");
+		let (collection_id, currency) = prepare_sale::<T>(&seller, &[&bidder])?;
+		<Pallet<T>>::create_auction(
+			origin::<T>(&seller),
+			collection_id,
+			TokenId(1),
+			1,
+			currency,
+			AuctionKind::English {
+				min_bid: PRICE / 2,
+				min_increment: 1,
+			},
+			DURATION,
+		)?;
+		let auction_id = <AuctionCount<T>>::get();
+		<Pallet<T>>::bid(origin::<T>(&bidder), auction_id, PRICE)?;
+		let end = <Auctions<T>>::get(auction_id)
+			.ok_or(BenchmarkError::Stop("auction not created"))?
+			.end;
+		set_block_number::<T>(end);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, auction_id);
+
+		assert!(!<Auctions<T>>::contains_key(auction_id));
+		Ok(())
+	}
+}
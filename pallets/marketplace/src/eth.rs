@@ -0,0 +1,212 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! Implementation of Marketplace contract.
+
+use core::marker::PhantomData;
+
+use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::*, AbiCoder};
+use frame_support::traits::Get;
+use pallet_common::eth::{self, collection_id_to_address, map_eth_to_id};
+use pallet_evm::{account::CrossAccountId, OnMethodCall, PrecompileHandle, PrecompileResult};
+use pallet_evm_coder_substrate::{
+	dispatch_to_evm,
+	execution::{PreDispatch, Result},
+	frontier_contract, SubstrateRecorder, WithRecorder,
+};
+use up_data_structs::{CollectionId, MarketListing};
+
+use crate::{weights::WeightInfo, Config, Pallet, SelfWeightOf};
+
+frontier_contract! {
+	macro_rules! Marketplace_result {...}
+	impl<T: Config> Contract for Marketplace<T> {...}
+}
+
+/// Ethereum representation of [`MarketListing`].
+#[derive(Debug, Default, AbiCoder)]
+pub struct Listing {
+	/// Amount of listed token pieces, always 1 for NFTs.
+	pub amount: U256,
+	/// Address of the collection in which the price is paid.
+	pub currency: Address,
+	/// Price of the whole listed amount.
+	pub price: U256,
+}
+
+impl From<MarketListing> for Listing {
+	fn from(listing: MarketListing) -> Self {
+		Self {
+			amount: listing.amount.into(),
+			currency: collection_id_to_address(listing.currency),
+			price: listing.price.into(),
+		}
+	}
+}
+
+/// See [`MarketplaceCall`]
+pub struct Marketplace<T: Config>(SubstrateRecorder<T>);
+impl<T: Config> WithRecorder<T> for Marketplace<T> {
+	fn recorder(&self) -> &SubstrateRecorder<T> {
+		&self.0
+	}
+
+	fn into_recorder(self) -> SubstrateRecorder<T> {
+		self.0
+	}
+}
+
+fn map_collection(collection: &Address) -> Result<CollectionId> {
+	map_eth_to_id(collection).ok_or_else(|| "not a collection address".into())
+}
+
+/// @title Fixed-price marketplace for tokens of Unique collections
+#[solidity_interface(name = Marketplace, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> Marketplace<T>
+where
+	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
+	/// @notice List token pieces for sale, or replace terms of an existing listing.
+	/// @dev The listing is cancelled when the seller transfers or burns pieces of the token.
+	/// @param collection Address of the NFT or RFT collection.
+	/// @param tokenId ID of the listed token.
+	/// @param amount Amount of listed pieces, should be 1 for NFTs.
+	/// @param currency Address of the fungible collection in which the price is paid,
+	///  address of the native fungible collection for the native currency.
+	/// @param price Price of the whole listed amount.
+	#[weight(<SelfWeightOf<T>>::list())]
+	fn list(
+		&mut self,
+		caller: Caller,
+		collection: Address,
+		token_id: U256,
+		amount: U256,
+		currency: Address,
+		price: U256,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let listing = MarketListing {
+			amount: amount.try_into().map_err(|_| "amount overflow")?,
+			currency: map_collection(&currency)?,
+			price: price.try_into().map_err(|_| "price overflow")?,
+		};
+
+		<Pallet<T>>::list_token(
+			&caller,
+			map_collection(&collection)?,
+			token_id.try_into()?,
+			listing,
+		)
+		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Remove the listing of the token made by the caller.
+	/// @param collection Address of the collection of the listed token.
+	/// @param tokenId ID of the listed token.
+	#[weight(<SelfWeightOf<T>>::cancel_listing())]
+	fn cancel_listing(
+		&mut self,
+		caller: Caller,
+		collection: Address,
+		token_id: U256,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let collection = map_collection(&collection)?;
+
+		<Pallet<T>>::cancel_token_listing(&caller, collection, token_id.try_into()?)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Buy listed token pieces.
+	/// @dev The price is paid by the caller to the royalty receiver, the treasury and the seller.
+	/// @param collection Address of the collection of the listed token.
+	/// @param tokenId ID of the listed token.
+	/// @param seller Seller of the token.
+	/// @param maxPrice Maximum price the caller is ready to pay.
+	#[weight(<SelfWeightOf<T>>::buy())]
+	fn buy(
+		&mut self,
+		caller: Caller,
+		collection: Address,
+		token_id: U256,
+		seller: eth::CrossAddress,
+		max_price: U256,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let seller = seller.into_sub_cross_account::<T>()?;
+		let max_price = max_price.try_into().map_err(|_| "price overflow")?;
+
+		<Pallet<T>>::buy_token(
+			&caller,
+			map_collection(&collection)?,
+			token_id.try_into()?,
+			&seller,
+			max_price,
+		)
+		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Get the listing of the token by the seller.
+	/// @param collection Address of the collection of the listed token.
+	/// @param tokenId ID of the listed token.
+	/// @param seller Seller of the token.
+	/// @return Listing terms.
+	fn get_listing(
+		&self,
+		collection: Address,
+		token_id: U256,
+		seller: eth::CrossAddress,
+	) -> Result<Listing> {
+		self.recorder().consume_sload()?;
+		let seller = seller.into_sub_cross_account::<T>()?;
+
+		<Pallet<T>>::listing(map_collection(&collection)?, token_id.try_into()?, &seller)
+			.map(Listing::from)
+			.ok_or_else(|| "token is not listed".into())
+	}
+}
+
+/// Implements [`OnMethodCall`], which delegates call to [`Marketplace`]
+pub struct MarketplaceOnMethodCall<T: Config>(PhantomData<*const T>);
+impl<T: Config> OnMethodCall<T> for MarketplaceOnMethodCall<T>
+where
+	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
+	fn is_reserved(contract: &sp_core::H160) -> bool {
+		contract == &<T as Config>::ContractAddress::get()
+	}
+
+	fn is_used(contract: &sp_core::H160) -> bool {
+		contract == &<T as Config>::ContractAddress::get()
+	}
+
+	fn call(handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		if handle.code_address() != <T as Config>::ContractAddress::get() {
+			return None;
+		}
+
+		let marketplace = Marketplace::<T>(SubstrateRecorder::<T>::new(handle.remaining_gas()));
+		pallet_evm_coder_substrate::call(handle, marketplace)
+	}
+
+	fn get_code(contract: &sp_core::H160) -> Option<Vec<u8>> {
+		(contract == &<T as Config>::ContractAddress::get())
+			.then(|| include_bytes!("./stubs/Marketplace.raw").to_vec())
+	}
+}
+
+generate_stubgen!(marketplace_impl, MarketplaceCall<()>, true);
+generate_stubgen!(marketplace_iface, MarketplaceCall<()>, false);
@@ -0,0 +1,936 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Marketplace pallet
+//!
+//! The pallet implements a fixed-price marketplace and auctions for tokens of Unique collections.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//! - [`Error`]
+//! - [`Event`]
+//!
+//! ## Overview
+//!
+//! Sellers list NFTs or RFT pieces at a price, which is paid either in the native currency
+//! or in tokens of a fungible collection. Listed tokens stay with the seller until the purchase.
+//! On purchase the token and the payment are exchanged atomically, the royalty of the token
+//! and the market fee are split off the price, and the rest goes to the seller.
+//!
+//! A listing is cancelled automatically, when the listed token leaves the seller
+//! (see [`OnTokenTransfer`] implementation).
+//!
+//! Tokens can also be sold at English (ascending price) or Dutch (descending price) auctions.
+//! Auctioned tokens and English auction bids are held in the marketplace escrow account,
+//! so the tokens can't be transferred or nested into while the auction runs.
+//! A bid placed shortly before the end of an English auction extends it by
+//! [`AntiSnipingPeriod`](Config::AntiSnipingPeriod) blocks. Auctions are settled by
+//! the [`Scheduler`](Config::Scheduler) at the start of their end block. An ended auction,
+//! which wasn't settled by the scheduler, can be settled by anyone.
+//!
+//! ### Dispatchable Functions
+//!
+//! - [`list`][`Pallet::list`] - list token pieces for sale or change an existing listing.
+//! - [`cancel_listing`][`Pallet::cancel_listing`] - remove a listing.
+//! - [`buy`][`Pallet::buy`] - buy listed token pieces.
+//! - [`create_auction`][`Pallet::create_auction`] - put token pieces up for an auction.
+//! - [`bid`][`Pallet::bid`] - place a bid at an English auction.
+//! - [`buy_auction`][`Pallet::buy_auction`] - buy a Dutch auction at the current price.
+//! - [`cancel_auction`][`Pallet::cancel_auction`] - cancel an auction without bids.
+//! - [`settle_auction`][`Pallet::settle_auction`] - settle an ended auction.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	storage::{with_storage_layer, Key},
+	traits::{
+		schedule::{v3::Named as ScheduleNamed, DispatchTime, HARD_DEADLINE},
+		Bounded, OriginTrait,
+	},
+	transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_common::{
+	dispatch::CollectionDispatch, CollectionHandle, Error as CommonError, OnTokenTransfer,
+	Pallet as PalletCommon,
+};
+use pallet_evm::account::CrossAccountId;
+use pallet_structure::Pallet as PalletStructure;
+use sp_core::hashing::blake2_256;
+use sp_runtime::{
+	traits::{AccountIdConversion, Dispatchable, UniqueSaturatedInto},
+	ArithmeticError, DispatchError, Perbill,
+};
+use up_data_structs::{
+	AuctionKind, CollectionId, CollectionMode, MarketAuction, MarketListing, TokenId,
+};
+use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod eth;
+pub mod weights;
+
+pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
+/// Call scheduled by the pallet.
+pub type CallOf<T> = <T as Config>::RuntimeCall;
+/// Origin of the calls scheduled by the pallet.
+pub type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_common::Config
+		+ pallet_structure::Config
+		+ pallet_evm_coder_substrate::Config
+	{
+		/// Events compatible with [`frame_system::Config::Event`].
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Share of the price (after royalty) which is taken as the market fee.
+		/// The fee goes to the treasury.
+		#[pallet::constant]
+		type MarketFee: Get<Perbill>;
+
+		/// Address under which the Marketplace contract would be available.
+		#[pallet::constant]
+		type ContractAddress: Get<sp_core::H160>;
+
+		/// The marketplace escrow id, used for deriving the account holding
+		/// auctioned tokens and bids.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// A bid placed less than this number of blocks before the end of an English auction
+		/// extends the auction to end this number of blocks after the bid.
+		#[pallet::constant]
+		type AntiSnipingPeriod: Get<u64>;
+
+		/// The overarching call type, in which the settlement of auctions is scheduled.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin>
+			+ From<Call<Self>>;
+
+		/// Scheduler of the auction settlement.
+		type Scheduler: ScheduleNamed<BlockNumberFor<Self>, CallOf<Self>, PalletsOriginOf<Self>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Token pieces were listed for sale.
+		Listed(
+			/// ID of the collection of the listed token.
+			CollectionId,
+			/// ID of the listed token.
+			TokenId,
+			/// Seller of the token.
+			T::CrossAccountId,
+			/// Listing terms.
+			MarketListing,
+		),
+
+		/// Listing was removed by the seller or because the token was moved.
+		ListingCancelled(
+			/// ID of the collection of the listed token.
+			CollectionId,
+			/// ID of the listed token.
+			TokenId,
+			/// Seller of the token.
+			T::CrossAccountId,
+		),
+
+		/// Listed token pieces were bought.
+		Sold(
+			/// ID of the collection of the sold token.
+			CollectionId,
+			/// ID of the sold token.
+			TokenId,
+			/// Seller of the token.
+			T::CrossAccountId,
+			/// Buyer of the token.
+			T::CrossAccountId,
+			/// Terms of the executed listing.
+			MarketListing,
+		),
+
+		/// Token pieces were put up for an auction and moved to the escrow.
+		AuctionCreated(
+			/// ID of the auction.
+			u32,
+			/// ID of the collection of the auctioned token.
+			CollectionId,
+			/// ID of the auctioned token.
+			TokenId,
+			/// Seller of the token.
+			T::CrossAccountId,
+		),
+
+		/// New highest bid was placed at an English auction.
+		BidPlaced(
+			/// ID of the auction.
+			u32,
+			/// Bidder.
+			T::CrossAccountId,
+			/// Amount of the bid.
+			u128,
+		),
+
+		/// English auction was extended by a bid placed shortly before its end.
+		AuctionExtended(
+			/// ID of the auction.
+			u32,
+			/// Number of the new end block.
+			u64,
+		),
+
+		/// Auctioned token pieces went to the winner, and the price was paid out.
+		AuctionSettled(
+			/// ID of the auction.
+			u32,
+			/// Winner of the auction.
+			T::CrossAccountId,
+			/// Price paid by the winner.
+			u128,
+		),
+
+		/// Auction was cancelled by the seller or ended without a buyer,
+		/// the token pieces were returned to the seller.
+		AuctionCancelled(
+			/// ID of the auction.
+			u32,
+		),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only tokens of NFT and RFT collections can be listed.
+		CollectionNotListable,
+		/// Price can only be paid in the native currency or in a fungible collection.
+		UnsupportedCurrency,
+		/// No listing of the token by the seller.
+		ListingNotFound,
+		/// Listing price is higher than the buyer is ready to pay.
+		PriceTooHigh,
+		/// Auction with the given ID doesn't exist or has already ended.
+		AuctionNotFound,
+		/// Auction duration should be positive, and the price of a Dutch auction can't increase.
+		InvalidAuctionTerms,
+		/// Auction can't be settled before its end.
+		AuctionNotEnded,
+		/// Bids can only be placed at English auctions.
+		NotEnglishAuction,
+		/// Only Dutch auctions can be bought at the current price.
+		NotDutchAuction,
+		/// Bid is lower than the minimal bid, or doesn't exceed the highest one
+		/// by the minimal increment.
+		BidTooLow,
+		/// Auction with bids can't be cancelled.
+		AuctionHasBids,
+	}
+
+	/// Active listings of the tokens by their sellers.
+	#[pallet::storage]
+	pub type Listings<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Twox64Concat, TokenId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = MarketListing,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Number of created auctions. Essentially contains the last auction ID.
+	#[pallet::storage]
+	pub type AuctionCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Running auctions.
+	#[pallet::storage]
+	pub type Auctions<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = u32,
+		Value = MarketAuction<T::CrossAccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List token pieces for sale, or replace terms of an existing listing.
+		///
+		/// Tokens stay with the seller until they are bought.
+		/// The listing is cancelled when the seller transfers or burns pieces of the token.
+		///
+		/// # Permissions
+		///
+		/// * Owner of the listed token pieces.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the NFT or RFT collection.
+		/// * `token_id`: ID of the listed token.
+		/// * `amount`: Amount of listed pieces, should be 1 for NFTs.
+		/// * `currency`: ID of the fungible collection, in which the price is paid,
+		/// or 0 for the native currency.
+		/// * `price`: Price of the whole listed amount.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<SelfWeightOf<T>>::list())]
+		pub fn list(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			amount: u128,
+			currency: CollectionId,
+			price: u128,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let listing = MarketListing {
+				amount,
+				currency,
+				price,
+			};
+
+			Self::list_token(&sender, collection_id, token_id, listing)
+		}
+
+		/// Remove the listing of the token.
+		///
+		/// # Permissions
+		///
+		/// * Seller of the token.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection of the listed token.
+		/// * `token_id`: ID of the listed token.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<SelfWeightOf<T>>::cancel_listing())]
+		pub fn cancel_listing(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::cancel_token_listing(&sender, collection_id, token_id)
+		}
+
+		/// Buy listed token pieces.
+		///
+		/// The token is transferred to the buyer, and the price is paid by the buyer
+		/// to the royalty receiver, the treasury and the seller.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection of the listed token.
+		/// * `token_id`: ID of the listed token.
+		/// * `seller`: Seller of the token.
+		/// * `max_price`: Maximum price the buyer is ready to pay,
+		/// protects against the listing being changed right before the purchase.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<SelfWeightOf<T>>::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			seller: T::CrossAccountId,
+			max_price: u128,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::buy_token(&sender, collection_id, token_id, &seller, max_price)
+		}
+
+		/// Put token pieces up for an auction.
+		///
+		/// The pieces are moved to the marketplace escrow until the auction ends,
+		/// and the settlement of the auction is scheduled at the start of its end block.
+		///
+		/// # Permissions
+		///
+		/// * Owner of the auctioned token pieces.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the NFT or RFT collection.
+		/// * `token_id`: ID of the auctioned token.
+		/// * `amount`: Amount of auctioned pieces, should be 1 for NFTs.
+		/// * `currency`: ID of the fungible collection, in which the price is paid,
+		/// or 0 for the native currency.
+		/// * `kind`: English or Dutch auction with its price terms.
+		/// * `duration`: Number of blocks after which the auction ends.
+		/// The scheduler agenda of the end block should have space for the settlement.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<SelfWeightOf<T>>::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			amount: u128,
+			currency: CollectionId,
+			kind: AuctionKind,
+			duration: u64,
+		) -> DispatchResult {
+			let seller = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			Self::check_listable(&collection)?;
+			Self::check_currency(currency)?;
+			ensure!(amount > 0, <CommonError<T>>::TokenValueTooLow);
+			let dispatch = T::CollectionDispatch::dispatch(collection_id)?;
+			ensure!(
+				dispatch.as_dyn().balance(seller.clone(), token_id) >= amount,
+				<CommonError<T>>::TokenValueTooLow
+			);
+			ensure!(duration > 0, <Error<T>>::InvalidAuctionTerms);
+			if let AuctionKind::Dutch {
+				start_price,
+				end_price,
+			} = kind
+			{
+				ensure!(start_price >= end_price, <Error<T>>::InvalidAuctionTerms);
+			}
+
+			let start = <PalletCommon<T>>::block_number();
+			let end = start
+				.checked_add(duration)
+				.ok_or(ArithmeticError::Overflow)?;
+			let auction_id = <AuctionCount<T>>::get()
+				.checked_add(1)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			// =========
+
+			<PalletStructure<T>>::transfer_as_owner(
+				collection_id,
+				token_id,
+				&seller,
+				&Self::escrow(),
+				amount,
+			)?;
+			Self::schedule_settlement(auction_id, end)?;
+			<AuctionCount<T>>::put(auction_id);
+			<Auctions<T>>::insert(
+				auction_id,
+				MarketAuction {
+					seller: seller.clone(),
+					collection: collection_id,
+					token: token_id,
+					amount,
+					currency,
+					kind,
+					start,
+					end,
+					highest_bid: None,
+				},
+			);
+
+			Self::deposit_event(Event::AuctionCreated(
+				auction_id,
+				collection_id,
+				token_id,
+				seller,
+			));
+			Ok(())
+		}
+
+		/// Place a bid at an English auction.
+		///
+		/// The bid is moved to the marketplace escrow, and the previous highest bid is refunded.
+		/// A bid placed less than [`AntiSnipingPeriod`](Config::AntiSnipingPeriod) blocks
+		/// before the end of the auction extends it. If the scheduler agenda of the new end block
+		/// is full, the settlement stays scheduled at the previous end and fails there,
+		/// and the auction should be settled with [`Pallet::settle_auction`].
+		///
+		/// # Permissions
+		///
+		/// * Anyone except the seller.
+		///
+		/// # Arguments
+		///
+		/// * `auction_id`: ID of the English auction.
+		/// * `amount`: Amount of the bid in the currency of the auction.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<SelfWeightOf<T>>::bid())]
+		pub fn bid(origin: OriginFor<T>, auction_id: u32, amount: u128) -> DispatchResult {
+			let bidder = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let mut auction = Self::running_auction(auction_id)?;
+			ensure!(bidder != auction.seller, <CommonError<T>>::NoPermission);
+			let AuctionKind::English {
+				min_bid,
+				min_increment,
+			} = auction.kind
+			else {
+				return Err(<Error<T>>::NotEnglishAuction.into());
+			};
+			let min_amount = match &auction.highest_bid {
+				Some((_, highest)) => highest
+					.checked_add(min_increment.max(1))
+					.ok_or(<Error<T>>::BidTooLow)?,
+				None => min_bid,
+			};
+			ensure!(amount >= min_amount, <Error<T>>::BidTooLow);
+
+			let extended_end =
+				<PalletCommon<T>>::block_number().saturating_add(T::AntiSnipingPeriod::get());
+
+			// =========
+
+			let escrow = Self::escrow();
+			<PalletStructure<T>>::pay(auction.currency, &bidder, &escrow, amount)?;
+			if let Some((previous_bidder, previous_amount)) = &auction.highest_bid {
+				<PalletStructure<T>>::pay(
+					auction.currency,
+					&escrow,
+					previous_bidder,
+					*previous_amount,
+				)?;
+			}
+			auction.highest_bid = Some((bidder.clone(), amount));
+
+			if extended_end > auction.end {
+				if let Err(e) = with_storage_layer(|| {
+					T::Scheduler::reschedule_named(
+						Self::settlement_task(auction_id),
+						DispatchTime::At(extended_end.unique_saturated_into()),
+					)
+				}) {
+					log::warn!(
+						"failed to reschedule the settlement of auction {auction_id}: {e:?}"
+					);
+				}
+				auction.end = extended_end;
+				Self::deposit_event(Event::AuctionExtended(auction_id, extended_end));
+			}
+			<Auctions<T>>::insert(auction_id, auction);
+
+			Self::deposit_event(Event::BidPlaced(auction_id, bidder, amount));
+			Ok(())
+		}
+
+		/// Buy token pieces of a Dutch auction at the current price.
+		///
+		/// The price decreases linearly from the start price to the end price during the auction.
+		/// The token is transferred from the escrow to the buyer, and the price is paid
+		/// by the buyer to the royalty receiver, the treasury and the seller.
+		///
+		/// # Arguments
+		///
+		/// * `auction_id`: ID of the Dutch auction.
+		/// * `max_price`: Maximum price the buyer is ready to pay.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<SelfWeightOf<T>>::buy_auction())]
+		pub fn buy_auction(
+			origin: OriginFor<T>,
+			auction_id: u32,
+			max_price: u128,
+		) -> DispatchResult {
+			let buyer = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let auction = Self::running_auction(auction_id)?;
+			let price = Self::dutch_price(&auction).ok_or(<Error<T>>::NotDutchAuction)?;
+			ensure!(price <= max_price, <Error<T>>::PriceTooHigh);
+
+			// =========
+
+			Self::close_auction(auction_id);
+			<PalletStructure<T>>::transfer_as_owner(
+				auction.collection,
+				auction.token,
+				&Self::escrow(),
+				&buyer,
+				auction.amount,
+			)?;
+			Self::pay_price(
+				auction.collection,
+				auction.token,
+				auction.currency,
+				&buyer,
+				&auction.seller,
+				price,
+			)?;
+
+			Self::deposit_event(Event::AuctionSettled(auction_id, buyer, price));
+			Ok(())
+		}
+
+		/// Cancel an auction and return the token pieces to the seller.
+		///
+		/// # Permissions
+		///
+		/// * Seller of the token, while there are no bids at the auction.
+		///
+		/// # Arguments
+		///
+		/// * `auction_id`: ID of the auction.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<SelfWeightOf<T>>::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, auction_id: u32) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let auction = Self::running_auction(auction_id)?;
+			ensure!(sender == auction.seller, <CommonError<T>>::NoPermission);
+			ensure!(auction.highest_bid.is_none(), <Error<T>>::AuctionHasBids);
+
+			// =========
+
+			Self::close_auction(auction_id);
+			<PalletStructure<T>>::transfer_as_owner(
+				auction.collection,
+				auction.token,
+				&Self::escrow(),
+				&auction.seller,
+				auction.amount,
+			)?;
+
+			Self::deposit_event(Event::AuctionCancelled(auction_id));
+			Ok(())
+		}
+
+		/// Settle an ended auction.
+		///
+		/// The token pieces go to the highest bidder, and the bid is paid out of the escrow.
+		/// If there is no bid, or the sale fails, the bid is refunded, and the pieces are returned
+		/// to the seller. If the pieces can't be returned either, the auction is kept,
+		/// so the settlement can be retried.
+		///
+		/// The settlement is scheduled at the end of the auction,
+		/// the call is needed only if the scheduled settlement has failed.
+		///
+		/// # Permissions
+		///
+		/// * Anyone.
+		///
+		/// # Arguments
+		///
+		/// * `auction_id`: ID of the ended auction.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<SelfWeightOf<T>>::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, auction_id: u32) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+
+			Self::settle(auction_id)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get the listing of the token by the seller.
+	pub fn listing(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		seller: &T::CrossAccountId,
+	) -> Option<MarketListing> {
+		<Listings<T>>::get((collection_id, token_id, seller))
+	}
+
+	/// Get all listings of the token.
+	pub fn token_listings(
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> Vec<(T::CrossAccountId, MarketListing)> {
+		<Listings<T>>::iter_prefix((collection_id, token_id)).collect()
+	}
+
+	/// List token pieces for sale. See [`Pallet::list`].
+	pub fn list_token(
+		seller: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		listing: MarketListing,
+	) -> DispatchResult {
+		let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+		Self::check_listable(&collection)?;
+		Self::check_currency(listing.currency)?;
+		ensure!(listing.amount > 0, <CommonError<T>>::TokenValueTooLow);
+
+		let dispatch = T::CollectionDispatch::dispatch(collection_id)?;
+		ensure!(
+			dispatch.as_dyn().balance(seller.clone(), token_id) >= listing.amount,
+			<CommonError<T>>::TokenValueTooLow
+		);
+
+		// =========
+
+		<Listings<T>>::insert((collection_id, token_id, seller), listing);
+		Self::deposit_event(Event::Listed(
+			collection_id,
+			token_id,
+			seller.clone(),
+			listing,
+		));
+		Ok(())
+	}
+
+	/// Remove the listing of the token. See [`Pallet::cancel_listing`].
+	pub fn cancel_token_listing(
+		seller: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> DispatchResult {
+		<Listings<T>>::take((collection_id, token_id, seller))
+			.ok_or(<Error<T>>::ListingNotFound)?;
+
+		Self::deposit_event(Event::ListingCancelled(
+			collection_id,
+			token_id,
+			seller.clone(),
+		));
+		Ok(())
+	}
+
+	/// Buy listed token pieces. See [`Pallet::buy`].
+	#[transactional]
+	pub fn buy_token(
+		buyer: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		seller: &T::CrossAccountId,
+		max_price: u128,
+	) -> DispatchResult {
+		let listing = <Listings<T>>::get((collection_id, token_id, seller))
+			.ok_or(<Error<T>>::ListingNotFound)?;
+		ensure!(listing.price <= max_price, <Error<T>>::PriceTooHigh);
+
+		// =========
+
+		<Listings<T>>::remove((collection_id, token_id, seller));
+		<PalletStructure<T>>::transfer_as_owner(
+			collection_id,
+			token_id,
+			seller,
+			buyer,
+			listing.amount,
+		)?;
+		Self::pay_price(
+			collection_id,
+			token_id,
+			listing.currency,
+			buyer,
+			seller,
+			listing.price,
+		)?;
+
+		Self::deposit_event(Event::Sold(
+			collection_id,
+			token_id,
+			seller.clone(),
+			buyer.clone(),
+			listing,
+		));
+		Ok(())
+	}
+
+	/// Get the running auction.
+	pub fn auction(auction_id: u32) -> Option<MarketAuction<T::CrossAccountId>> {
+		<Auctions<T>>::get(auction_id)
+	}
+
+	/// Account holding auctioned tokens and bids.
+	pub fn escrow() -> T::CrossAccountId {
+		T::CrossAccountId::from_sub(T::PalletId::get().into_account_truncating())
+	}
+
+	/// Current price of a Dutch auction, `None` for English auctions.
+	///
+	/// The price decreases linearly from the start price in the start block
+	/// to the end price in the end block.
+	pub fn dutch_price(auction: &MarketAuction<T::CrossAccountId>) -> Option<u128> {
+		let AuctionKind::Dutch {
+			start_price,
+			end_price,
+		} = auction.kind
+		else {
+			return None;
+		};
+		let elapsed = <PalletCommon<T>>::block_number().saturating_sub(auction.start);
+		let duration = auction.end - auction.start;
+		let discount = Perbill::from_rational(elapsed.min(duration), duration);
+
+		Some(start_price - discount * (start_price - end_price))
+	}
+
+	fn running_auction(auction_id: u32) -> Result<MarketAuction<T::CrossAccountId>, DispatchError> {
+		let auction = <Auctions<T>>::get(auction_id).ok_or(<Error<T>>::AuctionNotFound)?;
+		ensure!(
+			<PalletCommon<T>>::block_number() < auction.end,
+			<Error<T>>::AuctionNotFound
+		);
+		Ok(auction)
+	}
+
+	/// Name of the scheduler task settling the auction.
+	fn settlement_task(auction_id: u32) -> [u8; 32] {
+		(T::PalletId::get(), b"settle", auction_id).using_encoded(blake2_256)
+	}
+
+	fn schedule_settlement(auction_id: u32, block: u64) -> DispatchResult {
+		let call = CallOf::<T>::from(Call::<T>::settle_auction { auction_id }).encode();
+		T::Scheduler::schedule_named(
+			Self::settlement_task(auction_id),
+			DispatchTime::At(block.unique_saturated_into()),
+			None,
+			HARD_DEADLINE,
+			frame_system::RawOrigin::Root.into(),
+			Bounded::Inline(call.try_into().map_err(|_| DispatchError::Exhausted)?),
+		)?;
+		Ok(())
+	}
+
+	/// Removes the auction before the end, the caller should release its token pieces.
+	fn close_auction(auction_id: u32) {
+		<Auctions<T>>::remove(auction_id);
+		let _ = T::Scheduler::cancel_named(Self::settlement_task(auction_id));
+	}
+
+	/// Settles the ended auction. See [`Pallet::settle_auction`].
+	pub fn settle(auction_id: u32) -> DispatchResult {
+		let auction = <Auctions<T>>::get(auction_id).ok_or(<Error<T>>::AuctionNotFound)?;
+		ensure!(
+			<PalletCommon<T>>::block_number() >= auction.end,
+			<Error<T>>::AuctionNotEnded
+		);
+		let escrow = Self::escrow();
+
+		if let Some((winner, price)) = &auction.highest_bid {
+			match Self::complete_auction_sale(&auction, &escrow, winner, *price) {
+				Ok(()) => {
+					<Auctions<T>>::remove(auction_id);
+					Self::deposit_event(Event::AuctionSettled(auction_id, winner.clone(), *price));
+					return Ok(());
+				}
+				Err(e) => log::warn!("failed to sell the token of auction {auction_id}: {e:?}"),
+			}
+		}
+
+		Self::return_auction(&auction, &escrow)?;
+		<Auctions<T>>::remove(auction_id);
+		Self::deposit_event(Event::AuctionCancelled(auction_id));
+		Ok(())
+	}
+
+	/// Refunds the bid of the auction, and returns its token pieces to the seller.
+	#[transactional]
+	fn return_auction(
+		auction: &MarketAuction<T::CrossAccountId>,
+		escrow: &T::CrossAccountId,
+	) -> DispatchResult {
+		if let Some((bidder, amount)) = &auction.highest_bid {
+			<PalletStructure<T>>::pay(auction.currency, escrow, bidder, *amount)?;
+		}
+		<PalletStructure<T>>::transfer_as_owner(
+			auction.collection,
+			auction.token,
+			escrow,
+			&auction.seller,
+			auction.amount,
+		)
+	}
+
+	#[transactional]
+	fn complete_auction_sale(
+		auction: &MarketAuction<T::CrossAccountId>,
+		escrow: &T::CrossAccountId,
+		winner: &T::CrossAccountId,
+		price: u128,
+	) -> DispatchResult {
+		<PalletStructure<T>>::transfer_as_owner(
+			auction.collection,
+			auction.token,
+			escrow,
+			winner,
+			auction.amount,
+		)?;
+		Self::pay_price(
+			auction.collection,
+			auction.token,
+			auction.currency,
+			escrow,
+			&auction.seller,
+			price,
+		)
+	}
+
+	fn check_listable(collection: &CollectionHandle<T>) -> DispatchResult {
+		ensure!(
+			matches!(
+				collection.mode,
+				CollectionMode::NFT | CollectionMode::ReFungible
+			),
+			<Error<T>>::CollectionNotListable
+		);
+		Ok(())
+	}
+
+	fn check_currency(currency: CollectionId) -> DispatchResult {
+		ensure!(
+			<PalletStructure<T>>::is_currency(currency)?,
+			<Error<T>>::UnsupportedCurrency
+		);
+		Ok(())
+	}
+
+	/// Pays the price of the token pieces from `payer` to the royalty receiver of the token,
+	/// the treasury and the seller.
+	fn pay_price(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		currency: CollectionId,
+		payer: &T::CrossAccountId,
+		seller: &T::CrossAccountId,
+		price: u128,
+	) -> DispatchResult {
+		let dispatch = T::CollectionDispatch::dispatch(collection_id)?;
+		let royalty = <PalletCommon<T>>::token_royalty(collection_id, dispatch.as_dyn(), token_id);
+		let royalty_amount = royalty
+			.as_ref()
+			.map(|royalty| royalty.amount(price.into()).low_u128())
+			.unwrap_or(0);
+		let fee = T::MarketFee::get() * (price - royalty_amount);
+		let proceeds = price - royalty_amount - fee;
+
+		if let Some(royalty) = royalty {
+			<PalletStructure<T>>::pay(currency, payer, &royalty.receiver, royalty_amount)?;
+		}
+		let treasury = T::CrossAccountId::from_sub(T::TreasuryAccountId::get());
+		<PalletStructure<T>>::pay(currency, payer, &treasury, fee)?;
+		<PalletStructure<T>>::pay(currency, payer, seller, proceeds)
+	}
+}
+
+impl<T: Config> OnTokenTransfer<T::CrossAccountId> for Pallet<T> {
+	fn on_token_transfer(collection: CollectionId, token: TokenId, from: &T::CrossAccountId) {
+		if <Listings<T>>::take((collection, token, from)).is_some() {
+			Self::deposit_event(Event::ListingCancelled(collection, token, from.clone()));
+		}
+	}
+}
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: OTHER
+// This code is automatically generated
+
+pragma solidity >=0.8.0 <0.9.0;
+
+/// @dev common stubs holder
+contract Dummy {
+	uint8 dummy;
+	string stub_error = "this contract is implemented in native";
+}
+contract ERC165 is Dummy {
+	function supportsInterface(bytes4 interfaceID) external view returns (bool) {
+		require(false, stub_error);
+		interfaceID;
+		return true;
+	}
+}
+
+/// @title Fixed-price marketplace for tokens of Unique collections
+/// @dev the ERC-165 identifier for this interface is 0xa045cc5e
+contract Marketplace is Dummy, ERC165 {
+	/// @notice List token pieces for sale, or replace terms of an existing listing.
+	/// @dev The listing is cancelled when the seller transfers or burns pieces of the token.
+	/// @param collection Address of the NFT or RFT collection.
+	/// @param tokenId ID of the listed token.
+	/// @param amount Amount of listed pieces, should be 1 for NFTs.
+	/// @param currency Address of the fungible collection in which the price is paid,
+	///  address of the native fungible collection for the native currency.
+	/// @param price Price of the whole listed amount.
+	/// @dev EVM selector for this function is: 0xcd391c64,
+	///  or in textual repr: list(address,uint256,uint256,address,uint256)
+	function list(address collection, uint256 tokenId, uint256 amount, address currency, uint256 price) public {
+		require(false, stub_error);
+		collection;
+		tokenId;
+		amount;
+		currency;
+		price;
+		dummy = 0;
+	}
+	/// @notice Remove the listing of the token made by the caller.
+	/// @param collection Address of the collection of the listed token.
+	/// @param tokenId ID of the listed token.
+	/// @dev EVM selector for this function is: 0xb2ddee06,
+	///  or in textual repr: cancelListing(address,uint256)
+	function cancelListing(address collection, uint256 tokenId) public {
+		require(false, stub_error);
+		collection;
+		tokenId;
+		dummy = 0;
+	}
+	/// @notice Buy listed token pieces.
+	/// @dev The price is paid by the caller to the royalty receiver, the treasury and the seller.
+	/// @param collection Address of the collection of the listed token.
+	/// @param tokenId ID of the listed token.
+	/// @param seller Seller of the token.
+	/// @param maxPrice Maximum price the caller is ready to pay.
+	/// @dev EVM selector for this function is: 0x1334d122,
+	///  or in textual repr: buy(address,uint256,(address,uint256),uint256)
+	function buy(address collection, uint256 tokenId, CrossAddress memory seller, uint256 maxPrice) public {
+		require(false, stub_error);
+		collection;
+		tokenId;
+		seller;
+		maxPrice;
+		dummy = 0;
+	}
+	/// @notice Get the listing of the token by the seller.
+	/// @param collection Address of the collection of the listed token.
+	/// @param tokenId ID of the listed token.
+	/// @param seller Seller of the token.
+	/// @return Listing terms.
+	/// @dev EVM selector for this function is: 0xcc95ef1e,
+	///  or in textual repr: getListing(address,uint256,(address,uint256))
+	function getListing(address collection, uint256 tokenId, CrossAddress memory seller) public view returns (Listing memory) {
+		require(false, stub_error);
+		collection;
+		tokenId;
+		seller;
+		dummy;
+		return Listing(0,0x0000000000000000000000000000000000000000,0);
+	}
+}
+
+/// Cross account struct
+struct CrossAddress {
+	address eth;
+	uint256 sub;
+}
+
+/// Ethereum representation of [`MarketListing`].
+struct Listing {
+/// Amount of listed token pieces, always 1 for NFTs.
+	uint256 amount;
+/// Address of the collection in which the price is paid.
+	address currency;
+/// Price of the whole listed amount.
+	uint256 price;
+}
@@ -35,6 +35,7 @@ fn create_max_item_data<T: Config>(owner: T::CrossAccountId) -> CreateItemData<T
 	CreateItemData::<T> {
 		owner,
 		properties: Default::default(),
+		locked: false,
 	}
 }
 pub fn create_max_item<T: Config>(
@@ -164,7 +165,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance(&collection, &sender, item, Some(&spender))?;
+			<Pallet<T>>::set_allowance(&collection, &sender, item, Some(&spender), None)?;
 		}
 
 		Ok(())
@@ -187,6 +188,7 @@ mod benchmarks {
 				&owner_eth,
 				item,
 				Some(&spender),
+				None,
 			)?;
 		}
 
@@ -200,7 +202,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; spender: cross_sub;
 		};
 		let item = create_max_item(&collection, &owner, sender.clone())?;
-		<Pallet<T>>::set_allowance(&collection, &sender, item, Some(&spender))?;
+		<Pallet<T>>::set_allowance(&collection, &sender, item, Some(&spender), None)?;
 
 		#[block]
 		{
@@ -217,7 +219,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; burner: cross_sub;
 		};
 		let item = create_max_item(&collection, &owner, sender.clone())?;
-		<Pallet<T>>::set_allowance(&collection, &sender, item, Some(&burner))?;
+		<Pallet<T>>::set_allowance(&collection, &sender, item, Some(&burner), None)?;
 
 		#[block]
 		{
@@ -327,7 +329,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance_for_all(&collection, &owner, &operator, true)?;
+			<Pallet<T>>::set_allowance_for_all(&collection, &owner, &operator, true, None)?;
 		}
 
 		Ok(())
@@ -363,4 +365,40 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn set_token_lock() -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let item = create_max_item(&collection, &owner, owner.clone())?;
+
+		#[block]
+		{
+			<Pallet<T>>::set_token_lock(&collection, &owner, item, true)?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_token_user() -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub; user: cross_sub;
+		};
+		let item = create_max_item(&collection, &owner, owner.clone())?;
+		let user = TokenUser {
+			user,
+			expires: u64::MAX,
+		};
+
+		#[block]
+		{
+			<Pallet<T>>::set_token_user(&collection, &owner, item, Some(user))?;
+		}
+
+		Ok(())
+	}
 }
@@ -19,8 +19,8 @@ use core::marker::PhantomData;
 use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, fail, weights::Weight};
 use pallet_common::{
 	weights::WeightInfo as _, with_weight, write_token_properties_total_weight,
-	CommonCollectionOperations, CommonWeightInfo, SelfWeightOf as PalletCommonWeightOf,
-	XcmExtensions,
+	CommonCollectionOperations, CommonWeightInfo, Pallet as PalletCommon,
+	SelfWeightOf as PalletCommonWeightOf, XcmExtensions,
 };
 use pallet_structure::Pallet as PalletStructure;
 use sp_runtime::DispatchError;
@@ -29,12 +29,12 @@ use sp_std::vec;
 use sp_std::vec::Vec;
 use up_data_structs::{
 	budget::Budget, CollectionId, CreateItemExData, Property, PropertyKey, PropertyKeyPermission,
-	PropertyValue, TokenId, TokenOwnerError,
+	PropertyKeyValueType, PropertyValue, TokenId, TokenOwnerError, TokenUser,
 };
 
 use crate::{
-	weights::WeightInfo, AccountBalance, Allowance, Config, CreateItemData, Error,
-	NonfungibleHandle, Owned, Pallet, SelfWeightOf, TokenData, TokenProperties, TokensMinted,
+	weights::WeightInfo, AccountBalance, Config, CreateItemData, Error, NonfungibleHandle, Owned,
+	Pallet, SelfWeightOf, TokenData, TokenProperties, TokensMinted,
 };
 
 pub struct CommonWeights<T: Config>(PhantomData<T>);
@@ -64,7 +64,7 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	}
 
 	fn set_collection_properties(amount: u32) -> Weight {
-		<pallet_common::SelfWeightOf<T>>::set_collection_properties(amount)
+		pallet_common::set_collection_properties_weight::<T>(amount)
 	}
 
 	fn set_token_properties(amount: u32) -> Weight {
@@ -80,6 +80,7 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 
 	fn set_token_property_permissions(amount: u32) -> Weight {
 		<SelfWeightOf<T>>::set_token_property_permissions(amount)
+			.saturating_add(<PalletCommonWeightOf<T>>::load_frozen_token_uri_keys())
 	}
 
 	fn transfer() -> Weight {
@@ -110,6 +111,14 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	fn force_repair_item() -> Weight {
 		<SelfWeightOf<T>>::repair_item()
 	}
+
+	fn set_token_lock() -> Weight {
+		<SelfWeightOf<T>>::set_token_lock()
+	}
+
+	fn set_token_user() -> Weight {
+		<SelfWeightOf<T>>::set_token_user()
+	}
 }
 
 /// Weight of minting tokens with properties
@@ -134,6 +143,7 @@ fn map_create_data<T: Config>(
 		up_data_structs::CreateItemData::NFT(data) => Ok(CreateItemData::<T> {
 			properties: data.properties,
 			owner: to.clone(),
+			locked: false,
 		}),
 		_ => fail!(<Error<T>>::NotNonfungibleDataUsedToMintFungibleCollectionToken),
 	}
@@ -291,6 +301,19 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		)
 	}
 
+	fn set_token_property_value_types(
+		&self,
+		sender: &T::CrossAccountId,
+		value_types: Vec<PropertyKeyValueType>,
+	) -> DispatchResultWithPostInfo {
+		let weight = <CommonWeights<T>>::set_token_property_permissions(value_types.len() as u32);
+
+		with_weight(
+			<PalletCommon<T>>::set_token_property_value_types(self, sender, value_types),
+			weight,
+		)
+	}
+
 	fn burn_item(
 		&self,
 		sender: T::CrossAccountId,
@@ -309,6 +332,16 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		}
 	}
 
+	fn burn_item_recursively(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		burn_children: bool,
+		budget: &dyn Budget,
+	) -> DispatchResultWithPostInfo {
+		<Pallet<T>>::burn_recursively(self, &sender, token, burn_children, budget)
+	}
+
 	fn transfer(
 		&self,
 		from: T::CrossAccountId,
@@ -332,14 +365,15 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		spender: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		ensure!(amount <= 1, <Error<T>>::NonfungibleItemsHaveNoAmount);
 
 		with_weight(
 			if amount == 1 {
-				<Pallet<T>>::set_allowance(self, &sender, token, Some(&spender))
+				<Pallet<T>>::set_allowance(self, &sender, token, Some(&spender), deadline)
 			} else {
-				<Pallet<T>>::set_allowance(self, &sender, token, None)
+				<Pallet<T>>::set_allowance(self, &sender, token, None, None)
 			},
 			<CommonWeights<T>>::approve(),
 		)
@@ -352,14 +386,15 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		to: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		ensure!(amount <= 1, <Error<T>>::NonfungibleItemsHaveNoAmount);
 
 		with_weight(
 			if amount == 1 {
-				<Pallet<T>>::set_allowance_from(self, &sender, &from, token, Some(&to))
+				<Pallet<T>>::set_allowance_from(self, &sender, &from, token, Some(&to), deadline)
 			} else {
-				<Pallet<T>>::set_allowance_from(self, &sender, &from, token, None)
+				<Pallet<T>>::set_allowance_from(self, &sender, &from, token, None, None)
 			},
 			<CommonWeights<T>>::approve_from(),
 		)
@@ -472,32 +507,25 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 	}
 
 	fn token_property(&self, token_id: TokenId, key: &PropertyKey) -> Option<PropertyValue> {
-		<Pallet<T>>::token_properties((self.id, token_id))?
-			.get(key)
-			.cloned()
+		if !<Pallet<T>>::token_exists(self, token_id) {
+			return None;
+		}
+
+		<Pallet<T>>::token_properties((self.id, token_id))
+			.and_then(|properties| properties.get(key).cloned())
+			.or_else(|| <PalletCommon<T>>::default_token_property(self.id, key))
 	}
 
 	fn token_properties(&self, token_id: TokenId, keys: Option<Vec<PropertyKey>>) -> Vec<Property> {
-		let Some(properties) = <Pallet<T>>::token_properties((self.id, token_id)) else {
+		if !<Pallet<T>>::token_exists(self, token_id) {
 			return vec![];
-		};
+		}
 
-		keys.map(|keys| {
-			keys.into_iter()
-				.filter_map(|key| {
-					properties.get(&key).map(|value| Property {
-						key,
-						value: value.clone(),
-					})
-				})
-				.collect()
-		})
-		.unwrap_or_else(|| {
-			properties
-				.into_iter()
-				.map(|(key, value)| Property { key, value })
-				.collect()
-		})
+		<PalletCommon<T>>::filter_token_properties(
+			self.id,
+			<Pallet<T>>::token_properties((self.id, token_id)).unwrap_or_default(),
+			keys,
+		)
 	}
 
 	fn total_supply(&self) -> u32 {
@@ -530,13 +558,25 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 			.unwrap_or(true)
 		{
 			0
-		} else if <Allowance<T>>::get((self.id, token)) == Some(spender) {
+		} else if <Pallet<T>>::approved_spender(self, token) == Some(spender) {
 			1
 		} else {
 			0
 		}
 	}
 
+	fn allowance_deadline(
+		&self,
+		sender: T::CrossAccountId,
+		spender: T::CrossAccountId,
+		token: TokenId,
+	) -> Option<u64> {
+		if self.allowance(sender, spender, token) == 0 {
+			return None;
+		}
+		<Pallet<T>>::allowance_deadline(self, token)
+	}
+
 	fn total_pieces(&self, token: TokenId) -> Option<u128> {
 		if <TokenData<T>>::contains_key((self.id, token)) {
 			Some(1)
@@ -554,9 +594,10 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		owner: T::CrossAccountId,
 		operator: T::CrossAccountId,
 		approve: bool,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		with_weight(
-			<Pallet<T>>::set_allowance_for_all(self, &owner, &operator, approve),
+			<Pallet<T>>::set_allowance_for_all(self, &owner, &operator, approve, deadline),
 			<CommonWeights<T>>::set_allowance_for_all(),
 		)
 	}
@@ -565,12 +606,52 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		<Pallet<T>>::allowance_for_all(self, &owner, &operator)
 	}
 
+	fn allowance_for_all_deadline(
+		&self,
+		owner: T::CrossAccountId,
+		operator: T::CrossAccountId,
+	) -> Option<u64> {
+		<Pallet<T>>::allowance_for_all_deadline(self, &owner, &operator)
+	}
+
 	fn repair_item(&self, token: TokenId) -> DispatchResultWithPostInfo {
 		with_weight(
 			<Pallet<T>>::repair_item(self, token),
 			<CommonWeights<T>>::force_repair_item(),
 		)
 	}
+
+	fn set_token_lock(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		locked: bool,
+	) -> DispatchResultWithPostInfo {
+		with_weight(
+			<Pallet<T>>::set_token_lock(self, &sender, token, locked),
+			<CommonWeights<T>>::set_token_lock(),
+		)
+	}
+
+	fn token_locked(&self, token: TokenId) -> bool {
+		<Pallet<T>>::token_locked(self, token)
+	}
+
+	fn set_token_user(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		user: Option<TokenUser<T::CrossAccountId>>,
+	) -> DispatchResultWithPostInfo {
+		with_weight(
+			<Pallet<T>>::set_token_user(self, &sender, token, user),
+			<CommonWeights<T>>::set_token_user(),
+		)
+	}
+
+	fn token_user(&self, token: TokenId) -> Option<TokenUser<T::CrossAccountId>> {
+		<Pallet<T>>::token_user(self, token)
+	}
 }
 
 impl<T: Config> XcmExtensions<T> for NonfungibleHandle<T> {
@@ -28,9 +28,13 @@ use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
 use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::*, AbiCoder, ToLog};
 use frame_support::BoundedVec;
 use pallet_common::{
-	erc::{static_property::key, CollectionCall, CommonEvmHandler, PrecompileResult},
+	erc::{
+		check_on_erc721_received, static_property::key, CollectionCall, CommonEvmHandler,
+		PrecompileResult,
+	},
 	eth::{self, TokenUri},
 	CollectionHandle, CollectionPropertyPermissions, CommonCollectionOperations, CommonWeightInfo,
+	Pallet as PalletCommon, TokenPropertyWriteGrants,
 };
 use pallet_evm::{account::CrossAccountId, PrecompileHandle};
 use pallet_evm_coder_substrate::{
@@ -42,15 +46,16 @@ use pallet_structure::{weights::WeightInfo as _, SelfWeightOf as StructureWeight
 use sp_core::{Get, U256};
 use sp_std::vec;
 use up_data_structs::{
-	budget::Budget, CollectionId, CollectionPropertiesVec, Property, PropertyKey,
-	PropertyKeyPermission, PropertyPermission, TokenId,
+	budget::{self, Budget},
+	CollectionId, CollectionPropertiesVec, Property, PropertyKey, PropertyKeyPermission,
+	PropertyPermission, TokenId, TokenUser, MAX_BURN_RECURSIVELY_NODES,
 };
 
 use crate::{
 	common::{mint_with_props_weight, CommonWeights},
 	weights::WeightInfo,
 	AccountBalance, Config, CreateItemData, NonfungibleHandle, Pallet, SelfWeightOf, TokenData,
-	TokenProperties, TokensMinted,
+	TokenUsers, TokensMinted,
 };
 
 /// Nft events.
@@ -84,7 +89,10 @@ fn nesting_budget<T: Config>(recorder: &SubstrateRecorder<T>) -> impl Budget + '
 
 /// @title A contract that allows to set and delete token properties and change token property permissions.
 #[solidity_interface(name = TokenProperties, events(ERC721TokenEvent), enum(derive(PreDispatch)), enum_attr(weight))]
-impl<T: Config> NonfungibleHandle<T> {
+impl<T: Config> NonfungibleHandle<T>
+where
+	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
 	/// @notice Set permissions for token property.
 	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
 	/// @param key Property key.
@@ -92,7 +100,7 @@ impl<T: Config> NonfungibleHandle<T> {
 	/// @param collectionAdmin Permission to mutate property by collection admin if property is mutable.
 	/// @param tokenOwner Permission to mutate property by token owner if property is mutable.
 	#[solidity(hide)]
-	#[weight(<SelfWeightOf<T>>::set_token_property_permissions(1))]
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(1))]
 	fn set_token_property_permission(
 		&mut self,
 		caller: Caller,
@@ -122,7 +130,7 @@ impl<T: Config> NonfungibleHandle<T> {
 	/// @notice Set permissions for token property.
 	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
 	/// @param permissions Permissions for keys.
-	#[weight(<SelfWeightOf<T>>::set_token_property_permissions(permissions.len() as u32))]
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(permissions.len() as u32))]
 	fn set_token_property_permissions(
 		&mut self,
 		caller: Caller,
@@ -135,6 +143,28 @@ impl<T: Config> NonfungibleHandle<T> {
 			.map_err(dispatch_to_evm::<T>)
 	}
 
+	/// @notice Set permissions and value types for token property.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param permissions Permissions and value types for keys.
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(permissions.len() as u32)
+		.saturating_add(pallet_common::set_token_property_value_types_weight::<T>(
+			permissions.iter().map(eth::TypedTokenPropertyPermission::enum_values_num),
+		)))]
+	fn set_typed_token_property_permissions(
+		&mut self,
+		caller: Caller,
+		permissions: Vec<eth::TypedTokenPropertyPermission>,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let (perms, value_types) =
+			eth::TypedTokenPropertyPermission::into_property_key_permissions(permissions)?;
+
+		<Pallet<T>>::set_token_property_permissions(self, &caller, perms)
+			.map_err(dispatch_to_evm::<T>)?;
+		<PalletCommon<T>>::set_token_property_value_types(self, &caller, value_types)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
 	/// @notice Get permissions for token properties.
 	fn token_property_permissions(&self) -> Result<Vec<eth::TokenPropertyPermission>> {
 		let perms = <Pallet<T>>::token_property_permission(self.id);
@@ -144,6 +174,71 @@ impl<T: Config> NonfungibleHandle<T> {
 			.collect())
 	}
 
+	/// @notice Get permissions and value types for token properties.
+	fn typed_token_property_permissions(&self) -> Result<Vec<eth::TypedTokenPropertyPermission>> {
+		let perms = <PalletCommon<T>>::filter_property_permissions(self.id, None)
+			.map_err(dispatch_to_evm::<T>)?;
+		Ok(perms
+			.into_iter()
+			.map(eth::TypedTokenPropertyPermission::from)
+			.collect())
+	}
+
+	/// @notice Allow an account to write token properties with specific keys.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param account Cross account address to grant the right to.
+	/// @param keys Keys of the token properties.
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(keys.len() as u32))]
+	fn grant_token_property_write_cross(
+		&mut self,
+		caller: Caller,
+		account: eth::CrossAddress,
+		keys: Vec<String>,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let account = account.into_sub_cross_account::<T>()?;
+		let keys = keys
+			.into_iter()
+			.map(|k| Ok(<Vec<u8>>::from(k).try_into().map_err(|_| "key too long")?))
+			.collect::<Result<Vec<_>>>()?;
+
+		<PalletCommon<T>>::set_token_property_write_grants(self, &caller, &account, keys, true)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Revoke the right to write token properties with specific keys from an account.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param account Cross account address to revoke the right from.
+	/// @param keys Keys of the token properties.
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(keys.len() as u32))]
+	fn revoke_token_property_write_cross(
+		&mut self,
+		caller: Caller,
+		account: eth::CrossAddress,
+		keys: Vec<String>,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let account = account.into_sub_cross_account::<T>()?;
+		let keys = keys
+			.into_iter()
+			.map(|k| Ok(<Vec<u8>>::from(k).try_into().map_err(|_| "key too long")?))
+			.collect::<Result<Vec<_>>>()?;
+
+		<PalletCommon<T>>::set_token_property_write_grants(self, &caller, &account, keys, false)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Get keys of the token properties an account is allowed to write.
+	/// @param account Cross account address.
+	/// @return Keys of the token properties.
+	fn token_property_write_grants_cross(&self, account: eth::CrossAddress) -> Result<Vec<String>> {
+		let account = account.into_sub_cross_account::<T>()?;
+		Ok(<TokenPropertyWriteGrants<T>>::get((self.id, account))
+			.into_iter()
+			.map(|key| String::from_utf8(key.into_inner()).unwrap_or_default())
+			.collect())
+	}
+
 	/// @notice Set token property value.
 	/// @dev Throws error if `msg.sender` has no permission to edit the property.
 	/// @param tokenId ID of the token.
@@ -266,9 +361,8 @@ impl<T: Config> NonfungibleHandle<T> {
 			.try_into()
 			.map_err(|_| "key too long")?;
 
-		let props =
-			<TokenProperties<T>>::get((self.id, token_id)).ok_or("token properties not found")?;
-		let prop = props.get(&key).ok_or("key not found")?;
+		let prop = <Self as CommonCollectionOperations<T>>::token_property(self, token_id, &key)
+			.ok_or("key not found")?;
 
 		Ok(prop.to_vec().into())
 	}
@@ -337,6 +431,7 @@ where
 	/// @notice A distinct Uniform Resource Identifier (URI) for a given asset.
 	///
 	/// @dev If the token has a `url` property and it is not empty, it is returned.
+	///  Else if the collection property `URITemplate` is not empty, it is rendered for the token, substituting `{tokenId}`, `{prop:<key>}` token property and `{<key>}` collection property placeholders.
 	///  Else If the collection does not have a property with key `schemaName` or its value is not equal to `ERC721Metadata`, it return an error `tokenURI not set`.
 	///  If the collection property `baseURI` is empty or absent, return "" (empty string)
 	///  otherwise, if token property `suffix` present and is non-empty, return concatenation of baseURI and suffix
@@ -345,39 +440,13 @@ where
 	/// @return token's const_metadata
 	#[solidity(rename_selector = "tokenURI")]
 	fn token_uri(&self, token_id: U256) -> Result<String> {
-		let token_id_u32: u32 = token_id.try_into().map_err(|_| "token id overflow")?;
-
-		match get_token_property(self, token_id_u32, &key::url()).as_deref() {
-			Err(_) | Ok("") => (),
-			Ok(url) => {
-				return Ok(url.into());
-			}
-		};
-
-		let base_uri =
-			pallet_common::Pallet::<T>::get_collection_property(self.id, &key::base_uri())
-				.map(BoundedVec::into_inner)
-				.map(String::from_utf8)
-				.transpose()
-				.map_err(|e| {
-					Error::Revert(alloc::format!(
-						"can not convert value \"baseURI\" to string with error \"{e}\""
-					))
-				})?;
-
-		let base_uri = match base_uri.as_deref() {
-			None | Some("") => {
-				return Ok("".into());
-			}
-			Some(base_uri) => base_uri.into(),
-		};
+		let token_id: u32 = token_id.try_into().map_err(|_| "token id overflow")?;
+		self.consume_store_reads(3)?;
+		// Each placeholder of the URI template is substituted with a separately read value
+		let placeholders = <PalletCommon<T>>::collection_token_uri_placeholders(self.id);
+		self.consume_store_reads(placeholders.into())?;
 
-		Ok(
-			match get_token_property(self, token_id_u32, &key::suffix()).as_deref() {
-				Err(_) | Ok("") => base_uri,
-				Ok(suffix) => base_uri + suffix,
-			},
-		)
+		<PalletCommon<T>>::token_uri(self.id, self, token_id.into()).map_err(dispatch_to_evm::<T>)
 	}
 }
 
@@ -389,14 +458,33 @@ impl<T: Config> NonfungibleHandle<T> {
 	/// @param index A counter less than `totalSupply()`
 	/// @return The token identifier for the `index`th NFT,
 	///  (sort order not specified)
-	fn token_by_index(&self, index: U256) -> U256 {
-		index
+	fn token_by_index(&self, index: U256) -> Result<U256> {
+		self.consume_store_reads(2)?;
+		if <Pallet<T>>::enumeration_migrating() {
+			return Err("token enumeration is being migrated".into());
+		}
+		let index = index.try_into().map_err(|_| "index out of bounds")?;
+		let token = <Pallet<T>>::token_by_index(self.id, index).ok_or("index out of bounds")?;
+		Ok(token.into())
 	}
 
-	/// @dev Not implemented
-	fn token_of_owner_by_index(&self, _owner: Address, _index: U256) -> Result<U256> {
-		// TODO: Not implemetable
-		Err("not implemented".into())
+	/// @notice Enumerate NFTs assigned to an owner
+	/// @dev Throws if `index` >= `balanceOf(owner)` or if
+	///  `owner` is the zero address, representing invalid NFTs.
+	/// @param owner An address where we are interested in NFTs owned by them
+	/// @param index A counter less than `balanceOf(owner)`
+	/// @return The token identifier for the `index`th NFT assigned to `owner`,
+	///   (sort order not specified)
+	fn token_of_owner_by_index(&self, owner: Address, index: U256) -> Result<U256> {
+		self.consume_store_reads(2)?;
+		if <Pallet<T>>::enumeration_migrating() {
+			return Err("token enumeration is being migrated".into());
+		}
+		let owner = T::CrossAccountId::from_eth(owner);
+		let index = index.try_into().map_err(|_| "index out of bounds")?;
+		let token = <Pallet<T>>::token_of_owner_by_index(self.id, &owner, index)
+			.ok_or("index out of bounds")?;
+		Ok(token.into())
 	}
 
 	/// @notice Count NFTs tracked by this contract
@@ -408,6 +496,128 @@ impl<T: Config> NonfungibleHandle<T> {
 	}
 }
 
+/// @title ERC-2981 NFT Royalty Standard
+/// @dev See https://eips.ethereum.org/EIPS/eip-2981
+#[solidity_interface(name = ERC2981, expect_selector = 0x2a55205a, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NonfungibleHandle<T> {
+	/// @notice Called with the sale price to determine how much royalty
+	///  is owed and to whom.
+	/// @dev Token royalty is used if set, otherwise the collection royalty.
+	/// @param tokenId The NFT asset queried for royalty information
+	/// @param salePrice The sale price of the NFT asset specified by `tokenId`
+	/// @return Address of who should be sent the royalty payment and
+	///  the royalty payment amount for `salePrice`
+	fn royalty_info(&self, token_id: U256, sale_price: U256) -> Result<eth::RoyaltyInfo> {
+		self.consume_store_reads(2)?;
+		let token = token_id.try_into()?;
+
+		let royalty = pallet_common::Pallet::<T>::token_royalty(self.id, self, token);
+
+		Ok(royalty
+			.map(|royalty| eth::RoyaltyInfo::new::<T>(&royalty, sale_price))
+			.unwrap_or_default())
+	}
+}
+
+#[derive(ToLog)]
+pub enum ERC5192Events {
+	/// @notice Emitted when the locking status is changed to locked.
+	/// @dev If a token is minted and the status is locked, this event should be emitted.
+	Locked { token_id: U256 },
+	/// @notice Emitted when the locking status is changed to unlocked.
+	Unlocked { token_id: U256 },
+}
+
+/// @title ERC-5192 Minimal Soulbound NFTs
+/// @dev See https://eips.ethereum.org/EIPS/eip-5192
+#[solidity_interface(name = ERC5192, events(ERC5192Events), expect_selector = 0xb45a3c0e, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NonfungibleHandle<T> {
+	/// @notice Returns the locking status of a token.
+	/// @dev Locked tokens can not be transferred, but can be burnt.
+	///  Throws if `tokenId` is not a valid NFT.
+	/// @param tokenId The identifier for a token.
+	fn locked(&self, token_id: U256) -> Result<bool> {
+		self.consume_store_reads(2)?;
+		let token = token_id.try_into()?;
+
+		if !<Pallet<T>>::token_exists(self, token) {
+			return Err("token not found".into());
+		}
+		Ok(<Pallet<T>>::token_locked(self, token))
+	}
+}
+
+#[derive(ToLog)]
+pub enum ERC4907Events {
+	/// @notice Emitted when the `user` of an NFT or the `expires` of the `user` is changed.
+	///  The zero address for user indicates that there is no user address.
+	UpdateUser {
+		#[indexed]
+		token_id: U256,
+		#[indexed]
+		user: Address,
+		expires: u64,
+	},
+}
+
+/// @title ERC-4907 Rental NFT
+/// @dev See https://eips.ethereum.org/EIPS/eip-4907
+///  User expiry is measured in block numbers.
+#[solidity_interface(name = ERC4907, events(ERC4907Events), expect_selector = 0xad092b5c, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NonfungibleHandle<T> {
+	/// @notice Set the user and expires of an NFT.
+	/// @dev The zero address indicates there is no user.
+	///  The user is removed when the NFT is transferred.
+	///  Throws if `msg.sender` is not the owner of the NFT or approved to operate it.
+	///  Throws if `tokenId` is not a valid NFT.
+	/// @param tokenId The NFT to set the user of
+	/// @param user The new user of the NFT
+	/// @param expires Number of the last block in which `user` is the user of the NFT
+	#[weight(<CommonWeights<T>>::set_token_user())]
+	fn set_user(
+		&mut self,
+		caller: Caller,
+		token_id: U256,
+		user: Address,
+		expires: u64,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token = token_id.try_into()?;
+		let user = (user != Address::zero()).then(|| TokenUser {
+			user: T::CrossAccountId::from_eth(user),
+			expires,
+		});
+
+		<Pallet<T>>::set_token_user(self, &caller, token, user).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Get the user address of an NFT.
+	/// @dev The zero address indicates that there is no user or the user is expired.
+	/// @param tokenId The NFT to get the user address for
+	/// @return The user address for this NFT
+	fn user_of(&self, token_id: U256) -> Result<Address> {
+		self.consume_store_reads(1)?;
+		let token = token_id.try_into()?;
+
+		Ok(<Pallet<T>>::token_user(self, token)
+			.map(|user| *user.user.as_eth())
+			.unwrap_or_default())
+	}
+
+	/// @notice Get the user expires of an NFT.
+	/// @dev The zero value indicates that there is no user.
+	/// @param tokenId The NFT to get the user expires for
+	/// @return The last block in which the user of this NFT can use it
+	fn user_expires(&self, token_id: U256) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		let token: TokenId = token_id.try_into()?;
+
+		Ok(<TokenUsers<T>>::get((self.id, token))
+			.map(|user| user.expires.into())
+			.unwrap_or_default())
+	}
+}
+
 /// @title ERC-721 Non-Fungible Token Standard
 /// @dev See https://github.com/ethereum/EIPs/blob/master/EIPS/eip-721.md
 #[solidity_interface(name = ERC721, events(ERC721Events), enum(derive(PreDispatch)), enum_attr(weight), expect_selector = 0x80ac58cd)]
@@ -436,22 +646,47 @@ impl<T: Config> NonfungibleHandle<T> {
 			.owner
 			.as_eth())
 	}
-	/// @dev Not implemented
+	/// @notice Transfers the ownership of an NFT from one address to another address
+	/// @dev Throws unless `msg.sender` is the current owner or an authorized
+	///  operator for this NFT. Throws if `from` is not the current owner. Throws
+	///  if `to` is the zero address. Throws if `tokenId` is not a valid NFT.
+	///  When transfer is complete, this function checks if `to` is a smart
+	///  contract (code size > 0). If so, it calls `onERC721Received` on `to`
+	///  and throws if the return value is not
+	///  `bytes4(keccak256("onERC721Received(address,address,uint256,bytes)"))`.
+	/// @param from The current owner of the NFT
+	/// @param to The new owner
+	/// @param tokenId The NFT to transfer
+	/// @param data Additional data with no specified format, sent in call to `to`
 	#[solidity(rename_selector = "safeTransferFrom")]
+	#[weight(<CommonWeights<T>>::transfer_from())]
 	fn safe_transfer_from_with_data(
 		&mut self,
-		_from: Address,
-		_to: Address,
-		_token_id: U256,
-		_data: Bytes,
+		caller: Caller,
+		from: Address,
+		to: Address,
+		token_id: U256,
+		data: Bytes,
 	) -> Result<()> {
-		// TODO: Not implemetable
-		Err("not implemented".into())
+		self.transfer_from(caller, from, to, token_id)?;
+		check_on_erc721_received(&self.recorder, caller, from, to, token_id, data)
 	}
-	/// @dev Not implemented
-	fn safe_transfer_from(&mut self, _from: Address, _to: Address, _token_id: U256) -> Result<()> {
-		// TODO: Not implemetable
-		Err("not implemented".into())
+
+	/// @notice Transfers the ownership of an NFT from one address to another address
+	/// @dev This works identically to the other function with an extra data parameter,
+	///  except this function just sets data to "".
+	/// @param from The current owner of the NFT
+	/// @param to The new owner
+	/// @param tokenId The NFT to transfer
+	#[weight(<CommonWeights<T>>::transfer_from())]
+	fn safe_transfer_from(
+		&mut self,
+		caller: Caller,
+		from: Address,
+		to: Address,
+		token_id: U256,
+	) -> Result<()> {
+		self.safe_transfer_from_with_data(caller, from, to, token_id, Bytes(vec![]))
 	}
 
 	/// @notice Transfer ownership of an NFT -- THE CALLER IS RESPONSIBLE
@@ -500,7 +735,7 @@ impl<T: Config> NonfungibleHandle<T> {
 		let approved = T::CrossAccountId::from_eth(approved);
 		let token = token_id.try_into()?;
 
-		<Pallet<T>>::set_allowance(self, &caller, token, Some(&approved))
+		<Pallet<T>>::set_allowance(self, &caller, token, Some(&approved), None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(())
 	}
@@ -519,7 +754,7 @@ impl<T: Config> NonfungibleHandle<T> {
 		let caller = T::CrossAccountId::from_eth(caller);
 		let operator = T::CrossAccountId::from_eth(operator);
 
-		<Pallet<T>>::set_allowance_for_all(self, &caller, &operator, approved)
+		<Pallet<T>>::set_allowance_for_all(self, &caller, &operator, approved, None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(())
 	}
@@ -607,6 +842,7 @@ impl<T: Config> NonfungibleHandle<T> {
 			CreateItemData::<T> {
 				properties: BoundedVec::default(),
 				owner: to,
+				locked: false,
 			},
 			&nesting_budget(&self.recorder),
 		)
@@ -685,6 +921,7 @@ impl<T: Config> NonfungibleHandle<T> {
 			CreateItemData::<T> {
 				properties,
 				owner: to,
+				locked: false,
 			},
 			&nesting_budget(&self.recorder),
 		)
@@ -693,21 +930,6 @@ impl<T: Config> NonfungibleHandle<T> {
 	}
 }
 
-fn get_token_property<T: Config>(
-	collection: &CollectionHandle<T>,
-	token_id: u32,
-	key: &up_data_structs::PropertyKey,
-) -> Result<String> {
-	collection.consume_store_reads(1)?;
-	let properties = <TokenProperties<T>>::try_get((collection.id, token_id))
-		.map_err(|_| Error::Revert("token properties not found".into()))?;
-	if let Some(property) = properties.get(key) {
-		return Ok(String::from_utf8_lossy(property).into());
-	}
-
-	Err("property tokenURI not found".into())
-}
-
 fn get_token_permission<T: Config>(
 	collection_id: CollectionId,
 	key: &PropertyKey,
@@ -817,7 +1039,7 @@ where
 		let approved = approved.into_sub_cross_account::<T>()?;
 		let token = token_id.try_into()?;
 
-		<Pallet<T>>::set_allowance(self, &caller, token, Some(&approved))
+		<Pallet<T>>::set_allowance(self, &caller, token, Some(&approved), None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(())
 	}
@@ -890,6 +1112,24 @@ where
 		Ok(())
 	}
 
+	/// @notice Transfer ownership of multiple NFTs
+	/// @dev Throws unless `msg.sender` is the current owner of each NFT.
+	///  Either all NFTs are transferred, or none of them.
+	/// @param transfers Array of pairs of the new owner and the NFT to transfer
+	#[weight(<CommonWeights<T>>::transfer().saturating_mul(transfers.len() as u64))]
+	fn transfer_batch(&mut self, caller: Caller, transfers: Vec<eth::TokenTransfer>) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+
+		for eth::TokenTransfer { to, token_id } in transfers {
+			let to = to.into_sub_cross_account::<T>()?;
+			let token = token_id.try_into()?;
+
+			<Pallet<T>>::transfer(self, &caller, &to, token, &nesting_budget(&self.recorder))
+				.map_err(|e| dispatch_to_evm::<T>(e.error))?;
+		}
+		Ok(())
+	}
+
 	/// @notice Burns a specific ERC721 token.
 	/// @dev Throws unless `msg.sender` is the current owner or an authorized
 	///  operator for this NFT. Throws if `from` is not the current owner. Throws
@@ -930,6 +1170,27 @@ where
 		Ok(())
 	}
 
+	/// @notice Burns a specific ERC721 token together with the tokens nested in it.
+	/// @dev Throws unless `msg.sender` is the current owner of the NFT.
+	///  Nested tokens are either burned as well, or returned to `msg.sender`.
+	/// @param tokenId The NFT to burn
+	/// @param burnChildren Whether to burn the nested tokens instead of returning them
+	#[weight(<CommonWeights<T>>::burn_recursively(MAX_BURN_RECURSIVELY_NODES))]
+	fn burn_recursively(
+		&mut self,
+		caller: Caller,
+		token_id: U256,
+		burn_children: bool,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token = token_id.try_into()?;
+		let budget = budget::Value::new(MAX_BURN_RECURSIVELY_NODES);
+
+		<Pallet<T>>::burn_recursively(self, &caller, token, burn_children, &budget)
+			.map_err(|e| dispatch_to_evm::<T>(e.error))?;
+		Ok(())
+	}
+
 	/// @notice Returns next free NFT ID.
 	fn next_token_id(&self) -> Result<U256> {
 		self.consume_store_reads(1)?;
@@ -964,6 +1225,7 @@ where
 			.map(|_| CreateItemData::<T> {
 				properties: BoundedVec::default(),
 				owner: to.clone(),
+				locked: false,
 			})
 			.collect();
 
@@ -994,6 +1256,7 @@ where
 					.try_into()
 					.map_err(|_| "too many properties")?,
 				owner,
+				locked: false,
 			});
 		}
 
@@ -1054,6 +1317,7 @@ where
 			data.push(CreateItemData::<T> {
 				properties,
 				owner: to.clone(),
+				locked: false,
 			});
 		}
 
@@ -1094,6 +1358,7 @@ where
 			CreateItemData::<T> {
 				properties,
 				owner: to,
+				locked: false,
 			},
 			&nesting_budget(&self.recorder),
 		)
@@ -1106,6 +1371,20 @@ where
 	fn collection_helper_address(&self) -> Address {
 		T::ContractAddress::get()
 	}
+
+	/// @notice Lock or unlock the token against transfers.
+	/// @dev Throws if `msg.sender` is not the owner or admin of the collection.
+	///  Throws if `tokenId` is not a valid NFT.
+	/// @param tokenId The NFT to lock or unlock
+	/// @param locked Should the token be locked?
+	#[weight(<CommonWeights<T>>::set_token_lock())]
+	fn set_locked(&mut self, caller: Caller, token_id: U256, locked: bool) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token = token_id.try_into()?;
+
+		<Pallet<T>>::set_token_lock(self, &caller, token, locked).map_err(dispatch_to_evm::<T>)?;
+		Ok(())
+	}
 }
 
 #[solidity_interface(
@@ -1113,6 +1392,9 @@ where
 	is(
 		ERC721,
 		ERC721Enumerable,
+		ERC2981,
+		ERC5192,
+		ERC4907,
 		ERC721UniqueExtensions,
 		ERC721UniqueMintable,
 		ERC721Burnable,
@@ -71,8 +71,9 @@
 //!
 //! - `init_collection` - Create NFT collection. NFT collection can be configured to allow or deny access for
 //!   some accounts.
-//! - `destroy_collection` - Destroy exising NFT collection. There should be no tokens in the collection.
+//! - `destroy_collection` - Destroy exising NFT collection. Its tokens are removed in the background.
 //! - `burn` - Burn NFT token owned by account.
+//! - `burn_recursively` - Burn NFT token together with its nested tokens, or return them to the owner.
 //! - `transfer` - Transfer NFT token. Transfers should be enabled for NFT collection.
 //!   Nests the NFT token if it is sent to another token.
 //! - `create_item` - Mint NFT token in collection. Sender should have permission to mint tokens.
@@ -92,21 +93,25 @@
 
 use core::ops::Deref;
 
-use erc::ERC721Events;
+use erc::{ERC4907Events, ERC5192Events, ERC721Events};
 use evm_coder::ToLog;
 use frame_support::{
 	dispatch::PostDispatchInfo, ensure, fail, pallet_prelude::*, storage::with_transaction,
-	transactional,
+	transactional, weights::WeightMeter,
 };
 pub use pallet::*;
 use pallet_common::{
-	eth::collection_id_to_address, helpers::add_weight_to_post_info,
-	weights::WeightInfo as CommonWeightInfo, CollectionHandle, Error as CommonError,
-	Event as CommonEvent, Pallet as PalletCommon, SelfWeightOf as PalletCommonWeightOf,
+	dispatch::CollectionDispatch,
+	eth::collection_id_to_address,
+	helpers::{add_weight_to_post_info, migration_step_limit, StorageCleaner},
+	weights::WeightInfo as CommonWeightInfo,
+	CollectionById, CollectionHandle, CommonCollectionOperations, CommonWeightInfo as _,
+	Error as CommonError, Event as CommonEvent, FrozenTokens, Pallet as PalletCommon,
+	SelfWeightOf as PalletCommonWeightOf,
 };
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::{SubstrateRecorder, WithRecorder};
-use pallet_structure::Pallet as PalletStructure;
+use pallet_structure::{Error as StructureError, Pallet as PalletStructure};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::H160;
@@ -118,7 +123,7 @@ use up_data_structs::{
 	budget::Budget, mapping::TokenAddressMapping, AccessMode, AuxPropertyValue, CollectionId,
 	CreateNftExData, CustomDataLimit, PropertiesPermissionMap, Property, PropertyKey,
 	PropertyKeyPermission, PropertyScope, PropertyValue, TokenChild, TokenId,
-	TokenProperties as TokenPropertiesT,
+	TokenProperties as TokenPropertiesT, TokenUser,
 };
 use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
@@ -127,6 +132,9 @@ pub mod common;
 pub mod erc;
 pub mod weights;
 
+/// Limit for searching the owners of tokens nested in a destroyed collection.
+const DESTROYED_NESTING_BUDGET: u32 = 5;
+
 pub type CreateItemData<T> = CreateNftExData<<T as pallet_evm::Config>::CrossAccountId>;
 pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
 
@@ -144,11 +152,21 @@ pub struct ItemData<CrossAccountId> {
 	pub owner: CrossAccountId,
 }
 
+/// Step of the multi-block migration filling the token enumeration.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Debug)]
+pub enum EnumerationMigrationStep {
+	/// Enumerating the tokens following the given one in [`TokenData`].
+	Tokens(Option<(CollectionId, TokenId)>),
+	/// Removing the enumeration lengths tracked during the migration.
+	Cleanup,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*, storage::Key, traits::StorageVersion, Blake2_128Concat, Twox64Concat,
 	};
+	use frame_system::pallet_prelude::BlockNumberFor;
 	use up_data_structs::{CollectionId, TokenId};
 
 	use super::{weights::WeightInfo, *};
@@ -170,7 +188,7 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -237,6 +255,42 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Collection-wide enumeration of existing tokens, indexed from `0` to `total_supply - 1`.
+	#[pallet::storage]
+	pub type TokenByIndex<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, u32>),
+		Value = TokenId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Position of a token in [`TokenByIndex`].
+	#[pallet::storage]
+	pub type TokenIndex<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = u32,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Per-owner enumeration of tokens, indexed from `0` to `balance - 1`.
+	#[pallet::storage]
+	pub type OwnedTokenByIndex<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+			Key<Twox64Concat, u32>,
+		),
+		Value = TokenId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Position of a token in [`OwnedTokenByIndex`] of its current owner.
+	#[pallet::storage]
+	pub type OwnedTokenIndex<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = u32,
+		QueryKind = OptionQuery,
+	>;
+
 	/// Used to enumerate token's children.
 	#[pallet::storage]
 	#[pallet::getter(fn token_children)]
@@ -281,6 +335,65 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Deadlines of the token [`Allowance`]s, after which they can no longer be used.
+	#[pallet::storage]
+	pub type AllowanceDeadline<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = u64,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Deadlines of the [`CollectionAllowance`]s, after which they can no longer be used.
+	#[pallet::storage]
+	pub type CollectionAllowanceDeadline<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = u64,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Tokens locked against transfers.
+	#[pallet::storage]
+	pub type LockedTokens<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// ERC-4907 users of tokens, allowed to use them until the expiry block.
+	#[pallet::storage]
+	pub type TokenUsers<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = TokenUser<T::CrossAccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Progress of the multi-block migration filling the enumeration of existing tokens.
+	///
+	/// Exists only while the migration is running.
+	#[pallet::storage]
+	pub type EnumerationMigration<T> =
+		StorageValue<Value = EnumerationMigrationStep, QueryKind = OptionQuery>;
+
+	/// Length of [`TokenByIndex`], tracked while [`EnumerationMigration`] is running.
+	#[pallet::storage]
+	pub type EnumeratedTokens<T: Config> =
+		StorageMap<Hasher = Twox64Concat, Key = CollectionId, Value = u32, QueryKind = ValueQuery>;
+
+	/// Length of [`OwnedTokenByIndex`], tracked while [`EnumerationMigration`] is running.
+	#[pallet::storage]
+	pub type EnumeratedOwnedTokens<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = u32,
+		QueryKind = ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T>(PhantomData<T>);
 
@@ -293,7 +406,29 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
-			StorageVersion::new(1).put::<Pallet<T>>();
+			STORAGE_VERSION.put::<Pallet<T>>();
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut meter = WeightMeter::with_limit(migration_step_limit::<T>());
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_ok() {
+				Self::migrate_token_enumeration(&mut meter);
+			}
+			meter.consumed()
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if Self::on_chain_storage_version() < 2_u16 {
+				// The enumeration is filled by `on_initialize` over the following blocks
+				<EnumerationMigration<T>>::put(EnumerationMigrationStep::Tokens(None));
+				STORAGE_VERSION.put::<Self>();
+				T::DbWeight::get().writes(2)
+			} else {
+				Weight::zero()
+			}
 		}
 	}
 }
@@ -378,37 +513,298 @@ impl<T: Config> Pallet<T> {
 	pub fn current_token_id(collection_id: CollectionId) -> TokenId {
 		TokenId(<TokensMinted<T>>::get(collection_id))
 	}
+
+	/// Get the `index`th existing token of the collection.
+	///
+	/// Order is not specified and may change after burns.
+	pub fn token_by_index(collection_id: CollectionId, index: u32) -> Option<TokenId> {
+		<TokenByIndex<T>>::get((collection_id, index))
+	}
+
+	/// Get the `index`th token owned by `owner` in the collection.
+	///
+	/// Order is not specified and may change after transfers and burns.
+	pub fn token_of_owner_by_index(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		index: u32,
+	) -> Option<TokenId> {
+		<OwnedTokenByIndex<T>>::get((collection_id, owner, index))
+	}
+
+	/// Whether [`EnumerationMigration`] is still filling the enumeration of existing tokens.
+	///
+	/// Until it finishes, the enumeration doesn't contain all tokens, so its length
+	/// is tracked by [`EnumeratedTokens`] and [`EnumeratedOwnedTokens`] instead of the balances.
+	pub fn enumeration_migrating() -> bool {
+		matches!(
+			<EnumerationMigration<T>>::get(),
+			Some(EnumerationMigrationStep::Tokens(_))
+		)
+	}
+
+	/// Append `token` to the collection enumeration at `index`.
+	fn add_token_to_collection_enumeration(
+		collection_id: CollectionId,
+		token: TokenId,
+		index: u32,
+	) {
+		let index = if Self::enumeration_migrating() {
+			<EnumeratedTokens<T>>::mutate(collection_id, |len| {
+				*len += 1;
+				*len - 1
+			})
+		} else {
+			index
+		};
+		Self::set_collection_enumeration_index(collection_id, token, index);
+	}
+
+	fn set_collection_enumeration_index(collection_id: CollectionId, token: TokenId, index: u32) {
+		<TokenByIndex<T>>::insert((collection_id, index), token);
+		<TokenIndex<T>>::insert((collection_id, token), index);
+	}
+
+	/// Remove `token` from the collection enumeration,
+	/// moving the token at `last_index` into the freed slot.
+	fn remove_token_from_collection_enumeration(
+		collection_id: CollectionId,
+		token: TokenId,
+		last_index: u32,
+	) {
+		let Some(index) = <TokenIndex<T>>::take((collection_id, token)) else {
+			return;
+		};
+		let last_index = if Self::enumeration_migrating() {
+			<EnumeratedTokens<T>>::mutate(collection_id, |len| {
+				*len = len.saturating_sub(1);
+				*len
+			})
+		} else {
+			last_index
+		};
+		if index != last_index {
+			if let Some(last_token) = <TokenByIndex<T>>::get((collection_id, last_index)) {
+				Self::set_collection_enumeration_index(collection_id, last_token, index);
+			}
+		}
+		<TokenByIndex<T>>::remove((collection_id, last_index));
+	}
+
+	/// Append `token` to the enumeration of tokens owned by `owner` at `index`.
+	fn add_token_to_owner_enumeration(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		token: TokenId,
+		index: u32,
+	) {
+		let index = if Self::enumeration_migrating() {
+			<EnumeratedOwnedTokens<T>>::mutate((collection_id, owner), |len| {
+				*len += 1;
+				*len - 1
+			})
+		} else {
+			index
+		};
+		Self::set_owner_enumeration_index(collection_id, owner, token, index);
+	}
+
+	fn set_owner_enumeration_index(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		token: TokenId,
+		index: u32,
+	) {
+		<OwnedTokenByIndex<T>>::insert((collection_id, owner, index), token);
+		<OwnedTokenIndex<T>>::insert((collection_id, token), index);
+	}
+
+	/// Remove `token` from the enumeration of tokens owned by `owner`,
+	/// moving the token at `last_index` into the freed slot.
+	fn remove_token_from_owner_enumeration(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		token: TokenId,
+		last_index: u32,
+	) {
+		let Some(index) = <OwnedTokenIndex<T>>::take((collection_id, token)) else {
+			return;
+		};
+		let last_index = if Self::enumeration_migrating() {
+			<EnumeratedOwnedTokens<T>>::mutate((collection_id, owner), |len| {
+				*len = len.saturating_sub(1);
+				*len
+			})
+		} else {
+			last_index
+		};
+		if index != last_index {
+			if let Some(last_token) =
+				<OwnedTokenByIndex<T>>::get((collection_id, owner, last_index))
+			{
+				Self::set_owner_enumeration_index(collection_id, owner, last_token, index);
+			}
+		}
+		<OwnedTokenByIndex<T>>::remove((collection_id, owner, last_index));
+	}
+
+	/// Run the next step of [`EnumerationMigration`], as far as `meter` allows.
+	///
+	/// Tokens are enumerated in the order of [`TokenData`], skipping the ones
+	/// which were already enumerated by mints and transfers since the migration started.
+	fn migrate_token_enumeration(meter: &mut WeightMeter) {
+		let Some(step) = <EnumerationMigration<T>>::get() else {
+			return;
+		};
+		if let EnumerationMigrationStep::Tokens(mut last) = step {
+			let mut tokens = match last {
+				Some(key) => <TokenData<T>>::iter_from(<TokenData<T>>::hashed_key_for(key)),
+				None => <TokenData<T>>::iter(),
+			};
+			loop {
+				if meter
+					.try_consume(T::DbWeight::get().reads_writes(7, 6))
+					.is_err()
+				{
+					<EnumerationMigration<T>>::put(EnumerationMigrationStep::Tokens(last));
+					return;
+				}
+				let Some(((collection_id, token), data)) = tokens.next() else {
+					break;
+				};
+				last = Some((collection_id, token));
+				// Storage of destroyed collections is being removed
+				if !<CollectionById<T>>::contains_key(collection_id) {
+					continue;
+				}
+				if !<TokenIndex<T>>::contains_key((collection_id, token)) {
+					let index = <EnumeratedTokens<T>>::get(collection_id);
+					Self::add_token_to_collection_enumeration(collection_id, token, index);
+				}
+				if !<OwnedTokenIndex<T>>::contains_key((collection_id, token)) {
+					let index = <EnumeratedOwnedTokens<T>>::get((collection_id, &data.owner));
+					Self::add_token_to_owner_enumeration(collection_id, &data.owner, token, index);
+				}
+			}
+			<EnumerationMigration<T>>::put(EnumerationMigrationStep::Cleanup);
+		}
+
+		let mut cleaner = StorageCleaner::<T>::new(meter);
+		if cleaner.clear_prefix(|limit| <EnumeratedTokens<T>>::clear(limit, None))
+			&& cleaner.clear_prefix(|limit| <EnumeratedOwnedTokens<T>>::clear(limit, None))
+		{
+			<EnumerationMigration<T>>::kill();
+		}
+	}
 }
 
 // unchecked calls skips any permission checks
 impl<T: Config> Pallet<T> {
 	/// Destroy NFT collection
 	///
-	/// `destroy_collection` will throw error if collection contains any tokens.
+	/// The tokens of the collection are removed later by [`Self::clear_destroyed_collection`].
 	/// Only owner can destroy collection.
 	pub fn destroy_collection(
 		collection: NonfungibleHandle<T>,
 		sender: &T::CrossAccountId,
 	) -> DispatchResult {
-		let id = collection.id;
+		PalletCommon::destroy_collection(collection.0, sender)
+	}
 
-		if Self::collection_has_tokens(id) {
-			return Err(<CommonError<T>>::CantDestroyNotEmptyCollection.into());
+	/// Remove the tokens of the destroyed NFT collection, as far as `cleaner` allows.
+	///
+	/// Tokens of other collections nested in the removed tokens are returned to
+	/// the owners of their topmost parents from this collection, each costing
+	/// `child_transfer_weight` in addition to the lookup of the owners.
+	/// If a token can't be returned, the removal stops to be retried later.
+	/// Returns `true` once there is nothing left to remove.
+	pub fn clear_destroyed_collection(
+		id: CollectionId,
+		cleaner: &mut StorageCleaner<T>,
+		child_transfer_weight: Weight,
+	) -> bool {
+		while let Some(((token, child), _)) = <TokenChildren<T>>::iter_prefix((id,)).next() {
+			let foreign = child.0 != id;
+			let extra = if foreign {
+				child_transfer_weight
+					+ <PalletStructure<T>>::return_nested_token_weight()
+					+ T::DbWeight::get().reads(DESTROYED_NESTING_BUDGET.into())
+			} else {
+				Weight::zero()
+			};
+			if !cleaner.remove_entry(extra) {
+				return false;
+			}
+			if foreign {
+				if let Some(owner) = Self::destroyed_token_owner(id, token) {
+					if <PalletStructure<T>>::return_nested_token(
+						(id, token),
+						&owner,
+						child.0,
+						child.1,
+					)
+					.is_err()
+					{
+						return false;
+					}
+				}
+			}
+			<TokenChildren<T>>::remove((id, token, child));
 		}
 
-		// =========
+		while let Some((token, data)) = <TokenData<T>>::iter_prefix((id,)).next() {
+			if !cleaner.remove_entry(T::DbWeight::get().reads_writes(2, 2)) {
+				return false;
+			}
+			<TokenData<T>>::remove((id, token));
+			<PalletStructure<T>>::unnest_destroyed(&data.owner, id, token);
+			T::OnTokenTransfer::on_token_transfer(id, token, &data.owner);
+		}
 
-		PalletCommon::destroy_collection(collection.0, sender)?;
+		let done = cleaner
+			.clear_prefix(|limit| <TokenProperties<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <TokenAuxProperties<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <Owned<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <TokenByIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <TokenIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <OwnedTokenByIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <OwnedTokenIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <Allowance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <AccountBalance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <CollectionAllowance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <AllowanceDeadline<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| {
+				<CollectionAllowanceDeadline<T>>::clear_prefix((id,), limit, None)
+			}) && cleaner
+			.clear_prefix(|limit| <LockedTokens<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <TokenUsers<T>>::clear_prefix((id,), limit, None));
+		if done {
+			<TokensMinted<T>>::remove(id);
+			<TokensBurnt<T>>::remove(id);
+		}
+		done
+	}
 
-		let _ = <TokenData<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <TokenChildren<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <Owned<T>>::clear_prefix((id,), u32::MAX, None);
-		<TokensMinted<T>>::remove(id);
-		<TokensBurnt<T>>::remove(id);
-		let _ = <Allowance<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <AccountBalance<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <CollectionAllowance<T>>::clear_prefix((id,), u32::MAX, None);
-		Ok(())
+	/// Find the owner of the token of the destroyed collection,
+	/// skipping its parents from the same collection.
+	fn destroyed_token_owner(
+		collection_id: CollectionId,
+		mut token: TokenId,
+	) -> Option<T::CrossAccountId> {
+		for _ in 0..DESTROYED_NESTING_BUDGET {
+			let owner = <TokenData<T>>::get((collection_id, token))?.owner;
+			match T::CrossTokenAddressMapping::address_to_token(&owner) {
+				Some((parent_collection, parent)) if parent_collection == collection_id => {
+					token = parent
+				}
+				_ => return Some(owner),
+			}
+		}
+		None
 	}
 
 	/// Burn NFT token
@@ -447,6 +843,7 @@ impl<T: Config> Pallet<T> {
 
 		// =========
 
+		T::OnTokenTransfer::before_balance_change(collection.id, token, &token_data.owner);
 		if balance == 0 {
 			<AccountBalance<T>>::remove((collection.id, token_data.owner.clone()));
 		} else {
@@ -456,11 +853,23 @@ impl<T: Config> Pallet<T> {
 		<PalletStructure<T>>::unnest_if_nested(&token_data.owner, collection.id, token);
 
 		<Owned<T>>::remove((collection.id, &token_data.owner, token));
+		Self::remove_token_from_owner_enumeration(collection.id, &token_data.owner, token, balance);
+		Self::remove_token_from_collection_enumeration(
+			collection.id,
+			token,
+			<TokensMinted<T>>::get(collection.id) - burnt,
+		);
 		<TokensBurnt<T>>::insert(collection.id, burnt);
 		<TokenData<T>>::remove((collection.id, token));
 		<TokenProperties<T>>::remove((collection.id, token));
 		let _ = <TokenAuxProperties<T>>::clear_prefix((collection.id, token), u32::MAX, None);
+		<LockedTokens<T>>::remove((collection.id, token));
+		<FrozenTokens<T>>::remove((collection.id, token));
+		<PalletCommon<T>>::remove_token_properties_freeze(collection.id, token);
+		<TokenUsers<T>>::remove((collection.id, token));
 		let old_spender = <Allowance<T>>::take((collection.id, token));
+		<AllowanceDeadline<T>>::remove((collection.id, token));
+		T::OnTokenTransfer::on_token_transfer(collection.id, token, &token_data.owner);
 
 		if let Some(old_spender) = old_spender {
 			<PalletCommon<T>>::deposit_event(CommonEvent::Approved(
@@ -489,6 +898,64 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Burn NFT token together with the tokens nested in it
+	///
+	/// Nested tokens are either burned recursively, or transferred to the `sender`
+	/// if `burn_children` is false. Each visited token consumes the `budget`.
+	///
+	/// - `token`: Token that should be burned
+	/// - `collection`: Collection that contains the token
+	/// - `burn_children`: Whether to burn nested tokens instead of returning them
+	/// - `budget`: Limit for the number of visited tokens
+	#[transactional]
+	pub fn burn_recursively(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::CrossAccountId,
+		token: TokenId,
+		burn_children: bool,
+		budget: &dyn Budget,
+	) -> DispatchResultWithPostInfo {
+		ensure!(budget.consume(), <StructureError<T>>::BreadthLimit);
+		let token_data =
+			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
+		ensure!(&token_data.owner == sender, <CommonError<T>>::NoPermission);
+
+		let token_address = T::CrossTokenAddressMapping::token_to_address(collection.id, token);
+		let mut actual_weight = <SelfWeightOf<T>>::burn_item();
+
+		for child in Self::token_children_ids(collection.id, token) {
+			let post_info = if burn_children {
+				<PalletStructure<T>>::burn_item_recursively(
+					token_address.clone(),
+					child.collection,
+					child.token,
+					true,
+					budget,
+				)?
+			} else {
+				ensure!(budget.consume(), <StructureError<T>>::BreadthLimit);
+				let dispatch = T::CollectionDispatch::dispatch(child.collection)?;
+				let dispatch = dispatch.as_dyn();
+				let amount = dispatch.balance(token_address.clone(), child.token);
+				dispatch.transfer(
+					token_address.clone(),
+					sender.clone(),
+					child.token,
+					amount,
+					budget,
+				)?
+			};
+			actual_weight =
+				actual_weight.saturating_add(post_info.actual_weight.unwrap_or_default());
+		}
+
+		Self::burn(collection, sender, token)?;
+		Ok(PostDispatchInfo {
+			actual_weight: Some(actual_weight),
+			pays_fee: Pays::Yes,
+		})
+	}
+
 	/// A batch operation to add, edit or remove properties for a token.
 	///
 	/// - `nesting_budget`: Limit for searching parents in-depth to check ownership.
@@ -693,8 +1160,9 @@ impl<T: Config> Pallet<T> {
 	/// `from` account stops being the owner and `to` account becomes the owner of the token.
 	/// If `to` is token than `to` becomes owner of the token and the token become nested.
 	/// Unnests token from previous parent if it was nested before.
-	/// Removes allowance for the token if there was any.
-	/// Throws if transfers aren't allowed for collection or if receiver reached token ownership limit.
+	/// Removes allowance and ERC-4907 user for the token if there was any.
+	/// Throws if transfers aren't allowed for collection, if the token is locked or frozen,
+	/// if the sender is frozen or if receiver reached token ownership limit.
 	///
 	/// - `nesting_budget`: Limit for token nesting depth
 	pub fn transfer(
@@ -728,6 +1196,13 @@ impl<T: Config> Pallet<T> {
 		let token_data =
 			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
 		ensure!(&token_data.owner == from, <CommonError<T>>::NoPermission);
+		ensure!(
+			!<LockedTokens<T>>::get((collection.id, token)),
+			<CommonError<T>>::TokenTransferLocked
+		);
+		collection.check_token_not_frozen(token)?;
+		collection.check_account_not_frozen(from)?;
+		T::OnTokenTransfer::check_token_transfer(collection.id, token, from, to)?;
 
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(from)?;
@@ -770,6 +1245,8 @@ impl<T: Config> Pallet<T> {
 
 		if let Some(balance_to) = balance_to {
 			// from != to
+			T::OnTokenTransfer::before_balance_change(collection.id, token, from);
+			T::OnTokenTransfer::before_balance_change(collection.id, token, to);
 			if balance_from == 0 {
 				<AccountBalance<T>>::remove((collection.id, from));
 			} else {
@@ -778,8 +1255,14 @@ impl<T: Config> Pallet<T> {
 			<AccountBalance<T>>::insert((collection.id, to), balance_to);
 			<Owned<T>>::remove((collection.id, from, token));
 			<Owned<T>>::insert((collection.id, to, token), true);
+			Self::remove_token_from_owner_enumeration(collection.id, from, token, balance_from);
+			Self::add_token_to_owner_enumeration(collection.id, to, token, balance_to - 1);
+			T::OnTokenTransfer::on_token_transfer(collection.id, token, from);
 		}
 		Self::set_allowance_unchecked(collection, from, token, None, true);
+		if from != to && <TokenUsers<T>>::take((collection.id, token)).is_some() {
+			Self::deposit_user_events(collection, token, None);
+		}
 
 		<PalletEvm<T>>::deposit_log(
 			ERC721Events::Transfer {
@@ -809,6 +1292,7 @@ impl<T: Config> Pallet<T> {
 	/// to allow public minting.
 	/// Throws if amount of tokens reached it's limit for the collection or if caller reached
 	/// token ownership limit.
+	/// Only the owner/admin of the collection can mint locked tokens.
 	///
 	/// - `data`: Contains list of token properties and users who will become the owners of the
 	///   corresponging tokens.
@@ -819,7 +1303,7 @@ impl<T: Config> Pallet<T> {
 		data: Vec<CreateItemData<T>>,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		if !collection.is_owner_or_admin(sender) {
+		if !collection.has_admin_role(sender, |roles| roles.minter) {
 			ensure!(
 				collection.permissions.mint_mode(),
 				<CommonError<T>>::PublicMintingNotAllowed
@@ -828,6 +1312,7 @@ impl<T: Config> Pallet<T> {
 
 			for item in data.iter() {
 				collection.check_allowlist(&item.owner)?;
+				ensure!(!item.locked, <CommonError<T>>::NoPermission);
 			}
 		}
 
@@ -908,7 +1393,24 @@ impl<T: Config> Pallet<T> {
 			TransactionOutcome::Commit(Ok(()))
 		})?;
 
+		let first_index = first_token - <TokensBurnt<T>>::get(collection.id);
+		let mut owner_indices = BTreeMap::new();
+		for (i, data) in data.iter().enumerate() {
+			let token = TokenId(first_token + i as u32 + 1);
+			let owner_index = owner_indices
+				.entry(&data.owner)
+				.or_insert_with(|| <AccountBalance<T>>::get((collection.id, &data.owner)));
+
+			Self::add_token_to_collection_enumeration(collection.id, token, first_index + i as u32);
+			Self::add_token_to_owner_enumeration(collection.id, &data.owner, token, *owner_index);
+			*owner_index += 1;
+		}
+
 		<TokensMinted<T>>::insert(collection.id, tokens_minted);
+		for (i, data) in data.iter().enumerate() {
+			let token = TokenId(first_token + i as u32 + 1);
+			T::OnTokenTransfer::before_balance_change(collection.id, token, &data.owner);
+		}
 		for (account, balance) in balances {
 			<AccountBalance<T>>::insert((collection.id, account), balance);
 		}
@@ -930,6 +1432,11 @@ impl<T: Config> Pallet<T> {
 				data.owner.clone(),
 				1,
 			));
+
+			if data.locked {
+				<LockedTokens<T>>::insert((collection.id, TokenId(token)), true);
+				Self::deposit_lock_events(collection, TokenId(token), true);
+			}
 		}
 		Ok(())
 	}
@@ -976,6 +1483,7 @@ impl<T: Config> Pallet<T> {
 			}
 		} else {
 			let old_spender = <Allowance<T>>::take((collection.id, token));
+			<AllowanceDeadline<T>>::remove((collection.id, token));
 			if !assume_implicit_eth {
 				// In ERC721 there is only one possible approved user of token, so we set
 				// approved user to zero address
@@ -1010,17 +1518,36 @@ impl<T: Config> Pallet<T> {
 			<TokenData<T>>::get((collection.id, token_id)).is_some(),
 			<CommonError<T>>::TokenNotFound
 		);
-		Ok(<Allowance<T>>::get((collection.id, token_id)))
+		Ok(Self::approved_spender(collection, token_id))
+	}
+
+	/// Get the spender approved to `transfer` or `burn` the token, unless the approval has expired.
+	pub fn approved_spender(
+		collection: &NonfungibleHandle<T>,
+		token: TokenId,
+	) -> Option<T::CrossAccountId> {
+		let deadline = <AllowanceDeadline<T>>::get((collection.id, token));
+		if <PalletCommon<T>>::deadline_passed(deadline) {
+			return None;
+		}
+		<Allowance<T>>::get((collection.id, token))
+	}
+
+	/// Get the deadline of the approval to `transfer` or `burn` the token, if it has one.
+	pub fn allowance_deadline(collection: &NonfungibleHandle<T>, token: TokenId) -> Option<u64> {
+		<AllowanceDeadline<T>>::get((collection.id, token))
 	}
 
 	/// Set allowance for the spender to `transfer` or `burn` sender's token.
 	///
 	/// - `token`: Token the spender is allowed to `transfer` or `burn`.
+	/// - `deadline`: Last block in which the spender can use the allowance.
 	pub fn set_allowance(
 		collection: &NonfungibleHandle<T>,
 		sender: &T::CrossAccountId,
 		token: TokenId,
 		spender: Option<&T::CrossAccountId>,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(sender)?;
@@ -1032,6 +1559,8 @@ impl<T: Config> Pallet<T> {
 		if let Some(spender) = spender {
 			<PalletCommon<T>>::ensure_correct_receiver(spender)?;
 		}
+		collection.check_account_not_frozen(sender)?;
+		collection.check_token_not_frozen(token)?;
 
 		let token_data =
 			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
@@ -1041,10 +1570,12 @@ impl<T: Config> Pallet<T> {
 				<CommonError<T>>::CantApproveMoreThanOwned
 			);
 		}
+		<PalletCommon<T>>::check_deadline(deadline)?;
 
 		// =========
 
 		Self::set_allowance_unchecked(collection, sender, token, spender, false);
+		<AllowanceDeadline<T>>::set((collection.id, token), spender.and(deadline));
 		Ok(())
 	}
 
@@ -1053,12 +1584,14 @@ impl<T: Config> Pallet<T> {
 	/// - `from`: Address of sender's eth mirror.
 	/// - `to`: Adress of spender.
 	/// - `token`: Token the spender is allowed to `transfer` or `burn`.
+	/// - `deadline`: Last block in which the spender can use the allowance.
 	pub fn set_allowance_from(
 		collection: &NonfungibleHandle<T>,
 		sender: &T::CrossAccountId,
 		from: &T::CrossAccountId,
 		token: TokenId,
 		to: Option<&T::CrossAccountId>,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(sender)?;
@@ -1076,20 +1609,25 @@ impl<T: Config> Pallet<T> {
 			sender.conv_eq(from),
 			<CommonError<T>>::AddressIsNotEthMirror
 		);
+		collection.check_account_not_frozen(from)?;
+		collection.check_token_not_frozen(token)?;
 
 		let token_data =
 			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
 		if token_data.owner != *from {
 			ensure!(
 				collection.limits.owner_can_transfer()
-					&& (collection.is_owner_or_admin(sender) || collection.is_owner_or_admin(from)),
+					&& (collection.has_admin_role(sender, |roles| roles.token_manager)
+						|| collection.has_admin_role(from, |roles| roles.token_manager)),
 				<CommonError<T>>::CantApproveMoreThanOwned
 			);
 		}
+		<PalletCommon<T>>::check_deadline(deadline)?;
 
 		// =========
 
 		Self::set_allowance_unchecked(collection, from, token, to, false);
+		<AllowanceDeadline<T>>::set((collection.id, token), to.and(deadline));
 		Ok(())
 	}
 
@@ -1126,10 +1664,10 @@ impl<T: Config> Pallet<T> {
 			);
 			return Ok(());
 		}
-		if <Allowance<T>>::get((collection.id, token)).as_ref() == Some(spender) {
+		if Self::approved_spender(collection, token).as_ref() == Some(spender) {
 			return Ok(());
 		}
-		if <CollectionAllowance<T>>::get((collection.id, from, spender)) {
+		if Self::allowance_for_all(collection, from, spender) {
 			return Ok(());
 		}
 
@@ -1150,6 +1688,7 @@ impl<T: Config> Pallet<T> {
 		token: TokenId,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResultWithPostInfo {
+		collection.check_account_not_frozen(spender)?;
 		Self::check_allowed(collection, spender, from, token, nesting_budget)?;
 
 		// =========
@@ -1190,6 +1729,7 @@ impl<T: Config> Pallet<T> {
 		under: TokenId,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
+		handle.check_token_not_frozen(under)?;
 		let nesting = handle.permissions.nesting();
 
 		#[cfg(not(feature = "runtime-benchmarks"))]
@@ -1211,7 +1751,9 @@ impl<T: Config> Pallet<T> {
 				nesting_budget,
 			)? {
 			// Pass, token existence and ouroboros checks are done in `check_indirectly_owned`
-		} else if nesting.collection_admin && handle.is_owner_or_admin(sender) {
+		} else if nesting.collection_admin
+			&& handle.has_admin_role(sender, |roles| roles.token_manager)
+		{
 			// token existence and ouroboros checks are done in `get_checked_topmost_owner`
 			let _ = <PalletStructure<T>>::get_checked_topmost_owner(
 				handle.id,
@@ -1245,12 +1787,6 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	fn collection_has_tokens(collection_id: CollectionId) -> bool {
-		<TokenData<T>>::iter_prefix((collection_id,))
-			.next()
-			.is_some()
-	}
-
 	fn token_has_children(collection_id: CollectionId, token_id: TokenId) -> bool {
 		<TokenChildren<T>>::iter_prefix((collection_id, token_id))
 			.next()
@@ -1286,18 +1822,27 @@ impl<T: Config> Pallet<T> {
 	/// - `owner`: Token owner
 	/// - `operator`: Operator
 	/// - `approve`: Should operator status be granted or revoked?
+	/// - `deadline`: Last block in which the operator can use the granted status.
 	pub fn set_allowance_for_all(
 		collection: &NonfungibleHandle<T>,
 		owner: &T::CrossAccountId,
 		operator: &T::CrossAccountId,
 		approve: bool,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		<PalletCommon<T>>::set_allowance_for_all(
 			collection,
 			owner,
 			operator,
 			approve,
-			|| <CollectionAllowance<T>>::insert((collection.id, owner, operator), approve),
+			deadline,
+			|| {
+				<CollectionAllowance<T>>::insert((collection.id, owner, operator), approve);
+				<CollectionAllowanceDeadline<T>>::set(
+					(collection.id, owner, operator),
+					deadline.filter(|_| approve),
+				);
+			},
 			ERC721Events::ApprovalForAll {
 				owner: *owner.as_eth(),
 				operator: *operator.as_eth(),
@@ -1307,13 +1852,24 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	/// Tells whether the given `owner` approves the `operator`.
+	/// Tells whether the given `owner` approves the `operator`, and the approval hasn't expired.
 	pub fn allowance_for_all(
 		collection: &NonfungibleHandle<T>,
 		owner: &T::CrossAccountId,
 		operator: &T::CrossAccountId,
 	) -> bool {
+		let deadline = <CollectionAllowanceDeadline<T>>::get((collection.id, owner, operator));
 		<CollectionAllowance<T>>::get((collection.id, owner, operator))
+			&& !<PalletCommon<T>>::deadline_passed(deadline)
+	}
+
+	/// Get the deadline of the approval of the `operator` by the `owner`, if it has one.
+	pub fn allowance_for_all_deadline(
+		collection: &NonfungibleHandle<T>,
+		owner: &T::CrossAccountId,
+		operator: &T::CrossAccountId,
+	) -> Option<u64> {
+		<CollectionAllowanceDeadline<T>>::get((collection.id, owner, operator))
 	}
 
 	pub fn repair_item(collection: &NonfungibleHandle<T>, token: TokenId) -> DispatchResult {
@@ -1325,4 +1881,125 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Lock or unlock the token against transfers.
+	///
+	/// Only the owner or admin of the collection with the allowlist manager role can change the lock.
+	/// Locked tokens can still be burnt.
+	///
+	/// - `token`: Token to lock or unlock.
+	/// - `locked`: Should the token be locked?
+	pub fn set_token_lock(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::CrossAccountId,
+		token: TokenId,
+		locked: bool,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.allowlist_manager)?;
+		let token_data =
+			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
+		ensure!(
+			!locked || token_data.owner != <PalletCommon<T>>::swap_escrow(),
+			<CommonError<T>>::AssetIsEscrowed
+		);
+
+		// =========
+
+		if locked {
+			<LockedTokens<T>>::insert((collection.id, token), true);
+		} else {
+			<LockedTokens<T>>::remove((collection.id, token));
+		}
+
+		Self::deposit_lock_events(collection, token, locked);
+		Ok(())
+	}
+
+	/// Tells whether the token is locked against transfers.
+	pub fn token_locked(collection: &NonfungibleHandle<T>, token: TokenId) -> bool {
+		<LockedTokens<T>>::get((collection.id, token))
+	}
+
+	fn deposit_lock_events(collection: &NonfungibleHandle<T>, token: TokenId, locked: bool) {
+		let log = if locked {
+			ERC5192Events::Locked {
+				token_id: token.into(),
+			}
+		} else {
+			ERC5192Events::Unlocked {
+				token_id: token.into(),
+			}
+		};
+		<PalletEvm<T>>::deposit_log(log.to_log(collection_id_to_address(collection.id)));
+		<PalletCommon<T>>::deposit_event(CommonEvent::TokenLockChanged(
+			collection.id,
+			token,
+			locked,
+		));
+	}
+
+	/// Set or remove the ERC-4907 user of the token.
+	///
+	/// The user is allowed to use the token until the `expires` block of the record, and
+	/// is removed when the token is transferred.
+	/// Only the owner of the token, its approved spender or an operator approved
+	/// for all of the owner's tokens can change the user.
+	///
+	/// - `token`: Token to change the user of.
+	/// - `user`: New user of the token with its expiry block, `None` removes the user.
+	pub fn set_token_user(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::CrossAccountId,
+		token: TokenId,
+		user: Option<TokenUser<T::CrossAccountId>>,
+	) -> DispatchResult {
+		let token_data =
+			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
+		if &token_data.owner != sender {
+			ensure!(
+				Self::approved_spender(collection, token).as_ref() == Some(sender)
+					|| Self::allowance_for_all(collection, &token_data.owner, sender),
+				<CommonError<T>>::NoPermission
+			);
+		}
+		collection.check_token_not_frozen(token)?;
+		if let Some(user) = &user {
+			<PalletCommon<T>>::ensure_correct_receiver(&user.user)?;
+		}
+
+		// =========
+
+		<TokenUsers<T>>::set((collection.id, token), user.clone());
+
+		Self::deposit_user_events(collection, token, user);
+		Ok(())
+	}
+
+	/// Get the ERC-4907 user of the token, if it has an unexpired one.
+	pub fn token_user(
+		collection: &NonfungibleHandle<T>,
+		token: TokenId,
+	) -> Option<TokenUser<T::CrossAccountId>> {
+		let current_block = <PalletCommon<T>>::block_number();
+		<TokenUsers<T>>::get((collection.id, token)).filter(|user| user.expires >= current_block)
+	}
+
+	fn deposit_user_events(
+		collection: &NonfungibleHandle<T>,
+		token: TokenId,
+		user: Option<TokenUser<T::CrossAccountId>>,
+	) {
+		<PalletEvm<T>>::deposit_log(
+			ERC4907Events::UpdateUser {
+				token_id: token.into(),
+				user: user
+					.as_ref()
+					.map(|user| *user.user.as_eth())
+					.unwrap_or_default(),
+				expires: user.as_ref().map(|user| user.expires).unwrap_or_default(),
+			}
+			.to_log(collection_id_to_address(collection.id)),
+		);
+		<PalletCommon<T>>::deposit_event(CommonEvent::TokenUserChanged(collection.id, token, user));
+	}
 }
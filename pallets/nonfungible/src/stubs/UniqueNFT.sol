@@ -22,7 +22,7 @@ contract ERC721TokenEvent {
 }
 
 /// @title A contract that allows to set and delete token properties and change token property permissions.
-/// @dev the ERC-165 identifier for this interface is 0xde0695c2
+/// @dev the ERC-165 identifier for this interface is 0x725415f6
 contract TokenProperties is Dummy, ERC165, ERC721TokenEvent {
 	// /// @notice Set permissions for token property.
 	// /// @dev Throws error if `msg.sender` is not admin or owner of the collection.
@@ -51,6 +51,16 @@ contract TokenProperties is Dummy, ERC165, ERC721TokenEvent {
 		permissions;
 		dummy = 0;
 	}
+	/// @notice Set permissions and value types for token property.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param permissions Permissions and value types for keys.
+	/// @dev EVM selector for this function is: 0xe7e0e203,
+	///  or in textual repr: setTypedTokenPropertyPermissions((string,(uint8,bool)[],(uint8,uint32,bytes[]))[])
+	function setTypedTokenPropertyPermissions(TypedTokenPropertyPermission[] memory permissions) public {
+		require(false, stub_error);
+		permissions;
+		dummy = 0;
+	}
 	/// @notice Get permissions for token properties.
 	/// @dev EVM selector for this function is: 0xf23d7790,
 	///  or in textual repr: tokenPropertyPermissions()
@@ -59,6 +69,49 @@ contract TokenProperties is Dummy, ERC165, ERC721TokenEvent {
 		dummy;
 		return new TokenPropertyPermission[](0);
 	}
+	/// @notice Get permissions and value types for token properties.
+	/// @dev EVM selector for this function is: 0x6e8088bf,
+	///  or in textual repr: typedTokenPropertyPermissions()
+	function typedTokenPropertyPermissions() public view returns (TypedTokenPropertyPermission[] memory) {
+		require(false, stub_error);
+		dummy;
+		return new TypedTokenPropertyPermission[](0);
+	}
+	/// @notice Allow an account to write token properties with specific keys.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param account Cross account address to grant the right to.
+	/// @param keys Keys of the token properties.
+	/// @dev EVM selector for this function is: 0x5f89a4cc,
+	///  or in textual repr: grantTokenPropertyWriteCross((address,uint256),string[])
+	function grantTokenPropertyWriteCross(CrossAddress memory account, string[] memory keys) public {
+		require(false, stub_error);
+		account;
+		keys;
+		dummy = 0;
+	}
+	/// @notice Revoke the right to write token properties with specific keys from an account.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param account Cross account address to revoke the right from.
+	/// @param keys Keys of the token properties.
+	/// @dev EVM selector for this function is: 0x834b7e98,
+	///  or in textual repr: revokeTokenPropertyWriteCross((address,uint256),string[])
+	function revokeTokenPropertyWriteCross(CrossAddress memory account, string[] memory keys) public {
+		require(false, stub_error);
+		account;
+		keys;
+		dummy = 0;
+	}
+	/// @notice Get keys of the token properties an account is allowed to write.
+	/// @param account Cross account address.
+	/// @return Keys of the token properties.
+	/// @dev EVM selector for this function is: 0xf9f030dc,
+	///  or in textual repr: tokenPropertyWriteGrantsCross((address,uint256))
+	function tokenPropertyWriteGrantsCross(CrossAddress memory account) public view returns (string[] memory) {
+		require(false, stub_error);
+		account;
+		dummy;
+		return new string[](0);
+	}
 	// /// @notice Set token property value.
 	// /// @dev Throws error if `msg.sender` has no permission to edit the property.
 	// /// @param tokenId ID of the token.
@@ -159,9 +212,58 @@ enum TokenPermissionField {
 	CollectionAdmin
 }
 
+/// Ethereum representation of Token Property Permissions with the type of property values.
+struct TypedTokenPropertyPermission {
+/// Token property key.
+	string key;
+/// Token property permissions.
+	PropertyPermission[] permissions;
+/// Type of the token property values.
+	PropertyValueType valueType;
+}
+
+/// Ethereum representation of [`up_data_structs::PropertyValueType`].
+struct PropertyValueType {
+/// Kind of the property value.
+	PropertyValueKind kind;
+/// Maximal length of the value, used by [`PropertyValueKind::Bytes`].
+	uint32 maxLength;
+/// Allowed values, used by [`PropertyValueKind::Enum`].
+	bytes[] allowedValues;
+}
+
+/// Ethereum representation of the kind of [`up_data_structs::PropertyValueType`].
+enum PropertyValueKind {
+/// Any value is allowed.
+	Untyped,
+/// Decimal representation of `uint32`.
+	U32,
+/// Decimal representation of `uint64`.
+	U64,
+/// Decimal representation of `uint128`.
+	U128,
+/// `true` or `false`.
+	Bool,
+/// UTF-8 string.
+	String,
+/// URI with a scheme.
+	Uri,
+/// Address, 20 bytes.
+	Address,
+/// Bytes with maximal length.
+	Bytes,
+/// One of the allowed values.
+	Enum
+}
+
+/// @dev inlined interface
+contract CollectionMetadataEvents {
+	event PermanentURI(string value, uint256 indexed id);
+}
+
 /// @title A contract that allows you to work with collections.
-/// @dev the ERC-165 identifier for this interface is 0xb34d97e9
-contract Collection is Dummy, ERC165 {
+/// @dev the ERC-165 identifier for this interface is 0x68d9f991
+contract Collection is Dummy, ERC165, CollectionMetadataEvents {
 	// /// Set collection property.
 	// ///
 	// /// @param key Property key.
@@ -566,6 +668,179 @@ contract Collection is Dummy, ERC165 {
 		newOwner;
 		dummy = 0;
 	}
+	/// Get the royalty of the collection.
+	///
+	/// @return Royalty receiver and amount in basis points. If the collection has no royalty, zeroed royalty is returned.
+	/// @dev EVM selector for this function is: 0x24021258,
+	///  or in textual repr: collectionRoyalty()
+	function collectionRoyalty() public view returns (Royalty memory) {
+		require(false, stub_error);
+		dummy;
+		return Royalty(CrossAddress(0x0000000000000000000000000000000000000000,0),0);
+	}
+	/// Set the royalty of the collection, used by EIP-2981 `royaltyInfo` for tokens without their own royalty.
+	///
+	/// @param royalty Royalty receiver and amount in basis points, 10000 is 100%.
+	/// @dev EVM selector for this function is: 0x22fd4464,
+	///  or in textual repr: setCollectionRoyalty(((address,uint256),uint32))
+	function setCollectionRoyalty(Royalty memory royalty) public {
+		require(false, stub_error);
+		royalty;
+		dummy = 0;
+	}
+	/// Remove the royalty of the collection.
+	/// @dev EVM selector for this function is: 0xd69f3b42,
+	///  or in textual repr: deleteCollectionRoyalty()
+	function deleteCollectionRoyalty() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+	/// Set the royalty of the token, overriding the royalty of the collection.
+	///
+	/// @param tokenId Id of the token.
+	/// @param royalty Royalty receiver and amount in basis points, 10000 is 100%.
+	/// @dev EVM selector for this function is: 0x0060e084,
+	///  or in textual repr: setTokenRoyalty(uint256,((address,uint256),uint32))
+	function setTokenRoyalty(uint256 tokenId, Royalty memory royalty) public {
+		require(false, stub_error);
+		tokenId;
+		royalty;
+		dummy = 0;
+	}
+	/// Remove the royalty of the token, so the royalty of the collection applies to it.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xc11d5bb0,
+	///  or in textual repr: deleteTokenRoyalty(uint256)
+	function deleteTokenRoyalty(uint256 tokenId) public {
+		require(false, stub_error);
+		tokenId;
+		dummy = 0;
+	}
+	/// Check whether the token is frozen.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xcc888bab,
+	///  or in textual repr: isTokenFrozen(uint256)
+	function isTokenFrozen(uint256 tokenId) public view returns (bool) {
+		require(false, stub_error);
+		tokenId;
+		dummy;
+		return false;
+	}
+	/// Freeze the token, blocking its transfers, approvals and nesting under it.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xb6854f96,
+	///  or in textual repr: freezeToken(uint256)
+	function freezeToken(uint256 tokenId) public {
+		require(false, stub_error);
+		tokenId;
+		dummy = 0;
+	}
+	/// Thaw the frozen token.
+	///
+	/// @param tokenId Id of the token.
+	/// @dev EVM selector for this function is: 0xe1ecafd4,
+	///  or in textual repr: thawToken(uint256)
+	function thawToken(uint256 tokenId) public {
+		require(false, stub_error);
+		tokenId;
+		dummy = 0;
+	}
+	/// Check whether the account is frozen in the collection.
+	///
+	/// @param account Account cross address to check.
+	/// @dev EVM selector for this function is: 0xd9934ff4,
+	///  or in textual repr: isAccountFrozenCross((address,uint256))
+	function isAccountFrozenCross(CrossAddress memory account) public view returns (bool) {
+		require(false, stub_error);
+		account;
+		dummy;
+		return false;
+	}
+	/// Freeze the account in the collection, blocking transfers and approvals of its tokens.
+	///
+	/// @param account Account cross address to freeze.
+	/// @dev EVM selector for this function is: 0xe971d778,
+	///  or in textual repr: freezeAccountCross((address,uint256))
+	function freezeAccountCross(CrossAddress memory account) public {
+		require(false, stub_error);
+		account;
+		dummy = 0;
+	}
+	/// Thaw the frozen account in the collection.
+	///
+	/// @param account Account cross address to thaw.
+	/// @dev EVM selector for this function is: 0x649d5be9,
+	///  or in textual repr: thawAccountCross((address,uint256))
+	function thawAccountCross(CrossAddress memory account) public {
+		require(false, stub_error);
+		account;
+		dummy = 0;
+	}
+	/// Propose a new owner of the collection.
+	///
+	/// @dev Ownership is transferred only after the proposed owner accepts it with `acceptCollectionOwnership`.
+	/// @param newOwner Cross account address of the proposed owner.
+	/// @dev EVM selector for this function is: 0x59378b0d,
+	///  or in textual repr: proposeCollectionOwnerCross((address,uint256))
+	function proposeCollectionOwnerCross(CrossAddress memory newOwner) public {
+		require(false, stub_error);
+		newOwner;
+		dummy = 0;
+	}
+	/// Accept the proposed ownership of the collection.
+	///
+	/// @dev The caller must be proposed as the owner with `proposeCollectionOwnerCross`.
+	/// @dev EVM selector for this function is: 0xb48773ac,
+	///  or in textual repr: acceptCollectionOwnership()
+	function acceptCollectionOwnership() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+	/// Cancel the proposed transfer of the collection ownership.
+	/// @dev EVM selector for this function is: 0x01b866cc,
+	///  or in textual repr: cancelCollectionOwnershipTransfer()
+	function cancelCollectionOwnershipTransfer() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+	/// Get the proposed owner of the collection.
+	///
+	/// @return Cross address of the proposed owner. If there is no pending ownership transfer, zero address is returned.
+	/// @dev EVM selector for this function is: 0xe71220dd,
+	///  or in textual repr: collectionPendingOwner()
+	function collectionPendingOwner() public view returns (CrossAddress memory) {
+		require(false, stub_error);
+		dummy;
+		return CrossAddress(0x0000000000000000000000000000000000000000,0);
+	}
+	/// Set the roles of the collection admin.
+	///
+	/// @dev Admins added without explicitly set roles have all of them.
+	/// @param admin Cross account administrator address.
+	/// @param roles New roles of the administrator.
+	/// @dev EVM selector for this function is: 0xcc3dc830,
+	///  or in textual repr: setCollectionAdminRolesCross((address,uint256),uint8)
+	function setCollectionAdminRolesCross(CrossAddress memory admin, CollectionAdminRoles roles) public {
+		require(false, stub_error);
+		admin;
+		roles;
+		dummy = 0;
+	}
+	/// Get the roles of the collection admin.
+	///
+	/// @param admin Cross account administrator address.
+	/// @return Roles of the administrator. The owner has all the roles, other accounts have none.
+	/// @dev EVM selector for this function is: 0xc230663e,
+	///  or in textual repr: collectionAdminRolesCross((address,uint256))
+	function collectionAdminRolesCross(CrossAddress memory admin) public view returns (CollectionAdminRoles) {
+		require(false, stub_error);
+		admin;
+		dummy;
+		return CollectionAdminRoles.wrap(0);
+	}
 }
 
 /// Cross account struct
@@ -648,6 +923,36 @@ enum CollectionLimitField {
 	TransferEnabled
 }
 
+/// Ethereum representation of [`up_data_structs::Royalty`].
+struct Royalty {
+/// Account receiving the royalty.
+	CrossAddress receiver;
+/// Royalty amount in basis points of the sale price, 10000 is 100%.
+	uint32 basisPoints;
+}
+
+type CollectionAdminRoles is uint8;
+library CollectionAdminRolesLib {
+/// Can mint tokens
+	CollectionAdminRoles constant minterField = CollectionAdminRoles.wrap(128);
+/// Can change collection and token properties, property permissions and royalties
+	CollectionAdminRoles constant propertyEditorField = CollectionAdminRoles.wrap(64);
+/// Can manage the allowlist and freeze tokens and accounts
+	CollectionAdminRoles constant allowlistManagerField = CollectionAdminRoles.wrap(32);
+/// Can set and remove the collection sponsor
+	CollectionAdminRoles constant sponsorManagerField = CollectionAdminRoles.wrap(16);
+/// Can change collection limits and permissions
+	CollectionAdminRoles constant limitsManagerField = CollectionAdminRoles.wrap(8);
+/// Can transfer, burn and approve tokens of other owners when the collection limits allow it,
+/// and nest tokens when the nesting permissions allow it to admins
+	CollectionAdminRoles constant tokenManagerField = CollectionAdminRoles.wrap(4);
+/// Reserved roles
+	function reservedField(uint8 value) public pure returns (CollectionAdminRoles) {
+		require(value < 1 << 2, "out of bound value");
+		return CollectionAdminRoles.wrap(value << 0);
+	}
+}
+
 /// @title ERC-721 Non-Fungible Token Standard, optional metadata extension
 /// @dev See https://eips.ethereum.org/EIPS/eip-721
 /// @dev the ERC-165 identifier for this interface is 0x5b5e139f
@@ -675,6 +980,7 @@ contract ERC721Metadata is Dummy, ERC165 {
 	/// @notice A distinct Uniform Resource Identifier (URI) for a given asset.
 	///
 	/// @dev If the token has a `url` property and it is not empty, it is returned.
+	///  Else if the collection property `URITemplate` is not empty, it is rendered for the token, substituting `{tokenId}`, `{prop:<key>}` token property and `{<key>}` collection property placeholders.
 	///  Else If the collection does not have a property with key `schemaName` or its value is not equal to `ERC721Metadata`, it return an error `tokenURI not set`.
 	///  If the collection property `baseURI` is empty or absent, return "" (empty string)
 	///  otherwise, if token property `suffix` present and is non-empty, return concatenation of baseURI and suffix
@@ -769,7 +1075,7 @@ contract ERC721UniqueMintable is Dummy, ERC165 {
 }
 
 /// @title Unique extensions for ERC721.
-/// @dev the ERC-165 identifier for this interface is 0x9b397d16
+/// @dev the ERC-165 identifier for this interface is 0x027f88b6
 contract ERC721UniqueExtensions is Dummy, ERC165 {
 	/// @notice A descriptive name for a collection of NFTs in this contract
 	/// @dev EVM selector for this function is: 0x06fdde03,
@@ -898,6 +1204,17 @@ contract ERC721UniqueExtensions is Dummy, ERC165 {
 		tokenId;
 		dummy = 0;
 	}
+	/// @notice Transfer ownership of multiple NFTs
+	/// @dev Throws unless `msg.sender` is the current owner of each NFT.
+	///  Either all NFTs are transferred, or none of them.
+	/// @param transfers Array of pairs of the new owner and the NFT to transfer
+	/// @dev EVM selector for this function is: 0xedec5954,
+	///  or in textual repr: transferBatch(((address,uint256),uint256)[])
+	function transferBatch(TokenTransfer[] memory transfers) public {
+		require(false, stub_error);
+		transfers;
+		dummy = 0;
+	}
 	// /// @notice Burns a specific ERC721 token.
 	// /// @dev Throws unless `msg.sender` is the current owner or an authorized
 	// ///  operator for this NFT. Throws if `from` is not the current owner. Throws
@@ -927,6 +1244,19 @@ contract ERC721UniqueExtensions is Dummy, ERC165 {
 		tokenId;
 		dummy = 0;
 	}
+	/// @notice Burns a specific ERC721 token together with the tokens nested in it.
+	/// @dev Throws unless `msg.sender` is the current owner of the NFT.
+	///  Nested tokens are either burned as well, or returned to `msg.sender`.
+	/// @param tokenId The NFT to burn
+	/// @param burnChildren Whether to burn the nested tokens instead of returning them
+	/// @dev EVM selector for this function is: 0x7e96657d,
+	///  or in textual repr: burnRecursively(uint256,bool)
+	function burnRecursively(uint256 tokenId, bool burnChildren) public {
+		require(false, stub_error);
+		tokenId;
+		burnChildren;
+		dummy = 0;
+	}
 	/// @notice Returns next free NFT ID.
 	/// @dev EVM selector for this function is: 0x75794a3c,
 	///  or in textual repr: nextTokenId()
@@ -996,6 +1326,27 @@ contract ERC721UniqueExtensions is Dummy, ERC165 {
 		dummy;
 		return 0x0000000000000000000000000000000000000000;
 	}
+	/// @notice Lock or unlock the token against transfers.
+	/// @dev Throws if `msg.sender` is not the owner or admin of the collection.
+	///  Throws if `tokenId` is not a valid NFT.
+	/// @param tokenId The NFT to lock or unlock
+	/// @param locked Should the token be locked?
+	/// @dev EVM selector for this function is: 0x0a3cc989,
+	///  or in textual repr: setLocked(uint256,bool)
+	function setLocked(uint256 tokenId, bool locked) public {
+		require(false, stub_error);
+		tokenId;
+		locked;
+		dummy = 0;
+	}
+}
+
+/// Single transfer of a batch.
+struct TokenTransfer {
+/// Recipient of the token.
+	CrossAddress to;
+/// Id of the transferred token.
+	uint256 tokenId;
 }
 
 /// Data for creation token with uri.
@@ -1014,6 +1365,111 @@ struct MintTokenData {
 	Property[] properties;
 }
 
+contract ERC4907Events {
+	event UpdateUser(uint256 indexed tokenId, address indexed user, uint64 expires);
+}
+
+/// @title ERC-4907 Rental NFT
+/// @dev See https://eips.ethereum.org/EIPS/eip-4907
+///  User expiry is measured in block numbers.
+/// @dev the ERC-165 identifier for this interface is 0xad092b5c
+contract ERC4907 is Dummy, ERC165, ERC4907Events {
+	/// @notice Set the user and expires of an NFT.
+	/// @dev The zero address indicates there is no user.
+	///  The user is removed when the NFT is transferred.
+	///  Throws if `msg.sender` is not the owner of the NFT or approved to operate it.
+	///  Throws if `tokenId` is not a valid NFT.
+	/// @param tokenId The NFT to set the user of
+	/// @param user The new user of the NFT
+	/// @param expires Number of the last block in which `user` is the user of the NFT
+	/// @dev EVM selector for this function is: 0xe030565e,
+	///  or in textual repr: setUser(uint256,address,uint64)
+	function setUser(uint256 tokenId, address user, uint64 expires) public {
+		require(false, stub_error);
+		tokenId;
+		user;
+		expires;
+		dummy = 0;
+	}
+	/// @notice Get the user address of an NFT.
+	/// @dev The zero address indicates that there is no user or the user is expired.
+	/// @param tokenId The NFT to get the user address for
+	/// @return The user address for this NFT
+	/// @dev EVM selector for this function is: 0xc2f1f14a,
+	///  or in textual repr: userOf(uint256)
+	function userOf(uint256 tokenId) public view returns (address) {
+		require(false, stub_error);
+		tokenId;
+		dummy;
+		return 0x0000000000000000000000000000000000000000;
+	}
+	/// @notice Get the user expires of an NFT.
+	/// @dev The zero value indicates that there is no user.
+	/// @param tokenId The NFT to get the user expires for
+	/// @return The last block in which the user of this NFT can use it
+	/// @dev EVM selector for this function is: 0x8fc88c48,
+	///  or in textual repr: userExpires(uint256)
+	function userExpires(uint256 tokenId) public view returns (uint256) {
+		require(false, stub_error);
+		tokenId;
+		dummy;
+		return 0;
+	}
+}
+
+contract ERC5192Events {
+	event Locked(uint256 tokenId);
+	event Unlocked(uint256 tokenId);
+}
+
+/// @title ERC-5192 Minimal Soulbound NFTs
+/// @dev See https://eips.ethereum.org/EIPS/eip-5192
+/// @dev the ERC-165 identifier for this interface is 0xb45a3c0e
+contract ERC5192 is Dummy, ERC165, ERC5192Events {
+	/// @notice Returns the locking status of a token.
+	/// @dev Locked tokens can not be transferred, but can be burnt.
+	///  Throws if `tokenId` is not a valid NFT.
+	/// @param tokenId The identifier for a token.
+	/// @dev EVM selector for this function is: 0xb45a3c0e,
+	///  or in textual repr: locked(uint256)
+	function locked(uint256 tokenId) public view returns (bool) {
+		require(false, stub_error);
+		tokenId;
+		dummy;
+		return false;
+	}
+}
+
+/// @title ERC-2981 NFT Royalty Standard
+/// @dev See https://eips.ethereum.org/EIPS/eip-2981
+/// @dev the ERC-165 identifier for this interface is 0x2a55205a
+contract ERC2981 is Dummy, ERC165 {
+	/// @notice Called with the sale price to determine how much royalty
+	///  is owed and to whom.
+	/// @dev Token royalty is used if set, otherwise the collection royalty.
+	/// @param tokenId The NFT asset queried for royalty information
+	/// @param salePrice The sale price of the NFT asset specified by `tokenId`
+	/// @return Address of who should be sent the royalty payment and
+	///  the royalty payment amount for `salePrice`
+	/// @dev EVM selector for this function is: 0x2a55205a,
+	///  or in textual repr: royaltyInfo(uint256,uint256)
+	function royaltyInfo(uint256 tokenId, uint256 salePrice) public view returns (RoyaltyInfo memory) {
+		require(false, stub_error);
+		tokenId;
+		salePrice;
+		dummy;
+		return RoyaltyInfo(0x0000000000000000000000000000000000000000,0);
+	}
+}
+
+/// Result of EIP-2981 `royaltyInfo`.
+struct RoyaltyInfo {
+/// Address of who should be sent the royalty payment.
+	address receiver;
+/// The royalty payment amount for the sale price.
+	uint256 royaltyAmount;
+}
+
 /// @title ERC-721 Non-Fungible Token Standard, optional enumeration extension
 /// @dev See https://eips.ethereum.org/EIPS/eip-721
 /// @dev the ERC-165 identifier for this interface is 0x780e9d63
@@ -1030,7 +1486,13 @@ contract ERC721Enumerable is Dummy, ERC165 {
 		dummy;
 		return 0;
 	}
-	/// @dev Not implemented
+	/// @notice Enumerate NFTs assigned to an owner
+	/// @dev Throws if `index` >= `balanceOf(owner)` or if
+	///  `owner` is the zero address, representing invalid NFTs.
+	/// @param owner An address where we are interested in NFTs owned by them
+	/// @param index A counter less than `balanceOf(owner)`
+	/// @return The token identifier for the `index`th NFT assigned to `owner`,
+	///   (sort order not specified)
 	/// @dev EVM selector for this function is: 0x2f745c59,
 	///  or in textual repr: tokenOfOwnerByIndex(address,uint256)
 	function tokenOfOwnerByIndex(address owner, uint256 index) public view returns (uint256) {
@@ -1089,7 +1551,18 @@ contract ERC721 is Dummy, ERC165, ERC721Events {
 		dummy;
 		return 0x0000000000000000000000000000000000000000;
 	}
-	/// @dev Not implemented
+	/// @notice Transfers the ownership of an NFT from one address to another address
+	/// @dev Throws unless `msg.sender` is the current owner or an authorized
+	///  operator for this NFT. Throws if `from` is not the current owner. Throws
+	///  if `to` is the zero address. Throws if `tokenId` is not a valid NFT.
+	///  When transfer is complete, this function checks if `to` is a smart
+	///  contract (code size > 0). If so, it calls `onERC721Received` on `to`
+	///  and throws if the return value is not
+	///  `bytes4(keccak256("onERC721Received(address,address,uint256,bytes)"))`.
+	/// @param from The current owner of the NFT
+	/// @param to The new owner
+	/// @param tokenId The NFT to transfer
+	/// @param data Additional data with no specified format, sent in call to `to`
 	/// @dev EVM selector for this function is: 0xb88d4fde,
 	///  or in textual repr: safeTransferFrom(address,address,uint256,bytes)
 	function safeTransferFrom(address from, address to, uint256 tokenId, bytes memory data) public {
@@ -1100,7 +1573,12 @@ contract ERC721 is Dummy, ERC165, ERC721Events {
 		data;
 		dummy = 0;
 	}
-	/// @dev Not implemented
+	/// @notice Transfers the ownership of an NFT from one address to another address
+	/// @dev This works identically to the other function with an extra data parameter,
+	///  except this function just sets data to "".
+	/// @param from The current owner of the NFT
+	/// @param to The new owner
+	/// @param tokenId The NFT to transfer
 	/// @dev EVM selector for this function is: 0x42842e0e,
 	///  or in textual repr: safeTransferFrom(address,address,uint256)
 	function safeTransferFrom(address from, address to, uint256 tokenId) public {
@@ -1178,6 +1656,6 @@ contract ERC721 is Dummy, ERC165, ERC721Events {
 	}
 }
 
-contract UniqueNFT is Dummy, ERC165, ERC721, ERC721Enumerable, ERC721UniqueExtensions, ERC721UniqueMintable, ERC721Burnable, ERC721Metadata, Collection, TokenProperties {
+contract UniqueNFT is Dummy, ERC165, ERC721, ERC721Enumerable, ERC2981, ERC5192, ERC4907, ERC721UniqueExtensions, ERC721UniqueMintable, ERC721Burnable, ERC721Metadata, Collection, TokenProperties {
 }
 
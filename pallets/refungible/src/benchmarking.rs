@@ -41,6 +41,7 @@ fn create_max_item_data<T: Config>(
 			.try_into()
 			.unwrap(),
 		properties: Default::default(),
+		locked: false,
 	}
 }
 
@@ -270,7 +271,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 100)?;
+			<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 100, None)?;
 		}
 
 		Ok(())
@@ -287,7 +288,15 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance_from(&collection, &sender, &owner_eth, &spender, item, 100)?;
+			<Pallet<T>>::set_allowance_from(
+				&collection,
+				&sender,
+				&owner_eth,
+				&spender,
+				item,
+				100,
+				None,
+			)?;
 		}
 
 		Ok(())
@@ -304,7 +313,7 @@ mod benchmarks {
 			&owner,
 			[(sender.clone(), 200), (receiver.clone(), 200)],
 		)?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 100)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 100, None)?;
 
 		#[block]
 		{
@@ -330,7 +339,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; spender: cross_sub; receiver: cross_sub;
 		};
 		let item = create_max_item(&collection, &owner, [(sender.clone(), 200)])?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 100)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 100, None)?;
 
 		#[block]
 		{
@@ -360,7 +369,7 @@ mod benchmarks {
 			&owner,
 			[(sender.clone(), 200), (receiver.clone(), 200)],
 		)?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 200)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 200, None)?;
 
 		#[block]
 		{
@@ -386,7 +395,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; spender: cross_sub; receiver: cross_sub;
 		};
 		let item = create_max_item(&collection, &owner, [(sender.clone(), 200)])?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 200)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &spender, item, 200, None)?;
 
 		#[block]
 		{
@@ -412,7 +421,7 @@ mod benchmarks {
 			owner: cross_from_sub; sender: cross_sub; burner: cross_sub;
 		};
 		let item = create_max_item(&collection, &owner, [(sender.clone(), 200)])?;
-		<Pallet<T>>::set_allowance(&collection, &sender, &burner, item, 200)?;
+		<Pallet<T>>::set_allowance(&collection, &sender, &burner, item, 200, None)?;
 
 		#[block]
 		{
@@ -538,7 +547,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			<Pallet<T>>::set_allowance_for_all(&collection, &owner, &operator, true)?;
+			<Pallet<T>>::set_allowance_for_all(&collection, &owner, &operator, true, None)?;
 		}
 
 		Ok(())
@@ -574,4 +583,20 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn set_token_lock() -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let item = create_max_item(&collection, &owner, [(owner.clone(), 100)])?;
+
+		#[block]
+		{
+			<Pallet<T>>::set_token_lock(&collection, &owner, item, true)?;
+		}
+
+		Ok(())
+	}
 }
@@ -16,25 +16,26 @@
 
 use core::marker::PhantomData;
 
-use frame_support::{dispatch::DispatchResultWithPostInfo, fail, weights::Weight};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, fail, weights::Weight};
 use pallet_common::{
 	weights::WeightInfo as _, with_weight, write_token_properties_total_weight,
-	CommonCollectionOperations, CommonWeightInfo, RefungibleExtensions,
+	CommonCollectionOperations, CommonWeightInfo, Error as CommonError, Pallet as PalletCommon,
+	RefungibleExtensions,
 };
-use pallet_structure::Pallet as PalletStructure;
+use pallet_structure::{Error as StructureError, Pallet as PalletStructure};
 use sp_runtime::DispatchError;
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
 use sp_std::{collections::btree_map::BTreeMap, vec};
 use up_data_structs::{
 	budget::Budget, CollectionId, CreateItemExData, CreateRefungibleExMultipleOwners,
-	CreateRefungibleExSingleOwner, Property, PropertyKey, PropertyKeyPermission, PropertyValue,
-	TokenId, TokenOwnerError,
+	CreateRefungibleExSingleOwner, Property, PropertyKey, PropertyKeyPermission,
+	PropertyKeyValueType, PropertyValue, TokenId, TokenOwnerError, TokenUser,
 };
 
 use crate::{
-	weights::WeightInfo, AccountBalance, Allowance, Balance, Config, CreateItemData, Error, Owned,
-	Pallet, RefungibleHandle, SelfWeightOf, TokenProperties, TokensMinted, TotalSupply,
+	weights::WeightInfo, AccountBalance, Balance, Config, CreateItemData, Error, Owned, Pallet,
+	RefungibleHandle, SelfWeightOf, TokenProperties, TokensMinted, TotalSupply,
 };
 
 macro_rules! max_weight_of {
@@ -79,7 +80,7 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	}
 
 	fn set_collection_properties(amount: u32) -> Weight {
-		<pallet_common::SelfWeightOf<T>>::set_collection_properties(amount)
+		pallet_common::set_collection_properties_weight::<T>(amount)
 	}
 
 	fn set_token_properties(amount: u32) -> Weight {
@@ -91,6 +92,7 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 
 	fn set_token_property_permissions(amount: u32) -> Weight {
 		<SelfWeightOf<T>>::set_token_property_permissions(amount)
+			.saturating_add(<PalletCommonWeightOf<T>>::load_frozen_token_uri_keys())
 	}
 
 	fn transfer() -> Weight {
@@ -130,6 +132,15 @@ impl<T: Config> CommonWeightInfo<T::CrossAccountId> for CommonWeights<T> {
 	fn force_repair_item() -> Weight {
 		<SelfWeightOf<T>>::repair_item()
 	}
+
+	fn set_token_lock() -> Weight {
+		<SelfWeightOf<T>>::set_token_lock()
+	}
+
+	fn set_token_user() -> Weight {
+		// Error
+		Weight::zero()
+	}
 }
 
 /// Weight of minting tokens with properties
@@ -158,6 +169,7 @@ fn map_create_data<T: Config>(
 				out.try_into().expect("limit > 0")
 			},
 			properties: data.properties,
+			locked: false,
 		}),
 		_ => fail!(<Error<T>>::NotRefungibleDataUsedToMintFungibleCollectionToken),
 	}
@@ -215,7 +227,12 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 			CreateItemExData::RefungibleMultipleOwners(CreateRefungibleExMultipleOwners {
 				users,
 				properties,
-			}) => vec![CreateItemData::<T> { users, properties }],
+				locked,
+			}) => vec![CreateItemData::<T> {
+				users,
+				properties,
+				locked,
+			}],
 			CreateItemExData::RefungibleMultipleItems(r) => r
 				.into_inner()
 				.into_iter()
@@ -224,11 +241,13 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 					     user,
 					     pieces,
 					     properties,
+					     locked,
 					 }| CreateItemData::<T> {
 						users: BTreeMap::from([(user, pieces)])
 							.try_into()
 							.expect("limit >= 1"),
 						properties,
+						locked,
 					},
 				)
 				.collect(),
@@ -253,6 +272,22 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		)
 	}
 
+	fn burn_item_recursively(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		_burn_children: bool,
+		budget: &dyn Budget,
+	) -> DispatchResultWithPostInfo {
+		ensure!(budget.consume(), <StructureError<T>>::BreadthLimit);
+
+		let amount = <Balance<T>>::get((self.id, token, &sender));
+		with_weight(
+			<Pallet<T>>::burn(self, &sender, token, amount),
+			<CommonWeights<T>>::burn_item(),
+		)
+	}
+
 	fn transfer(
 		&self,
 		from: T::CrossAccountId,
@@ -273,9 +308,10 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		spender: T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		with_weight(
-			<Pallet<T>>::set_allowance(self, &sender, &spender, token, amount),
+			<Pallet<T>>::set_allowance(self, &sender, &spender, token, amount, deadline),
 			<CommonWeights<T>>::approve(),
 		)
 	}
@@ -287,9 +323,10 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		to: T::CrossAccountId,
 		token_id: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		with_weight(
-			<Pallet<T>>::set_allowance_from(self, &sender, &from, &to, token_id, amount),
+			<Pallet<T>>::set_allowance_from(self, &sender, &from, &to, token_id, amount, deadline),
 			<CommonWeights<T>>::approve_from(),
 		)
 	}
@@ -384,6 +421,19 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		)
 	}
 
+	fn set_token_property_value_types(
+		&self,
+		sender: &T::CrossAccountId,
+		value_types: Vec<PropertyKeyValueType>,
+	) -> DispatchResultWithPostInfo {
+		let weight = <CommonWeights<T>>::set_token_property_permissions(value_types.len() as u32);
+
+		with_weight(
+			<PalletCommon<T>>::set_token_property_value_types(self, sender, value_types),
+			weight,
+		)
+	}
+
 	fn delete_token_properties(
 		&self,
 		sender: T::CrossAccountId,
@@ -481,32 +531,25 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 	}
 
 	fn token_property(&self, token_id: TokenId, key: &PropertyKey) -> Option<PropertyValue> {
-		<Pallet<T>>::token_properties((self.id, token_id))?
-			.get(key)
-			.cloned()
+		if !<Pallet<T>>::token_exists(self, token_id) {
+			return None;
+		}
+
+		<Pallet<T>>::token_properties((self.id, token_id))
+			.and_then(|properties| properties.get(key).cloned())
+			.or_else(|| <PalletCommon<T>>::default_token_property(self.id, key))
 	}
 
 	fn token_properties(&self, token_id: TokenId, keys: Option<Vec<PropertyKey>>) -> Vec<Property> {
-		let Some(properties) = <Pallet<T>>::token_properties((self.id, token_id)) else {
+		if !<Pallet<T>>::token_exists(self, token_id) {
 			return vec![];
-		};
+		}
 
-		keys.map(|keys| {
-			keys.into_iter()
-				.filter_map(|key| {
-					properties.get(&key).map(|value| Property {
-						key,
-						value: value.clone(),
-					})
-				})
-				.collect()
-		})
-		.unwrap_or_else(|| {
-			properties
-				.into_iter()
-				.map(|(key, value)| Property { key, value })
-				.collect()
-		})
+		<PalletCommon<T>>::filter_token_properties(
+			self.id,
+			<Pallet<T>>::token_properties((self.id, token_id)).unwrap_or_default(),
+			keys,
+		)
 	}
 
 	fn total_supply(&self) -> u32 {
@@ -527,7 +570,16 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		spender: T::CrossAccountId,
 		token: TokenId,
 	) -> u128 {
-		<Allowance<T>>::get((self.id, token, sender, spender))
+		<Pallet<T>>::allowance(self, token, &sender, &spender)
+	}
+
+	fn allowance_deadline(
+		&self,
+		sender: T::CrossAccountId,
+		spender: T::CrossAccountId,
+		token: TokenId,
+	) -> Option<u64> {
+		<Pallet<T>>::allowance_deadline(self, token, &sender, &spender)
 	}
 
 	fn refungible_extensions(&self) -> Option<&dyn RefungibleExtensions<T>> {
@@ -543,9 +595,10 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		owner: T::CrossAccountId,
 		operator: T::CrossAccountId,
 		approve: bool,
+		deadline: Option<u64>,
 	) -> DispatchResultWithPostInfo {
 		with_weight(
-			<Pallet<T>>::set_allowance_for_all(self, &owner, &operator, approve),
+			<Pallet<T>>::set_allowance_for_all(self, &owner, &operator, approve, deadline),
 			<CommonWeights<T>>::set_allowance_for_all(),
 		)
 	}
@@ -554,12 +607,49 @@ impl<T: Config> CommonCollectionOperations<T> for RefungibleHandle<T> {
 		<Pallet<T>>::allowance_for_all(self, &owner, &operator)
 	}
 
+	fn allowance_for_all_deadline(
+		&self,
+		owner: T::CrossAccountId,
+		operator: T::CrossAccountId,
+	) -> Option<u64> {
+		<Pallet<T>>::allowance_for_all_deadline(self, &owner, &operator)
+	}
+
 	fn repair_item(&self, token: TokenId) -> DispatchResultWithPostInfo {
 		with_weight(
 			<Pallet<T>>::repair_item(self, token),
 			<CommonWeights<T>>::force_repair_item(),
 		)
 	}
+
+	fn set_token_lock(
+		&self,
+		sender: T::CrossAccountId,
+		token: TokenId,
+		locked: bool,
+	) -> DispatchResultWithPostInfo {
+		with_weight(
+			<Pallet<T>>::set_token_lock(self, &sender, token, locked),
+			<CommonWeights<T>>::set_token_lock(),
+		)
+	}
+
+	fn token_locked(&self, token: TokenId) -> bool {
+		<Pallet<T>>::token_locked(self, token)
+	}
+
+	fn set_token_user(
+		&self,
+		_sender: T::CrossAccountId,
+		_token: TokenId,
+		_user: Option<TokenUser<T::CrossAccountId>>,
+	) -> DispatchResultWithPostInfo {
+		fail!(<CommonError<T>>::UnsupportedOperation)
+	}
+
+	fn token_user(&self, _token: TokenId) -> Option<TokenUser<T::CrossAccountId>> {
+		None
+	}
 }
 
 impl<T: Config> RefungibleExtensions<T> for RefungibleHandle<T> {
@@ -26,12 +26,12 @@ use alloc::string::ToString;
 use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::*, AbiCoder, ToLog};
-use frame_support::{BoundedBTreeMap, BoundedVec};
+use frame_support::BoundedBTreeMap;
 use pallet_common::{
-	erc::{static_property::key, CollectionCall, CommonEvmHandler},
+	erc::{check_on_erc721_received, static_property::key, CollectionCall, CommonEvmHandler},
 	eth::{self, TokenUri},
 	CollectionHandle, CollectionPropertyPermissions, CommonCollectionOperations, CommonWeightInfo,
-	Error as CommonError,
+	Error as CommonError, Pallet as PalletCommon, TokenPropertyWriteGrants,
 };
 use pallet_evm::{account::CrossAccountId, PrecompileHandle};
 use pallet_evm_coder_substrate::{
@@ -51,7 +51,7 @@ use crate::{
 	common::{mint_with_props_weight, CommonWeights},
 	weights::WeightInfo,
 	AccountBalance, Balance, Config, CreateItemData, Pallet, RefungibleHandle, SelfWeightOf,
-	TokenProperties, TokensMinted, TotalSupply,
+	TokensMinted, TotalSupply,
 };
 
 frontier_contract! {
@@ -96,7 +96,10 @@ pub fn nesting_budget<T: Config>(recorder: &SubstrateRecorder<T>) -> impl Budget
 
 /// @title A contract that allows to set and delete token properties and change token property permissions.
 #[solidity_interface(name = TokenProperties, events(ERC721TokenEvent), enum(derive(PreDispatch)), enum_attr(weight))]
-impl<T: Config> RefungibleHandle<T> {
+impl<T: Config> RefungibleHandle<T>
+where
+	T::AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
 	/// @notice Set permissions for token property.
 	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
 	/// @param key Property key.
@@ -104,7 +107,7 @@ impl<T: Config> RefungibleHandle<T> {
 	/// @param collectionAdmin Permission to mutate property by collection admin if property is mutable.
 	/// @param tokenOwner Permission to mutate property by token owner if property is mutable.
 	#[solidity(hide)]
-	#[weight(<SelfWeightOf<T>>::set_token_property_permissions(1))]
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(1))]
 	fn set_token_property_permission(
 		&mut self,
 		caller: Caller,
@@ -134,7 +137,7 @@ impl<T: Config> RefungibleHandle<T> {
 	/// @notice Set permissions for token property.
 	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
 	/// @param permissions Permissions for keys.
-	#[weight(<SelfWeightOf<T>>::set_token_property_permissions(permissions.len() as u32))]
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(permissions.len() as u32))]
 	fn set_token_property_permissions(
 		&mut self,
 		caller: Caller,
@@ -147,6 +150,28 @@ impl<T: Config> RefungibleHandle<T> {
 			.map_err(dispatch_to_evm::<T>)
 	}
 
+	/// @notice Set permissions and value types for token property.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param permissions Permissions and value types for keys.
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(permissions.len() as u32)
+		.saturating_add(pallet_common::set_token_property_value_types_weight::<T>(
+			permissions.iter().map(eth::TypedTokenPropertyPermission::enum_values_num),
+		)))]
+	fn set_typed_token_property_permissions(
+		&mut self,
+		caller: Caller,
+		permissions: Vec<eth::TypedTokenPropertyPermission>,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let (perms, value_types) =
+			eth::TypedTokenPropertyPermission::into_property_key_permissions(permissions)?;
+
+		<Pallet<T>>::set_token_property_permissions(self, &caller, perms)
+			.map_err(dispatch_to_evm::<T>)?;
+		<PalletCommon<T>>::set_token_property_value_types(self, &caller, value_types)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
 	/// @notice Get permissions for token properties.
 	fn token_property_permissions(&self) -> Result<Vec<eth::TokenPropertyPermission>> {
 		let perms = <Pallet<T>>::token_property_permission(self.id);
@@ -156,6 +181,71 @@ impl<T: Config> RefungibleHandle<T> {
 			.collect())
 	}
 
+	/// @notice Get permissions and value types for token properties.
+	fn typed_token_property_permissions(&self) -> Result<Vec<eth::TypedTokenPropertyPermission>> {
+		let perms = <PalletCommon<T>>::filter_property_permissions(self.id, None)
+			.map_err(dispatch_to_evm::<T>)?;
+		Ok(perms
+			.into_iter()
+			.map(eth::TypedTokenPropertyPermission::from)
+			.collect())
+	}
+
+	/// @notice Allow an account to write token properties with specific keys.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param account Cross account address to grant the right to.
+	/// @param keys Keys of the token properties.
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(keys.len() as u32))]
+	fn grant_token_property_write_cross(
+		&mut self,
+		caller: Caller,
+		account: eth::CrossAddress,
+		keys: Vec<String>,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let account = account.into_sub_cross_account::<T>()?;
+		let keys = keys
+			.into_iter()
+			.map(|k| Ok(<Vec<u8>>::from(k).try_into().map_err(|_| "key too long")?))
+			.collect::<Result<Vec<_>>>()?;
+
+		<PalletCommon<T>>::set_token_property_write_grants(self, &caller, &account, keys, true)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Revoke the right to write token properties with specific keys from an account.
+	/// @dev Throws error if `msg.sender` is not admin or owner of the collection.
+	/// @param account Cross account address to revoke the right from.
+	/// @param keys Keys of the token properties.
+	#[weight(<CommonWeights<T>>::set_token_property_permissions(keys.len() as u32))]
+	fn revoke_token_property_write_cross(
+		&mut self,
+		caller: Caller,
+		account: eth::CrossAddress,
+		keys: Vec<String>,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let account = account.into_sub_cross_account::<T>()?;
+		let keys = keys
+			.into_iter()
+			.map(|k| Ok(<Vec<u8>>::from(k).try_into().map_err(|_| "key too long")?))
+			.collect::<Result<Vec<_>>>()?;
+
+		<PalletCommon<T>>::set_token_property_write_grants(self, &caller, &account, keys, false)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Get keys of the token properties an account is allowed to write.
+	/// @param account Cross account address.
+	/// @return Keys of the token properties.
+	fn token_property_write_grants_cross(&self, account: eth::CrossAddress) -> Result<Vec<String>> {
+		let account = account.into_sub_cross_account::<T>()?;
+		Ok(<TokenPropertyWriteGrants<T>>::get((self.id, account))
+			.into_iter()
+			.map(|key| String::from_utf8(key.into_inner()).unwrap_or_default())
+			.collect())
+	}
+
 	/// @notice Set token property value.
 	/// @dev Throws error if `msg.sender` has no permission to edit the property.
 	/// @param tokenId ID of the token.
@@ -278,9 +368,8 @@ impl<T: Config> RefungibleHandle<T> {
 			.try_into()
 			.map_err(|_| "key too long")?;
 
-		let props =
-			<TokenProperties<T>>::get((self.id, token_id)).ok_or("token properties not found")?;
-		let prop = props.get(&key).ok_or("key not found")?;
+		let prop = <Self as CommonCollectionOperations<T>>::token_property(self, token_id, &key)
+			.ok_or("key not found")?;
 
 		Ok(prop.to_vec().into())
 	}
@@ -343,6 +432,7 @@ where
 	/// @notice A distinct Uniform Resource Identifier (URI) for a given asset.
 	///
 	/// @dev If the token has a `url` property and it is not empty, it is returned.
+	///  Else if the collection property `URITemplate` is not empty, it is rendered for the token, substituting `{tokenId}`, `{prop:<key>}` token property and `{<key>}` collection property placeholders.
 	///  Else If the collection does not have a property with key `schemaName` or its value is not equal to `ERC721Metadata`, it return an error `tokenURI not set`.
 	///  If the collection property `baseURI` is empty or absent, return "" (empty string)
 	///  otherwise, if token property `suffix` present and is non-empty, return concatenation of baseURI and suffix
@@ -351,39 +441,13 @@ where
 	/// @return token's const_metadata
 	#[solidity(rename_selector = "tokenURI")]
 	fn token_uri(&self, token_id: U256) -> Result<String> {
-		let token_id_u32: u32 = token_id.try_into().map_err(|_| "token id overflow")?;
-
-		match get_token_property(self, token_id_u32, &key::url()).as_deref() {
-			Err(_) | Ok("") => (),
-			Ok(url) => {
-				return Ok(url.into());
-			}
-		};
-
-		let base_uri =
-			pallet_common::Pallet::<T>::get_collection_property(self.id, &key::base_uri())
-				.map(BoundedVec::into_inner)
-				.map(String::from_utf8)
-				.transpose()
-				.map_err(|e| {
-					Error::Revert(alloc::format!(
-						"can not convert value \"baseURI\" to string with error \"{e}\""
-					))
-				})?;
-
-		let base_uri = match base_uri.as_deref() {
-			None | Some("") => {
-				return Ok("".into());
-			}
-			Some(base_uri) => base_uri.into(),
-		};
+		let token_id: u32 = token_id.try_into().map_err(|_| "token id overflow")?;
+		self.consume_store_reads(3)?;
+		// Each placeholder of the URI template is substituted with a separately read value
+		let placeholders = <PalletCommon<T>>::collection_token_uri_placeholders(self.id);
+		self.consume_store_reads(placeholders.into())?;
 
-		Ok(
-			match get_token_property(self, token_id_u32, &key::suffix()).as_deref() {
-				Err(_) | Ok("") => base_uri,
-				Ok(suffix) => base_uri + suffix,
-			},
-		)
+		<PalletCommon<T>>::token_uri(self.id, self, token_id.into()).map_err(dispatch_to_evm::<T>)
 	}
 }
 
@@ -395,14 +459,32 @@ impl<T: Config> RefungibleHandle<T> {
 	/// @param index A counter less than `totalSupply()`
 	/// @return The token identifier for the `index`th NFT,
 	///  (sort order not specified)
-	fn token_by_index(&self, index: U256) -> U256 {
-		index
+	fn token_by_index(&self, index: U256) -> Result<U256> {
+		self.consume_store_reads(2)?;
+		if <Pallet<T>>::enumeration_migrating() {
+			return Err("token enumeration is being migrated".into());
+		}
+		let index = index.try_into().map_err(|_| "index out of bounds")?;
+		let token = <Pallet<T>>::token_by_index(self.id, index).ok_or("index out of bounds")?;
+		Ok(token.into())
 	}
 
-	/// Not implemented
-	fn token_of_owner_by_index(&self, _owner: Address, _index: U256) -> Result<U256> {
-		// TODO: Not implemetable
-		Err("not implemented".into())
+	/// @notice Enumerate RFTs (partially) owned by an account
+	/// @dev Throws if `index` >= `balanceOf(owner)`
+	/// @param owner An address where we are interested in RFTs owned by them
+	/// @param index A counter less than `balanceOf(owner)`
+	/// @return The token identifier for the `index`th RFT assigned to `owner`,
+	///   (sort order not specified)
+	fn token_of_owner_by_index(&self, owner: Address, index: U256) -> Result<U256> {
+		self.consume_store_reads(2)?;
+		if <Pallet<T>>::enumeration_migrating() {
+			return Err("token enumeration is being migrated".into());
+		}
+		let owner = T::CrossAccountId::from_eth(owner);
+		let index = index.try_into().map_err(|_| "index out of bounds")?;
+		let token = <Pallet<T>>::token_of_owner_by_index(self.id, &owner, index)
+			.ok_or("index out of bounds")?;
+		Ok(token.into())
 	}
 
 	/// @notice Count RFTs tracked by this contract
@@ -414,6 +496,57 @@ impl<T: Config> RefungibleHandle<T> {
 	}
 }
 
+/// @title ERC-2981 NFT Royalty Standard
+/// @dev See https://eips.ethereum.org/EIPS/eip-2981
+#[solidity_interface(name = ERC2981, enum(derive(PreDispatch)), expect_selector = 0x2a55205a)]
+impl<T: Config> RefungibleHandle<T> {
+	/// @notice Called with the sale price to determine how much royalty
+	///  is owed and to whom.
+	/// @dev Token royalty is used if set, otherwise the collection royalty.
+	/// @param tokenId The RFT asset queried for royalty information
+	/// @param salePrice The sale price of the RFT asset specified by `tokenId`
+	/// @return Address of who should be sent the royalty payment and
+	///  the royalty payment amount for `salePrice`
+	fn royalty_info(&self, token_id: U256, sale_price: U256) -> Result<eth::RoyaltyInfo> {
+		self.consume_store_reads(2)?;
+		let token = token_id.try_into()?;
+
+		let royalty = pallet_common::Pallet::<T>::token_royalty(self.id, self, token);
+
+		Ok(royalty
+			.map(|royalty| eth::RoyaltyInfo::new::<T>(&royalty, sale_price))
+			.unwrap_or_default())
+	}
+}
+
+#[derive(ToLog)]
+pub enum ERC5192Events {
+	/// @notice Emitted when the locking status is changed to locked.
+	/// @dev If a token is minted and the status is locked, this event should be emitted.
+	Locked { token_id: U256 },
+	/// @notice Emitted when the locking status is changed to unlocked.
+	Unlocked { token_id: U256 },
+}
+
+/// @title ERC-5192 Minimal Soulbound NFTs
+/// @dev See https://eips.ethereum.org/EIPS/eip-5192
+#[solidity_interface(name = ERC5192, events(ERC5192Events), enum(derive(PreDispatch)), expect_selector = 0xb45a3c0e)]
+impl<T: Config> RefungibleHandle<T> {
+	/// @notice Returns the locking status of a token.
+	/// @dev Locked tokens can not be transferred, but can be burnt.
+	///  Throws if `tokenId` is not a valid RFT.
+	/// @param tokenId The identifier for a token.
+	fn locked(&self, token_id: U256) -> Result<bool> {
+		self.consume_store_reads(2)?;
+		let token = token_id.try_into()?;
+
+		if !<Pallet<T>>::token_exists(self, token) {
+			return Err("token not found".into());
+		}
+		Ok(<Pallet<T>>::token_locked(self, token))
+	}
+}
+
 /// @title ERC-721 Non-Fungible Token Standard
 /// @dev See https://github.com/ethereum/EIPs/blob/master/EIPS/eip-721.md
 #[solidity_interface(name = ERC721, events(ERC721Events), enum(derive(PreDispatch)), enum_attr(weight), expect_selector = 0x80ac58cd)]
@@ -449,24 +582,49 @@ impl<T: Config> RefungibleHandle<T> {
 			})
 	}
 
-	/// @dev Not implemented
+	/// @notice Transfers the ownership of an RFT from one address to another address
+	/// @dev Throws unless `msg.sender` is the current owner or an authorized
+	///  operator for this RFT. Throws if `from` is not the current owner. Throws
+	///  if `to` is the zero address. Throws if `tokenId` is not a valid RFT.
+	///  Throws if RFT pieces have multiple owners.
+	///  When transfer is complete, this function checks if `to` is a smart
+	///  contract (code size > 0). If so, it calls `onERC721Received` on `to`
+	///  and throws if the return value is not
+	///  `bytes4(keccak256("onERC721Received(address,address,uint256,bytes)"))`.
+	/// @param from The current owner of the RFT
+	/// @param to The new owner
+	/// @param tokenId The RFT to transfer
+	/// @param data Additional data with no specified format, sent in call to `to`
 	#[solidity(rename_selector = "safeTransferFrom")]
+	#[weight(<SelfWeightOf<T>>::transfer_from_creating_removing())]
 	fn safe_transfer_from_with_data(
 		&mut self,
-		_from: Address,
-		_to: Address,
-		_token_id: U256,
-		_data: Bytes,
+		caller: Caller,
+		from: Address,
+		to: Address,
+		token_id: U256,
+		data: Bytes,
 	) -> Result<()> {
-		// TODO: Not implemetable
-		Err("not implemented".into())
+		self.transfer_from(caller, from, to, token_id)?;
+		check_on_erc721_received(&self.recorder, caller, from, to, token_id, data)
 	}
 
-	/// @dev Not implemented
+	/// @notice Transfers the ownership of an RFT from one address to another address
+	/// @dev This works identically to the other function with an extra data parameter,
+	///  except this function just sets data to "".
+	/// @param from The current owner of the RFT
+	/// @param to The new owner
+	/// @param tokenId The RFT to transfer
 	#[solidity(rename_selector = "safeTransferFrom")]
-	fn safe_transfer_from(&mut self, _from: Address, _to: Address, _token_id: U256) -> Result<()> {
-		// TODO: Not implemetable
-		Err("not implemented".into())
+	#[weight(<SelfWeightOf<T>>::transfer_from_creating_removing())]
+	fn safe_transfer_from(
+		&mut self,
+		caller: Caller,
+		from: Address,
+		to: Address,
+		token_id: U256,
+	) -> Result<()> {
+		self.safe_transfer_from_with_data(caller, from, to, token_id, Bytes(vec![]))
 	}
 
 	/// @notice Transfer ownership of an RFT -- THE CALLER IS RESPONSIBLE
@@ -528,7 +686,7 @@ impl<T: Config> RefungibleHandle<T> {
 		let caller = T::CrossAccountId::from_eth(caller);
 		let operator = T::CrossAccountId::from_eth(operator);
 
-		<Pallet<T>>::set_allowance_for_all(self, &caller, &operator, approved)
+		<Pallet<T>>::set_allowance_for_all(self, &caller, &operator, approved, None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(())
 	}
@@ -648,6 +806,7 @@ impl<T: Config> RefungibleHandle<T> {
 			CreateItemData::<T> {
 				users,
 				properties: CollectionPropertiesVec::default(),
+				locked: false,
 			},
 			&nesting_budget(&self.recorder),
 		)
@@ -728,7 +887,11 @@ impl<T: Config> RefungibleHandle<T> {
 		<Pallet<T>>::create_item(
 			self,
 			&caller,
-			CreateItemData::<T> { users, properties },
+			CreateItemData::<T> {
+				users,
+				properties,
+				locked: false,
+			},
 			&nesting_budget(&self.recorder),
 		)
 		.map_err(dispatch_to_evm::<T>)?;
@@ -736,21 +899,6 @@ impl<T: Config> RefungibleHandle<T> {
 	}
 }
 
-fn get_token_property<T: Config>(
-	collection: &CollectionHandle<T>,
-	token_id: u32,
-	key: &up_data_structs::PropertyKey,
-) -> Result<String> {
-	collection.consume_store_reads(1)?;
-	let properties = <TokenProperties<T>>::try_get((collection.id, token_id))
-		.map_err(|_| Error::Revert("token properties not found".into()))?;
-	if let Some(property) = properties.get(key) {
-		return Ok(String::from_utf8_lossy(property).into());
-	}
-
-	Err("property tokenURI not found".into())
-}
-
 fn get_token_permission<T: Config>(
 	collection_id: CollectionId,
 	key: &PropertyKey,
@@ -941,6 +1089,37 @@ where
 		Ok(())
 	}
 
+	/// @notice Transfer ownership of multiple RFTs
+	/// @dev Throws unless `msg.sender` is the current owner of each RFT.
+	///  Throws if pieces of any RFT have multiple owners.
+	///  Either all RFTs are transferred, or none of them.
+	/// @param transfers Array of pairs of the new owner and the RFT to transfer
+	#[weight(
+		<SelfWeightOf<T>>::transfer_creating_removing().saturating_mul(transfers.len() as u64)
+	)]
+	fn transfer_batch(&mut self, caller: Caller, transfers: Vec<eth::TokenTransfer>) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+
+		for eth::TokenTransfer { to, token_id } in transfers {
+			let to = to.into_sub_cross_account::<T>()?;
+			let token = token_id.try_into()?;
+
+			let balance = balance(self, token, &caller)?;
+			ensure_single_owner(self, token, balance)?;
+
+			<Pallet<T>>::transfer(
+				self,
+				&caller,
+				&to,
+				token,
+				balance,
+				&nesting_budget(&self.recorder),
+			)
+			.map_err(dispatch_to_evm::<T>)?;
+		}
+		Ok(())
+	}
+
 	/// @notice Burns a specific ERC721 token.
 	/// @dev Throws unless `msg.sender` is the current owner or an authorized
 	///  operator for this RFT. Throws if `from` is not the current owner. Throws
@@ -1041,6 +1220,7 @@ where
 		let create_item_data = CreateItemData::<T> {
 			users,
 			properties: CollectionPropertiesVec::default(),
+			locked: false,
 		};
 		let data = (0..total_tokens)
 			.map(|_| create_item_data.clone())
@@ -1093,6 +1273,7 @@ where
 					.try_into()
 					.map_err(|_| "too many properties")?,
 				users,
+				locked: false,
 			});
 		}
 
@@ -1158,6 +1339,7 @@ where
 			let create_item_data = CreateItemData::<T> {
 				users: users.clone(),
 				properties,
+				locked: false,
 			};
 			data.push(create_item_data);
 		}
@@ -1201,7 +1383,11 @@ where
 		<Pallet<T>>::create_item(
 			self,
 			&caller,
-			CreateItemData::<T> { users, properties },
+			CreateItemData::<T> {
+				users,
+				properties,
+				locked: false,
+			},
 			&nesting_budget(&self.recorder),
 		)
 		.map_err(dispatch_to_evm::<T>)?;
@@ -1223,6 +1409,20 @@ where
 	fn collection_helper_address(&self) -> Result<Address> {
 		Ok(T::ContractAddress::get())
 	}
+
+	/// @notice Lock or unlock the token against transfers.
+	/// @dev Throws if `msg.sender` is not the owner or admin of the collection.
+	///  Throws if `tokenId` is not a valid RFT.
+	/// @param tokenId The RFT to lock or unlock
+	/// @param locked Should the token be locked?
+	#[weight(<CommonWeights<T>>::set_token_lock())]
+	fn set_locked(&mut self, caller: Caller, token_id: U256, locked: bool) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token = token_id.try_into()?;
+
+		<Pallet<T>>::set_token_lock(self, &caller, token, locked).map_err(dispatch_to_evm::<T>)?;
+		Ok(())
+	}
 }
 
 #[solidity_interface(
@@ -1230,6 +1430,8 @@ where
 	is(
 		ERC721,
 		ERC721Enumerable,
+		ERC2981,
+		ERC5192,
 		ERC721UniqueExtensions,
 		ERC721UniqueMintable,
 		ERC721Burnable,
@@ -27,8 +27,8 @@ use core::{
 use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::*, ToLog};
 use pallet_common::{
 	erc::{CommonEvmHandler, PrecompileResult},
-	eth::{collection_id_to_address, CrossAddress},
-	CommonWeightInfo,
+	eth::{self, collection_id_to_address, CrossAddress},
+	CommonWeightInfo, Pallet as PalletCommon,
 };
 use pallet_evm::{account::CrossAccountId, PrecompileHandle};
 use pallet_evm_coder_substrate::{
@@ -36,12 +36,12 @@ use pallet_evm_coder_substrate::{
 	execution::{PreDispatch, Result},
 	frontier_contract, WithRecorder,
 };
-use sp_core::U256;
-use up_data_structs::TokenId;
+use sp_core::{H256, U256};
+use up_data_structs::{mapping::TokenAddressMapping, TokenId};
 
 use crate::{
-	common::CommonWeights, erc::nesting_budget, weights::WeightInfo, Allowance, Balance, Config,
-	Pallet, RefungibleHandle, SelfWeightOf, TotalSupply,
+	common::CommonWeights, erc::nesting_budget, weights::WeightInfo, Balance, Config, Pallet,
+	RefungibleHandle, SelfWeightOf, TotalSupply,
 };
 
 /// Refungible token handle contains information about token's collection and id
@@ -193,7 +193,7 @@ impl<T: Config> RefungibleTokenHandle<T> {
 		let spender = T::CrossAccountId::from_eth(spender);
 		let amount = amount.try_into().map_err(|_| "amount overflow")?;
 
-		<Pallet<T>>::set_allowance(self, &caller, &spender, self.1, amount)
+		<Pallet<T>>::set_allowance(self, &caller, &spender, self.1, amount, None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
 	}
@@ -207,7 +207,7 @@ impl<T: Config> RefungibleTokenHandle<T> {
 		let owner = T::CrossAccountId::from_eth(owner);
 		let spender = T::CrossAccountId::from_eth(spender);
 
-		Ok(<Allowance<T>>::get((self.id, self.1, owner, spender)).into())
+		Ok(<Pallet<T>>::allowance(self, self.1, &owner, &spender).into())
 	}
 }
 
@@ -224,7 +224,7 @@ where
 		let owner = owner.into_sub_cross_account::<T>()?;
 		let spender = spender.into_sub_cross_account::<T>()?;
 
-		Ok(<Allowance<T>>::get((self.id, self.1, owner, spender)).into())
+		Ok(<Pallet<T>>::allowance(self, self.1, &owner, &spender).into())
 	}
 
 	/// @dev Function that burns an amount of the token of a given account,
@@ -295,7 +295,7 @@ where
 		let spender = spender.into_sub_cross_account::<T>()?;
 		let amount = amount.try_into().map_err(|_| "amount overflow")?;
 
-		<Pallet<T>>::set_allowance(self, &caller, &spender, self.1, amount)
+		<Pallet<T>>::set_allowance(self, &caller, &spender, self.1, amount, None)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
 	}
@@ -371,6 +371,107 @@ where
 		.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
 	}
+
+	/// @notice Set the allowance of `spender` over the pieces of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `PermitCross` struct.
+	///  Ethereum owners sign it with ECDSA (`r`, `s` and `v` concatenated),
+	///  Substrate owners sign it with sr25519.
+	/// @param owner Cross address, which signed the permit.
+	/// @param spender Cross address, which will spend the pieces.
+	/// @param value Amount of pieces the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param signature Signature of the owner.
+	#[weight(<SelfWeightOf<T>>::approve().saturating_add(<PalletCommon<T>>::use_permit_weight()))]
+	fn permit_cross(
+		&mut self,
+		owner: CrossAddress,
+		spender: CrossAddress,
+		value: U256,
+		deadline: U256,
+		signature: Bytes,
+	) -> Result<()> {
+		let owner_cross = owner.into_sub_cross_account::<T>()?;
+		let spender_cross = spender.into_sub_cross_account::<T>()?;
+		let amount = value.try_into().map_err(|_| "amount overflow")?;
+
+		<PalletCommon<T>>::use_permit(
+			self.id,
+			self.1,
+			&owner_cross,
+			self.permit_domain_separator(),
+			|nonce| eth::permit_cross_hash(&owner, &spender, value, nonce, deadline),
+			deadline,
+			&signature.0,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_allowance(self, &owner_cross, &spender_cross, self.1, amount, None)
+			.map_err(dispatch_to_evm::<T>)
+	}
+}
+
+/// @title ERC-20 extension for approvals by signature, see EIP-2612.
+#[solidity_interface(name = ERC20Permit, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> RefungibleTokenHandle<T> {
+	/// @notice Set the allowance of `spender` over the pieces of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `Permit` struct.
+	/// @param owner Address, which signed the permit.
+	/// @param spender Address, which will spend the pieces.
+	/// @param value Amount of pieces the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param v Recovery ID of the signature.
+	/// @param r First half of the signature.
+	/// @param s Second half of the signature.
+	#[weight(<SelfWeightOf<T>>::approve().saturating_add(<PalletCommon<T>>::use_permit_weight()))]
+	#[allow(clippy::too_many_arguments)]
+	fn permit(
+		&mut self,
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> Result<()> {
+		let owner_cross = T::CrossAccountId::from_eth(owner);
+		let spender_cross = T::CrossAccountId::from_eth(spender);
+		let amount = value.try_into().map_err(|_| "amount overflow")?;
+
+		<PalletCommon<T>>::use_permit(
+			self.id,
+			self.1,
+			&owner_cross,
+			self.permit_domain_separator(),
+			|nonce| eth::permit_hash(owner, spender, value, nonce, deadline),
+			deadline,
+			&[r.as_bytes(), s.as_bytes(), &[v]].concat(),
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_allowance(self, &owner_cross, &spender_cross, self.1, amount, None)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Nonce of the next permit signed by the `owner`.
+	fn nonces(&self, owner: Address) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		let owner = T::CrossAccountId::from_eth(owner);
+		Ok(<PalletCommon<T>>::permit_nonce(self.id, self.1, &owner).into())
+	}
+
+	/// @notice EIP-712 domain separator of the permits.
+	#[solidity(rename_selector = "DOMAIN_SEPARATOR")]
+	fn domain_separator(&self) -> H256 {
+		self.permit_domain_separator()
+	}
+}
+
+impl<T: Config> RefungibleTokenHandle<T> {
+	fn permit_domain_separator(&self) -> H256 {
+		<PalletCommon<T>>::permit_domain_separator(
+			&self.name(),
+			T::EvmTokenAddressMapping::token_to_address(self.id, self.1),
+		)
+	}
 }
 
 impl<T: Config> RefungibleTokenHandle<T> {
@@ -401,7 +502,7 @@ impl<T: Config> Deref for RefungibleTokenHandle<T> {
 
 #[solidity_interface(
 	name = UniqueRefungibleToken,
-	is(ERC20, ERC20UniqueExtensions, ERC1633),
+	is(ERC20, ERC20UniqueExtensions, ERC20Permit, ERC1633),
 	enum(derive(PreDispatch)),
 )]
 impl<T: Config> RefungibleTokenHandle<T> where T::AccountId: From<[u8; 32]> {}
@@ -71,7 +71,7 @@
 //!
 //! - `init_collection` - Create RFT collection. RFT collection can be configured to allow or deny access for
 //!   some accounts.
-//! - `destroy_collection` - Destroy exising RFT collection. There should be no tokens in the collection.
+//! - `destroy_collection` - Destroy exising RFT collection. Its tokens are removed in the background.
 //! - `burn` - Burn some amount of RFT token pieces owned by account. Burns the RFT token if no pieces left.
 //! - `transfer` - Transfer some amount of RFT token pieces. Transfers should be enabled for RFT collection.
 //!   Nests the RFT token if RFT token pieces are sent to another token.
@@ -90,15 +90,19 @@
 use core::{cmp::Ordering, ops::Deref};
 
 use evm_coder::ToLog;
-use frame_support::{ensure, storage::with_transaction, transactional};
+use frame_support::{ensure, storage::with_transaction, transactional, weights::WeightMeter};
 pub use pallet::*;
 use pallet_common::{
-	eth::collection_id_to_address, Error as CommonError, Event as CommonEvent,
+	eth::collection_id_to_address,
+	helpers::{migration_step_limit, StorageCleaner},
+	CollectionById, Error as CommonError, Event as CommonEvent, FrozenTokens,
 	Pallet as PalletCommon,
 };
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::WithRecorder;
 use pallet_structure::Pallet as PalletStructure;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::{Get, H160};
 use sp_runtime::{ArithmeticError, DispatchError, DispatchResult, TransactionOutcome};
 #[cfg(not(feature = "std"))]
@@ -111,7 +115,10 @@ use up_data_structs::{
 	TokenProperties as TokenPropertiesT, MAX_REFUNGIBLE_PIECES,
 };
 
-use crate::{erc::ERC721Events, erc_token::ERC20Events};
+use crate::{
+	erc::{ERC5192Events, ERC721Events},
+	erc_token::ERC20Events,
+};
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 pub mod common;
@@ -123,12 +130,24 @@ pub type CreateItemData<T> =
 	CreateRefungibleExMultipleOwners<<T as pallet_evm::Config>::CrossAccountId>;
 pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
 
+/// Step of the multi-block migration filling the token enumeration.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Debug)]
+pub enum EnumerationMigrationStep<CrossAccountId> {
+	/// Enumerating the tokens following the given one in [`TotalSupply`].
+	Tokens(Option<(CollectionId, TokenId)>),
+	/// Enumerating the owned tokens following the given one in [`Owned`].
+	OwnedTokens(Option<(CollectionId, CrossAccountId, TokenId)>),
+	/// Removing the enumeration lengths tracked during the migration.
+	Cleanup,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*, storage::Key, traits::StorageVersion, Blake2_128, Blake2_128Concat,
 		Twox64Concat,
 	};
+	use frame_system::pallet_prelude::BlockNumberFor;
 	use up_data_structs::{CollectionId, TokenId};
 
 	use super::{weights::WeightInfo, *};
@@ -154,7 +173,7 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -199,6 +218,47 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Collection-wide enumeration of existing tokens, indexed from `0` to `total_supply - 1`.
+	#[pallet::storage]
+	pub type TokenByIndex<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, u32>),
+		Value = TokenId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Position of a token in [`TokenByIndex`].
+	#[pallet::storage]
+	pub type TokenIndex<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = u32,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Per-owner enumeration of (partially) owned tokens,
+	/// indexed from `0` to the length kept in [`EnumeratedOwnedTokens`].
+	#[pallet::storage]
+	pub type OwnedTokenByIndex<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+			Key<Twox64Concat, u32>,
+		),
+		Value = TokenId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Position of a token in [`OwnedTokenByIndex`] of the given owner.
+	#[pallet::storage]
+	pub type OwnedTokenIndex<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+			Key<Twox64Concat, TokenId>,
+		),
+		Value = u32,
+		QueryKind = OptionQuery,
+	>;
+
 	/// Amount of tokens (not pieces) partially owned by an account within a collection.
 	#[pallet::storage]
 	pub type AccountBalance<T: Config> = StorageNMap<
@@ -250,6 +310,89 @@ pub mod pallet {
 		Value = bool,
 		QueryKind = ValueQuery,
 	>;
+
+	/// Deadlines of the token piece [`Allowance`]s, after which they can no longer be used.
+	#[pallet::storage]
+	pub type AllowanceDeadline<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Twox64Concat, TokenId>,
+			// Owner
+			Key<Blake2_128, T::CrossAccountId>,
+			// Spender
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = u64,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Deadlines of the [`CollectionAllowance`]s, after which they can no longer be used.
+	#[pallet::storage]
+	pub type CollectionAllowanceDeadline<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>, // Owner
+			Key<Blake2_128Concat, T::CrossAccountId>, // Spender
+		),
+		Value = u64,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Tokens locked against transfers.
+	#[pallet::storage]
+	pub type LockedTokens<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Progress of the multi-block migration filling the enumeration of existing tokens.
+	///
+	/// Exists only while the migration is running.
+	#[pallet::storage]
+	pub type EnumerationMigration<T: Config> =
+		StorageValue<Value = EnumerationMigrationStep<T::CrossAccountId>, QueryKind = OptionQuery>;
+
+	/// Length of [`TokenByIndex`], tracked while the enumeration is migrated.
+	#[pallet::storage]
+	pub type EnumeratedTokens<T: Config> =
+		StorageMap<Hasher = Twox64Concat, Key = CollectionId, Value = u32, QueryKind = ValueQuery>;
+
+	/// Length of [`OwnedTokenByIndex`].
+	///
+	/// Unlike [`AccountBalance`], it doesn't count the tokens minted to the account
+	/// with zero pieces, which the account doesn't own.
+	#[pallet::storage]
+	pub type EnumeratedOwnedTokens<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = u32,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut meter = WeightMeter::with_limit(migration_step_limit::<T>());
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_ok() {
+				Self::migrate_token_enumeration(&mut meter);
+			}
+			meter.consumed()
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if Self::on_chain_storage_version() < 3_u16 {
+				// The migration is run by `on_initialize` over the following blocks
+				<EnumerationMigration<T>>::put(EnumerationMigrationStep::Tokens(None));
+				STORAGE_VERSION.put::<Self>();
+				T::DbWeight::get().writes(2)
+			} else {
+				Weight::zero()
+			}
+		}
+	}
 }
 
 pub struct RefungibleHandle<T: Config>(pallet_common::CollectionHandle<T>);
@@ -294,42 +437,256 @@ impl<T: Config> Pallet<T> {
 	pub fn token_exists(collection: &RefungibleHandle<T>, token: TokenId) -> bool {
 		<TotalSupply<T>>::contains_key((collection.id, token))
 	}
+
+	/// Get the `index`th existing token of the collection.
+	///
+	/// Order is not specified and may change after burns.
+	pub fn token_by_index(collection_id: CollectionId, index: u32) -> Option<TokenId> {
+		<TokenByIndex<T>>::get((collection_id, index))
+	}
+
+	/// Get the `index`th token (partially) owned by `owner` in the collection.
+	///
+	/// Order is not specified and may change after transfers and burns.
+	pub fn token_of_owner_by_index(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		index: u32,
+	) -> Option<TokenId> {
+		<OwnedTokenByIndex<T>>::get((collection_id, owner, index))
+	}
+
+	/// Whether the enumeration of existing tokens is still being filled.
+	///
+	/// Until [`EnumerationMigration`] finishes, the enumeration doesn't contain all tokens,
+	/// so the collection enumeration length is tracked by [`EnumeratedTokens`]
+	/// instead of the total supply.
+	pub fn enumeration_migrating() -> bool {
+		matches!(
+			<EnumerationMigration<T>>::get(),
+			Some(EnumerationMigrationStep::Tokens(_) | EnumerationMigrationStep::OwnedTokens(_))
+		)
+	}
+
+	/// Append `token` to the collection enumeration at `index`.
+	fn add_token_to_collection_enumeration(
+		collection_id: CollectionId,
+		token: TokenId,
+		index: u32,
+	) {
+		let index = if Self::enumeration_migrating() {
+			<EnumeratedTokens<T>>::mutate(collection_id, |len| {
+				*len += 1;
+				*len - 1
+			})
+		} else {
+			index
+		};
+		Self::set_collection_enumeration_index(collection_id, token, index);
+	}
+
+	fn set_collection_enumeration_index(collection_id: CollectionId, token: TokenId, index: u32) {
+		<TokenByIndex<T>>::insert((collection_id, index), token);
+		<TokenIndex<T>>::insert((collection_id, token), index);
+	}
+
+	/// Remove `token` from the collection enumeration,
+	/// moving the token at `last_index` into the freed slot.
+	fn remove_token_from_collection_enumeration(
+		collection_id: CollectionId,
+		token: TokenId,
+		last_index: u32,
+	) {
+		let Some(index) = <TokenIndex<T>>::take((collection_id, token)) else {
+			return;
+		};
+		let last_index = if Self::enumeration_migrating() {
+			<EnumeratedTokens<T>>::mutate(collection_id, |len| {
+				*len = len.saturating_sub(1);
+				*len
+			})
+		} else {
+			last_index
+		};
+		if index != last_index {
+			if let Some(last_token) = <TokenByIndex<T>>::get((collection_id, last_index)) {
+				Self::set_collection_enumeration_index(collection_id, last_token, index);
+			}
+		}
+		<TokenByIndex<T>>::remove((collection_id, last_index));
+	}
+
+	/// Append `token` to the enumeration of tokens owned by `owner`.
+	fn add_token_to_owner_enumeration(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		token: TokenId,
+	) {
+		let index = <EnumeratedOwnedTokens<T>>::mutate((collection_id, owner), |len| {
+			*len += 1;
+			*len - 1
+		});
+		Self::set_owner_enumeration_index(collection_id, owner, token, index);
+	}
+
+	fn set_owner_enumeration_index(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		token: TokenId,
+		index: u32,
+	) {
+		<OwnedTokenByIndex<T>>::insert((collection_id, owner, index), token);
+		<OwnedTokenIndex<T>>::insert((collection_id, owner, token), index);
+	}
+
+	/// Remove `token` from the enumeration of tokens owned by `owner`,
+	/// moving the last enumerated token into the freed slot.
+	fn remove_token_from_owner_enumeration(
+		collection_id: CollectionId,
+		owner: &T::CrossAccountId,
+		token: TokenId,
+	) {
+		let Some(index) = <OwnedTokenIndex<T>>::take((collection_id, owner, token)) else {
+			return;
+		};
+		let last_index = <EnumeratedOwnedTokens<T>>::mutate_exists((collection_id, owner), |len| {
+			let last_index = len.unwrap_or_default().saturating_sub(1);
+			*len = (last_index > 0).then_some(last_index);
+			last_index
+		});
+		if index != last_index {
+			if let Some(last_token) =
+				<OwnedTokenByIndex<T>>::get((collection_id, owner, last_index))
+			{
+				Self::set_owner_enumeration_index(collection_id, owner, last_token, index);
+			}
+		}
+		<OwnedTokenByIndex<T>>::remove((collection_id, owner, last_index));
+	}
+
+	/// Run the next step of [`EnumerationMigration`], as far as `meter` allows.
+	///
+	/// Tokens are enumerated in the order of [`TotalSupply`] and [`Owned`], skipping the ones
+	/// which were already enumerated by mints and transfers since the migration started.
+	fn migrate_token_enumeration(meter: &mut WeightMeter) {
+		match <EnumerationMigration<T>>::get() {
+			Some(EnumerationMigrationStep::Tokens(mut last)) => {
+				let mut tokens = match last {
+					Some(key) => {
+						<TotalSupply<T>>::iter_keys_from(<TotalSupply<T>>::hashed_key_for(key))
+					}
+					None => <TotalSupply<T>>::iter_keys(),
+				};
+				loop {
+					if meter
+						.try_consume(T::DbWeight::get().reads_writes(5, 3))
+						.is_err()
+					{
+						<EnumerationMigration<T>>::put(EnumerationMigrationStep::Tokens(last));
+						return;
+					}
+					let Some((collection_id, token)) = tokens.next() else {
+						break;
+					};
+					last = Some((collection_id, token));
+					// Storage of destroyed collections is being removed
+					if <CollectionById<T>>::contains_key(collection_id)
+						&& !<TokenIndex<T>>::contains_key((collection_id, token))
+					{
+						let index = <EnumeratedTokens<T>>::get(collection_id);
+						Self::add_token_to_collection_enumeration(collection_id, token, index);
+					}
+				}
+				<EnumerationMigration<T>>::put(EnumerationMigrationStep::OwnedTokens(None));
+				Self::migrate_token_enumeration(meter)
+			}
+			Some(EnumerationMigrationStep::OwnedTokens(mut last)) => {
+				let mut owned = match &last {
+					Some((collection_id, owner, token)) => <Owned<T>>::iter_keys_from(
+						<Owned<T>>::hashed_key_for((*collection_id, owner, *token)),
+					),
+					None => <Owned<T>>::iter_keys(),
+				};
+				loop {
+					if meter
+						.try_consume(T::DbWeight::get().reads_writes(5, 3))
+						.is_err()
+					{
+						<EnumerationMigration<T>>::put(EnumerationMigrationStep::OwnedTokens(last));
+						return;
+					}
+					let Some((collection_id, owner, token)) = owned.next() else {
+						break;
+					};
+					if <CollectionById<T>>::contains_key(collection_id)
+						&& !<OwnedTokenIndex<T>>::contains_key((collection_id, &owner, token))
+					{
+						Self::add_token_to_owner_enumeration(collection_id, &owner, token);
+					}
+					last = Some((collection_id, owner, token));
+				}
+				<EnumerationMigration<T>>::put(EnumerationMigrationStep::Cleanup);
+			}
+			Some(EnumerationMigrationStep::Cleanup) => {
+				let mut cleaner = StorageCleaner::<T>::new(meter);
+				if cleaner.clear_prefix(|limit| <EnumeratedTokens<T>>::clear(limit, None)) {
+					<EnumerationMigration<T>>::kill();
+				}
+			}
+			_ => {}
+		}
+	}
 }
 
 // unchecked calls skips any permission checks
 impl<T: Config> Pallet<T> {
 	/// Destroy RFT collection
 	///
-	/// `destroy_collection` will throw error if collection contains any tokens.
+	/// The tokens of the collection are removed later by [`Self::clear_destroyed_collection`].
 	/// Only owner can destroy collection.
 	pub fn destroy_collection(
 		collection: RefungibleHandle<T>,
 		sender: &T::CrossAccountId,
 	) -> DispatchResult {
-		let id = collection.id;
+		PalletCommon::destroy_collection(collection.0, sender)
+	}
 
-		if Self::collection_has_tokens(id) {
-			return Err(<CommonError<T>>::CantDestroyNotEmptyCollection.into());
+	/// Remove the tokens of the destroyed RFT collection, as far as `cleaner` allows.
+	/// Returns `true` once there is nothing left to remove.
+	pub fn clear_destroyed_collection(id: CollectionId, cleaner: &mut StorageCleaner<T>) -> bool {
+		while let Some(((token, owner), _)) = <Balance<T>>::iter_prefix((id,)).next() {
+			if !cleaner.remove_entry(T::DbWeight::get().reads_writes(1, 1)) {
+				return false;
+			}
+			<Balance<T>>::remove((id, token, &owner));
+			<PalletStructure<T>>::unnest_destroyed(&owner, id, token);
 		}
 
-		// =========
-
-		PalletCommon::destroy_collection(collection.0, sender)?;
-
-		<TokensMinted<T>>::remove(id);
-		<TokensBurnt<T>>::remove(id);
-		let _ = <TotalSupply<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <Balance<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <Allowance<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <Owned<T>>::clear_prefix((id,), u32::MAX, None);
-		let _ = <AccountBalance<T>>::clear_prefix((id,), u32::MAX, None);
-		Ok(())
-	}
-
-	fn collection_has_tokens(collection_id: CollectionId) -> bool {
-		<TotalSupply<T>>::iter_prefix((collection_id,))
-			.next()
-			.is_some()
+		let done = cleaner.clear_prefix(|limit| <TotalSupply<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <TokenProperties<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <Owned<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <AccountBalance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <EnumeratedOwnedTokens<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <Allowance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <CollectionAllowance<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <AllowanceDeadline<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| {
+				<CollectionAllowanceDeadline<T>>::clear_prefix((id,), limit, None)
+			}) && cleaner
+			.clear_prefix(|limit| <TokenByIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <TokenIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner
+				.clear_prefix(|limit| <OwnedTokenByIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <OwnedTokenIndex<T>>::clear_prefix((id,), limit, None))
+			&& cleaner.clear_prefix(|limit| <LockedTokens<T>>::clear_prefix((id,), limit, None));
+		if done {
+			<TokensMinted<T>>::remove(id);
+			<TokensBurnt<T>>::remove(id);
+		}
+		done
 	}
 
 	pub fn burn_token_unchecked(
@@ -341,11 +698,20 @@ impl<T: Config> Pallet<T> {
 			.checked_add(1)
 			.ok_or(ArithmeticError::Overflow)?;
 
+		Self::remove_token_from_collection_enumeration(
+			collection.id,
+			token_id,
+			<TokensMinted<T>>::get(collection.id) - burnt,
+		);
 		<TokensBurnt<T>>::insert(collection.id, burnt);
 		<TokenProperties<T>>::remove((collection.id, token_id));
 		<TotalSupply<T>>::remove((collection.id, token_id));
 		let _ = <Balance<T>>::clear_prefix((collection.id, token_id), u32::MAX, None);
 		let _ = <Allowance<T>>::clear_prefix((collection.id, token_id), u32::MAX, None);
+		let _ = <AllowanceDeadline<T>>::clear_prefix((collection.id, token_id), u32::MAX, None);
+		<LockedTokens<T>>::remove((collection.id, token_id));
+		<FrozenTokens<T>>::remove((collection.id, token_id));
+		<PalletCommon<T>>::remove_token_properties_freeze(collection.id, token_id);
 		<PalletEvm<T>>::deposit_log(
 			ERC721Events::Transfer {
 				from: *owner.as_eth(),
@@ -396,10 +762,13 @@ impl<T: Config> Pallet<T> {
 
 			// =========
 
+			T::OnTokenTransfer::before_balance_change(collection.id, token, owner);
 			<Owned<T>>::remove((collection.id, owner, token));
+			Self::remove_token_from_owner_enumeration(collection.id, owner, token);
 			<PalletStructure<T>>::unnest_if_nested(owner, collection.id, token);
 			<AccountBalance<T>>::insert((collection.id, owner), account_balance);
 			Self::burn_token_unchecked(collection, owner, token)?;
+			T::OnTokenTransfer::on_token_transfer(collection.id, token, owner);
 			<PalletEvm<T>>::deposit_log(
 				ERC20Events::Transfer {
 					from: *owner.as_eth(),
@@ -431,8 +800,10 @@ impl<T: Config> Pallet<T> {
 
 		// =========
 
+		T::OnTokenTransfer::before_balance_change(collection.id, token, owner);
 		if balance == 0 {
 			<Owned<T>>::remove((collection.id, owner, token));
+			Self::remove_token_from_owner_enumeration(collection.id, owner, token);
 			<PalletStructure<T>>::unnest_if_nested(owner, collection.id, token);
 			<Balance<T>>::remove((collection.id, token, owner));
 			<AccountBalance<T>>::insert((collection.id, owner), account_balance);
@@ -451,6 +822,7 @@ impl<T: Config> Pallet<T> {
 			<Balance<T>>::insert((collection.id, token, owner), balance);
 		}
 		<TotalSupply<T>>::insert((collection.id, token), total_supply);
+		T::OnTokenTransfer::on_token_transfer(collection.id, token, owner);
 
 		<PalletEvm<T>>::deposit_log(
 			ERC20Events::Transfer {
@@ -632,6 +1004,13 @@ impl<T: Config> Pallet<T> {
 			collection.limits.transfers_enabled(),
 			<CommonError<T>>::TransferNotAllowed
 		);
+		ensure!(
+			!<LockedTokens<T>>::get((collection.id, token)),
+			<CommonError<T>>::TokenTransferLocked
+		);
+		collection.check_token_not_frozen(token)?;
+		collection.check_account_not_frozen(from)?;
+		T::OnTokenTransfer::check_token_transfer(collection.id, token, from, to)?;
 
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(from)?;
@@ -703,6 +1082,8 @@ impl<T: Config> Pallet<T> {
 				nesting_budget,
 			)?;
 
+			T::OnTokenTransfer::before_balance_change(collection.id, token, from);
+			T::OnTokenTransfer::before_balance_change(collection.id, token, to);
 			if updated_balance_from == 0 {
 				<Balance<T>>::remove((collection.id, token, from));
 				<PalletStructure<T>>::unnest_if_nested(from, collection.id, token);
@@ -710,13 +1091,16 @@ impl<T: Config> Pallet<T> {
 				<Balance<T>>::insert((collection.id, token, from), updated_balance_from);
 			}
 			<Balance<T>>::insert((collection.id, token, to), updated_balance_to);
+			T::OnTokenTransfer::on_token_transfer(collection.id, token, from);
 			if let Some(account_balance_from) = account_balance_from {
 				<AccountBalance<T>>::insert((collection.id, from), account_balance_from);
 				<Owned<T>>::remove((collection.id, from, token));
+				Self::remove_token_from_owner_enumeration(collection.id, from, token);
 			}
 			if let Some(account_balance_to) = account_balance_to {
 				<AccountBalance<T>>::insert((collection.id, to), account_balance_to);
 				<Owned<T>>::insert((collection.id, to, token), true);
+				Self::add_token_to_owner_enumeration(collection.id, to, token);
 			}
 		}
 
@@ -785,6 +1169,7 @@ impl<T: Config> Pallet<T> {
 	/// Batched operation to create multiple RFT tokens.
 	///
 	/// Same as `create_item` but creates multiple tokens.
+	/// Only the owner/admin of the collection can mint locked tokens.
 	///
 	/// - `data`: Same as 'data` in `create_item` but contains data for multiple tokens.
 	pub fn create_multiple_items(
@@ -793,7 +1178,7 @@ impl<T: Config> Pallet<T> {
 		data: Vec<CreateItemData<T>>,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		if !collection.is_owner_or_admin(sender) {
+		if !collection.has_admin_role(sender, |roles| roles.minter) {
 			ensure!(
 				collection.permissions.mint_mode(),
 				<CommonError<T>>::PublicMintingNotAllowed
@@ -804,6 +1189,7 @@ impl<T: Config> Pallet<T> {
 				for user in item.users.keys() {
 					collection.check_allowlist(user)?;
 				}
+				ensure!(!item.locked, <CommonError<T>>::NoPermission);
 			}
 		}
 
@@ -872,6 +1258,7 @@ impl<T: Config> Pallet<T> {
 		// =========
 
 		let mut property_writer = pallet_common::NewTokenPropertyWriter::new(collection, sender);
+		let first_index = first_token_id - <TokensBurnt<T>>::get(collection.id);
 
 		with_transaction(|| {
 			for (i, data) in data.iter().enumerate() {
@@ -879,6 +1266,11 @@ impl<T: Config> Pallet<T> {
 				<TotalSupply<T>>::insert((collection.id, token_id), totals[i]);
 
 				let token = TokenId(token_id);
+				Self::add_token_to_collection_enumeration(
+					collection.id,
+					token,
+					first_index + i as u32,
+				);
 
 				let mut mint_target_is_sender = true;
 				for (user, amount) in data.users.iter() {
@@ -888,8 +1280,10 @@ impl<T: Config> Pallet<T> {
 
 					mint_target_is_sender = mint_target_is_sender && sender.conv_eq(user);
 
+					T::OnTokenTransfer::before_balance_change(collection.id, token, user);
 					<Balance<T>>::insert((collection.id, token_id, &user), amount);
 					<Owned<T>>::insert((collection.id, &user, token), true);
+					Self::add_token_to_owner_enumeration(collection.id, user, token);
 					<PalletStructure<T>>::nest_if_sent_to_token_unchecked(
 						user,
 						collection.id,
@@ -968,6 +1362,11 @@ impl<T: Config> Pallet<T> {
 					amount,
 				));
 			}
+
+			if token.locked {
+				<LockedTokens<T>>::insert((collection.id, TokenId(token_id)), true);
+				Self::deposit_lock_events(collection, TokenId(token_id), true);
+			}
 		}
 		Ok(())
 	}
@@ -981,6 +1380,7 @@ impl<T: Config> Pallet<T> {
 	) {
 		if amount == 0 {
 			<Allowance<T>>::remove((collection.id, token, sender, spender));
+			<AllowanceDeadline<T>>::remove((collection.id, token, sender, spender));
 		} else {
 			<Allowance<T>>::insert((collection.id, token, sender, spender), amount);
 		}
@@ -1008,12 +1408,14 @@ impl<T: Config> Pallet<T> {
 	/// Set allowance for the spender to `transfer` or `burn` sender's token pieces.
 	///
 	/// - `amount`: Amount of token pieces the spender is allowed to `transfer` or `burn.
+	/// - `deadline`: Last block in which the spender can use the allowance.
 	pub fn set_allowance(
 		collection: &RefungibleHandle<T>,
 		sender: &T::CrossAccountId,
 		spender: &T::CrossAccountId,
 		token: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(sender)?;
@@ -1021,6 +1423,8 @@ impl<T: Config> Pallet<T> {
 		}
 
 		<PalletCommon<T>>::ensure_correct_receiver(spender)?;
+		collection.check_account_not_frozen(sender)?;
+		collection.check_token_not_frozen(token)?;
 
 		if <Balance<T>>::get((collection.id, token, sender)) < amount {
 			ensure!(
@@ -1028,10 +1432,14 @@ impl<T: Config> Pallet<T> {
 				<CommonError<T>>::CantApproveMoreThanOwned
 			);
 		}
+		<PalletCommon<T>>::check_deadline(deadline)?;
 
 		// =========
 
 		Self::set_allowance_unchecked(collection, sender, spender, token, amount);
+		if amount > 0 {
+			<AllowanceDeadline<T>>::set((collection.id, token, sender, spender), deadline);
+		}
 		Ok(())
 	}
 
@@ -1040,6 +1448,7 @@ impl<T: Config> Pallet<T> {
 	/// - `from`: Address of sender's eth mirror.
 	/// - `to`: Adress of spender.
 	/// - `amount`: Amount of token pieces the spender is allowed to `transfer` or `burn.
+	/// - `deadline`: Last block in which the spender can use the allowance.
 	pub fn set_allowance_from(
 		collection: &RefungibleHandle<T>,
 		sender: &T::CrossAccountId,
@@ -1047,6 +1456,7 @@ impl<T: Config> Pallet<T> {
 		to: &T::CrossAccountId,
 		token_id: TokenId,
 		amount: u128,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		if collection.permissions.access() == AccessMode::AllowList {
 			collection.check_allowlist(sender)?;
@@ -1060,19 +1470,26 @@ impl<T: Config> Pallet<T> {
 			sender.conv_eq(from),
 			<CommonError<T>>::AddressIsNotEthMirror
 		);
+		collection.check_account_not_frozen(from)?;
+		collection.check_token_not_frozen(token_id)?;
 
 		if <Balance<T>>::get((collection.id, token_id, from)) < amount {
 			ensure!(
 				collection.limits.owner_can_transfer()
-					&& (collection.is_owner_or_admin(sender) || collection.is_owner_or_admin(from))
+					&& (collection.has_admin_role(sender, |roles| roles.token_manager)
+						|| collection.has_admin_role(from, |roles| roles.token_manager))
 					&& Self::token_exists(collection, token_id),
 				<CommonError<T>>::CantApproveMoreThanOwned
 			);
 		}
+		<PalletCommon<T>>::check_deadline(deadline)?;
 
 		// =========
 
 		Self::set_allowance_unchecked(collection, from, to, token_id, amount);
+		if amount > 0 {
+			<AllowanceDeadline<T>>::set((collection.id, token_id, from, to), deadline);
+		}
 		Ok(())
 	}
 
@@ -1120,7 +1537,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		// Allowance (if any) would be reduced if spender is also wallet operator
-		if <CollectionAllowance<T>>::get((collection.id, from, spender)) {
+		if Self::allowance_for_all(collection, from, spender) {
 			return Ok(allowance);
 		}
 
@@ -1136,7 +1553,32 @@ impl<T: Config> Pallet<T> {
 		spender: &T::CrossAccountId,
 		amount: u128,
 	) -> Option<u128> {
-		<Allowance<T>>::get((collection.id, token, from, spender)).checked_sub(amount)
+		Self::allowance(collection, token, from, spender).checked_sub(amount)
+	}
+
+	/// Get the amount of token pieces the `spender` is allowed to `transfer` or `burn`.
+	/// Expired allowances are treated as zero.
+	pub fn allowance(
+		collection: &RefungibleHandle<T>,
+		token: TokenId,
+		sender: &T::CrossAccountId,
+		spender: &T::CrossAccountId,
+	) -> u128 {
+		let deadline = <AllowanceDeadline<T>>::get((collection.id, token, sender, spender));
+		if <PalletCommon<T>>::deadline_passed(deadline) {
+			return 0;
+		}
+		<Allowance<T>>::get((collection.id, token, sender, spender))
+	}
+
+	/// Get the deadline of the allowance set by the `sender` for the `spender`, if it has one.
+	pub fn allowance_deadline(
+		collection: &RefungibleHandle<T>,
+		token: TokenId,
+		sender: &T::CrossAccountId,
+		spender: &T::CrossAccountId,
+	) -> Option<u64> {
+		<AllowanceDeadline<T>>::get((collection.id, token, sender, spender))
 	}
 
 	/// Transfer RFT token pieces from one account to another.
@@ -1154,6 +1596,7 @@ impl<T: Config> Pallet<T> {
 		amount: u128,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
+		collection.check_account_not_frozen(spender)?;
 		let allowance =
 			Self::check_allowed(collection, spender, from, token, amount, nesting_budget)?;
 
@@ -1234,6 +1677,7 @@ impl<T: Config> Pallet<T> {
 			<Error<T>>::RepartitionWhileNotOwningAllPieces
 		);
 
+		T::OnTokenTransfer::before_balance_change(collection.id, token, owner);
 		<Balance<T>>::insert((collection.id, token, owner), amount);
 		<TotalSupply<T>>::insert((collection.id, token), amount);
 
@@ -1260,6 +1704,7 @@ impl<T: Config> Pallet<T> {
 			}
 			Ordering::Greater => {
 				let burn_amount = total_pieces - amount;
+				T::OnTokenTransfer::on_token_transfer(collection.id, token, owner);
 				<PalletEvm<T>>::deposit_log(
 					ERC20Events::Transfer {
 						from: *owner.as_eth(),
@@ -1378,18 +1823,27 @@ impl<T: Config> Pallet<T> {
 	/// - `owner`: Token owner
 	/// - `operator`: Operator
 	/// - `approve`: Should operator status be granted or revoked?
+	/// - `deadline`: Last block in which the operator can use the granted status.
 	pub fn set_allowance_for_all(
 		collection: &RefungibleHandle<T>,
 		owner: &T::CrossAccountId,
 		spender: &T::CrossAccountId,
 		approve: bool,
+		deadline: Option<u64>,
 	) -> DispatchResult {
 		<PalletCommon<T>>::set_allowance_for_all(
 			collection,
 			owner,
 			spender,
 			approve,
-			|| <CollectionAllowance<T>>::insert((collection.id, owner, spender), approve),
+			deadline,
+			|| {
+				<CollectionAllowance<T>>::insert((collection.id, owner, spender), approve);
+				<CollectionAllowanceDeadline<T>>::set(
+					(collection.id, owner, spender),
+					deadline.filter(|_| approve),
+				);
+			},
 			ERC721Events::ApprovalForAll {
 				owner: *owner.as_eth(),
 				operator: *spender.as_eth(),
@@ -1399,13 +1853,24 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	/// Tells whether the given `owner` approves the `operator`.
+	/// Tells whether the given `owner` approves the `operator`, and the approval hasn't expired.
 	pub fn allowance_for_all(
 		collection: &RefungibleHandle<T>,
 		owner: &T::CrossAccountId,
 		spender: &T::CrossAccountId,
 	) -> bool {
+		let deadline = <CollectionAllowanceDeadline<T>>::get((collection.id, owner, spender));
 		<CollectionAllowance<T>>::get((collection.id, owner, spender))
+			&& !<PalletCommon<T>>::deadline_passed(deadline)
+	}
+
+	/// Get the deadline of the approval of the `operator` by the `owner`, if it has one.
+	pub fn allowance_for_all_deadline(
+		collection: &RefungibleHandle<T>,
+		owner: &T::CrossAccountId,
+		spender: &T::CrossAccountId,
+	) -> Option<u64> {
+		<CollectionAllowanceDeadline<T>>::get((collection.id, owner, spender))
 	}
 
 	pub fn repair_item(collection: &RefungibleHandle<T>, token: TokenId) -> DispatchResult {
@@ -1417,4 +1882,63 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Lock or unlock the token against transfers.
+	///
+	/// Only the owner or admin of the collection with the allowlist manager role can change the lock.
+	/// Pieces of a locked token can still be burnt.
+	///
+	/// - `token`: Token to lock or unlock.
+	/// - `locked`: Should the token be locked?
+	pub fn set_token_lock(
+		collection: &RefungibleHandle<T>,
+		sender: &T::CrossAccountId,
+		token: TokenId,
+		locked: bool,
+	) -> DispatchResult {
+		collection.check_has_admin_role(sender, |roles| roles.allowlist_manager)?;
+		ensure!(
+			Self::token_exists(collection, token),
+			<CommonError<T>>::TokenNotFound
+		);
+		ensure!(
+			!locked
+				|| <Balance<T>>::get((collection.id, token, <PalletCommon<T>>::swap_escrow())) == 0,
+			<CommonError<T>>::AssetIsEscrowed
+		);
+
+		// =========
+
+		if locked {
+			<LockedTokens<T>>::insert((collection.id, token), true);
+		} else {
+			<LockedTokens<T>>::remove((collection.id, token));
+		}
+
+		Self::deposit_lock_events(collection, token, locked);
+		Ok(())
+	}
+
+	/// Tells whether the token is locked against transfers.
+	pub fn token_locked(collection: &RefungibleHandle<T>, token: TokenId) -> bool {
+		<LockedTokens<T>>::get((collection.id, token))
+	}
+
+	fn deposit_lock_events(collection: &RefungibleHandle<T>, token: TokenId, locked: bool) {
+		let log = if locked {
+			ERC5192Events::Locked {
+				token_id: token.into(),
+			}
+		} else {
+			ERC5192Events::Unlocked {
+				token_id: token.into(),
+			}
+		};
+		<PalletEvm<T>>::deposit_log(log.to_log(collection_id_to_address(collection.id)));
+		<PalletCommon<T>>::deposit_event(CommonEvent::TokenLockChanged(
+			collection.id,
+			token,
+			locked,
+		));
+	}
 }
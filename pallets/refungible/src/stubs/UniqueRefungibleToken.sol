@@ -34,7 +34,51 @@ contract ERC1633 is Dummy, ERC165 {
 	}
 }
 
-/// @dev the ERC-165 identifier for this interface is 0xedd3a564
+/// @title ERC-20 extension for approvals by signature, see EIP-2612.
+/// @dev the ERC-165 identifier for this interface is 0x9d8ff7da
+contract ERC20Permit is Dummy, ERC165 {
+	/// @notice Set the allowance of `spender` over the pieces of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `Permit` struct.
+	/// @param owner Address, which signed the permit.
+	/// @param spender Address, which will spend the pieces.
+	/// @param value Amount of pieces the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param v Recovery ID of the signature.
+	/// @param r First half of the signature.
+	/// @param s Second half of the signature.
+	/// @dev EVM selector for this function is: 0xd505accf,
+	///  or in textual repr: permit(address,address,uint256,uint256,uint8,bytes32,bytes32)
+	function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) public {
+		require(false, stub_error);
+		owner;
+		spender;
+		value;
+		deadline;
+		v;
+		r;
+		s;
+		dummy = 0;
+	}
+	/// @notice Nonce of the next permit signed by the `owner`.
+	/// @dev EVM selector for this function is: 0x7ecebe00,
+	///  or in textual repr: nonces(address)
+	function nonces(address owner) public view returns (uint256) {
+		require(false, stub_error);
+		owner;
+		dummy;
+		return 0;
+	}
+	/// @notice EIP-712 domain separator of the permits.
+	/// @dev EVM selector for this function is: 0x3644e515,
+	///  or in textual repr: DOMAIN_SEPARATOR()
+	function DOMAIN_SEPARATOR() public view returns (bytes32) {
+		require(false, stub_error);
+		dummy;
+		return 0x0000000000000000000000000000000000000000000000000000000000000000;
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x59417dc9
 contract ERC20UniqueExtensions is Dummy, ERC165 {
 	/// @dev Function to check the amount of tokens that an owner allowed to a spender.
 	/// @param owner crossAddress The address which owns the funds.
@@ -140,6 +184,26 @@ contract ERC20UniqueExtensions is Dummy, ERC165 {
 		dummy = 0;
 		return false;
 	}
+	/// @notice Set the allowance of `spender` over the pieces of `owner`, given the owner's signature.
+	/// @dev The signature is made over the EIP-712 `PermitCross` struct.
+	///  Ethereum owners sign it with ECDSA (`r`, `s` and `v` concatenated),
+	///  Substrate owners sign it with sr25519.
+	/// @param owner Cross address, which signed the permit.
+	/// @param spender Cross address, which will spend the pieces.
+	/// @param value Amount of pieces the spender is allowed to spend.
+	/// @param deadline Timestamp in seconds, after which the permit is no longer valid.
+	/// @param signature Signature of the owner.
+	/// @dev EVM selector for this function is: 0xb492d8ad,
+	///  or in textual repr: permitCross((address,uint256),(address,uint256),uint256,uint256,bytes)
+	function permitCross(CrossAddress memory owner, CrossAddress memory spender, uint256 value, uint256 deadline, bytes memory signature) public {
+		require(false, stub_error);
+		owner;
+		spender;
+		value;
+		deadline;
+		signature;
+		dummy = 0;
+	}
 }
 
 /// Cross account struct
@@ -260,6 +324,6 @@ contract ERC20 is Dummy, ERC165, ERC20Events {
 	}
 }
 
-contract UniqueRefungibleToken is Dummy, ERC165, ERC20, ERC20UniqueExtensions, ERC1633 {
+contract UniqueRefungibleToken is Dummy, ERC165, ERC20, ERC20UniqueExtensions, ERC20Permit, ERC1633 {
 }
 
@@ -0,0 +1,164 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::v2::{account, benchmarks, BenchmarkError, Linear};
+use frame_support::traits::{fungible::Balanced, tokens::Precision};
+use frame_system::RawOrigin;
+use pallet_common::{dispatch::CollectionDispatch, CollectionIssuer, Config as CommonConfig};
+use up_data_structs::{
+	budget::Unlimited, CreateCollectionData, CreateFungibleData, CreateItemData,
+	CreateReFungibleData,
+};
+
+use super::*;
+
+const SEED: u32 = 1;
+const PIECES: u128 = 100;
+const PRICE: u128 = 1_000_000;
+
+fn cross_account<T: Config>(name: &'static str) -> T::CrossAccountId {
+	T::CrossAccountId::from_sub(account(name, 0, SEED))
+}
+
+fn origin<T: Config>(account: &T::CrossAccountId) -> OriginFor<T> {
+	RawOrigin::Signed(account.as_sub().clone()).into()
+}
+
+fn create_collection<T: Config>(
+	owner: &T::CrossAccountId,
+	mode: CollectionMode,
+) -> Result<CollectionId, DispatchError> {
+	let _ = <T as CommonConfig>::Currency::deposit(
+		owner.as_sub(),
+		T::CollectionCreationPrice::get(),
+		Precision::Exact,
+	)?;
+	T::CollectionDispatch::create(
+		owner.clone(),
+		CollectionIssuer::User(owner.clone()),
+		CreateCollectionData {
+			mode,
+			..Default::default()
+		},
+	)
+}
+
+fn mint<T: Config>(
+	collection_id: CollectionId,
+	owner: &T::CrossAccountId,
+	to: &T::CrossAccountId,
+	data: CreateItemData,
+) -> DispatchResult {
+	T::CollectionDispatch::dispatch(collection_id)?
+		.as_dyn()
+		.create_item(owner.clone(), to.clone(), data, &Unlimited)
+		.map(|_| ())
+		.map_err(|error| error.error)
+}
+
+/// Mints an RFT token to `buyer`, and gives a single piece of it to each of the `holders`.
+/// Creates a fungible collection, in which the `buyer` can pay for all the pieces.
+///
+/// Returns IDs of the RFT collection and of the currency collection.
+fn prepare_buyout<T: Config>(
+	buyer: &T::CrossAccountId,
+	holders: u32,
+) -> Result<(CollectionId, CollectionId), DispatchError> {
+	let owner = cross_account::<T>("owner");
+	let collection_id = create_collection::<T>(&owner, CollectionMode::ReFungible)?;
+	mint::<T>(
+		collection_id,
+		&owner,
+		buyer,
+		CreateItemData::ReFungible(CreateReFungibleData {
+			pieces: PIECES,
+			properties: Default::default(),
+		}),
+	)?;
+	for i in 0..holders {
+		let holder = T::CrossAccountId::from_sub(account("holder", i, SEED));
+		<PalletStructure<T>>::transfer_as_owner(collection_id, TokenId(1), buyer, &holder, 1)?;
+	}
+
+	let currency = create_collection::<T>(&owner, CollectionMode::Fungible(0))?;
+	mint::<T>(
+		currency,
+		&owner,
+		buyer,
+		CreateItemData::Fungible(CreateFungibleData {
+			value: PRICE * PIECES,
+		}),
+	)?;
+
+	Ok((collection_id, currency))
+}
+
+fn set_block_number<T: Config>(block: u64) {
+	<frame_system::Pallet<T>>::set_block_number(block.unique_saturated_into());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn start_buyout() -> Result<(), BenchmarkError> {
+		let buyer = cross_account::<T>("buyer");
+		let (collection_id, currency) = prepare_buyout::<T>(&buyer, 1)?;
+
+		#[extrinsic_call]
+		_(
+			origin::<T>(&buyer),
+			collection_id,
+			TokenId(1),
+			currency,
+			PRICE,
+		);
+
+		assert!(<Buyouts<T>>::contains_key((collection_id, TokenId(1))));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_buyout() -> Result<(), BenchmarkError> {
+		let buyer = cross_account::<T>("buyer");
+		let (collection_id, currency) = prepare_buyout::<T>(&buyer, 1)?;
+		<Pallet<T>>::start_token_buyout(&buyer, collection_id, TokenId(1), currency, PRICE)?;
+
+		#[extrinsic_call]
+		_(origin::<T>(&buyer), collection_id, TokenId(1));
+
+		assert!(!<Buyouts<T>>::contains_key((collection_id, TokenId(1))));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn settle_buyout(b: Linear<1, MAX_HOLDERS_PER_SETTLEMENT>) -> Result<(), BenchmarkError> {
+		let buyer = cross_account::<T>("buyer");
+		let (collection_id, currency) = prepare_buyout::<T>(&buyer, b)?;
+		<Pallet<T>>::start_token_buyout(&buyer, collection_id, TokenId(1), currency, PRICE)?;
+		let end = <Buyouts<T>>::get((collection_id, TokenId(1)))
+			.ok_or(BenchmarkError::Stop("buyout not started"))?
+			.end;
+		set_block_number::<T>(end);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, collection_id, TokenId(1));
+
+		assert!(!<Buyouts<T>>::contains_key((collection_id, TokenId(1))));
+		Ok(())
+	}
+}
@@ -0,0 +1,584 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! # RFT Buyout pallet
+//!
+//! The pallet lets a major holder of an RFT token buy out the rest of its pieces.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//! - [`Error`]
+//! - [`Event`]
+//!
+//! ## Overview
+//!
+//! An account holding more than [`BuyoutThreshold`](Config::BuyoutThreshold) of the pieces
+//! of any RFT token can post a buyout at a price per piece. The payment for the pieces
+//! of the other holders is moved to the vault account of the pallet. After
+//! [`NoticePeriod`](Config::NoticePeriod) blocks, the pieces of the other holders
+//! are transferred to the buyer, and each holder is paid for their pieces out of the vault.
+//! Once the notice period has ended, the pieces can only be transferred to the buyer.
+//!
+//! The buyout is settled by the [`Scheduler`](Config::Scheduler), starting at the end
+//! of the notice period. Each settlement buys out the pieces of up to
+//! [`MAX_HOLDERS_PER_SETTLEMENT`] holders, and schedules the next one in the following block.
+//! If a settlement couldn't be scheduled, the buyout can be settled by anyone.
+//! If the payment doesn't cover the pieces of the other holders, the vault holds some of
+//! the pieces, or the pieces of a holder can't be transferred, the buyout stops,
+//! and the rest of the payment is returned to the buyer.
+//!
+//! ### Dispatchable Functions
+//!
+//! - [`start_buyout`][`Pallet::start_buyout`] - post a buyout of the pieces of an RFT token.
+//! - [`cancel_buyout`][`Pallet::cancel_buyout`] - cancel a buyout during its notice period.
+//! - [`settle_buyout`][`Pallet::settle_buyout`] - buy out the pieces after the notice period.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	storage::Key,
+	traits::{
+		schedule::{v3::Named as ScheduleNamed, DispatchTime, HARD_DEADLINE},
+		Bounded, OriginTrait,
+	},
+	transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_common::{
+	CollectionHandle, Error as CommonError, OnTokenTransfer, Pallet as PalletCommon,
+};
+use pallet_evm::account::CrossAccountId;
+use pallet_refungible::{Balance as RefungibleBalance, TotalSupply};
+use pallet_structure::Pallet as PalletStructure;
+use sp_core::hashing::blake2_256;
+use sp_runtime::{
+	traits::{AccountIdConversion, Dispatchable, UniqueSaturatedInto},
+	ArithmeticError, DispatchError, Perbill,
+};
+use up_data_structs::{CollectionId, CollectionMode, RftBuyout, TokenId};
+use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
+/// Call scheduled by the pallet.
+pub type CallOf<T> = <T as Config>::RuntimeCall;
+/// Origin of the calls scheduled by the pallet.
+pub type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+/// Maximum number of holders, whose pieces are bought out by a single settlement of a buyout.
+pub const MAX_HOLDERS_PER_SETTLEMENT: u32 = 16;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_common::Config
+		+ pallet_structure::Config
+		+ pallet_refungible::Config
+	{
+		/// Events compatible with [`frame_system::Config::Event`].
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The vault id, used for deriving the account holding buyout payments.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Share of the pieces of an RFT token, which should be exceeded to post a buyout.
+		#[pallet::constant]
+		type BuyoutThreshold: Get<Perbill>;
+
+		/// Number of blocks between posting a buyout and buying out the pieces.
+		#[pallet::constant]
+		type NoticePeriod: Get<u64>;
+
+		/// The overarching call type, in which the settlement of buyouts is scheduled.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin>
+			+ From<Call<Self>>;
+
+		/// Scheduler of the buyout settlement.
+		type Scheduler: ScheduleNamed<BlockNumberFor<Self>, CallOf<Self>, PalletsOriginOf<Self>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Buyout of the pieces of an RFT token was posted, and its payment was moved to the vault.
+		BuyoutStarted(
+			/// ID of the RFT collection.
+			CollectionId,
+			/// ID of the RFT token.
+			TokenId,
+			/// Buyout terms.
+			RftBuyout<T::CrossAccountId>,
+		),
+
+		/// Pieces of a holder were transferred to the buyer, and the holder was paid for them.
+		PiecesBoughtOut(
+			/// ID of the RFT collection.
+			CollectionId,
+			/// ID of the RFT token.
+			TokenId,
+			/// Former holder of the pieces.
+			T::CrossAccountId,
+			/// Number of the bought out pieces.
+			u128,
+			/// Payment for the pieces.
+			u128,
+		),
+
+		/// Pieces of all the other holders were bought out.
+		BuyoutCompleted(
+			/// ID of the RFT collection.
+			CollectionId,
+			/// ID of the RFT token.
+			TokenId,
+			/// Buyer, who holds all the pieces now.
+			T::CrossAccountId,
+		),
+
+		/// Buyout was cancelled by the buyer or couldn't be completed,
+		/// the rest of the payment was returned to the buyer.
+		BuyoutCancelled(
+			/// ID of the RFT collection.
+			CollectionId,
+			/// ID of the RFT token.
+			TokenId,
+		),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Pieces can only be bought out in RFT collections.
+		RftCollectionExpected,
+		/// Price can only be paid in the native currency or in a fungible collection.
+		UnsupportedCurrency,
+		/// Buyer doesn't hold more than [`BuyoutThreshold`](Config::BuyoutThreshold)
+		/// of the pieces, or already holds all of them.
+		BuyoutThresholdNotReached,
+		/// There is already a buyout of the RFT token.
+		BuyoutAlreadyExists,
+		/// No buyout of the RFT token, or its notice period has ended.
+		BuyoutNotFound,
+		/// Buyout can't be settled before the end of its notice period.
+		BuyoutNotEnded,
+		/// Payment for the pieces exceeds the rest of the buyout payment.
+		InsufficientBuyoutDeposit,
+		/// Pieces held by the vault can't be bought out.
+		PiecesHeldByVault,
+		/// Once the notice period of the buyout has ended, the pieces can only be transferred
+		/// to the buyer.
+		PiecesLockedByBuyout,
+	}
+
+	/// Buyouts of the RFT tokens.
+	#[pallet::storage]
+	pub type Buyouts<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = RftBuyout<T::CrossAccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Post a buyout of the pieces of an RFT token held by the other holders.
+		///
+		/// The payment for their pieces is moved to the vault. After the notice period,
+		/// the pieces are transferred to the buyer, and the holders are paid for them.
+		/// The settlement of the buyout is scheduled at the start of the end block,
+		/// if the scheduler agenda of the block is full, the buyout should be settled
+		/// with [`Pallet::settle_buyout`].
+		///
+		/// # Permissions
+		///
+		/// * Holder of more than [`BuyoutThreshold`](Config::BuyoutThreshold) of the pieces.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the RFT collection.
+		/// * `token_id`: ID of the RFT token.
+		/// * `currency`: ID of the fungible collection, in which the price is paid,
+		/// or 0 for the native currency.
+		/// * `price`: Price of a single piece.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<SelfWeightOf<T>>::start_buyout())]
+		pub fn start_buyout(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			currency: CollectionId,
+			price: u128,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			Self::start_token_buyout(&sender, collection_id, token_id, currency, price)
+		}
+
+		/// Cancel a buyout and get its payment back from the vault.
+		///
+		/// # Permissions
+		///
+		/// * Buyer, during the notice period.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the RFT collection.
+		/// * `token_id`: ID of the RFT token.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<SelfWeightOf<T>>::cancel_buyout())]
+		pub fn cancel_buyout(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let buyout = <Buyouts<T>>::get((collection_id, token_id))
+				.filter(|buyout| <PalletCommon<T>>::block_number() < buyout.end)
+				.ok_or(<Error<T>>::BuyoutNotFound)?;
+			ensure!(sender == buyout.buyer, <CommonError<T>>::NoPermission);
+
+			// =========
+
+			let _ = T::Scheduler::cancel_named(Self::settlement_task(
+				collection_id,
+				token_id,
+				buyout.end,
+			));
+			Self::close_buyout(collection_id, token_id, &buyout)?;
+
+			Self::deposit_event(Event::BuyoutCancelled(collection_id, token_id));
+			Ok(())
+		}
+
+		/// Buy out the pieces of the other holders after the notice period of a buyout.
+		///
+		/// The pieces of up to [`MAX_HOLDERS_PER_SETTLEMENT`] holders are transferred
+		/// to the buyer, and the holders are paid for them out of the vault.
+		/// If the pieces of more holders are left, the next settlement is scheduled
+		/// at the start of the next block. When only the buyer holds the pieces,
+		/// the buyout is completed, and the rest of its payment is returned to the buyer.
+		///
+		/// If the payment doesn't cover the pieces of the other holders, the vault holds
+		/// some of the pieces, or the pieces of a holder can't be transferred,
+		/// the buyout stops, and the rest of its payment is returned to the buyer.
+		///
+		/// The settlement is scheduled at the end of the notice period,
+		/// the call is needed only if the settlement couldn't be scheduled.
+		///
+		/// # Permissions
+		///
+		/// * Anyone.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the RFT collection.
+		/// * `token_id`: ID of the RFT token.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<SelfWeightOf<T>>::settle_buyout(MAX_HOLDERS_PER_SETTLEMENT))]
+		pub fn settle_buyout(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+
+			Self::settle(collection_id, token_id)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get the buyout of the RFT token.
+	pub fn buyout(
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> Option<RftBuyout<T::CrossAccountId>> {
+		<Buyouts<T>>::get((collection_id, token_id))
+	}
+
+	/// Account holding the buyout payments.
+	pub fn vault() -> T::CrossAccountId {
+		T::CrossAccountId::from_sub(T::PalletId::get().into_account_truncating())
+	}
+
+	/// Post a buyout of the pieces of the RFT token. See [`Pallet::start_buyout`].
+	#[transactional]
+	pub fn start_token_buyout(
+		buyer: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		currency: CollectionId,
+		price: u128,
+	) -> DispatchResult {
+		let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+		ensure!(
+			collection.mode == CollectionMode::ReFungible,
+			<Error<T>>::RftCollectionExpected
+		);
+		ensure!(
+			<PalletStructure<T>>::is_currency(currency)?,
+			<Error<T>>::UnsupportedCurrency
+		);
+		ensure!(
+			!<Buyouts<T>>::contains_key((collection_id, token_id)),
+			<Error<T>>::BuyoutAlreadyExists
+		);
+
+		let total = <TotalSupply<T>>::get((collection_id, token_id));
+		let pieces = <RefungibleBalance<T>>::get((collection_id, token_id, buyer));
+		ensure!(
+			pieces > T::BuyoutThreshold::get() * total && pieces < total,
+			<Error<T>>::BuyoutThresholdNotReached
+		);
+		let deposit = price
+			.checked_mul(total - pieces)
+			.ok_or(ArithmeticError::Overflow)?;
+		let end = <PalletCommon<T>>::block_number()
+			.checked_add(T::NoticePeriod::get())
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// =========
+
+		<PalletStructure<T>>::pay(currency, buyer, &Self::vault(), deposit)?;
+		Self::try_schedule_settlement(collection_id, token_id, end);
+		let buyout = RftBuyout {
+			buyer: buyer.clone(),
+			currency,
+			price,
+			deposit,
+			end,
+		};
+		<Buyouts<T>>::insert((collection_id, token_id), buyout.clone());
+
+		Self::deposit_event(Event::BuyoutStarted(collection_id, token_id, buyout));
+		Ok(())
+	}
+
+	/// Name of the scheduler task settling the buyout at the start of the `block`.
+	fn settlement_task(collection_id: CollectionId, token_id: TokenId, block: u64) -> [u8; 32] {
+		(
+			T::PalletId::get(),
+			b"buyout",
+			collection_id,
+			token_id,
+			block,
+		)
+			.using_encoded(blake2_256)
+	}
+
+	/// Schedules the settlement of the buyout at the start of the `block`.
+	///
+	/// If the scheduler agenda of the block is full, the buyout is left
+	/// to be settled with [`Pallet::settle_buyout`].
+	fn try_schedule_settlement(collection_id: CollectionId, token_id: TokenId, block: u64) {
+		let call = CallOf::<T>::from(Call::<T>::settle_buyout {
+			collection_id,
+			token_id,
+		})
+		.encode();
+		let scheduled = call
+			.try_into()
+			.map_err(|_| DispatchError::Exhausted)
+			.and_then(|call| {
+				T::Scheduler::schedule_named(
+					Self::settlement_task(collection_id, token_id, block),
+					DispatchTime::At(block.unique_saturated_into()),
+					None,
+					HARD_DEADLINE,
+					frame_system::RawOrigin::Root.into(),
+					Bounded::Inline(call),
+				)
+			});
+		if let Err(e) = scheduled {
+			log::warn!(
+				"failed to schedule the settlement of the buyout of token {:?}/{:?}: {:?}",
+				collection_id,
+				token_id,
+				e
+			);
+		}
+	}
+
+	/// Settles the buyout after its notice period. See [`Pallet::settle_buyout`].
+	pub fn settle(collection_id: CollectionId, token_id: TokenId) -> DispatchResult {
+		let mut buyout =
+			<Buyouts<T>>::get((collection_id, token_id)).ok_or(<Error<T>>::BuyoutNotFound)?;
+		let block = <PalletCommon<T>>::block_number();
+		ensure!(block >= buyout.end, <Error<T>>::BuyoutNotEnded);
+
+		if let Err(e) = Self::check_buyout_covered(collection_id, token_id, &buyout) {
+			log::warn!(
+				"failed to buy out pieces of token {:?}/{:?}: {:?}",
+				collection_id,
+				token_id,
+				e
+			);
+			Self::close_buyout(collection_id, token_id, &buyout)?;
+			Self::deposit_event(Event::BuyoutCancelled(collection_id, token_id));
+			return Ok(());
+		}
+
+		for _ in 0..MAX_HOLDERS_PER_SETTLEMENT {
+			let holder = <RefungibleBalance<T>>::iter_prefix((collection_id, token_id))
+				.find(|(holder, _)| *holder != buyout.buyer);
+			let Some((holder, pieces)) = holder else {
+				Self::close_buyout(collection_id, token_id, &buyout)?;
+				Self::deposit_event(Event::BuyoutCompleted(
+					collection_id,
+					token_id,
+					buyout.buyer,
+				));
+				return Ok(());
+			};
+
+			match Self::buy_out_pieces(collection_id, token_id, &buyout, &holder, pieces) {
+				Ok(payment) => {
+					buyout.deposit -= payment;
+					Self::deposit_event(Event::PiecesBoughtOut(
+						collection_id,
+						token_id,
+						holder,
+						pieces,
+						payment,
+					));
+				}
+				Err(e) => {
+					log::warn!(
+						"failed to buy out pieces of token {:?}/{:?}: {:?}",
+						collection_id,
+						token_id,
+						e
+					);
+					Self::close_buyout(collection_id, token_id, &buyout)?;
+					Self::deposit_event(Event::BuyoutCancelled(collection_id, token_id));
+					return Ok(());
+				}
+			}
+		}
+
+		<Buyouts<T>>::insert((collection_id, token_id), buyout);
+		Self::try_schedule_settlement(collection_id, token_id, block.saturating_add(1));
+		Ok(())
+	}
+
+	/// Checks, that the rest of the buyout payment covers the pieces of the other holders,
+	/// and that none of them are held by the vault.
+	///
+	/// Pieces can only be transferred to the buyer after the notice period,
+	/// so the check stays valid while the pieces are being bought out.
+	fn check_buyout_covered(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		buyout: &RftBuyout<T::CrossAccountId>,
+	) -> DispatchResult {
+		ensure!(
+			<RefungibleBalance<T>>::get((collection_id, token_id, Self::vault())) == 0,
+			<Error<T>>::PiecesHeldByVault
+		);
+		let total = <TotalSupply<T>>::get((collection_id, token_id));
+		let bought = <RefungibleBalance<T>>::get((collection_id, token_id, &buyout.buyer));
+		let payment = buyout
+			.price
+			.checked_mul(total.saturating_sub(bought))
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			payment <= buyout.deposit,
+			<Error<T>>::InsufficientBuyoutDeposit
+		);
+		Ok(())
+	}
+
+	/// Transfers the pieces of the holder to the buyer and pays for them out of the vault.
+	#[transactional]
+	fn buy_out_pieces(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		buyout: &RftBuyout<T::CrossAccountId>,
+		holder: &T::CrossAccountId,
+		pieces: u128,
+	) -> Result<u128, DispatchError> {
+		let payment = buyout
+			.price
+			.checked_mul(pieces)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			payment <= buyout.deposit,
+			<Error<T>>::InsufficientBuyoutDeposit
+		);
+
+		<PalletStructure<T>>::transfer_as_owner(
+			collection_id,
+			token_id,
+			holder,
+			&buyout.buyer,
+			pieces,
+		)?;
+		<PalletStructure<T>>::pay(buyout.currency, &Self::vault(), holder, payment)?;
+		Ok(payment)
+	}
+
+	/// Removes the buyout and returns the rest of its payment to the buyer.
+	#[transactional]
+	fn close_buyout(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		buyout: &RftBuyout<T::CrossAccountId>,
+	) -> DispatchResult {
+		<Buyouts<T>>::remove((collection_id, token_id));
+		<PalletStructure<T>>::pay(
+			buyout.currency,
+			&Self::vault(),
+			&buyout.buyer,
+			buyout.deposit,
+		)
+	}
+}
+
+impl<T: Config> OnTokenTransfer<T::CrossAccountId> for Pallet<T> {
+	fn check_token_transfer(
+		collection: CollectionId,
+		token: TokenId,
+		_from: &T::CrossAccountId,
+		to: &T::CrossAccountId,
+	) -> DispatchResult {
+		if let Some(buyout) = <Buyouts<T>>::get((collection, token)) {
+			ensure!(
+				<PalletCommon<T>>::block_number() < buyout.end || *to == buyout.buyer,
+				<Error<T>>::PiecesLockedByBuyout
+			);
+		}
+		Ok(())
+	}
+
+	fn on_token_transfer(_collection: CollectionId, _token: TokenId, _from: &T::CrossAccountId) {}
+}
@@ -50,17 +50,28 @@
 //! - `check_nesting` - Check if the token could be nested in the other token
 //! - `nest_if_sent_to_token` - Nest the token in the other token
 //! - `unnest_if_nested` - Unnest the token from the other token
+//! - `burn_item_recursively` - Burn the token together with all of its nested tokens
+//! - `unnest_destroyed` - Unnest the token of a destroyed collection from the other token
+//! - `return_nested_token` - Return the token nested in a token of a destroyed collection
+//! - `transfer_as_owner` - Transfer the token on behalf of its owner for other pallets
+//! - `pay` - Pay in the native currency or a fungible collection for other pallets
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{dispatch::DispatchResult, fail, pallet_prelude::*};
+use frame_support::{
+	dispatch::DispatchResult, fail, pallet_prelude::*, storage::with_storage_layer,
+};
 use pallet_common::{
-	dispatch::CollectionDispatch, erc::CrossAccountId, eth::is_collection,
-	CommonCollectionOperations,
+	dispatch::{dispatch_tx, dispatch_weight, CollectionDispatch},
+	erc::CrossAccountId,
+	eth::is_collection,
+	CollectionHandle, CommonCollectionOperations, NATIVE_FUNGIBLE_COLLECTION_ID,
 };
 use sp_std::collections::btree_set::BTreeSet;
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, CollectionId, TokenId, TokenOwnerError,
+	budget::{self, Budget},
+	mapping::TokenAddressMapping,
+	CollectionId, CollectionMode, TokenId, TokenOwnerError,
 };
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -71,6 +82,12 @@ pub use pallet::*;
 
 pub type SelfWeightOf<T> = <T as crate::Config>::WeightInfo;
 
+/// Limit for searching parents of the tokens returned from destroyed collections.
+const RETURN_NESTING_BUDGET: u32 = 5;
+
+/// Limit for searching parents of the tokens transferred on behalf of other pallets.
+pub const TRANSFER_NESTING_BUDGET: u32 = 5;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::GetDispatchInfo, traits::UnfilteredDispatchable, Parameter};
@@ -359,6 +376,131 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Unnests `token_id` of the destroyed collection from `owner`.
+	///
+	/// Parents from the same collection are skipped, as they are being removed as well.
+	pub fn unnest_destroyed(
+		owner: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) {
+		if !matches!(
+			T::CrossTokenAddressMapping::address_to_token(owner),
+			Some((parent_collection, _)) if parent_collection == collection_id
+		) {
+			Self::unnest_if_nested(owner, collection_id, token_id);
+		}
+	}
+
+	/// Transfers the whole balance of `token_id` nested in the `parent` token
+	/// of a destroyed collection to `owner`, the last owner of the `parent`.
+	///
+	/// Failures are logged, and nothing is changed on them,
+	/// so the token stays owned by the address of the `parent`.
+	pub fn return_nested_token(
+		parent: (CollectionId, TokenId),
+		owner: &T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+	) -> DispatchResult {
+		let from = T::CrossTokenAddressMapping::token_to_address(parent.0, parent.1);
+		with_storage_layer(|| {
+			let dispatch = T::CollectionDispatch::dispatch(collection_id)?;
+			let dispatch = dispatch.as_dyn();
+			let amount = dispatch.balance(from.clone(), token_id);
+			let budget = budget::Value::new(RETURN_NESTING_BUDGET);
+			dispatch
+				.transfer(from, owner.clone(), token_id, amount, &budget)
+				.map(|_| ())
+				.map_err(|e| e.error)
+		})
+		.inspect_err(|e| {
+			log::warn!("failed to return a token nested in a destroyed collection: {e:?}")
+		})
+	}
+
+	/// Weight of [`Self::return_nested_token`] besides the transfer itself.
+	pub fn return_nested_token_weight() -> Weight {
+		T::DbWeight::get().reads(1)
+			+ dispatch_weight::<T>()
+			+ <SelfWeightOf<T>>::find_parent().saturating_mul(RETURN_NESTING_BUDGET.into())
+	}
+
+	/// Checks whether prices can be paid in `currency`:
+	/// it should be the native currency or a fungible collection.
+	pub fn is_currency(currency: CollectionId) -> Result<bool, DispatchError> {
+		if currency == NATIVE_FUNGIBLE_COLLECTION_ID {
+			return Ok(true);
+		}
+		let collection = <CollectionHandle<T>>::try_get(currency)?;
+		Ok(matches!(collection.mode, CollectionMode::Fungible(_)))
+	}
+
+	/// Pays `amount` of `currency` via [`Self::transfer_as_owner`].
+	///
+	/// Nothing is transferred if `amount` is zero.
+	pub fn pay(
+		currency: CollectionId,
+		from: &T::CrossAccountId,
+		to: &T::CrossAccountId,
+		amount: u128,
+	) -> DispatchResult {
+		if amount == 0 {
+			return Ok(());
+		}
+		Self::transfer_as_owner(currency, TokenId::default(), from, to, amount)
+	}
+
+	/// Moves token pieces on behalf of other pallets
+	/// via [`CommonCollectionOperations::transfer_from`], with the owner acting as the spender.
+	pub fn transfer_as_owner(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		from: &T::CrossAccountId,
+		to: &T::CrossAccountId,
+		amount: u128,
+	) -> DispatchResult {
+		let budget = budget::Value::new(TRANSFER_NESTING_BUDGET);
+		dispatch_tx::<T, _>(collection_id, |d| {
+			d.transfer_from(
+				from.clone(),
+				from.clone(),
+				to.clone(),
+				token_id,
+				amount,
+				&budget,
+			)
+		})
+		.map(|_| ())
+		.map_err(|error| error.error)
+	}
+
+	/// Weight of [`Self::transfer_as_owner`] and [`Self::pay`],
+	/// given the weight of the `transfer_from` itself.
+	pub fn transfer_as_owner_weight(transfer_from: Weight) -> Weight {
+		transfer_from
+			+ dispatch_weight::<T>()
+			+ <SelfWeightOf<T>>::find_parent().saturating_mul(TRANSFER_NESTING_BUDGET.into())
+	}
+
+	/// Burns `token_id` owned by `owner` together with the tokens nested in it.
+	///
+	/// Actual implementation is done by the pallet corresponding to the collection type.
+	///
+	/// - `burn_children`: Whether nested tokens are burned too, or returned to `owner`.
+	/// - `budget`: Limit for the number of visited tokens.
+	pub fn burn_item_recursively(
+		owner: T::CrossAccountId,
+		collection_id: CollectionId,
+		token_id: TokenId,
+		burn_children: bool,
+		budget: &dyn Budget,
+	) -> DispatchResultWithPostInfo {
+		let dispatch = T::CollectionDispatch::dispatch(collection_id)?;
+		let dispatch = dispatch.as_dyn();
+		dispatch.burn_item_recursively(owner, token_id, burn_children, budget)
+	}
+
 	/// # Panics
 	/// If [`Self::try_exec_if_token`] fails
 	fn exec_if_token(
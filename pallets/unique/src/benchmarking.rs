@@ -20,13 +20,16 @@ use frame_benchmarking::v2::{account, benchmarks, BenchmarkError};
 use frame_support::traits::{fungible::Balanced, tokens::Precision};
 use frame_system::RawOrigin;
 use pallet_common::{
-	benchmarking::{create_data, create_u16_data},
-	erc::CrossAccountId,
+	benchmarking::{create_data, create_u16_data, create_var_data, property_key},
+	erc::{static_property::key, CrossAccountId},
 	Config as CommonConfig,
 };
+use sp_std::vec::Vec;
 use up_data_structs::{
-	CollectionId, CollectionLimits, CollectionMode, MAX_COLLECTION_DESCRIPTION_LENGTH,
-	MAX_COLLECTION_NAME_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
+	CollectionAdminRoles, CollectionId, CollectionLimits, CollectionMode, CreateItemData,
+	CreateNftData, Property, PropertyKey, PropertyKeyPermission, PropertyPermission, Royalty,
+	TokenId, MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_COLLECTION_NAME_LENGTH,
+	MAX_PROPERTIES_PER_ITEM, MAX_PROPERTY_VALUE_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
 };
 
 use super::*;
@@ -34,6 +37,29 @@ use crate::Pallet;
 
 const SEED: u32 = 1;
 
+fn set_token_property_permissions_helper<T: Config>(
+	owner: T::AccountId,
+	collection: CollectionId,
+	keys: &[PropertyKey],
+) -> DispatchResult {
+	<Pallet<T>>::set_token_property_permissions(
+		RawOrigin::Signed(owner).into(),
+		collection,
+		keys.iter()
+			.map(|key| PropertyKeyPermission {
+				key: key.clone(),
+				permission: PropertyPermission {
+					mutable: true,
+					collection_admin: true,
+					token_owner: true,
+				},
+			})
+			.collect(),
+	)
+	.map(|_| ())
+	.map_err(|error| error.error)
+}
+
 fn create_collection_helper<T: Config>(
 	owner: T::AccountId,
 	mode: CollectionMode,
@@ -159,6 +185,52 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn propose_collection_owner() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_owner: T::AccountId = account("admin", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, new_owner);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_collection_ownership() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_owner: T::AccountId = account("admin", 0, SEED);
+		<Pallet<T>>::propose_collection_owner(
+			RawOrigin::Signed(caller).into(),
+			collection,
+			new_owner.clone(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(new_owner), collection);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_collection_ownership_transfer() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_owner: T::AccountId = account("admin", 0, SEED);
+		<Pallet<T>>::propose_collection_owner(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			new_owner,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn add_collection_admin() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -196,6 +268,32 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_collection_admin_roles() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_admin: T::AccountId = account("admin", 0, SEED);
+		<Pallet<T>>::add_collection_admin(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(new_admin.clone()),
+		)?;
+		let roles = CollectionAdminRoles {
+			minter: true,
+			..Default::default()
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			T::CrossAccountId::from_sub(new_admin),
+			roles,
+		);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_collection_sponsor() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -274,6 +372,196 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_supply_cap() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection =
+			create_collection_helper::<T>(caller.clone(), CollectionMode::Fungible(0))?;
+
+		#[extrinsic_call]
+		set_supply_cap(RawOrigin::Signed(caller), collection, Some(1000));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_mint_authority() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection =
+			create_collection_helper::<T>(caller.clone(), CollectionMode::Fungible(0))?;
+		let authority = T::CrossAccountId::from_sub(account("authority", 0, SEED));
+
+		#[extrinsic_call]
+		set_mint_authority(RawOrigin::Signed(caller), collection, Some(authority));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collection_royalty() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let royalty = Royalty {
+			receiver: T::CrossAccountId::from_sub(caller.clone()),
+			basis_points: 500,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, Some(royalty));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_token_royalty() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		<Pallet<T>>::create_item(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(caller.clone()),
+			CreateItemData::NFT(CreateNftData::default()),
+		)?;
+		let royalty = Royalty {
+			receiver: T::CrossAccountId::from_sub(caller.clone()),
+			basis_points: 500,
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			TokenId(1),
+			Some(royalty),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_token() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		<Pallet<T>>::create_item(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(caller.clone()),
+			CreateItemData::NFT(CreateNftData::default()),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, TokenId(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn thaw_token() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		<Pallet<T>>::create_item(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(caller.clone()),
+			CreateItemData::NFT(CreateNftData::default()),
+		)?;
+		<Pallet<T>>::freeze_token(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			TokenId(1),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, TokenId(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_account() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let frozen_account: T::AccountId = account("admin", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			T::CrossAccountId::from_sub(frozen_account),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn thaw_account() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let frozen_account: T::AccountId = account("admin", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		<Pallet<T>>::freeze_account(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(frozen_account.clone()),
+		)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			T::CrossAccountId::from_sub(frozen_account),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn grant_token_property_write(
+		b: Linear<1, MAX_PROPERTIES_PER_ITEM>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let writer: T::AccountId = account("writer", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let keys = (0..b).map(|k| property_key(k as usize)).collect::<Vec<_>>();
+		set_token_property_permissions_helper::<T>(caller.clone(), collection, &keys)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			T::CrossAccountId::from_sub(writer),
+			keys,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_token_property_write(
+		b: Linear<1, MAX_PROPERTIES_PER_ITEM>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let writer: T::AccountId = account("writer", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let keys = (0..b).map(|k| property_key(k as usize)).collect::<Vec<_>>();
+		set_token_property_permissions_helper::<T>(caller.clone(), collection, &keys)?;
+		<Pallet<T>>::grant_token_property_write(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(writer.clone()),
+			keys.clone(),
+		)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			T::CrossAccountId::from_sub(writer),
+			keys,
+		);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn force_repair_collection() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -284,4 +572,77 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn freeze_token_properties() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let uri_key: PropertyKey = b"a".to_vec().try_into().unwrap();
+		let other_key: PropertyKey = b"b".to_vec().try_into().unwrap();
+
+		// The worst case: the URI template consists of token property placeholders only,
+		// and the token has another property of the maximum length
+		let placeholder = b"{prop:a}";
+		let template = placeholder.repeat(MAX_PROPERTY_VALUE_LENGTH as usize / placeholder.len());
+		<Pallet<T>>::set_collection_properties(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			vec![Property {
+				key: key::uri_template(),
+				value: template.try_into().unwrap(),
+			}],
+		)?;
+		<Pallet<T>>::set_token_property_permissions(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			[uri_key.clone(), other_key.clone()]
+				.into_iter()
+				.map(|key| PropertyKeyPermission {
+					key,
+					permission: PropertyPermission {
+						mutable: false,
+						collection_admin: true,
+						token_owner: false,
+					},
+				})
+				.collect(),
+		)?;
+		<Pallet<T>>::create_item(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(caller.clone()),
+			CreateItemData::NFT(CreateNftData {
+				properties: vec![
+					Property {
+						key: uri_key,
+						value: b"1".to_vec().try_into().unwrap(),
+					},
+					Property {
+						key: other_key,
+						value: create_var_data::<MAX_PROPERTY_VALUE_LENGTH>(
+							MAX_PROPERTY_VALUE_LENGTH,
+						),
+					},
+				]
+				.try_into()
+				.unwrap(),
+			}),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, TokenId(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_collection_metadata() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection);
+
+		Ok(())
+	}
 }
@@ -380,7 +380,7 @@ where
 
 		self.recorder().consume_sstore()?;
 		collection
-			.check_is_owner_or_admin(&caller)
+			.check_has_admin_role(&caller, |roles| roles.property_editor)
 			.map_err(dispatch_to_evm::<T>)?;
 
 		if collection.flags.erc721metadata {
@@ -446,7 +446,7 @@ where
 		Ok(())
 	}
 
-	#[weight(<SelfWeightOf<T>>::destroy_collection())]
+	#[weight(<Pallet<T>>::destroy_collection_weight())]
 	fn destroy_collection(&mut self, caller: Caller, collection_address: Address) -> Result<()> {
 		let caller = T::CrossAccountId::from_eth(caller);
 
@@ -36,12 +36,16 @@
 //!
 //! - `create_collection` - Create a collection of tokens. **Deprecated**, use `create_collection_ex`.
 //! - `create_collection_ex` - Create a collection of tokens with explicit parameters.
-//! - `destroy_collection` - Destroy a collection if no tokens exist within.
+//! - `destroy_collection` - Destroy a collection, its tokens are removed in the background.
 //! - `add_to_allow_list` - Add an address to allow list.
 //! - `remove_from_allow_list` - Remove an address from allow list.
 //! - `change_collection_owner` - Change the owner of the collection.
+//! - `propose_collection_owner` - Propose a new owner of the collection.
+//! - `accept_collection_ownership` - Accept the proposed ownership of a collection, becoming the owner.
+//! - `cancel_collection_ownership_transfer` - Cancel the proposed transfer of the collection ownership.
 //! - `add_collection_admin` - Add an admin to a collection.
 //! - `remove_collection_admin` - Remove admin of a collection.
+//! - `set_collection_admin_roles` - Restrict the operations a collection admin is allowed to perform.
 //! - `set_collection_sponsor` - Invite a new collection sponsor.
 //! - `confirm_sponsorship` - Confirm own sponsorship of a collection, becoming the sponsor.
 //! - `remove_collection_sponsor` - Remove a sponsor from a collection.
@@ -52,16 +56,39 @@
 //! - `set_token_properties` - Add or change token properties.
 //! - `delete_token_properties` - Delete token properties.
 //! - `set_token_property_permissions` - Add or change token property permissions of a collection.
+//! - `set_token_property_value_types` - Declare or remove the types of token property values of a collection.
+//! - `grant_token_property_write` - Allow an account to write specific token properties.
+//! - `revoke_token_property_write` - Revoke the right to write specific token properties.
+//! - `set_default_token_properties` - Add or change default token properties of a collection.
+//! - `delete_default_token_properties` - Delete default token properties of a collection.
+//! - `freeze_token_properties` - Permanently freeze the properties of a token.
+//! - `freeze_collection_metadata` - Permanently freeze the properties, royalty and token property permissions of a collection.
 //! - `create_multiple_items_ex` - Create multiple items within a collection with explicitly specified initial parameters.
 //! - `set_transfers_enabled_flag` - Completely allow or disallow transfers for a particular collection.
 //! - `burn_item` - Destroy an item.
 //! - `burn_from` - Destroy an item on behalf of the owner as a non-owner account.
+//! - `burn_recursively` - Destroy an item together with its nested items, or return them to the owner.
 //! - `transfer` - Change ownership of the token.
 //! - `transfer_from` - Change ownership of the token on behalf of the owner as a non-owner account.
 //! - `approve` - Allow a non-permissioned address to transfer or burn an item.
 //! - `set_collection_limits` - Set specific limits of a collection.
+//! - `set_mint_authority` - Set the only account allowed to mint and burn tokens of a fungible collection.
+//! - `set_supply_cap` - Set the maximum total supply of a fungible collection.
 //! - `set_collection_permissions` - Set specific permissions of a collection.
 //! - `repartition` - Re-partition a refungible token, while owning all of its parts.
+//! - `set_collection_royalty` - Set or remove the EIP-2981 royalty of a collection.
+//! - `set_token_royalty` - Set or remove the EIP-2981 royalty of a token, overriding the collection one.
+//! - `set_token_lock` - Lock or unlock a token against transfers.
+//! - `freeze_token` - Freeze a token, blocking its transfers, approvals and nesting.
+//! - `thaw_token` - Thaw a frozen token.
+//! - `freeze_account` - Freeze an account in a collection, blocking transfers and approvals of its holdings.
+//! - `thaw_account` - Thaw a frozen account in a collection.
+//! - `set_token_user` - Set or remove the ERC-4907 user of an NFT.
+//! - `approve_with_deadline` - Allow a non-permissioned address to transfer or burn an item until the given block.
+//! - `set_allowance_for_all_with_deadline` - Grant operator status until the given block.
+//! - `create_swap_offer` - Offer to swap assets of any collections, holding the offered ones in escrow.
+//! - `accept_swap_offer` - Accept a swap offer, atomically exchanging the assets.
+//! - `cancel_swap_offer` - Cancel a swap offer, returning the offered assets to the maker.
 
 #![recursion_limit = "1024"]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -88,25 +115,32 @@ pub mod pallet {
 		dispatch::{DispatchErrorWithPostInfo, DispatchResult, PostDispatchInfo},
 		ensure, fail,
 		storage::Key,
+		weights::WeightMeter,
 		BoundedVec,
 	};
 	use frame_system::{ensure_root, ensure_signed};
 	use pallet_common::{
-		dispatch::{dispatch_tx, CollectionDispatch},
-		CollectionHandle, CollectionIssuer, CommonWeightInfo, Pallet as PalletCommon,
-		RefungibleExtensionsWeightInfo,
+		dispatch::{dispatch_tx, dispatch_weight, CollectionDispatch},
+		helpers::{migration_step_limit, StorageCleaner},
+		weights::WeightInfo as _,
+		CollectionHandle, CollectionIssuer, CommonWeightInfo, DestroyingCollections,
+		Event as CommonEvent, Pallet as PalletCommon, RefungibleExtensionsWeightInfo,
+		SelfWeightOf as PalletCommonWeightOf,
 	};
 	use pallet_evm::account::CrossAccountId;
 	use pallet_structure::weights::WeightInfo as StructureWeightInfo;
 	use scale_info::TypeInfo;
+	use sp_runtime::ArithmeticError;
 	use sp_std::{vec, vec::Vec};
 	use up_data_structs::{
-		budget, CollectionId, CollectionLimits, CollectionMode, CollectionPermissions,
-		CreateCollectionData, CreateItemData, CreateItemExData, Property, PropertyKey,
-		PropertyKeyPermission, TokenId, COLLECTION_ADMINS_LIMIT, MAX_COLLECTION_DESCRIPTION_LENGTH,
+		budget, CollectionAdminRoles, CollectionDestruction, CollectionId, CollectionLimits,
+		CollectionMode, CollectionPermissions, CreateCollectionData, CreateItemData,
+		CreateItemExData, Property, PropertyKey, PropertyKeyPermission, PropertyKeyValueType,
+		Royalty, SwapAsset, SwapAssets, SwapOffer, TokenId, TokenUser, TransferItems,
+		COLLECTION_ADMINS_LIMIT, MAX_BURN_RECURSIVELY_NODES, MAX_COLLECTION_DESCRIPTION_LENGTH,
 		MAX_COLLECTION_NAME_LENGTH, MAX_COLLECTION_PROPERTIES_SIZE, MAX_PROPERTIES_PER_ITEM,
-		MAX_PROPERTY_KEY_LENGTH, MAX_PROPERTY_VALUE_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
-		MAX_TOKEN_PROPERTIES_SIZE,
+		MAX_PROPERTY_KEY_LENGTH, MAX_PROPERTY_VALUE_LENGTH, MAX_SWAP_OFFER_ASSETS,
+		MAX_TOKEN_PREFIX_LENGTH, MAX_TOKEN_PROPERTIES_SIZE,
 	};
 	use weights::WeightInfo;
 
@@ -121,6 +155,34 @@ pub mod pallet {
 		EmptyArgument,
 		/// Repertition is only supported by refungible collection.
 		RepartitionCalledOnNonRefungibleCollection,
+		/// Swap offer with the given ID does not exist.
+		SwapOfferNotFound,
+		/// Swap offer can no longer be accepted.
+		SwapOfferExpired,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Swap offer was created, the assets of the maker are held in escrow.
+		///
+		/// # Arguments
+		/// * offer_id: ID of the created offer.
+		/// * maker: Account which created the offer.
+		SwapOfferCreated(u32, T::CrossAccountId),
+
+		/// Swap offer was accepted, the assets were exchanged.
+		///
+		/// # Arguments
+		/// * offer_id: ID of the accepted offer.
+		/// * taker: Account which accepted the offer.
+		SwapOfferAccepted(u32, T::CrossAccountId),
+
+		/// Swap offer was cancelled, the assets were returned to the maker.
+		///
+		/// # Arguments
+		/// * offer_id: ID of the cancelled offer.
+		SwapOfferCancelled(u32),
 	}
 
 	/// Configuration trait of this pallet.
@@ -136,13 +198,23 @@ pub mod pallet {
 
 		/// Weight info information for extra refungible pallet operations.
 		type RefungibleExtensionsWeightInfo: RefungibleExtensionsWeightInfo;
+
+		/// The overarching event type.
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	pub type SelfWeightOf<T> = <T as Config>::WeightInfo;
 
+	/// Number of storage entries of a destroyed collection,
+	/// removed within the destroying transaction.
+	const DESTRUCTION_CHUNK_ENTRIES: u64 = 64;
+
 	// # Used definitions
 	//
 	// ## User control levels
@@ -173,16 +245,22 @@ pub mod pallet {
 	//#endregion
 
 	//#region Tokens transfer sponosoring rate limit baskets
-	/// (Collection id (controlled?2), who created (real))
-	/// TODO: Off chain worker should remove from this map when collection gets removed
+	/// Collection id (controlled?2), who created (real)
 	#[pallet::storage]
 	#[pallet::getter(fn create_item_busket)]
-	pub type CreateItemBasket<T: Config> = StorageMap<
-		Hasher = Blake2_128Concat,
-		Key = (CollectionId, T::AccountId),
+	pub type CreateItemBasket<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = CollectionId,
+		Hasher2 = Twox64Concat,
+		Key2 = T::AccountId,
 		Value = BlockNumberFor<T>,
 		QueryKind = OptionQuery,
 	>;
+	/// Whether the entries of [`CreateItemBasket`] from before the collection became its first key
+	/// are still being removed.
+	#[pallet::storage]
+	pub type ClearingCreateItemBasket<T> = StorageValue<Value = bool, QueryKind = ValueQuery>;
+
 	/// Collection id (controlled?2), token id (controlled?2)
 	#[pallet::storage]
 	#[pallet::getter(fn nft_transfer_basket)]
@@ -266,6 +344,19 @@ pub mod pallet {
 		QueryKind = OptionQuery,
 	>;
 
+	/// Number of created swap offers. Essentially contains the last offer ID.
+	#[pallet::storage]
+	pub type SwapOfferCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Open swap offers.
+	#[pallet::storage]
+	pub type SwapOffers<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = u32,
+		Value = SwapOffer<T::CrossAccountId>,
+		QueryKind = OptionQuery,
+	>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		/// A maximum number of levels of depth in the token nesting tree.
@@ -334,6 +425,60 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Removes the old entries of [`CreateItemBasket`] over multiple blocks.
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			if !<ClearingCreateItemBasket<T>>::get() {
+				return T::DbWeight::get().reads(1);
+			}
+			let mut meter = WeightMeter::with_limit(migration_step_limit::<T>());
+			meter.consume(T::DbWeight::get().reads(1));
+			let mut cleaner = StorageCleaner::<T>::new(&mut meter);
+			if cleaner.clear_prefix(|limit| <CreateItemBasket<T>>::clear(limit, None)) {
+				<ClearingCreateItemBasket<T>>::kill();
+				meter.consume(T::DbWeight::get().writes(1));
+			}
+			meter.consumed()
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if Self::on_chain_storage_version() < 1_u16 {
+				// `CreateItemBasket` is now keyed by the collection first.
+				// Its entries only rate-limit sponsoring, so the old ones are dropped
+				// by `on_initialize` over the following blocks.
+				<ClearingCreateItemBasket<T>>::put(true);
+				STORAGE_VERSION.put::<Self>();
+				T::DbWeight::get().writes(2)
+			} else {
+				Weight::zero()
+			}
+		}
+
+		/// Removes the storage of the destroyed collections with the weight left in the block.
+		///
+		/// The collections are visited in turn, so a collection whose destruction can't
+		/// progress doesn't hold back the others.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			let mut last = None;
+			while meter.try_consume(T::DbWeight::get().reads(1)).is_ok() {
+				let mut collections = match last {
+					Some(collection_id) => <DestroyingCollections<T>>::iter_keys_from(
+						<DestroyingCollections<T>>::hashed_key_for(collection_id),
+					),
+					None => <DestroyingCollections<T>>::iter_keys(),
+				};
+				let Some(collection_id) = collections.next() else {
+					break;
+				};
+				Self::clear_destroying_collection(collection_id, &mut meter);
+				last = Some(collection_id);
+			}
+			meter.consumed()
+		}
+	}
+
 	/// Type alias to Pallet, to be used by construct_runtime.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -411,7 +556,13 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Destroy a collection if no tokens exist within.
+		/// Destroy a collection.
+		///
+		/// The collection stops accepting operations right away. Its tokens, balances
+		/// and the rest of the storage are removed in bounded chunks with the weight
+		/// left in the blocks, until [`CollectionDestroyed`] is emitted.
+		///
+		/// [`CollectionDestroyed`]: pallet_common::Event::CollectionDestroyed
 		///
 		/// # Permissions
 		///
@@ -421,7 +572,7 @@ pub mod pallet {
 		///
 		/// * `collection_id`: Collection to destroy.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<SelfWeightOf<T>>::destroy_collection())]
+		#[pallet::weight(Pallet::<T>::destroy_collection_weight())]
 		pub fn destroy_collection(
 			origin: OriginFor<T>,
 			collection_id: CollectionId,
@@ -1107,7 +1258,7 @@ pub mod pallet {
 			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
 
 			dispatch_tx::<T, _>(collection_id, |d| {
-				d.approve(sender, spender, item_id, amount)
+				d.approve(sender, spender, item_id, amount, None)
 			})
 		}
 
@@ -1140,7 +1291,7 @@ pub mod pallet {
 			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
 
 			dispatch_tx::<T, _>(collection_id, |d| {
-				d.approve_from(sender, from, to, item_id, amount)
+				d.approve_from(sender, from, to, item_id, amount, None)
 			})
 		}
 
@@ -1291,7 +1442,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
 			dispatch_tx::<T, _>(collection_id, |d| {
-				d.set_allowance_for_all(sender, operator, approve)
+				d.set_allowance_for_all(sender, operator, approve, None)
 			})
 		}
 
@@ -1329,66 +1480,1129 @@ pub mod pallet {
 			ensure_root(origin)?;
 			dispatch_tx::<T, _>(collection_id, |d| d.repair_item(item_id))
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		/// Force set `sponsor` for `collection`.
+		/// Set or remove the royalty of a collection.
 		///
-		/// Differs from [`set_collection_sponsor`][`Pallet::set_collection_sponsor`] in that confirmation
-		/// from the `sponsor` is not required.
+		/// The royalty is reported by EIP-2981 `royaltyInfo`
+		/// for tokens that don't have their own royalty.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
 		///
 		/// # Arguments
 		///
-		/// * `sponsor`: ID of the account of the sponsor-to-be.
 		/// * `collection_id`: ID of the modified collection.
-		pub fn force_set_sponsor(
-			sponsor: T::AccountId,
+		/// * `royalty`: Royalty receiver and amount in basis points, `None` to remove the royalty.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
 			collection_id: CollectionId,
+			royalty: Option<Royalty<T::CrossAccountId>>,
 		) -> DispatchResult {
-			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
-			target_collection.force_set_sponsor(sponsor)
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.check_is_internal()?;
+
+			<PalletCommon<T>>::set_collection_royalty(&collection, &sender, royalty)
 		}
 
-		/// Force remove `sponsor` for `collection`.
+		/// Set or remove the royalty of a token, overriding the royalty of its collection.
 		///
-		/// Differs from `remove_sponsor` in that
-		/// it doesn't require consent from the `owner` of the collection.
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
 		///
 		/// # Arguments
 		///
-		/// * `collection_id`: ID of the modified collection.
-		pub fn force_remove_collection_sponsor(collection_id: CollectionId) -> DispatchResult {
-			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
-			target_collection.force_remove_sponsor()
+		/// * `collection_id`: ID of the collection to which the token belongs.
+		/// * `token_id`: ID of the modified token.
+		/// * `royalty`: Royalty receiver and amount in basis points,
+		/// `None` to fall back to the collection royalty.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_token_royalty())]
+		pub fn set_token_royalty(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			royalty: Option<Royalty<T::CrossAccountId>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				<PalletCommon<T>>::set_token_royalty(&collection, d, &sender, token_id, royalty)?;
+				Ok(().into())
+			})
 		}
 
-		#[inline(always)]
-		pub(crate) fn destroy_collection_internal(
-			sender: T::CrossAccountId,
+		/// Lock or unlock a token against transfers.
+		///
+		/// Locked tokens can not be transferred, but can still be burnt.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to which the token belongs.
+		/// * `token_id`: ID of the token to lock or unlock.
+		/// * `locked`: Should the token be locked?
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::CommonWeightInfo::set_token_lock())]
+		pub fn set_token_lock(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			locked: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			dispatch_tx::<T, _>(collection_id, |d| {
+				d.set_token_lock(sender, token_id, locked)
+			})
+		}
+
+		/// Freeze a token, blocking its transfers, approvals and nesting under it.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to which the token belongs.
+		/// * `token_id`: ID of the token to freeze.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<SelfWeightOf<T>>::freeze_token())]
+		pub fn freeze_token(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				<PalletCommon<T>>::toggle_token_freeze(&collection, d, &sender, token_id, true)?;
+				Ok(().into())
+			})
+		}
+
+		/// Thaw a token, unblocking its transfers, approvals and nesting under it.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to which the token belongs.
+		/// * `token_id`: ID of the token to thaw.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<SelfWeightOf<T>>::thaw_token())]
+		pub fn thaw_token(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				<PalletCommon<T>>::toggle_token_freeze(&collection, d, &sender, token_id, false)?;
+				Ok(().into())
+			})
+		}
+
+		/// Freeze an account in a collection, blocking transfers and approvals of its holdings.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `address`: ID of the address to freeze.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<SelfWeightOf<T>>::freeze_account())]
+		pub fn freeze_account(
+			origin: OriginFor<T>,
 			collection_id: CollectionId,
+			address: T::CrossAccountId,
 		) -> DispatchResult {
-			T::CollectionDispatch::destroy(sender, collection_id)?;
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.check_is_internal()?;
+
+			<PalletCommon<T>>::toggle_account_freeze(&collection, &sender, &address, true)?;
+
+			Ok(())
+		}
 
-			// TODO: basket cleanup should be moved elsewhere
-			// Maybe runtime dispatch.rs should perform it?
+		/// Thaw an account in a collection, unblocking transfers and approvals of its holdings.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `address`: ID of the address to thaw.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<SelfWeightOf<T>>::thaw_account())]
+		pub fn thaw_account(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			address: T::CrossAccountId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
 
-			let _ = <NftTransferBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
-			let _ = <FungibleTransferBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
-			let _ = <ReFungibleTransferBasket<T>>::clear_prefix((collection_id,), u32::MAX, None);
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.check_is_internal()?;
 
-			let _ = <NftApproveBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
-			let _ = <FungibleApproveBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
-			let _ = <RefungibleApproveBasket<T>>::clear_prefix((collection_id,), u32::MAX, None);
+			<PalletCommon<T>>::toggle_account_freeze(&collection, &sender, &address, false)?;
 
 			Ok(())
 		}
 
-		fn structure_nesting_budget() -> budget::Value {
-			budget::Value::new(Self::nesting_budget())
+		/// Set or remove the ERC-4907 user of an NFT.
+		///
+		/// The user is allowed to use the token until the expiry block,
+		/// and is removed when the token is transferred.
+		///
+		/// # Permissions
+		///
+		/// * Token owner
+		/// * Approved spender of the token
+		/// * Operator approved for all tokens of the owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to which the token belongs.
+		/// * `token_id`: ID of the token to set the user of.
+		/// * `user`: New user of the token with its expiry block, `None` removes the user.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::CommonWeightInfo::set_token_user())]
+		pub fn set_token_user(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			user: Option<TokenUser<T::CrossAccountId>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			dispatch_tx::<T, _>(collection_id, |d| d.set_token_user(sender, token_id, user))
 		}
 
-		fn nesting_budget_predispatch_weight() -> Weight {
-			T::StructureWeightInfo::find_parent().saturating_mul(Self::nesting_budget() as u64)
+		/// Allow a non-permissioned address to transfer or burn an item until the given block.
+		///
+		/// Same as [`approve`][`Pallet::approve`], but the approval can no longer be used
+		/// once the `deadline` block has passed.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		/// * Current item owner
+		///
+		/// # Arguments
+		///
+		/// * `spender`: Account to be approved to make specific transactions on non-owned tokens.
+		/// * `collection_id`: ID of the collection the item belongs to.
+		/// * `item_id`: ID of the item transactions on which are now approved.
+		/// * `amount`: Number of pieces of the item approved for a transaction (maximum of 1 for NFTs).
+		/// Set to 0 to revoke the approval.
+		/// * `deadline`: Last block in which the approval can be used.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::CommonWeightInfo::approve())]
+		pub fn approve_with_deadline(
+			origin: OriginFor<T>,
+			spender: T::CrossAccountId,
+			collection_id: CollectionId,
+			item_id: TokenId,
+			amount: u128,
+			deadline: u64,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				d.approve(sender, spender, item_id, amount, Some(deadline))
+			})
+		}
+
+		/// Grant the operator status to the given `operator` until the given block.
+		///
+		/// Same as [`set_allowance_for_all`][`Pallet::set_allowance_for_all`], but the
+		/// operator status can no longer be used once the `deadline` block has passed.
+		///
+		/// # Arguments
+		///
+		/// * `operator`: Operator
+		/// * `deadline`: Last block in which the operator status can be used.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::CommonWeightInfo::set_allowance_for_all())]
+		pub fn set_allowance_for_all_with_deadline(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			operator: T::CrossAccountId,
+			deadline: u64,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			dispatch_tx::<T, _>(collection_id, |d| {
+				d.set_allowance_for_all(sender, operator, true, Some(deadline))
+			})
+		}
+
+		/// Offer to swap assets of any collections, including the native fungible one.
+		///
+		/// The offered assets are transferred to the swap escrow account until
+		/// the offer is accepted or cancelled. Neither the escrowed tokens
+		/// nor the escrow account itself can be frozen or locked meanwhile.
+		///
+		/// # Arguments
+		///
+		/// * `give`: Assets the sender gives.
+		/// * `want`: Assets the sender wants in return.
+		/// * `counterparty`: Account which is allowed to accept the offer, anyone if not set.
+		/// * `expires`: Last block in which the offer can be accepted.
+		#[pallet::call_index(42)]
+		#[pallet::weight(<Pallet<T>>::swap_transfers_weight(give.len()) + T::DbWeight::get().reads_writes(1, 2))]
+		pub fn create_swap_offer(
+			origin: OriginFor<T>,
+			give: SwapAssets,
+			want: SwapAssets,
+			counterparty: Option<T::CrossAccountId>,
+			expires: Option<u64>,
+		) -> DispatchResult {
+			let maker = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			ensure!(
+				!give.is_empty() && !want.is_empty(),
+				<Error<T>>::EmptyArgument
+			);
+			<PalletCommon<T>>::check_deadline(expires)?;
+
+			let escrow = <PalletCommon<T>>::swap_escrow();
+			for asset in &give {
+				Self::swap_transfer(asset, &maker, &escrow)?;
+			}
+
+			let offer_id = <SwapOfferCount<T>>::get()
+				.checked_add(1)
+				.ok_or(ArithmeticError::Overflow)?;
+			<SwapOfferCount<T>>::put(offer_id);
+			<SwapOffers<T>>::insert(
+				offer_id,
+				SwapOffer {
+					maker: maker.clone(),
+					counterparty,
+					give,
+					want,
+					expires,
+				},
+			);
+
+			Self::deposit_event(Event::SwapOfferCreated(offer_id, maker));
+			Ok(())
+		}
+
+		/// Accept a swap offer.
+		///
+		/// The wanted assets are transferred from the sender to the maker of the offer,
+		/// and the offered assets are transferred from the swap escrow to the sender, atomically.
+		///
+		/// # Permissions
+		///
+		/// * Counterparty of the offer, if set
+		///
+		/// # Arguments
+		///
+		/// * `offer_id`: ID of the offer to accept.
+		#[pallet::call_index(43)]
+		#[pallet::weight(<Pallet<T>>::swap_transfers_weight(2 * MAX_SWAP_OFFER_ASSETS as usize) + T::DbWeight::get().reads_writes(1, 1))]
+		pub fn accept_swap_offer(
+			origin: OriginFor<T>,
+			offer_id: u32,
+		) -> DispatchResultWithPostInfo {
+			let taker = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let offer = <SwapOffers<T>>::get(offer_id).ok_or(<Error<T>>::SwapOfferNotFound)?;
+			if let Some(counterparty) = &offer.counterparty {
+				ensure!(
+					counterparty == &taker,
+					<pallet_common::Error<T>>::NoPermission
+				);
+			}
+			ensure!(
+				!<PalletCommon<T>>::deadline_passed(offer.expires),
+				<Error<T>>::SwapOfferExpired
+			);
+
+			// =========
+
+			<SwapOffers<T>>::remove(offer_id);
+			for asset in &offer.want {
+				Self::swap_transfer(asset, &taker, &offer.maker)?;
+			}
+			let escrow = <PalletCommon<T>>::swap_escrow();
+			for asset in &offer.give {
+				Self::swap_transfer(asset, &escrow, &taker)?;
+			}
+
+			Self::deposit_event(Event::SwapOfferAccepted(offer_id, taker));
+			let weight = Self::swap_transfers_weight(offer.want.len() + offer.give.len());
+			Ok(Some(weight + T::DbWeight::get().reads_writes(1, 1)).into())
+		}
+
+		/// Cancel a swap offer, returning the offered assets to the maker.
+		///
+		/// # Permissions
+		///
+		/// * Maker of the offer
+		/// * Anyone, once the offer has expired
+		///
+		/// # Arguments
+		///
+		/// * `offer_id`: ID of the offer to cancel.
+		#[pallet::call_index(44)]
+		#[pallet::weight(<Pallet<T>>::swap_transfers_weight(MAX_SWAP_OFFER_ASSETS as usize) + T::DbWeight::get().reads_writes(1, 1))]
+		pub fn cancel_swap_offer(
+			origin: OriginFor<T>,
+			offer_id: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let offer = <SwapOffers<T>>::get(offer_id).ok_or(<Error<T>>::SwapOfferNotFound)?;
+			ensure!(
+				offer.maker == sender || <PalletCommon<T>>::deadline_passed(offer.expires),
+				<pallet_common::Error<T>>::NoPermission
+			);
+
+			// =========
+
+			<SwapOffers<T>>::remove(offer_id);
+			let escrow = <PalletCommon<T>>::swap_escrow();
+			for asset in &offer.give {
+				Self::swap_transfer(asset, &escrow, &offer.maker)?;
+			}
+
+			Self::deposit_event(Event::SwapOfferCancelled(offer_id));
+			let weight = Self::swap_transfers_weight(offer.give.len());
+			Ok(Some(weight + T::DbWeight::get().reads_writes(1, 1)).into())
+		}
+
+		/// Transfer multiple tokens, possibly of different collections, in one transaction.
+		///
+		/// Either all transfers succeed, or none of them is applied.
+		/// Nesting is checked for each transfer separately.
+		///
+		/// # Permissions
+		///
+		/// * Current owner of each token
+		///
+		/// # Arguments
+		///
+		/// * `items`: Collection, token, recipient and amount of each transfer,
+		/// see [`transfer`][`Pallet::transfer`] for the meaning of the token and the amount.
+		#[pallet::call_index(45)]
+		#[pallet::weight(<Pallet<T>>::transfer_multiple_weight(items.len(), T::CommonWeightInfo::transfer()))]
+		pub fn transfer_multiple(
+			origin: OriginFor<T>,
+			items: TransferItems<T::CrossAccountId>,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			ensure!(!items.is_empty(), Error::<T>::EmptyArgument);
+
+			for item in items {
+				let budget = Self::structure_nesting_budget();
+				dispatch_tx::<T, _>(item.collection, |d| {
+					d.transfer(
+						sender.clone(),
+						item.recipient,
+						item.token,
+						item.amount,
+						&budget,
+					)
+				})
+				.map_err(|error| error.error)?;
+			}
+			Ok(())
+		}
+
+		/// Transfer multiple tokens of the same owner on their behalf, possibly
+		/// of different collections, in one transaction.
+		///
+		/// Either all transfers succeed, or none of them is applied.
+		/// Nesting is checked for each transfer separately.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		/// * Current owner of each token
+		/// * Address approved by the owner for each token
+		///
+		/// # Arguments
+		///
+		/// * `from`: Address that currently owns the tokens.
+		/// * `items`: Collection, token, recipient and amount of each transfer,
+		/// see [`transfer_from`][`Pallet::transfer_from`] for the meaning
+		/// of the token and the amount.
+		#[pallet::call_index(46)]
+		#[pallet::weight(<Pallet<T>>::transfer_multiple_weight(items.len(), T::CommonWeightInfo::transfer_from()))]
+		pub fn transfer_from_multiple(
+			origin: OriginFor<T>,
+			from: T::CrossAccountId,
+			items: TransferItems<T::CrossAccountId>,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			ensure!(!items.is_empty(), Error::<T>::EmptyArgument);
+
+			for item in items {
+				let budget = Self::structure_nesting_budget();
+				dispatch_tx::<T, _>(item.collection, |d| {
+					d.transfer_from(
+						sender.clone(),
+						from.clone(),
+						item.recipient,
+						item.token,
+						item.amount,
+						&budget,
+					)
+				})
+				.map_err(|error| error.error)?;
+			}
+			Ok(())
+		}
+
+		/// Destroy a token together with all of the tokens nested in it.
+		///
+		/// Nested tokens are walked through the nesting tree and either destroyed
+		/// as well, or transferred to the sender, depending on `burn_children`.
+		/// At most [`MAX_BURN_RECURSIVELY_NODES`] tokens can be visited,
+		/// the fee is charged only for the actually visited ones.
+		///
+		/// # Permissions
+		///
+		/// * Current item owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to which the item belongs.
+		/// * `item_id`: ID of item to burn.
+		/// * `burn_children`: Whether to destroy the nested tokens or return them to the sender.
+		/// 	* Fungible and Re-Fungible Mode: the whole balance of the sender is destroyed.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::CommonWeightInfo::burn_recursively(MAX_BURN_RECURSIVELY_NODES))]
+		pub fn burn_recursively(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			item_id: TokenId,
+			burn_children: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let budget = budget::Value::new(MAX_BURN_RECURSIVELY_NODES);
+
+			let post_info = dispatch_tx::<T, _>(collection_id, |d| {
+				d.burn_item_recursively(sender, item_id, burn_children, &budget)
+			})?;
+			<NftTransferBasket<T>>::remove(collection_id, item_id);
+			<NftApproveBasket<T>>::remove(collection_id, item_id);
+			Ok(post_info)
+		}
+
+		/// Propose a new owner of the collection.
+		///
+		/// Unlike [`change_collection_owner`][`Pallet::change_collection_owner`],
+		/// the ownership is not transferred until the proposed owner accepts it,
+		/// see [`accept_collection_ownership`][`Pallet::accept_collection_ownership`].
+		/// Proposing another owner replaces the previous proposal.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `new_owner`: ID of the account proposed as the new owner.
+		#[pallet::call_index(48)]
+		#[pallet::weight(<SelfWeightOf<T>>::propose_collection_owner())]
+		pub fn propose_collection_owner(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let new_owner = T::CrossAccountId::from_sub(new_owner);
+			let target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.propose_owner(&sender, new_owner)
+		}
+
+		/// Accept the proposed ownership of a collection, becoming the owner.
+		///
+		/// A proposal must be pending, see [`propose_collection_owner`][`Pallet::propose_collection_owner`].
+		///
+		/// # Permissions
+		///
+		/// * Proposed owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection with the pending owner.
+		#[pallet::call_index(49)]
+		#[pallet::weight(<SelfWeightOf<T>>::accept_collection_ownership())]
+		pub fn accept_collection_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.accept_ownership(&sender)
+		}
+
+		/// Cancel the proposed transfer of the collection ownership.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection with the pending owner.
+		#[pallet::call_index(50)]
+		#[pallet::weight(<SelfWeightOf<T>>::cancel_collection_ownership_transfer())]
+		pub fn cancel_collection_ownership_transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.cancel_ownership_transfer(&sender)
+		}
+
+		/// Set the roles of a collection admin.
+		///
+		/// Roles restrict the operations the admin is allowed to perform:
+		/// minting, editing properties, managing the allowlist, the sponsor or the limits,
+		/// and managing tokens of other owners, i.e. transferring, burning and approving them
+		/// when the collection limits allow it and nesting under them when the nesting
+		/// permissions allow it to admins.
+		/// Admins added with [`add_collection_admin`][`Pallet::add_collection_admin`] have all the roles.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the Collection the admin belongs to.
+		/// * `admin`: Address of the admin.
+		/// * `roles`: New roles of the admin.
+		#[pallet::call_index(51)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_collection_admin_roles())]
+		pub fn set_collection_admin_roles(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			admin: T::CrossAccountId,
+			roles: CollectionAdminRoles,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::set_admin_roles(&collection, &sender, &admin, roles)
+		}
+
+		/// Allow an account to write token properties with specific keys.
+		///
+		/// The account can then set and delete these properties of any token in the collection
+		/// without being a collection admin or the token owner, e.g. to let an off-chain oracle
+		/// update them. Immutable properties still can't be changed once they are set.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `account`: Address of the account to grant the right to.
+		/// * `property_keys`: Keys of the token properties the account is allowed to write.
+		#[pallet::call_index(52)]
+		#[pallet::weight(<SelfWeightOf<T>>::grant_token_property_write(property_keys.len() as u32))]
+		pub fn grant_token_property_write(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			account: T::CrossAccountId,
+			property_keys: Vec<PropertyKey>,
+		) -> DispatchResult {
+			ensure!(!property_keys.is_empty(), Error::<T>::EmptyArgument);
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::set_token_property_write_grants(
+				&collection,
+				&sender,
+				&account,
+				property_keys,
+				true,
+			)
+		}
+
+		/// Revoke the right to write token properties with specific keys from an account.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `account`: Address of the account to revoke the right from.
+		/// * `property_keys`: Keys of the token properties the account is no longer allowed to write.
+		#[pallet::call_index(53)]
+		#[pallet::weight(<SelfWeightOf<T>>::revoke_token_property_write(property_keys.len() as u32))]
+		pub fn revoke_token_property_write(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			account: T::CrossAccountId,
+			property_keys: Vec<PropertyKey>,
+		) -> DispatchResult {
+			ensure!(!property_keys.is_empty(), Error::<T>::EmptyArgument);
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::set_token_property_write_grants(
+				&collection,
+				&sender,
+				&account,
+				property_keys,
+				false,
+			)
+		}
+
+		/// Add or change default token properties of a collection.
+		///
+		/// Tokens without their own value for a property key fall back to the default one.
+		/// Values are checked against the value types declared in the token property permissions.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `properties`: Vector of key-value pairs used as the default token metadata.
+		/// Keys support Latin letters, `-`, `_`, and `.` as symbols.
+		#[pallet::call_index(54)]
+		#[pallet::weight(<PalletCommonWeightOf<T>>::set_default_token_properties(properties.len() as u32)
+			.saturating_add(<PalletCommonWeightOf<T>>::load_frozen_token_uri_keys()))]
+		pub fn set_default_token_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			properties: Vec<Property>,
+		) -> DispatchResult {
+			ensure!(!properties.is_empty(), Error::<T>::EmptyArgument);
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::set_default_token_properties(
+				&collection,
+				&sender,
+				properties.into_iter(),
+			)
+		}
+
+		/// Delete default token properties of a collection.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `property_keys`: Vector of keys of the default token properties to be deleted.
+		#[pallet::call_index(55)]
+		#[pallet::weight(<PalletCommonWeightOf<T>>::delete_default_token_properties(property_keys.len() as u32)
+			.saturating_add(<PalletCommonWeightOf<T>>::load_frozen_token_uri_keys()))]
+		pub fn delete_default_token_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			property_keys: Vec<PropertyKey>,
+		) -> DispatchResult {
+			ensure!(!property_keys.is_empty(), Error::<T>::EmptyArgument);
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::delete_default_token_properties(
+				&collection,
+				&sender,
+				property_keys.into_iter(),
+			)
+		}
+
+		/// Permanently freeze the properties of a token.
+		///
+		/// The properties of the token can't be set or deleted after the freeze
+		/// by anyone, regardless of the token property permissions.
+		/// The freeze can't be undone.
+		///
+		/// While the collection has tokens with frozen properties, the collection properties,
+		/// default token properties and token property permissions the token URI
+		/// is derived from can't be changed either.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to which the token belongs.
+		/// * `token_id`: ID of the token whose properties are frozen.
+		#[pallet::call_index(56)]
+		#[pallet::weight(<SelfWeightOf<T>>::freeze_token_properties())]
+		pub fn freeze_token_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResultWithPostInfo {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				<PalletCommon<T>>::freeze_token_properties(&collection, d, &sender, token_id)?;
+				Ok(().into())
+			})
+		}
+
+		/// Permanently freeze the metadata of a collection.
+		///
+		/// The collection properties, royalty, token property permissions
+		/// and default token properties can't be changed after the freeze.
+		/// The freeze can't be undone.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection to freeze.
+		#[pallet::call_index(57)]
+		#[pallet::weight(<SelfWeightOf<T>>::freeze_collection_metadata())]
+		pub fn freeze_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::freeze_collection_metadata(&collection, &sender)
+		}
+
+		/// Set the mint authority of a fungible collection.
+		///
+		/// Once set, only the mint authority can mint tokens of the collection,
+		/// and it can burn tokens of any holder without an allowance.
+		/// Passing `None` returns minting to the collection owner and admins.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `authority`: Account of the new mint authority, if any.
+		#[pallet::call_index(58)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_mint_authority())]
+		pub fn set_mint_authority(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			authority: Option<T::CrossAccountId>,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			<PalletCommon<T>>::set_mint_authority(&collection, &sender, authority)
+		}
+
+		/// Declare or remove the types of token property values of a collection.
+		///
+		/// Values of another type are rejected when written to tokens.
+		/// A type can be declared only for a key with a token property permission,
+		/// and can't be changed once declared if the permission isn't mutable.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `value_types`: Vector of value types for property keys, `None` allows any value.
+		#[pallet::call_index(59)]
+		#[pallet::weight(pallet_common::set_token_property_value_types_weight::<T>(
+			value_types.iter().map(PropertyKeyValueType::enum_values_num),
+		))]
+		pub fn set_token_property_value_types(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			value_types: Vec<PropertyKeyValueType>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(!value_types.is_empty(), Error::<T>::EmptyArgument);
+
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				d.set_token_property_value_types(&sender, value_types)
+			})
+		}
+
+		/// Set the supply cap of a fungible collection.
+		///
+		/// Minting can't raise the total supply above the cap.
+		/// The cap can be changed to any value not lower than the current total supply,
+		/// or removed.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `cap`: Maximum total supply of the collection, `None` removes the cap.
+		#[pallet::call_index(60)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_supply_cap())]
+		pub fn set_supply_cap(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			cap: Option<u128>,
+		) -> DispatchResultWithPostInfo {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			dispatch_tx::<T, _>(collection_id, |d| {
+				<PalletCommon<T>>::set_supply_cap(&collection, d, &sender, cap)?;
+				Ok(().into())
+			})
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Force set `sponsor` for `collection`.
+		///
+		/// Differs from [`set_collection_sponsor`][`Pallet::set_collection_sponsor`] in that confirmation
+		/// from the `sponsor` is not required.
+		///
+		/// # Arguments
+		///
+		/// * `sponsor`: ID of the account of the sponsor-to-be.
+		/// * `collection_id`: ID of the modified collection.
+		pub fn force_set_sponsor(
+			sponsor: T::AccountId,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.force_set_sponsor(sponsor)
+		}
+
+		/// Force remove `sponsor` for `collection`.
+		///
+		/// Differs from `remove_sponsor` in that
+		/// it doesn't require consent from the `owner` of the collection.
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		pub fn force_remove_collection_sponsor(collection_id: CollectionId) -> DispatchResult {
+			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.force_remove_sponsor()
+		}
+
+		/// Weight of the collection destruction, including the storage removed right away.
+		pub(crate) fn destroy_collection_weight() -> Weight {
+			<SelfWeightOf<T>>::destroy_collection().saturating_add(Self::destruction_chunk_weight())
+		}
+
+		/// Weight of the collection storage removed within the destroying transaction.
+		fn destruction_chunk_weight() -> Weight {
+			T::DbWeight::get().reads_writes(DESTRUCTION_CHUNK_ENTRIES, DESTRUCTION_CHUNK_ENTRIES)
+		}
+
+		/// Destroy the collection.
+		///
+		/// Collections with little storage are removed right away, the rest
+		/// is removed in the background by [`Hooks::on_idle`].
+		#[inline(always)]
+		pub(crate) fn destroy_collection_internal(
+			sender: T::CrossAccountId,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			T::CollectionDispatch::destroy(sender, collection_id)?;
+
+			// =========
+
+			let mut meter = WeightMeter::with_limit(Self::destruction_chunk_weight());
+			if !Self::clear_destroying_collection(collection_id, &mut meter) {
+				<PalletCommon<T>>::deposit_event(CommonEvent::CollectionDestructionStarted(
+					collection_id,
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Remove the storage of the destroyed collection, as far as `meter` allows.
+		///
+		/// Returns `true` once the collection is fully removed.
+		pub fn clear_destroying_collection(
+			collection_id: CollectionId,
+			meter: &mut WeightMeter,
+		) -> bool {
+			if meter
+				.try_consume(T::DbWeight::get().reads_writes(1, 1))
+				.is_err()
+			{
+				return false;
+			}
+			let Some(destruction) = <DestroyingCollections<T>>::get(collection_id) else {
+				return true;
+			};
+
+			let mut cleaner = StorageCleaner::<T>::new(meter);
+			let done = T::CollectionDispatch::clear_destroyed(
+				collection_id,
+				&destruction.mode,
+				&mut cleaner,
+			) && Self::clear_baskets(collection_id, &mut cleaner)
+				&& <PalletCommon<T>>::clear_destroyed_collection(collection_id, &mut cleaner);
+
+			if done {
+				<PalletCommon<T>>::finish_collection_destruction(collection_id);
+			} else {
+				let removed = cleaner.removed();
+				<DestroyingCollections<T>>::insert(
+					collection_id,
+					CollectionDestruction {
+						removed_entries: destruction.removed_entries.saturating_add(removed),
+						..destruction
+					},
+				);
+			}
+			done
+		}
+
+		/// Remove the sponsoring rate limit baskets of the destroyed collection.
+		fn clear_baskets(id: CollectionId, cleaner: &mut StorageCleaner<T>) -> bool {
+			cleaner.clear_prefix(|limit| <CreateItemBasket<T>>::clear_prefix(id, limit, None))
+				&& cleaner
+					.clear_prefix(|limit| <NftTransferBasket<T>>::clear_prefix(id, limit, None))
+				&& cleaner.clear_prefix(|limit| {
+					<FungibleTransferBasket<T>>::clear_prefix(id, limit, None)
+				}) && cleaner.clear_prefix(|limit| {
+				<ReFungibleTransferBasket<T>>::clear_prefix((id,), limit, None)
+			}) && cleaner
+				.clear_prefix(|limit| <TokenPropertyBasket<T>>::clear_prefix(id, limit, None))
+				&& cleaner
+					.clear_prefix(|limit| <NftApproveBasket<T>>::clear_prefix(id, limit, None))
+				&& cleaner
+					.clear_prefix(|limit| <FungibleApproveBasket<T>>::clear_prefix(id, limit, None))
+				&& cleaner.clear_prefix(|limit| {
+					<RefungibleApproveBasket<T>>::clear_prefix((id,), limit, None)
+				})
+		}
+
+		fn structure_nesting_budget() -> budget::Value {
+			budget::Value::new(Self::nesting_budget())
+		}
+
+		fn nesting_budget_predispatch_weight() -> Weight {
+			T::StructureWeightInfo::find_parent().saturating_mul(Self::nesting_budget() as u64)
+		}
+
+		/// Moves a swap asset between accounts via [`CommonCollectionOperations::transfer_from`].
+		///
+		/// [`CommonCollectionOperations::transfer_from`]: pallet_common::CommonCollectionOperations::transfer_from
+		fn swap_transfer(
+			asset: &SwapAsset,
+			from: &T::CrossAccountId,
+			to: &T::CrossAccountId,
+		) -> DispatchResult {
+			let budget = Self::structure_nesting_budget();
+			dispatch_tx::<T, _>(asset.collection, |d| {
+				d.transfer_from(
+					from.clone(),
+					from.clone(),
+					to.clone(),
+					asset.token,
+					asset.amount,
+					&budget,
+				)
+			})
+			.map(|_| ())
+			.map_err(|error| error.error)
+		}
+
+		fn transfer_multiple_weight(count: usize, transfer: Weight) -> Weight {
+			(transfer + Self::nesting_budget_predispatch_weight() + dispatch_weight::<T>())
+				.saturating_mul(count as u64)
+		}
+
+		fn swap_transfers_weight(count: usize) -> Weight {
+			(T::CommonWeightInfo::transfer_from()
+				+ Self::nesting_budget_predispatch_weight()
+				+ dispatch_weight::<T>())
+			.saturating_mul(count as u64)
 		}
 
 		pub fn refund_nesting_budget(
@@ -9,6 +9,7 @@ macro_rules! bondrewd_codec {
 				dest.write(&self.into_bytes())
 			}
 		}
+		impl parity_scale_codec::EncodeLike for $T {}
 		impl parity_scale_codec::Decode for $T {
 			fn decode<I: parity_scale_codec::Input + ?Sized>(
 				from: &mut I,
@@ -119,6 +119,15 @@ pub const MAX_PROPERTY_KEY_LENGTH: u32 = 256;
 /// Maximal length of a property value.
 pub const MAX_PROPERTY_VALUE_LENGTH: u32 = 32768;
 
+/// Maximal length of a token URI rendered from the collection `URITemplate` property.
+pub const MAX_TOKEN_URI_LENGTH: u32 = 32768;
+
+/// Maximal number of allowed values of an enum property type.
+pub const MAX_PROPERTY_ENUM_VALUES: u32 = 16;
+
+/// Maximal length of an allowed value of an enum property type.
+pub const MAX_PROPERTY_ENUM_VALUE_LENGTH: u32 = 32;
+
 /// A maximum number of token properties.
 pub const MAX_PROPERTIES_PER_ITEM: u32 = 64;
 
@@ -135,6 +144,18 @@ pub const MAX_TOKEN_PROPERTIES_SIZE: u32 = 32768;
 /// create_many call.
 pub const MAX_ITEMS_PER_BATCH: u32 = 120;
 
+/// Denominator of [`Royalty::basis_points`], i.e. 100%.
+pub const ROYALTY_BASIS_POINTS_DENOMINATOR: u16 = 10_000;
+
+/// Key of the reserved [`PropertyScope::Royalty`] property holding a [`Royalty`].
+pub const ROYALTY_PROPERTY_KEY: &[u8] = b"info";
+
+/// Maximum number of assets on each side of a swap offer.
+pub const MAX_SWAP_OFFER_ASSETS: u32 = 16;
+
+/// Maximum number of tokens visited by a single recursive burn.
+pub const MAX_BURN_RECURSIVELY_NODES: u32 = 64;
+
 /// Used for limit bounded types of token custom data.
 pub type CustomDataLimit = ConstU32<CUSTOM_DATA_LIMIT>;
 
@@ -223,18 +244,209 @@ impl TryFrom<U256> for TokenId {
 }
 
 /// Token data.
-#[struct_versioning::versioned(version = 2, upper)]
+#[struct_versioning::versioned(version = 4, upper)]
 #[derive(Encode, Decode, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
 pub struct TokenData<CrossAccountId> {
 	/// Properties of token.
+	#[version(..4)]
 	pub properties: Vec<Property>,
 
+	/// Properties of token, including the inherited collection default ones.
+	#[version(4.., upper(properties.into_iter().map(Into::into).collect()))]
+	pub properties: Vec<RpcTokenProperty>,
+
 	/// Token owner.
 	pub owner: Option<CrossAccountId>,
 
 	/// Token pieces.
 	#[version(2.., upper(0))]
 	pub pieces: u128,
+
+	/// ERC-4907 user of the token, if it has an unexpired one.
+	#[version(3.., upper(None))]
+	pub user: Option<TokenUser<CrossAccountId>>,
+}
+
+/// ERC-4907 user of a token, which is allowed to use the token without owning it.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct TokenUser<CrossAccountId> {
+	/// Account using the token.
+	pub user: CrossAccountId,
+	/// Number of the last block in which the account is the user of the token.
+	pub expires: u64,
+}
+
+/// Asset participating in a swap offer.
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub struct SwapAsset {
+	/// Collection of the asset, may be the native fungible collection.
+	pub collection: CollectionId,
+	/// Token of the asset, should be 0 for fungible collections.
+	pub token: TokenId,
+	/// Amount of token pieces, should be 1 for NFTs.
+	pub amount: u128,
+}
+
+/// Assets of one side of a swap offer.
+pub type SwapAssets = BoundedVec<SwapAsset, ConstU32<MAX_SWAP_OFFER_ASSETS>>;
+
+/// Single transfer of a batch, the batch may span several collections.
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub struct TransferItem<CrossAccountId> {
+	/// Collection of the transferred token.
+	pub collection: CollectionId,
+	/// Transferred token, should be 0 for fungible collections.
+	pub token: TokenId,
+	/// Address of the token recipient.
+	pub recipient: CrossAccountId,
+	/// Amount of token pieces, should be 1 for NFTs.
+	pub amount: u128,
+}
+
+/// Transfers of a batch transfer.
+pub type TransferItems<CrossAccountId> =
+	BoundedVec<TransferItem<CrossAccountId>, ConstU32<MAX_ITEMS_PER_BATCH>>;
+
+/// Offer to atomically swap assets of the maker for assets of the counterparty.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct SwapOffer<CrossAccountId> {
+	/// Account which created the offer. Its assets are held in escrow until the offer is closed.
+	pub maker: CrossAccountId,
+	/// Account which is allowed to accept the offer, anyone if not set.
+	pub counterparty: Option<CrossAccountId>,
+	/// Assets the maker gives.
+	pub give: SwapAssets,
+	/// Assets the maker wants in return.
+	pub want: SwapAssets,
+	/// Number of the last block in which the offer can be accepted.
+	pub expires: Option<u64>,
+}
+
+/// Fixed-price marketplace listing of token pieces.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct MarketListing {
+	/// Amount of listed token pieces, always 1 for NFTs.
+	pub amount: u128,
+	/// Collection in which the price is paid, 0 for the native currency.
+	pub currency: CollectionId,
+	/// Price of the whole listed amount.
+	pub price: u128,
+}
+
+/// Kind and price terms of a marketplace auction.
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Debug, Clone, Copy, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub enum AuctionKind {
+	/// Ascending price auction. Bids are held in escrow until they are outbid or the auction ends.
+	English {
+		/// Minimal amount of the first bid.
+		min_bid: u128,
+		/// Minimal amount by which each next bid should exceed the highest one.
+		min_increment: u128,
+	},
+	/// Descending price auction. The first buyer gets the token at the current price.
+	Dutch {
+		/// Price at the start block.
+		start_price: u128,
+		/// Price at the end block, the price decreases linearly in between.
+		end_price: u128,
+	},
+}
+
+/// Auction of token pieces. The pieces are held in escrow while the auction runs.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct MarketAuction<CrossAccountId> {
+	/// Account which created the auction and receives the payment.
+	pub seller: CrossAccountId,
+	/// Collection of the auctioned token.
+	pub collection: CollectionId,
+	/// Auctioned token.
+	pub token: TokenId,
+	/// Amount of auctioned token pieces, always 1 for NFTs.
+	pub amount: u128,
+	/// Collection in which the price is paid, 0 for the native currency.
+	pub currency: CollectionId,
+	/// Kind and price terms of the auction.
+	pub kind: AuctionKind,
+	/// Number of the block in which the auction was created.
+	pub start: u64,
+	/// Number of the block in which the auction is settled.
+	pub end: u64,
+	/// Highest bid of an English auction, with its bidder.
+	pub highest_bid: Option<(CrossAccountId, u128)>,
+}
+
+/// Buyout of the pieces of an RFT token, posted by its major holder.
+/// The payment for the pieces of the other holders is held in escrow.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct RftBuyout<CrossAccountId> {
+	/// Account which posted the buyout and receives the pieces.
+	pub buyer: CrossAccountId,
+	/// Collection in which the price is paid, 0 for the native currency.
+	pub currency: CollectionId,
+	/// Price of a single piece.
+	pub price: u128,
+	/// Part of the escrowed payment, which is not paid out yet.
+	pub deposit: u128,
+	/// Number of the block in which the notice period ends and the pieces are bought out.
+	pub end: u64,
+}
+
+/// ID of a dividend distribution.
+pub type DistributionId = u32;
+
+/// Funds deposited for the holders of the tokens of a collection,
+/// to be claimed in proportion to their snapshotted balances.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct DividendDistribution<CrossAccountId> {
+	/// Account which deposited the funds.
+	pub payer: CrossAccountId,
+	/// Collection, whose holders receive the funds.
+	pub collection: CollectionId,
+	/// RFT token, whose holders receive the funds, or all the tokens of the collection.
+	pub token: Option<TokenId>,
+	/// Collection in which the funds are paid, 0 for the native currency.
+	pub currency: CollectionId,
+	/// Deposited funds.
+	pub amount: u128,
+	/// Sum of the snapshotted balances of all the holders.
+	pub total_shares: u128,
+	/// Part of the funds, which is already claimed.
+	pub claimed: u128,
+	/// Whether the snapshot of the balances is taken, and the funds can be claimed.
+	pub snapshot_taken: bool,
 }
 
 // TODO: unused type
@@ -286,6 +498,26 @@ impl CollectionMode {
 	}
 }
 
+/// State of a destroyed collection, whose storage is still being removed.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct CollectionDestruction {
+	/// Mode of the destroyed collection.
+	pub mode: CollectionMode,
+	/// Number of storage entries of the collection removed so far.
+	pub removed_entries: u32,
+}
+
 // TODO: unused trait
 pub trait SponsoringResolve<AccountId, Call> {
 	fn resolve(who: &AccountId, call: &Call) -> Option<AccountId>;
@@ -412,6 +644,85 @@ impl CollectionFlags {
 	}
 }
 
+/// Roles of a collection admin, limiting the operations the admin is allowed to perform.
+///
+/// Admins added without explicitly set roles have all of them.
+#[derive(
+	AbiCoderFlags, Bitfields, Clone, Copy, PartialEq, Eq, Debug, DecodeWithMemTracking, Default,
+)]
+#[bondrewd(enforce_bytes = 1)]
+pub struct CollectionAdminRoles {
+	/// Can mint tokens
+	#[bondrewd(bits = "0..1")]
+	pub minter: bool,
+	/// Can change collection and token properties, property permissions and royalties
+	#[bondrewd(bits = "1..2")]
+	pub property_editor: bool,
+	/// Can manage the allowlist and freeze tokens and accounts
+	#[bondrewd(bits = "2..3")]
+	pub allowlist_manager: bool,
+	/// Can set and remove the collection sponsor
+	#[bondrewd(bits = "3..4")]
+	pub sponsor_manager: bool,
+	/// Can change collection limits and permissions
+	#[bondrewd(bits = "4..5")]
+	pub limits_manager: bool,
+	/// Can transfer, burn and approve tokens of other owners when the collection limits allow it,
+	/// and nest tokens when the nesting permissions allow it to admins
+	#[bondrewd(bits = "5..6")]
+	pub token_manager: bool,
+	/// Reserved roles
+	#[bondrewd(bits = "6..8")]
+	pub reserved: u8,
+}
+bondrewd_codec!(CollectionAdminRoles);
+
+impl CollectionAdminRoles {
+	/// Roles of an admin without restrictions.
+	pub fn all() -> Self {
+		Self {
+			minter: true,
+			property_editor: true,
+			allowlist_manager: true,
+			sponsor_manager: true,
+			limits_manager: true,
+			token_manager: true,
+			reserved: 0,
+		}
+	}
+}
+
+/// Roles of a collection admin, used in RPC calls (see [`CollectionAdminRoles`]).
+#[derive(Debug, Encode, Decode, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
+pub struct RpcCollectionAdminRoles {
+	/// Can mint tokens.
+	pub minter: bool,
+	/// Can change collection and token properties, property permissions and royalties.
+	pub property_editor: bool,
+	/// Can manage the allowlist and freeze tokens and accounts.
+	pub allowlist_manager: bool,
+	/// Can set and remove the collection sponsor.
+	pub sponsor_manager: bool,
+	/// Can change collection limits and permissions.
+	pub limits_manager: bool,
+	/// Can transfer, burn and approve tokens of other owners when the collection limits allow it,
+	/// and nest tokens when the nesting permissions allow it to admins.
+	pub token_manager: bool,
+}
+
+impl From<CollectionAdminRoles> for RpcCollectionAdminRoles {
+	fn from(roles: CollectionAdminRoles) -> Self {
+		Self {
+			minter: roles.minter,
+			property_editor: roles.property_editor,
+			allowlist_manager: roles.allowlist_manager,
+			sponsor_manager: roles.sponsor_manager,
+			limits_manager: roles.limits_manager,
+			token_manager: roles.token_manager,
+		}
+	}
+}
+
 /// Base structure for represent collection.
 ///
 /// Used to provide basic functionality for all types of collections.
@@ -481,7 +792,7 @@ pub struct RpcCollectionFlags {
 }
 
 /// Collection parameters, used in RPC calls (see [`Collection`] for the storage version).
-#[struct_versioning::versioned(version = 2, upper)]
+#[struct_versioning::versioned(version = 4, upper)]
 #[derive(Debug, Encode, Decode, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
 pub struct RpcCollection<AccountId> {
 	/// Collection owner account.
@@ -509,8 +820,13 @@ pub struct RpcCollection<AccountId> {
 	pub permissions: CollectionPermissions,
 
 	/// Token property permissions.
+	#[version(..4)]
 	pub token_property_permissions: Vec<PropertyKeyPermission>,
 
+	/// Token property permissions with the types of property values.
+	#[version(4.., upper(token_property_permissions.into_iter().map(Into::into).collect()))]
+	pub token_property_permissions: Vec<RpcPropertyKeyPermission>,
+
 	/// Collection properties.
 	pub properties: Vec<Property>,
 
@@ -520,6 +836,10 @@ pub struct RpcCollection<AccountId> {
 	/// Extra collection flags
 	#[version(2.., upper(RpcCollectionFlags {foreign: false, erc721metadata: false}))]
 	pub flags: RpcCollectionFlags,
+
+	/// Proposed owner, who has not accepted the ownership yet.
+	#[version(3.., upper(None))]
+	pub pending_owner: Option<AccountId>,
 }
 
 impl<AccountId> From<CollectionVersion1<AccountId>> for RpcCollection<AccountId> {
@@ -558,6 +878,7 @@ impl<AccountId> From<CollectionVersion1<AccountId>> for RpcCollection<AccountId>
 				foreign: false,
 				erc721metadata: false,
 			},
+			pending_owner: None,
 		}
 	}
 }
@@ -1076,6 +1397,9 @@ pub struct CreateNftExData<CrossAccountId> {
 
 	/// Owner of creating item.
 	pub owner: CrossAccountId,
+
+	/// Whether the created item should be locked against transfers.
+	pub locked: bool,
 }
 
 /// Extended data for create ReFungible item.
@@ -1086,6 +1410,7 @@ pub struct CreateRefungibleExMultipleOwners<CrossAccountId> {
 	pub users: BoundedBTreeMap<CrossAccountId, u128, ConstU32<MAX_ITEMS_PER_BATCH>>,
 	#[derivative(Debug(format_with = "bounded::vec_debug"))]
 	pub properties: CollectionPropertiesVec,
+	pub locked: bool,
 }
 
 impl<CrossAccountId: DecodeWithMemTracking> DecodeWithMemTracking
@@ -1105,6 +1430,7 @@ pub struct CreateRefungibleExSingleOwner<CrossAccountId> {
 	pub pieces: u128,
 	#[derivative(Debug(format_with = "bounded::vec_debug"))]
 	pub properties: CollectionPropertiesVec,
+	pub locked: bool,
 }
 
 /// Unified extended data for creating item.
@@ -1266,6 +1592,107 @@ impl PropertyPermission {
 	}
 }
 
+/// Value of a property enum variant. Max length is [`MAX_PROPERTY_ENUM_VALUE_LENGTH`].
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Debug,
+	MaxEncodedLen,
+	PartialEq,
+	Clone,
+	Serialize,
+	Deserialize,
+)]
+pub struct PropertyEnumValue(
+	#[serde(with = "bounded::vec_serde")] pub BoundedBytes<ConstU32<MAX_PROPERTY_ENUM_VALUE_LENGTH>>,
+);
+
+/// Type of a token property value.
+///
+/// Values are stored as bytes, typed values are checked on write
+/// with [`PropertyValueType::check`].
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Debug,
+	MaxEncodedLen,
+	PartialEq,
+	Clone,
+	Serialize,
+	Deserialize,
+)]
+pub enum PropertyValueType {
+	/// Decimal representation of `u32`.
+	U32,
+
+	/// Decimal representation of `u64`.
+	U64,
+
+	/// Decimal representation of `u128`.
+	U128,
+
+	/// `true` or `false`.
+	Bool,
+
+	/// UTF-8 string.
+	String,
+
+	/// URI with a scheme, e.g. `ipfs://...` or `https://...`.
+	Uri,
+
+	/// Ethereum address, 20 bytes.
+	Address,
+
+	/// Arbitrary bytes with maximal length.
+	Bytes(u32),
+
+	/// One of the allowed values. Max number of values is [`MAX_PROPERTY_ENUM_VALUES`].
+	Enum(
+		#[serde(with = "bounded::vec_serde")]
+		BoundedVec<PropertyEnumValue, ConstU32<MAX_PROPERTY_ENUM_VALUES>>,
+	),
+}
+
+impl PropertyValueType {
+	/// Check if property value matches the type.
+	pub fn check(&self, value: &[u8]) -> bool {
+		fn parse<N: core::str::FromStr>(value: &[u8]) -> bool {
+			// Only plain decimal digits, without signs or whitespace.
+			!value.is_empty()
+				&& value.iter().all(u8::is_ascii_digit)
+				&& core::str::from_utf8(value).map_or(false, |value| value.parse::<N>().is_ok())
+		}
+
+		match self {
+			Self::U32 => parse::<u32>(value),
+			Self::U64 => parse::<u64>(value),
+			Self::U128 => parse::<u128>(value),
+			Self::Bool => value == b"true" || value == b"false",
+			Self::String => core::str::from_utf8(value).is_ok(),
+			Self::Uri => {
+				let Some(colon) = value.iter().position(|b| *b == b':') else {
+					return false;
+				};
+				let (scheme, rest) = (&value[..colon], &value[colon + 1..]);
+
+				scheme.first().map_or(false, u8::is_ascii_alphabetic)
+					&& scheme
+						.iter()
+						.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+					&& !rest.is_empty()
+					&& rest.iter().all(u8::is_ascii_graphic)
+			}
+			Self::Address => value.len() == 20,
+			Self::Bytes(max_length) => value.len() <= *max_length as usize,
+			Self::Enum(values) => values.iter().any(|allowed| allowed.0.as_slice() == value),
+		}
+	}
+}
+
 /// Property is simpl key-value record.
 #[derive(
 	Encode,
@@ -1295,6 +1722,31 @@ impl From<Property> for (PropertyKey, PropertyValue) {
 	}
 }
 
+/// Token property as returned by the RPC.
+#[derive(Encode, Decode, Debug, TypeInfo, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcTokenProperty {
+	/// Property key.
+	#[serde(with = "bounded::vec_serde")]
+	pub key: PropertyKey,
+
+	/// Property value.
+	#[serde(with = "bounded::vec_serde")]
+	pub value: PropertyValue,
+
+	/// Whether the value is inherited from the default token properties of the collection.
+	pub inherited: bool,
+}
+
+impl From<Property> for RpcTokenProperty {
+	fn from(value: Property) -> Self {
+		Self {
+			key: value.key,
+			value: value.value,
+			inherited: false,
+		}
+	}
+}
+
 /// Record for proprty key permission.
 #[derive(
 	Encode,
@@ -1323,6 +1775,78 @@ impl From<PropertyKeyPermission> for (PropertyKey, PropertyPermission) {
 	}
 }
 
+/// Record for the type of property values with the key.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Debug,
+	MaxEncodedLen,
+	PartialEq,
+	Clone,
+	Serialize,
+	Deserialize,
+)]
+pub struct PropertyKeyValueType {
+	/// Key.
+	#[cfg_attr(feature = "serde1", serde(with = "bounded::vec_serde"))]
+	pub key: PropertyKey,
+
+	/// Type of the property values, `None` allows any value.
+	pub value_type: Option<PropertyValueType>,
+}
+
+impl PropertyKeyValueType {
+	/// Number of the allowed values of an enum type, zero for other types.
+	pub fn enum_values_num(&self) -> u32 {
+		match &self.value_type {
+			Some(PropertyValueType::Enum(values)) => values.len() as u32,
+			_ => 0,
+		}
+	}
+}
+
+/// Property key permission with the type of property values, used in RPC calls.
+#[derive(Encode, Decode, TypeInfo, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RpcPropertyKeyPermission {
+	/// Key.
+	#[cfg_attr(feature = "serde1", serde(with = "bounded::vec_serde"))]
+	pub key: PropertyKey,
+
+	/// Permission.
+	pub permission: PropertyPermission,
+
+	/// Type of the property values, `None` allows any value.
+	pub value_type: Option<PropertyValueType>,
+}
+
+impl From<PropertyKeyPermission> for RpcPropertyKeyPermission {
+	fn from(value: PropertyKeyPermission) -> Self {
+		Self {
+			key: value.key,
+			permission: value.permission,
+			value_type: None,
+		}
+	}
+}
+
+/// Set of token property keys an account is granted to write.
+///
+/// Max length is [`MAX_PROPERTIES_PER_ITEM`].
+pub type PropertyWriteGrantKeys = BoundedBTreeSet<PropertyKey, ConstU32<MAX_PROPERTIES_PER_ITEM>>;
+
+/// Grant to write a token property given to an account, used in RPC calls.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
+pub struct PropertyWriteGrant<CrossAccountId> {
+	/// Account allowed to write the property.
+	pub account: CrossAccountId,
+
+	/// Key of the token property.
+	#[serde(with = "bounded::vec_serde")]
+	pub key: PropertyKey,
+}
+
 /// Errors for properties actions.
 #[derive(Debug)]
 pub enum PropertiesError {
@@ -1363,6 +1887,7 @@ pub enum TokenOwnerError {
 pub enum PropertyScope {
 	None,
 	Rmrk,
+	Royalty,
 }
 
 impl PropertyScope {
@@ -1370,6 +1895,7 @@ impl PropertyScope {
 		match self {
 			Self::None => b"",
 			Self::Rmrk => b"rmrk:",
+			Self::Royalty => b"royalty:",
 		}
 	}
 	/// Apply scope to property key.
@@ -1449,9 +1975,19 @@ impl<Value> PropertiesMap<Value> {
 
 	/// Remove property from map.
 	pub fn remove(&mut self, key: &PropertyKey) -> Result<Option<Value>, PropertiesError> {
-		Self::check_property_key(key)?;
+		self.try_scoped_remove(PropertyScope::None, key.clone())
+	}
 
-		Ok(self.0.remove(key))
+	/// Remove property with scope from map.
+	pub fn try_scoped_remove(
+		&mut self,
+		scope: PropertyScope,
+		key: PropertyKey,
+	) -> Result<Option<Value>, PropertiesError> {
+		Self::check_property_key(&key)?;
+
+		let key = scope.apply(key)?;
+		Ok(self.0.remove(&key))
 	}
 
 	/// Get property with appropriate key from map.
@@ -1464,8 +2000,8 @@ impl<Value> PropertiesMap<Value> {
 		self.0.contains_key(key)
 	}
 
-	/// Check if map contains key with key validation.
-	fn check_property_key(key: &PropertyKey) -> Result<(), PropertiesError> {
+	/// Check that the key is not empty and consists of allowed characters only.
+	pub fn check_property_key(key: &PropertyKey) -> Result<(), PropertiesError> {
 		if key.is_empty() {
 			return Err(PropertiesError::EmptyPropertyKey);
 		}
@@ -1526,6 +2062,9 @@ impl<Value> TrySetProperty for PropertiesMap<Value> {
 /// Alias for property permissions map.
 pub type PropertiesPermissionMap = PropertiesMap<PropertyPermission>;
 
+/// Alias for property value types map.
+pub type PropertiesValueTypeMap = PropertiesMap<PropertyValueType>;
+
 fn slice_size(data: &[u8]) -> u32 {
 	scoped_slice_size(PropertyScope::None, data)
 }
@@ -1571,10 +2110,20 @@ impl<const S: u32> Properties<S> {
 
 	/// Remove propery with appropiate key.
 	pub fn remove(&mut self, key: &PropertyKey) -> Result<Option<PropertyValue>, PropertiesError> {
-		let value = self.map.remove(key)?;
+		self.try_scoped_remove(PropertyScope::None, key.clone())
+	}
+
+	/// Remove property with scope and appropriate key.
+	pub fn try_scoped_remove(
+		&mut self,
+		scope: PropertyScope,
+		key: PropertyKey,
+	) -> Result<Option<PropertyValue>, PropertiesError> {
+		let key_size = scoped_slice_size(scope, &key);
+		let value = self.map.try_scoped_remove(scope, key)?;
 
 		if let Some(ref value) = value {
-			let kv_len = slice_size(key) + slice_size(value);
+			let kv_len = key_size + slice_size(value);
 			self.consumed_space = self.consumed_space.saturating_sub(kv_len);
 		}
 
@@ -1586,6 +2135,12 @@ impl<const S: u32> Properties<S> {
 		self.map.get(key)
 	}
 
+	/// Get property with scope and appropriate key.
+	pub fn get_scoped(&self, scope: PropertyScope, key: PropertyKey) -> Option<&PropertyValue> {
+		let key = scope.apply(key).ok()?;
+		self.map.get(&key)
+	}
+
 	/// Recomputes the consumed space for the current properties state.
 	/// Needed to repair a token due to a bug fixed in the [PR #733](https://github.com/UniqueNetwork/unique-chain/pull/773).
 	pub fn recompute_consumed_space(&mut self) {
@@ -1638,3 +2193,36 @@ impl<const S: u32> TrySetProperty for Properties<S> {
 
 pub type CollectionProperties = Properties<MAX_COLLECTION_PROPERTIES_SIZE>;
 pub type TokenProperties = Properties<MAX_TOKEN_PROPERTIES_SIZE>;
+
+/// EIP-2981 royalty definition.
+///
+/// Stored SCALE-encoded under the reserved [`PropertyScope::Royalty`] property
+/// of a collection, and optionally of a token to override the collection one.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Clone,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct Royalty<CrossAccountId> {
+	/// Account receiving the royalty.
+	pub receiver: CrossAccountId,
+	/// Royalty amount in basis points of the sale price.
+	///
+	/// * Limit - [`ROYALTY_BASIS_POINTS_DENOMINATOR`].
+	pub basis_points: u16,
+}
+
+impl<CrossAccountId> Royalty<CrossAccountId> {
+	/// Royalty amount for the given `sale_price`.
+	pub fn amount(&self, sale_price: U256) -> U256 {
+		sale_price.saturating_mul(self.basis_points.into())
+			/ U256::from(ROYALTY_BASIS_POINTS_DENOMINATOR)
+	}
+}
@@ -20,16 +20,18 @@ extern crate alloc;
 
 use parity_scale_codec::Decode;
 use sp_runtime::DispatchError;
-use sp_std::vec::Vec;
+use sp_std::{string::String, vec::Vec};
 use up_data_structs::{
-	CollectionId, CollectionLimits, CollectionStats, Property, PropertyKeyPermission,
-	RpcCollection, TokenChild, TokenData, TokenId,
+	CollectionDestruction, CollectionId, CollectionLimits, CollectionStats, DistributionId,
+	DividendDistribution, MarketListing, Property, PropertyKeyPermission, PropertyWriteGrant,
+	RftBuyout, Royalty, RpcCollection, RpcCollectionAdminRoles, RpcPropertyKeyPermission,
+	RpcTokenProperty, TokenChild, TokenData, TokenId,
 };
 
 type Result<T> = core::result::Result<T, DispatchError>;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(3)]
+	#[api_version(7)]
 	/// Trait for generate rpc.
 	pub trait UniqueApi<CrossAccountId, AccountId> where
 		AccountId: Decode,
@@ -56,7 +58,14 @@ sp_api::decl_runtime_apis! {
 		/// Get collection properties.
 		fn collection_properties(collection: CollectionId, properties: Option<Vec<Vec<u8>>>) -> Result<Vec<Property>>;
 
-		/// Get token properties.
+		/// Get token properties, including the inherited default token properties of the collection.
+		fn token_properties(
+			collection: CollectionId,
+			token_id: TokenId,
+			properties: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<RpcTokenProperty>>;
+
+		#[changed_in(7)]
 		fn token_properties(
 			collection: CollectionId,
 			token_id: TokenId,
@@ -64,11 +73,20 @@ sp_api::decl_runtime_apis! {
 		) -> Result<Vec<Property>>;
 
 		/// Get permissions for token properties.
+		fn property_permissions(
+			collection: CollectionId,
+			properties: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<RpcPropertyKeyPermission>>;
+
+		#[changed_in(6)]
 		fn property_permissions(
 			collection: CollectionId,
 			properties: Option<Vec<Vec<u8>>>
 		) -> Result<Vec<PropertyKeyPermission>>;
 
+		/// Get grants to write token properties given to accounts.
+		fn property_write_grants(collection: CollectionId) -> Result<Vec<PropertyWriteGrant<CrossAccountId>>>;
+
 		/// Get token data.
 		fn token_data(
 			collection: CollectionId,
@@ -76,6 +94,20 @@ sp_api::decl_runtime_apis! {
 			keys: Option<Vec<Vec<u8>>>
 		) -> Result<TokenData<CrossAccountId>>;
 
+		#[changed_in(7)]
+		fn token_data(
+			collection: CollectionId,
+			token_id: TokenId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<up_data_structs::TokenDataVersion3<CrossAccountId>>;
+
+		#[changed_in(4)]
+		fn token_data(
+			collection: CollectionId,
+			token_id: TokenId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<up_data_structs::TokenDataVersion2<CrossAccountId>>;
+
 		#[changed_in(3)]
 		fn token_data(
 			collection: CollectionId,
@@ -103,6 +135,9 @@ sp_api::decl_runtime_apis! {
 		/// Get list of collection admins.
 		fn adminlist(collection: CollectionId) -> Result<Vec<CrossAccountId>>;
 
+		/// Get list of collection admins together with their roles.
+		fn adminlist_with_roles(collection: CollectionId) -> Result<Vec<(CrossAccountId, RpcCollectionAdminRoles)>>;
+
 		/// Get list of users that allowet to mint tikens in collection.
 		fn allowlist(collection: CollectionId) -> Result<Vec<CrossAccountId>>;
 
@@ -115,6 +150,12 @@ sp_api::decl_runtime_apis! {
 		/// Get collection by id.
 		fn collection_by_id(collection: CollectionId) -> Result<Option<RpcCollection<AccountId>>>;
 
+		#[changed_in(6)]
+		fn collection_by_id(collection: CollectionId) -> Result<Option<up_data_structs::RpcCollectionVersion3<AccountId>>>;
+
+		#[changed_in(5)]
+		fn collection_by_id(collection: CollectionId) -> Result<Option<up_data_structs::RpcCollectionVersion2<AccountId>>>;
+
 		#[changed_in(3)]
 		fn collection_by_id(collection: CollectionId) -> Result<Option<up_data_structs::RawEncoded>>;
 
@@ -134,5 +175,67 @@ sp_api::decl_runtime_apis! {
 
 		/// Get whether an operator is approved by a given owner.
 		fn allowance_for_all(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> Result<bool>;
+
+		/// Get EIP-2981 royalty of the token, or of the collection if token is not specified.
+		fn royalty(collection: CollectionId, token: Option<TokenId>) -> Result<Option<Royalty<CrossAccountId>>>;
+
+		/// Check whether the token is frozen.
+		fn token_frozen(collection: CollectionId, token: TokenId) -> Result<bool>;
+
+		/// Check whether the account is frozen in the collection.
+		fn account_frozen(collection: CollectionId, account: CrossAccountId) -> Result<bool>;
+
+		/// Get the deadline of the allowance, if it has one.
+		fn allowance_deadline(
+			collection: CollectionId,
+			sender: CrossAccountId,
+			spender: CrossAccountId,
+			token: TokenId,
+		) -> Result<Option<u64>>;
+
+		/// Get the deadline of the operator approval, if it has one.
+		fn allowance_for_all_deadline(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> Result<Option<u64>>;
+
+		/// Get the marketplace listing of the token by the seller.
+		fn market_listing(collection: CollectionId, token: TokenId, seller: CrossAccountId) -> Result<Option<MarketListing>>;
+
+		/// Get all marketplace listings of the token.
+		fn market_listings(collection: CollectionId, token: TokenId) -> Result<Vec<(CrossAccountId, MarketListing)>>;
+
+		/// Get the progress of the collection destruction, if it is being destroyed.
+		fn collection_destruction_progress(collection: CollectionId) -> Result<Option<CollectionDestruction>>;
+
+		/// Get the URI of the token, resolved the same way as the ERC-721 `tokenURI` method.
+		fn token_uri(collection: CollectionId, token: TokenId) -> Result<Option<String>>;
+
+		/// Check whether the token properties are permanently frozen.
+		fn token_properties_frozen(collection: CollectionId, token: TokenId) -> Result<bool>;
+
+		/// Check whether the collection metadata is permanently frozen.
+		fn collection_metadata_frozen(collection: CollectionId) -> Result<bool>;
+
+		/// Get the RFT token linked to the fractionalized NFT.
+		fn rft_of_nft(collection: CollectionId, token: TokenId) -> Result<Option<(CollectionId, TokenId)>>;
+
+		/// Get the fractionalized NFT linked to the RFT token.
+		fn nft_of_rft(collection: CollectionId, token: TokenId) -> Result<Option<(CollectionId, TokenId)>>;
+
+		/// Get the buyout of the RFT token.
+		fn rft_buyout(collection: CollectionId, token: TokenId) -> Result<Option<RftBuyout<CrossAccountId>>>;
+
+		/// Get the dividend distribution.
+		fn dividend_distribution(distribution: DistributionId) -> Result<Option<DividendDistribution<CrossAccountId>>>;
+
+		/// Get the dividends, which the account can claim from the distribution.
+		fn claimable_dividends(distribution: DistributionId, account: CrossAccountId) -> Result<u128>;
+
+		/// Get the distributions, from which the account can claim dividends, with the amounts.
+		fn account_claimable_dividends(account: CrossAccountId) -> Result<Vec<(DistributionId, u128)>>;
+
+		/// Get the supply cap of the fungible collection, if it is set.
+		fn supply_cap(collection: CollectionId) -> Result<Option<u128>>;
+
+		/// Get the mint authority of the fungible collection, if it is set.
+		fn mint_authority(collection: CollectionId) -> Result<Option<CrossAccountId>>;
 	}
 }
@@ -94,6 +94,8 @@ impl pallet_evm::Config for Runtime {
 		pallet_evm_contract_helpers::HelpersOnMethodCall<Self>,
 		CollectionDispatchT<Self>,
 		pallet_unique::eth::CollectionHelpersOnMethodCall<Self>,
+		pallet_marketplace::eth::MarketplaceOnMethodCall<Self>,
+		pallet_fractionalizer::eth::FractionalizerOnMethodCall<Self>,
 	);
 	type OnCreate = pallet_evm_contract_helpers::HelpersOnCreate<Self>;
 	type ChainId = ChainId;
@@ -135,6 +137,16 @@ parameter_types! {
 	pub const EvmCollectionHelpersAddress: H160 = H160([
 		0x6c, 0x4e, 0x9f, 0xe1, 0xae, 0x37, 0xa4, 0x1e, 0x93, 0xce, 0xe4, 0x29, 0xe8, 0xe1, 0x88, 0x1a, 0xbd, 0xcb, 0xb5, 0x4f,
 	]);
+
+	// 0x893ab71b0c6fc92bef28fc4ee2bc8018ee56220e
+	pub const MarketplaceContractAddress: H160 = H160([
+		0x89, 0x3a, 0xb7, 0x1b, 0x0c, 0x6f, 0xc9, 0x2b, 0xef, 0x28, 0xfc, 0x4e, 0xe2, 0xbc, 0x80, 0x18, 0xee, 0x56, 0x22, 0x0e,
+	]);
+
+	// 0x8fb8eda0d7f7f3e76085b7ca00b95bc4256c141a
+	pub const FractionalizerContractAddress: H160 = H160([
+		0x8f, 0xb8, 0xed, 0xa0, 0xd7, 0xf7, 0xf3, 0xe7, 0x60, 0x85, 0xb7, 0xca, 0x00, 0xb9, 0x5b, 0xc4, 0x25, 0x6c, 0x14, 0x1a,
+	]);
 }
 
 impl pallet_evm_contract_helpers::Config for Runtime {
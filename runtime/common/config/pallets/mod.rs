@@ -20,6 +20,7 @@ use alloc::string::{String, ToString};
 use frame_support::{
 	parameter_types,
 	traits::{ConstU32, ConstU64, Currency},
+	PalletId,
 };
 use sp_arithmetic::Perbill;
 use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider};
@@ -31,14 +32,23 @@ use up_data_structs::mapping::{CrossTokenAddressMapping, EvmTokenAddressMapping}
 
 #[cfg(feature = "governance")]
 use crate::runtime_common::config::governance;
+#[cfg(not(feature = "buyout-test-timings"))]
+use crate::RftBuyoutNoticePeriod;
 use crate::{
 	runtime_common::{
-		config::{ethereum::EvmCollectionHelpersAddress, substrate::TreasuryModuleId},
+		config::{
+			ethereum::{
+				EvmCollectionHelpersAddress, FractionalizerContractAddress,
+				MarketplaceContractAddress,
+			},
+			substrate::TreasuryModuleId,
+		},
 		dispatch::CollectionDispatchT,
 		weights::CommonWeights,
 		RelayChainBlockNumberProvider,
 	},
-	Balances, Runtime, RuntimeCall, RuntimeEvent, DECIMALS, TOKEN_SYMBOL, VERSION,
+	Balances, Dividends, Marketplace, RftBuyout, Runtime, RuntimeCall, RuntimeEvent, Scheduler,
+	DECIMALS, TOKEN_SYMBOL, VERSION,
 };
 
 #[cfg(feature = "foreign-assets")]
@@ -56,6 +66,7 @@ pub mod preimage;
 parameter_types! {
 	pub const CollectionCreationPrice: Balance = 2 * UNIQUE;
 	pub TreasuryAccountId: AccountId = TreasuryModuleId::get().into_account_truncating();
+	pub const UniqueSwapPalletId: PalletId = PalletId(*b"unq/swap");
 }
 
 impl pallet_common::Config for Runtime {
@@ -69,6 +80,8 @@ impl pallet_common::Config for Runtime {
 	type EvmTokenAddressMapping = EvmTokenAddressMapping;
 	type CrossTokenAddressMapping = CrossTokenAddressMapping<Self::AccountId>;
 	type ContractAddress = EvmCollectionHelpersAddress;
+	type OnTokenTransfer = (Marketplace, RftBuyout, Dividends);
+	type SwapPalletId = UniqueSwapPalletId;
 }
 
 impl pallet_structure::Config for Runtime {
@@ -149,6 +162,67 @@ impl pallet_unique::Config for Runtime {
 	type CommonWeightInfo = CommonWeights<Self>;
 	type StructureWeightInfo = pallet_structure::weights::SubstrateWeight<Self>;
 	type RefungibleExtensionsWeightInfo = CommonWeights<Self>;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub const MarketFee: Perbill = Perbill::from_percent(1);
+	pub const MarketplacePalletId: PalletId = PalletId(*b"unq/mrkt");
+	pub const AuctionAntiSnipingPeriod: u64 = 2 * MINUTES as u64;
+}
+
+impl pallet_marketplace::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_marketplace::weights::SubstrateWeight<Self>;
+	type MarketFee = MarketFee;
+	type ContractAddress = MarketplaceContractAddress;
+	type PalletId = MarketplacePalletId;
+	type AntiSnipingPeriod = AuctionAntiSnipingPeriod;
+	type RuntimeCall = RuntimeCall;
+	type Scheduler = Scheduler;
+}
+
+parameter_types! {
+	pub const FractionalizerPalletId: PalletId = PalletId(*b"unq/frac");
+}
+
+impl pallet_fractionalizer::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_fractionalizer::weights::SubstrateWeight<Self>;
+	type ContractAddress = FractionalizerContractAddress;
+	type PalletId = FractionalizerPalletId;
+}
+
+parameter_types! {
+	pub const RftBuyoutPalletId: PalletId = PalletId(*b"unq/rbuy");
+	pub const RftBuyoutThreshold: Perbill = Perbill::from_percent(50);
+}
+
+#[cfg(feature = "buyout-test-timings")]
+parameter_types! {
+	pub const RftBuyoutNoticePeriod: u64 = 2 * MINUTES as u64;
+}
+
+impl pallet_rft_buyout::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_rft_buyout::weights::SubstrateWeight<Self>;
+	type PalletId = RftBuyoutPalletId;
+	type BuyoutThreshold = RftBuyoutThreshold;
+	type NoticePeriod = RftBuyoutNoticePeriod;
+	type RuntimeCall = RuntimeCall;
+	type Scheduler = Scheduler;
+}
+
+parameter_types! {
+	pub const DividendsPalletId: PalletId = PalletId(*b"unq/divs");
+	pub const DividendsSnapshotFee: Balance = UNIQUE;
+}
+
+impl pallet_dividends::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_dividends::weights::SubstrateWeight<Self>;
+	type PalletId = DividendsPalletId;
+	type SnapshotFee = DividendsSnapshotFee;
 }
 
 parameter_types! {
@@ -97,7 +97,7 @@ macro_rules! construct_runtime {
 
 				// Unique Pallets
 				Inflation: pallet_inflation = 60,
-				Unique: pallet_unique::{Pallet, Call, Storage} = 61,
+				Unique: pallet_unique::{Pallet, Call, Storage, Event<T>} = 61,
 
 				// #[cfg(feature = "scheduler")]
 				// UniqueScheduler: pallet_unique_scheduler_v2 = 62,
@@ -122,6 +122,11 @@ macro_rules! construct_runtime {
 				#[cfg(feature = "app-promotion")]
 				AppPromotion: pallet_app_promotion = 73,
 
+				Marketplace: pallet_marketplace = 74,
+				Fractionalizer: pallet_fractionalizer = 75,
+				Dividends: pallet_dividends = 76,
+				RftBuyout: pallet_rft_buyout = 77,
+
 				#[cfg(feature = "foreign-assets")]
 				ForeignAssets: pallet_foreign_assets = 80,
 
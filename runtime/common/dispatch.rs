@@ -18,8 +18,9 @@ use frame_support::{dispatch::DispatchResult, ensure, fail};
 use pallet_balances_adapter::NativeFungibleHandle;
 pub use pallet_common::dispatch::CollectionDispatch;
 use pallet_common::{
-	erc::CommonEvmHandler, eth::map_eth_to_id, CollectionById, CollectionHandle, CollectionIssuer,
-	CommonCollectionOperations, Pallet as PalletCommon,
+	erc::CommonEvmHandler, eth::map_eth_to_id, helpers::StorageCleaner, CollectionById,
+	CollectionHandle, CollectionIssuer, CommonCollectionOperations, CommonWeightInfo,
+	Pallet as PalletCommon,
 };
 use pallet_evm::{PrecompileHandle, PrecompileResult};
 use pallet_fungible::{FungibleHandle, Pallet as PalletFungible};
@@ -111,6 +112,27 @@ where
 		Ok(())
 	}
 
+	fn clear_destroyed(
+		collection_id: CollectionId,
+		mode: &CollectionMode,
+		cleaner: &mut StorageCleaner<T>,
+	) -> bool {
+		match mode {
+			CollectionMode::ReFungible => {
+				PalletRefungible::<T>::clear_destroyed_collection(collection_id, cleaner)
+			}
+			CollectionMode::Fungible(_) => {
+				PalletFungible::<T>::clear_destroyed_collection(collection_id, cleaner)
+			}
+			CollectionMode::NFT => PalletNonfungible::<T>::clear_destroyed_collection(
+				collection_id,
+				cleaner,
+				// Nested tokens may belong to a collection of any mode
+				<T as pallet_unique::Config>::CommonWeightInfo::transfer(),
+			),
+		}
+	}
+
 	fn dispatch(collection_id: CollectionId) -> Result<Self, DispatchError> {
 		if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
 			return Ok(Self::NativeFungible(
@@ -105,6 +105,7 @@ impl ApplyFeeCoefficient<Runtime> for FeeCoefficientApplier {
 		match runtime_call {
 			pallet_unique::Call::set_token_properties { .. }
 			| pallet_unique::Call::set_token_property_permissions { .. }
+			| pallet_unique::Call::set_token_property_value_types { .. }
 			| pallet_unique::Call::delete_token_properties { .. }
 			| pallet_unique::Call::set_collection_properties { .. }
 			| pallet_unique::Call::delete_collection_properties { .. } => {
@@ -107,18 +107,20 @@ macro_rules! impl_common_runtime_apis {
 					collection: CollectionId,
 					token_id: TokenId,
 					keys: Option<Vec<Vec<u8>>>
-				) -> Result<Vec<Property>, DispatchError> {
+				) -> Result<Vec<RpcTokenProperty>, DispatchError> {
 					let keys = keys.map(
 						|keys| Common::bytes_keys_to_property_keys(keys)
 					).transpose()?;
 
-					dispatch_unique_runtime!(collection.token_properties(token_id, keys))
+					let dispatch = <Runtime as pallet_common::Config>::CollectionDispatch::dispatch(collection)?;
+
+					Ok(Common::rpc_token_properties(dispatch.as_dyn(), token_id, keys))
 				}
 
 				fn property_permissions(
 					collection: CollectionId,
 					keys: Option<Vec<Vec<u8>>>
-				) -> Result<Vec<PropertyKeyPermission>, DispatchError> {
+				) -> Result<Vec<RpcPropertyKeyPermission>, DispatchError> {
 					let keys = keys.map(
 						|keys| Common::bytes_keys_to_property_keys(keys)
 					).transpose()?;
@@ -126,6 +128,10 @@ macro_rules! impl_common_runtime_apis {
 					Common::filter_property_permissions(collection, keys)
 				}
 
+				fn property_write_grants(collection: CollectionId) -> Result<Vec<PropertyWriteGrant<CrossAccountId>>, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::token_property_write_grants(collection))
+				}
+
 				fn token_data(
 					collection: CollectionId,
 					token_id: TokenId,
@@ -135,6 +141,7 @@ macro_rules! impl_common_runtime_apis {
 						properties: Self::token_properties(collection, token_id, keys)?,
 						owner: Self::token_owner(collection, token_id)?,
 						pieces: Self::total_pieces(collection, token_id)?.unwrap_or(0),
+						user: dispatch_unique_runtime!(collection.token_user(token_id))?,
 					};
 
 					Ok(token_data)
@@ -161,6 +168,12 @@ macro_rules! impl_common_runtime_apis {
 				fn adminlist(collection: CollectionId) -> Result<Vec<CrossAccountId>, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::adminlist(collection))
 				}
+				fn adminlist_with_roles(collection: CollectionId) -> Result<Vec<(CrossAccountId, RpcCollectionAdminRoles)>, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::adminlist_with_roles(collection)
+						.into_iter()
+						.map(|(admin, roles)| (admin, roles.into()))
+						.collect())
+				}
 				fn allowlist(collection: CollectionId) -> Result<Vec<CrossAccountId>, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::allowlist(collection))
 				}
@@ -195,6 +208,98 @@ macro_rules! impl_common_runtime_apis {
 				fn allowance_for_all(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> Result<bool, DispatchError> {
 					dispatch_unique_runtime!(collection.allowance_for_all(owner, operator))
 				}
+
+				fn royalty(collection: CollectionId, token: Option<TokenId>) -> Result<Option<Royalty<CrossAccountId>>, DispatchError> {
+					let Some(token) = token else {
+						return Ok(<pallet_common::Pallet<Runtime>>::collection_royalty(collection));
+					};
+					let dispatch = <Runtime as pallet_common::Config>::CollectionDispatch::dispatch(collection)?;
+
+					Ok(<pallet_common::Pallet<Runtime>>::token_royalty(collection, dispatch.as_dyn(), token))
+				}
+
+				fn token_frozen(collection: CollectionId, token: TokenId) -> Result<bool, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::token_frozen(collection, token))
+				}
+
+				fn account_frozen(collection: CollectionId, account: CrossAccountId) -> Result<bool, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::account_frozen(collection, account))
+				}
+
+				fn allowance_deadline(
+					collection: CollectionId,
+					sender: CrossAccountId,
+					spender: CrossAccountId,
+					token: TokenId,
+				) -> Result<Option<u64>, DispatchError> {
+					dispatch_unique_runtime!(collection.allowance_deadline(sender, spender, token))
+				}
+
+				fn allowance_for_all_deadline(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> Result<Option<u64>, DispatchError> {
+					dispatch_unique_runtime!(collection.allowance_for_all_deadline(owner, operator))
+				}
+
+				fn market_listing(collection: CollectionId, token: TokenId, seller: CrossAccountId) -> Result<Option<MarketListing>, DispatchError> {
+					Ok(<pallet_marketplace::Pallet<Runtime>>::listing(collection, token, &seller))
+				}
+
+				fn market_listings(collection: CollectionId, token: TokenId) -> Result<Vec<(CrossAccountId, MarketListing)>, DispatchError> {
+					Ok(<pallet_marketplace::Pallet<Runtime>>::token_listings(collection, token))
+				}
+
+				fn collection_destruction_progress(collection: CollectionId) -> Result<Option<CollectionDestruction>, DispatchError> {
+					Ok(<pallet_common::DestroyingCollections<Runtime>>::get(collection))
+				}
+
+				fn token_uri(collection: CollectionId, token: TokenId) -> Result<Option<String>, DispatchError> {
+					let dispatch = <Runtime as pallet_common::Config>::CollectionDispatch::dispatch(collection)?;
+					let tokens = dispatch.as_dyn();
+					if !tokens.token_exists(token) {
+						return Ok(None);
+					}
+
+					<pallet_common::Pallet<Runtime>>::token_uri(collection, tokens, token).map(Some)
+				}
+
+				fn token_properties_frozen(collection: CollectionId, token: TokenId) -> Result<bool, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::token_properties_frozen(collection, token))
+				}
+
+				fn collection_metadata_frozen(collection: CollectionId) -> Result<bool, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::collection_metadata_frozen(collection))
+				}
+
+				fn rft_of_nft(collection: CollectionId, token: TokenId) -> Result<Option<(CollectionId, TokenId)>, DispatchError> {
+					Ok(<pallet_fractionalizer::Pallet<Runtime>>::rft_of_nft(collection, token))
+				}
+
+				fn nft_of_rft(collection: CollectionId, token: TokenId) -> Result<Option<(CollectionId, TokenId)>, DispatchError> {
+					Ok(<pallet_fractionalizer::Pallet<Runtime>>::nft_of_rft(collection, token))
+				}
+
+				fn rft_buyout(collection: CollectionId, token: TokenId) -> Result<Option<RftBuyout<CrossAccountId>>, DispatchError> {
+					Ok(<pallet_rft_buyout::Pallet<Runtime>>::buyout(collection, token))
+				}
+
+				fn dividend_distribution(distribution: DistributionId) -> Result<Option<DividendDistribution<CrossAccountId>>, DispatchError> {
+					Ok(<pallet_dividends::Pallet<Runtime>>::distribution(distribution))
+				}
+
+				fn claimable_dividends(distribution: DistributionId, account: CrossAccountId) -> Result<u128, DispatchError> {
+					Ok(<pallet_dividends::Pallet<Runtime>>::claimable(&account, distribution))
+				}
+
+				fn account_claimable_dividends(account: CrossAccountId) -> Result<Vec<(DistributionId, u128)>, DispatchError> {
+					Ok(<pallet_dividends::Pallet<Runtime>>::claimable_distributions(&account))
+				}
+
+				fn supply_cap(collection: CollectionId) -> Result<Option<u128>, DispatchError> {
+					Ok(<pallet_common::SupplyCap<Runtime>>::get(collection))
+				}
+
+				fn mint_authority(collection: CollectionId) -> Result<Option<CrossAccountId>, DispatchError> {
+					Ok(<pallet_common::MintAuthority<Runtime>>::get(collection))
+				}
 			}
 
 			impl app_promotion_rpc::AppPromotionApi<Block, BlockNumber, CrossAccountId, AccountId> for Runtime {
@@ -565,6 +670,10 @@ macro_rules! impl_common_runtime_apis {
 					list_benchmark!(list, extra, pallet_foreign_assets, ForeignAssets);
 
 					list_benchmark!(list, extra, pallet_maintenance, Maintenance);
+					list_benchmark!(list, extra, pallet_marketplace, Marketplace);
+					list_benchmark!(list, extra, pallet_fractionalizer, Fractionalizer);
+					list_benchmark!(list, extra, pallet_rft_buyout, RftBuyout);
+					list_benchmark!(list, extra, pallet_dividends, Dividends);
 					list_benchmark!(list, extra, pallet_scheduler, Scheduler);
 
 					// list_benchmark!(list, extra, pallet_evm_coder_substrate, EvmCoderSubstrate);
@@ -632,6 +741,10 @@ macro_rules! impl_common_runtime_apis {
 					add_benchmark!(params, batches, pallet_foreign_assets, ForeignAssets);
 
 					add_benchmark!(params, batches, pallet_maintenance, Maintenance);
+					add_benchmark!(params, batches, pallet_marketplace, Marketplace);
+					add_benchmark!(params, batches, pallet_fractionalizer, Fractionalizer);
+					add_benchmark!(params, batches, pallet_rft_buyout, RftBuyout);
+					add_benchmark!(params, batches, pallet_dividends, Dividends);
 					add_benchmark!(params, batches, pallet_scheduler, Scheduler);
 
 					// add_benchmark!(params, batches, pallet_evm_coder_substrate, EvmCoderSubstrate);
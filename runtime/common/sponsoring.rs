@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
-use frame_support::traits::IsSubType;
+use frame_support::{storage::with_storage_layer, traits::IsSubType};
 use frame_system::pallet_prelude::*;
 use pallet_common::CollectionHandle;
 use pallet_evm::account::CrossAccountId;
@@ -28,10 +29,11 @@ use pallet_unique::{
 	FungibleTransferBasket, NftApproveBasket, NftTransferBasket, ReFungibleTransferBasket,
 	RefungibleApproveBasket, TokenPropertyBasket,
 };
-use sp_runtime::traits::Saturating;
+use sp_runtime::{traits::Saturating, DispatchError};
 use up_data_structs::{
-	CollectionId, CollectionMode, CreateItemData, TokenId, FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
-	NFT_SPONSOR_TRANSFER_TIMEOUT, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
+	CollectionId, CollectionMode, CreateItemData, TokenId, TransferItem,
+	FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT, NFT_SPONSOR_TRANSFER_TIMEOUT,
+	REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
 };
 use up_sponsorship::SponsorshipHandler;
 
@@ -163,6 +165,31 @@ pub fn withdraw_transfer<T: Config>(
 	Some(())
 }
 
+/// Batch transfer is sponsored only if all of its items belong to the same collection,
+/// and a transfer of each token could be sponsored on its own.
+/// Each token is accounted for once, no matter how many items transfer its pieces.
+pub fn withdraw_transfer_multiple<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	items: &[TransferItem<T::CrossAccountId>],
+) -> Option<()> {
+	if items.iter().any(|item| item.collection != collection.id) {
+		return None;
+	}
+	let mut tokens: Vec<TokenId> = items.iter().map(|item| item.token).collect();
+	tokens.sort();
+	tokens.dedup();
+
+	// Don't update sponsoring baskets of the other tokens, if one of them can't be sponsored
+	with_storage_layer(|| {
+		tokens.iter().try_for_each(|token| {
+			withdraw_transfer(collection, who, token)
+				.ok_or(DispatchError::Other("transfer is not sponsored"))
+		})
+	})
+	.ok()
+}
+
 pub fn withdraw_create_item<T: Config>(
 	collection: &CollectionHandle<T>,
 	who: &T::CrossAccountId,
@@ -178,13 +205,13 @@ pub fn withdraw_create_item<T: Config>(
 			CreateItemData::ReFungible(_) => REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
 		});
 
-	if let Some(last_tx_block) = <CreateItemBasket<T>>::get((collection.id, who.as_sub())) {
+	if let Some(last_tx_block) = <CreateItemBasket<T>>::get(collection.id, who.as_sub()) {
 		let timeout = last_tx_block + limit.into();
 		if block_number < timeout {
 			return None;
 		}
 	}
-	CreateItemBasket::<T>::insert((collection.id, who.as_sub()), block_number);
+	CreateItemBasket::<T>::insert(collection.id, who.as_sub(), block_number);
 
 	Some(())
 }
@@ -291,10 +318,28 @@ where
 				let (sponsor, collection) = load(*collection_id)?;
 				withdraw_transfer::<T>(&collection, from, item_id).map(|()| sponsor)
 			}
+			UniqueCall::transfer_multiple { items } => {
+				let (sponsor, collection) = load(items.first()?.collection)?;
+				withdraw_transfer_multiple::<T>(
+					&collection,
+					&T::CrossAccountId::from_sub(who.clone()),
+					items,
+				)
+				.map(|()| sponsor)
+			}
+			UniqueCall::transfer_from_multiple { from, items } => {
+				let (sponsor, collection) = load(items.first()?.collection)?;
+				withdraw_transfer_multiple::<T>(&collection, from, items).map(|()| sponsor)
+			}
 			UniqueCall::approve {
 				collection_id,
 				item_id,
 				..
+			}
+			| UniqueCall::approve_with_deadline {
+				collection_id,
+				item_id,
+				..
 			} => {
 				let (sponsor, collection) = load(*collection_id)?;
 				withdraw_approve::<T>(&collection, who, item_id).map(|()| sponsor)
@@ -130,6 +130,14 @@ where
 	fn force_repair_item() -> Weight {
 		dispatch_weight::<T>() + max_weight_of!(force_repair_item())
 	}
+
+	fn set_token_lock() -> Weight {
+		dispatch_weight::<T>() + max_weight_of!(set_token_lock())
+	}
+
+	fn set_token_user() -> Weight {
+		dispatch_weight::<T>() + max_weight_of!(set_token_user())
+	}
 }
 
 #[cfg(feature = "refungible")]
@@ -33,7 +33,7 @@ use ::staging_xcm::latest::NetworkId;
 use frame_support::parameter_types;
 use sp_runtime::create_runtime_str;
 use sp_version::RuntimeVersion;
-use up_common::types::*;
+use up_common::{constants::DAYS, types::*};
 
 mod runtime_common;
 
@@ -76,6 +76,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 parameter_types! {
 	pub const Version: RuntimeVersion = VERSION;
 	pub const RelayNetwork: NetworkId = NetworkId::Kusama;
+	pub const RftBuyoutNoticePeriod: u64 = 3 * DAYS as u64;
 }
 #[cfg(feature = "become-sapphire")]
 parameter_types! {
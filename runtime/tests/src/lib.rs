@@ -21,6 +21,7 @@ use frame_support::{
 	parameter_types,
 	traits::{fungible::Inspect, ConstU32, ConstU64, Everything},
 	weights::IdentityFee,
+	PalletId,
 };
 use frame_system as system;
 use pallet_ethereum::PostLogContent;
@@ -267,6 +268,10 @@ impl pallet_evm::Config for Test {
 }
 impl pallet_evm_coder_substrate::Config for Test {}
 
+parameter_types! {
+	pub const UniqueSwapPalletId: PalletId = PalletId(*b"unq/swap");
+}
+
 impl pallet_common::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
@@ -278,6 +283,8 @@ impl pallet_common::Config for Test {
 	type EvmTokenAddressMapping = EvmTokenAddressMapping;
 	type CrossTokenAddressMapping = CrossTokenAddressMapping<Self::AccountId>;
 	type ContractAddress = EvmCollectionHelpersAddress;
+	type OnTokenTransfer = ();
+	type SwapPalletId = UniqueSwapPalletId;
 }
 
 impl pallet_structure::Config for Test {
@@ -321,6 +328,7 @@ impl pallet_unique::Config for Test {
 	type CommonWeightInfo = CommonWeights<Self>;
 	type RefungibleExtensionsWeightInfo = CommonWeights<Self>;
 	type StructureWeightInfo = pallet_structure::weights::SubstrateWeight<Self>;
+	type RuntimeEvent = RuntimeEvent;
 }
 
 // Build genesis storage according to the mock runtime.
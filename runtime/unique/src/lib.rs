@@ -33,7 +33,7 @@ use ::staging_xcm::latest::NetworkId;
 use frame_support::parameter_types;
 use sp_runtime::create_runtime_str;
 use sp_version::RuntimeVersion;
-use up_common::types::*;
+use up_common::{constants::DAYS, types::*};
 
 mod runtime_common;
 
@@ -63,6 +63,7 @@ parameter_types! {
 	pub const SS58Prefix: u16 = 7391;
 	pub const ChainId: u64 = 8880;
 	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
+	pub const RftBuyoutNoticePeriod: u64 = 7 * DAYS as u64;
 }
 
 construct_runtime!();